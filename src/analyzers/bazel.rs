@@ -0,0 +1,509 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::content::{ContentFile, ContentMatcher};
+use crate::core::project::Project;
+use crate::utils::fs::{is_ignored_name, read_to_string};
+
+/// Files that mark a Bazel workspace root.
+const BAZEL_MARKERS: &[&str] = &["MODULE.bazel", "WORKSPACE", "WORKSPACE.bazel"];
+
+/// Files that mark a Buck project root.
+const BUCK_MARKERS: &[&str] = &[".buckconfig", "BUCK"];
+
+/// External dependency rules, with the attributes that pin them.
+const PINNED_BY: &[(&str, &[&str])] = &[
+    ("http_archive", &["sha256", "integrity"]),
+    ("http_file", &["sha256", "integrity"]),
+    ("http_jar", &["sha256", "integrity"]),
+    ("archive_override", &["integrity"]),
+    ("git_repository", &["commit"]),
+    ("new_git_repository", &["commit"]),
+    ("git_override", &["commit"]),
+];
+
+pub struct BazelAnalyzer;
+
+fn is_bazel(path: &Path) -> bool {
+    BAZEL_MARKERS.iter().any(|m| path.join(m).is_file())
+}
+
+fn is_buck(path: &Path) -> bool {
+    BUCK_MARKERS.iter().any(|m| path.join(m).is_file())
+}
+
+#[async_trait]
+impl Analyzer for BazelAnalyzer {
+    fn name(&self) -> &'static str {
+        "bazel"
+    }
+
+    fn description(&self) -> &'static str {
+        "Bazel/Buck build configuration: pinned dependencies, versions, caching, and BUILD files"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Configuration
+    }
+
+    fn applies_to(&self, project: &Project) -> bool {
+        is_bazel(&project.path) || is_buck(&project.path)
+    }
+
+    async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+        let path = &project.path;
+
+        // Dependency checks
+        for marker in BAZEL_MARKERS {
+            check_unpinned_deps(&path.join(marker), &mut issues);
+        }
+
+        // Configuration checks
+        if is_bazel(path) {
+            check_missing_bazelversion(path, &mut issues);
+        }
+        check_missing_build_cache(path, &mut issues);
+
+        Ok(issues)
+    }
+
+    fn content_matchers(&self, _project: &Project) -> Vec<Box<dyn ContentMatcher>> {
+        // Structure checks
+        vec![Box::new(MissingSourcesMatcher)]
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Dependency checks
+// ---------------------------------------------------------------------------
+
+static RULE_CALL_RE: Lazy<Regex> = Lazy::new(|| {
+    let rules: Vec<&str> = PINNED_BY.iter().map(|(rule, _)| *rule).collect();
+    Regex::new(&format!(r"(?m)^\s*({})\s*\(", rules.join("|"))).unwrap()
+});
+
+static NAME_ATTR_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\b(?:module_)?name\s*=\s*"([^"]+)""#).unwrap());
+
+/// Body of the call whose opening parenthesis ends at `start`, up to the
+/// matching closing parenthesis.
+fn call_body(content: &str, start: usize) -> &str {
+    let mut depth = 1;
+    let mut in_string = false;
+    for (i, c) in content[start..].char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '(' if !in_string => depth += 1,
+            ')' if !in_string => {
+                depth -= 1;
+                if depth == 0 {
+                    return &content[start..start + i];
+                }
+            }
+            _ => {}
+        }
+    }
+    &content[start..]
+}
+
+/// Assignment of any attribute listed in `PINNED_BY`.
+static PIN_ATTR_RE: Lazy<Regex> = Lazy::new(|| {
+    let mut attrs: Vec<&str> = PINNED_BY
+        .iter()
+        .flat_map(|(_, pins)| pins.iter().copied())
+        .collect();
+    attrs.sort_unstable();
+    attrs.dedup();
+    Regex::new(&format!(r"\b({})\s*=", attrs.join("|"))).unwrap()
+});
+
+fn has_attr(body: &str, attr: &str) -> bool {
+    PIN_ATTR_RE.captures_iter(body).any(|caps| &caps[1] == attr)
+}
+
+fn line_of(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count() + 1
+}
+
+fn check_unpinned_deps(file: &Path, issues: &mut Vec<Issue>) {
+    let Ok(content) = read_to_string(file) else {
+        return;
+    };
+
+    for caps in RULE_CALL_RE.captures_iter(&content) {
+        let rule = &caps[1];
+        let call = caps.get(0).unwrap();
+        let body = call_body(&content, call.end());
+        let pins = PINNED_BY
+            .iter()
+            .find(|(r, _)| *r == rule)
+            .map_or(&[][..], |(_, pins)| *pins);
+        if pins.iter().any(|attr| has_attr(body, attr)) {
+            continue;
+        }
+
+        let name = NAME_ATTR_RE
+            .captures(body)
+            .map_or_else(|| rule.to_string(), |c| c[1].to_string());
+        let line_num = line_of(
+            &content,
+            call.start() + call.as_str().find(rule).unwrap_or(0),
+        );
+        let line = content.lines().nth(line_num - 1).unwrap_or_default();
+        issues.push(
+            Issue {
+                id: "BZL-001".to_string(),
+                analyzer: "bazel".to_string(),
+                category: AnalyzerCategory::Dependencies,
+                severity: Severity::High,
+                title: format!("Unpinned external dependency: {}", name),
+                description: format!(
+                    "{} '{}' has no {} attribute, so builds may fetch different content over time.",
+                    rule,
+                    name,
+                    pins.join(" or ")
+                ),
+                file: Some(file.to_path_buf()),
+                line: Some(line_num),
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some(format!("Pin '{}' with {}", name, pins.join(" or "))),
+                auto_fixable: false,
+                references: vec!["https://bazel.build/external/overview".to_string()],
+            }
+            .with_snippet(line, line.find(rule).unwrap_or(0)),
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Configuration checks
+// ---------------------------------------------------------------------------
+
+fn check_missing_bazelversion(path: &Path, issues: &mut Vec<Issue>) {
+    if !path.join(".bazelversion").is_file() {
+        issues.push(Issue {
+            id: "BZL-002".to_string(),
+            analyzer: "bazel".to_string(),
+            category: AnalyzerCategory::Configuration,
+            severity: Severity::Medium,
+            title: "Missing .bazelversion".to_string(),
+            description: "No .bazelversion found. Without it, Bazelisk uses the latest Bazel release and builds can break unexpectedly.".to_string(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Create .bazelversion containing the Bazel version the project is tested with".to_string()),
+            auto_fixable: false,
+            references: vec!["https://github.com/bazelbuild/bazelisk".to_string()],
+        });
+    }
+}
+
+fn check_missing_build_cache(path: &Path, issues: &mut Vec<Issue>) {
+    let (config, has_cache) = if is_bazel(path) {
+        let content = read_to_string(path.join(".bazelrc")).unwrap_or_default();
+        let has_cache = content
+            .lines()
+            .filter(|l| !l.trim_start().starts_with('#'))
+            .any(|l| l.contains("--disk_cache") || l.contains("--remote_cache"));
+        (".bazelrc", has_cache)
+    } else {
+        let content = read_to_string(path.join(".buckconfig")).unwrap_or_default();
+        let has_cache = content.lines().any(|l| l.trim() == "[cache]");
+        (".buckconfig", has_cache)
+    };
+
+    if !has_cache {
+        issues.push(Issue {
+            id: "BZL-003".to_string(),
+            analyzer: "bazel".to_string(),
+            category: AnalyzerCategory::Performance,
+            severity: Severity::Low,
+            title: "No build cache configured".to_string(),
+            description: format!(
+                "{} does not configure a disk or remote cache, so every CI run rebuilds from scratch.",
+                config
+            ),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some(if config == ".bazelrc" {
+                "Add `build --disk_cache=~/.cache/bazel` or `build --remote_cache=<url>` to .bazelrc".to_string()
+            } else {
+                "Add a [cache] section to .buckconfig".to_string()
+            }),
+            auto_fixable: false,
+            references: vec![],
+        });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Structure checks
+// ---------------------------------------------------------------------------
+
+static SOURCE_LIST_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b(srcs|hdrs|data)\s*=\s*\[([^\]]*)\]").unwrap());
+
+static STRING_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#""([^"]*)""#).unwrap());
+
+/// Whether a `srcs` entry names a file in the package rather than a label or pattern.
+fn is_plain_file(entry: &str) -> bool {
+    !entry.is_empty()
+        && !entry.starts_with("//")
+        && !entry.starts_with('@')
+        && !entry.contains(':')
+        && !entry.contains('*')
+        && !entry.contains('$')
+}
+
+/// Reports `srcs`/`hdrs`/`data` entries in BUILD and BUCK files that name
+/// files which do not exist in the package.
+struct MissingSourcesMatcher;
+
+impl ContentMatcher for MissingSourcesMatcher {
+    fn skips_dir(&self, name: &str) -> bool {
+        is_ignored_name(name) || name.starts_with("bazel-") || name == "buck-out"
+    }
+
+    fn wants(&self, relative: &str) -> bool {
+        let name = relative.rsplit('/').next().unwrap_or(relative);
+        matches!(name, "BUILD" | "BUILD.bazel" | "BUCK")
+    }
+
+    fn begin_file(&mut self, file: &ContentFile, issues: &mut Vec<Issue>) -> bool {
+        let package = file
+            .path
+            .parent()
+            .map_or_else(PathBuf::new, Path::to_path_buf);
+        // Plain strings may also name a target in the same package
+        let targets: HashSet<&str> = NAME_ATTR_RE
+            .captures_iter(file.content)
+            .map(|c| c.get(1).unwrap().as_str())
+            .collect();
+
+        for list in SOURCE_LIST_RE.captures_iter(file.content) {
+            let attr = &list[1];
+            let entries = list.get(2).unwrap();
+            for entry in STRING_RE.captures_iter(entries.as_str()) {
+                let value = entry.get(1).unwrap();
+                let source = value.as_str();
+                if !is_plain_file(source)
+                    || targets.contains(source)
+                    || package.join(source).exists()
+                {
+                    continue;
+                }
+
+                let offset = entries.start() + value.start();
+                let line_num = line_of(file.content, offset);
+                let line_start = file.content[..offset].rfind('\n').map_or(0, |i| i + 1);
+                let line = file.content[line_start..]
+                    .lines()
+                    .next()
+                    .unwrap_or_default();
+                issues.push(
+                    Issue {
+                        id: "BZL-004".to_string(),
+                        analyzer: "bazel".to_string(),
+                        category: AnalyzerCategory::Structure,
+                        severity: Severity::Medium,
+                        title: format!("{} references missing source: {}", file.relative, source),
+                        description: format!(
+                            "'{}' is listed in {} but does not exist next to {}.",
+                            source, attr, file.relative
+                        ),
+                        file: Some(file.path.to_path_buf()),
+                        line: Some(line_num),
+                        column: None,
+                        snippet: None,
+                        fingerprint: None,
+                        estimated_effort: None,
+                        impact: None,
+                        suggestion: Some(format!(
+                            "Restore '{}' or remove it from {}",
+                            source, attr
+                        )),
+                        auto_fixable: false,
+                        references: vec![],
+                    }
+                    .with_snippet(line, offset - line_start),
+                );
+            }
+        }
+        false
+    }
+
+    fn check_line(
+        &mut self,
+        _file: &ContentFile,
+        _line_num: usize,
+        _line: &str,
+        _issues: &mut Vec<Issue>,
+    ) -> bool {
+        false
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::content::analyze_with_content;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs as stdfs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::Unknown,
+                language: Language::Unknown,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_applies_to_bazel_and_buck() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+        assert!(!BazelAnalyzer.applies_to(&project));
+
+        stdfs::write(tmp.path().join("MODULE.bazel"), "").unwrap();
+        assert!(BazelAnalyzer.applies_to(&project));
+
+        let buck = TempDir::new().unwrap();
+        stdfs::write(buck.path().join(".buckconfig"), "").unwrap();
+        assert!(BazelAnalyzer.applies_to(&make_project(&buck)));
+    }
+
+    #[tokio::test]
+    async fn test_unpinned_deps() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(
+            tmp.path().join("WORKSPACE"),
+            r#"load("@bazel_tools//tools/build_defs/repo:http.bzl", "http_archive")
+
+http_archive(
+    name = "pinned",
+    urls = ["https://example.com/a.tar.gz"],
+    sha256 = "abc123",
+)
+
+http_archive(
+    name = "rules_floating",
+    urls = ["https://example.com/b.tar.gz"],
+)
+
+git_repository(
+    name = "on_branch",
+    remote = "https://github.com/acme/lib.git",
+    branch = "main",
+)
+"#,
+        )
+        .unwrap();
+        let issues = BazelAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        let unpinned: Vec<_> = issues.iter().filter(|i| i.id == "BZL-001").collect();
+        assert_eq!(unpinned.len(), 2);
+        assert_eq!(
+            unpinned[0].title,
+            "Unpinned external dependency: rules_floating"
+        );
+        assert_eq!(unpinned[0].line, Some(9));
+        assert_eq!(unpinned[1].title, "Unpinned external dependency: on_branch");
+        assert!(unpinned[1].description.contains("commit"));
+    }
+
+    #[tokio::test]
+    async fn test_bazelversion_and_cache() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(
+            tmp.path().join("MODULE.bazel"),
+            "bazel_dep(name = \"x\", version = \"1\")\n",
+        )
+        .unwrap();
+        let issues = BazelAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        assert!(issues.iter().any(|i| i.id == "BZL-002"));
+        assert!(issues.iter().any(|i| i.id == "BZL-003"));
+
+        stdfs::write(tmp.path().join(".bazelversion"), "7.1.0\n").unwrap();
+        stdfs::write(
+            tmp.path().join(".bazelrc"),
+            "build --disk_cache=~/.cache/bazel\n",
+        )
+        .unwrap();
+        let issues = BazelAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        assert!(!issues
+            .iter()
+            .any(|i| i.id == "BZL-002" || i.id == "BZL-003"));
+    }
+
+    #[tokio::test]
+    async fn test_buck_cache_config() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(tmp.path().join(".buckconfig"), "[cache]\n  mode = dir\n").unwrap();
+        let issues = BazelAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        // .bazelversion only applies to Bazel
+        assert!(issues.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_build_file_missing_sources() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(tmp.path().join("MODULE.bazel"), "").unwrap();
+        stdfs::create_dir_all(tmp.path().join("lib")).unwrap();
+        stdfs::write(tmp.path().join("lib/a.cc"), "").unwrap();
+        stdfs::write(
+            tmp.path().join("lib/BUILD.bazel"),
+            r#"cc_library(
+    name = "lib",
+    srcs = [
+        "a.cc",
+        "gone.cc",
+        ":generated",
+        "//other:dep",
+    ] + glob(["*.inc"]),
+    hdrs = ["lib"],
+)
+"#,
+        )
+        .unwrap();
+        let issues = analyze_with_content(&BazelAnalyzer, &make_project(&tmp))
+            .await
+            .unwrap();
+        let missing: Vec<_> = issues.iter().filter(|i| i.id == "BZL-004").collect();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(
+            missing[0].title,
+            "lib/BUILD.bazel references missing source: gone.cc"
+        );
+        assert_eq!(missing[0].line, Some(5));
+        assert_eq!(missing[0].column, Some(10));
+    }
+}
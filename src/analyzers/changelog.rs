@@ -0,0 +1,478 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::project::Project;
+use crate::frameworks::detector::FrameworkDetector;
+use crate::utils::fs::{find_file_ci, read_to_string};
+use crate::utils::git;
+
+/// Releases an Unreleased section may lag behind before it is considered stale.
+const STALE_RELEASES: usize = 2;
+
+/// Keep a Changelog change types.
+const KEEP_A_CHANGELOG_SECTIONS: &[&str] = &[
+    "added",
+    "changed",
+    "deprecated",
+    "removed",
+    "fixed",
+    "security",
+];
+
+/// Section titles written by conventional-changelog and release-please.
+const CONVENTIONAL_SECTIONS: &[&str] = &[
+    "features",
+    "bug fixes",
+    "performance improvements",
+    "breaking changes",
+    "reverts",
+    "documentation",
+    "code refactoring",
+    "tests",
+    "build system",
+    "continuous integration",
+    "styles",
+    "chores",
+    "miscellaneous",
+    "miscellaneous chores",
+    "dependencies",
+];
+
+pub struct ChangelogAnalyzer;
+
+#[async_trait]
+impl Analyzer for ChangelogAnalyzer {
+    fn name(&self) -> &'static str {
+        "changelog"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks CHANGELOG.md structure, version alignment, and stale Unreleased entries"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Documentation
+    }
+
+    fn applies_to(&self, project: &Project) -> bool {
+        find_file_ci(&project.path, &["CHANGELOG.md"]).is_some()
+    }
+
+    async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+        let path = &project.path;
+        let Some(file) = find_file_ci(path, &["CHANGELOG.md"]) else {
+            return Ok(issues);
+        };
+        let Ok(content) = read_to_string(&file) else {
+            return Ok(issues);
+        };
+        let changelog = Changelog::parse(&content);
+        let file: PathBuf = file.file_name().unwrap().into();
+
+        // CHG-001 / CHG-002: structure
+        if changelog.versions.is_empty() && changelog.unreleased.is_none() {
+            issues.push(Issue {
+                id: "CHG-001".to_string(),
+                analyzer: "changelog".to_string(),
+                category: AnalyzerCategory::Documentation,
+                severity: Severity::Low,
+                title: "CHANGELOG.md has no version entries".to_string(),
+                description: "No `## [x.y.z]` or `## [Unreleased]` headings found, so readers cannot tell which change shipped in which release.".to_string(),
+                file: Some(file.clone()),
+                line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some("Use one `## [x.y.z] - YYYY-MM-DD` heading per release".to_string()),
+                auto_fixable: false,
+                references: vec!["https://keepachangelog.com".to_string()],
+            });
+            return Ok(issues);
+        }
+        check_sections(&changelog, &file, &mut issues);
+
+        // CHG-010: latest entry vs manifest version
+        if let Some(version) = FrameworkDetector::manifest_version(path) {
+            check_manifest_version(&changelog, &version, &file, &mut issues);
+        }
+
+        // CHG-011: Unreleased changes left behind by tagged releases
+        if let Some(tags) = git::tags(path) {
+            check_stale_unreleased(&changelog, &tags, &file, &mut issues);
+        }
+
+        Ok(issues)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Parsing
+// ---------------------------------------------------------------------------
+
+/// `## [1.2.0] - 2024-01-01`, `## v1.2.0`, `# [1.2.0](https://...) (2024-01-01)`
+static VERSION_HEADING_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^#{1,3}\s+\[?v?(\d+\.\d+(?:\.\d+)?(?:-[\w.]+)?)\]?").unwrap());
+
+static UNRELEASED_HEADING_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^#{1,3}\s+\[?unreleased\]?").unwrap());
+
+static SECTION_HEADING_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^###\s+(.+)$").unwrap());
+
+struct Changelog {
+    /// Released versions in file order, with their 1-based heading line
+    versions: Vec<(String, usize)>,
+    /// Heading line of the Unreleased section and whether it lists changes
+    unreleased: Option<(usize, bool)>,
+    /// `### ...` change type headings with their line
+    sections: Vec<(String, usize)>,
+}
+
+impl Changelog {
+    fn parse(content: &str) -> Self {
+        let mut changelog = Changelog {
+            versions: Vec::new(),
+            unreleased: None,
+            sections: Vec::new(),
+        };
+        let mut in_unreleased = false;
+        for (i, line) in content.lines().enumerate() {
+            if let Some(caps) = SECTION_HEADING_RE.captures(line) {
+                changelog.sections.push((caps[1].trim().to_string(), i + 1));
+                continue;
+            }
+            if UNRELEASED_HEADING_RE.is_match(line) {
+                changelog.unreleased = Some((i + 1, false));
+                in_unreleased = true;
+            } else if let Some(caps) = VERSION_HEADING_RE.captures(line) {
+                changelog.versions.push((caps[1].to_string(), i + 1));
+                in_unreleased = false;
+            } else if in_unreleased && line.trim_start().starts_with(['-', '*']) {
+                if let Some((_, has_entries)) = &mut changelog.unreleased {
+                    *has_entries = true;
+                }
+            }
+        }
+        changelog
+    }
+
+    fn latest(&self) -> Option<&str> {
+        self.versions.first().map(|(v, _)| v.as_str())
+    }
+}
+
+/// Numeric `major.minor.patch` components, ignoring pre-release suffixes.
+fn version_key(version: &str) -> Option<Vec<u64>> {
+    let version = version.trim().trim_start_matches('v');
+    let core = version.split(['-', '+']).next()?;
+    let parts: Option<Vec<u64>> = core.split('.').map(|p| p.parse().ok()).collect();
+    parts.filter(|p| p.len() >= 2)
+}
+
+// ---------------------------------------------------------------------------
+// Checks
+// ---------------------------------------------------------------------------
+
+fn normalize_section(title: &str) -> String {
+    title
+        .trim_start_matches(|c: char| !c.is_alphanumeric())
+        .trim_end_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+fn check_sections(changelog: &Changelog, file: &Path, issues: &mut Vec<Issue>) {
+    let unknown: Vec<&(String, usize)> = changelog
+        .sections
+        .iter()
+        .filter(|(title, _)| {
+            let title = normalize_section(title);
+            !KEEP_A_CHANGELOG_SECTIONS.contains(&title.as_str())
+                && !CONVENTIONAL_SECTIONS.contains(&title.as_str())
+        })
+        .collect();
+    let Some((_, first_line)) = unknown.first() else {
+        return;
+    };
+
+    let mut names: Vec<&str> = Vec::new();
+    for (title, _) in &unknown {
+        if !names.contains(&title.as_str()) {
+            names.push(title);
+        }
+    }
+    issues.push(Issue {
+        id: "CHG-002".to_string(),
+        analyzer: "changelog".to_string(),
+        category: AnalyzerCategory::Documentation,
+        severity: Severity::Low,
+        title: "Non-standard changelog sections".to_string(),
+        description: format!(
+            "Section(s) {} are neither Keep a Changelog types (Added, Changed, Deprecated, Removed, Fixed, Security) nor conventional-changelog sections.",
+            names.join(", ")
+        ),
+        file: Some(file.to_path_buf()),
+        line: Some(*first_line),
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some("Group changes under the standard change types".to_string()),
+        auto_fixable: false,
+        references: vec!["https://keepachangelog.com/en/1.1.0/#how".to_string()],
+    });
+}
+
+fn check_manifest_version(
+    changelog: &Changelog,
+    manifest_version: &str,
+    file: &Path,
+    issues: &mut Vec<Issue>,
+) {
+    let Some(latest) = changelog.latest() else {
+        return;
+    };
+    let Some(manifest) = version_key(manifest_version) else {
+        return;
+    };
+    if version_key(latest).is_some_and(|latest| latest >= manifest) {
+        return;
+    }
+
+    issues.push(Issue {
+        id: "CHG-010".to_string(),
+        analyzer: "changelog".to_string(),
+        category: AnalyzerCategory::Documentation,
+        severity: Severity::Medium,
+        title: format!("CHANGELOG.md has no entry for version {}", manifest_version),
+        description: format!(
+            "The manifest declares version {} but the latest changelog entry is {}.",
+            manifest_version, latest
+        ),
+        file: Some(file.to_path_buf()),
+        line: changelog.versions.first().map(|(_, line)| *line),
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some(format!(
+            "Add a `## [{}]` entry (or move the Unreleased changes under it)",
+            manifest_version
+        )),
+        auto_fixable: false,
+        references: vec![],
+    });
+}
+
+fn check_stale_unreleased(
+    changelog: &Changelog,
+    tags: &[String],
+    file: &Path,
+    issues: &mut Vec<Issue>,
+) {
+    let Some((line, true)) = changelog.unreleased else {
+        return;
+    };
+    let latest = changelog.latest().and_then(version_key).unwrap_or_default();
+    let newer = tags
+        .iter()
+        .filter_map(|tag| version_key(tag))
+        .filter(|tag| *tag > latest)
+        .count();
+    if newer < STALE_RELEASES {
+        return;
+    }
+
+    issues.push(Issue {
+        id: "CHG-011".to_string(),
+        analyzer: "changelog".to_string(),
+        category: AnalyzerCategory::Documentation,
+        severity: Severity::Low,
+        title: format!("Unreleased changelog section is {} releases old", newer),
+        description: format!(
+            "{} tagged releases are newer than the latest changelog entry ({}), but their changes are still listed under Unreleased.",
+            newer,
+            changelog.latest().unwrap_or("none")
+        ),
+        file: Some(file.to_path_buf()),
+        line: Some(line),
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some("Move Unreleased entries under a heading for each release when tagging".to_string()),
+        auto_fixable: false,
+        references: vec![],
+    });
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::NodeJs,
+                language: Language::JavaScript,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    const KEEP_A_CHANGELOG: &str = "# Changelog\n\n## [Unreleased]\n\n### Added\n- New flag\n\n## [1.2.0] - 2024-03-01\n\n### Fixed\n- Crash\n\n## [1.1.0] - 2024-01-01\n\n### Changed\n- Faster\n";
+
+    #[test]
+    fn test_parse() {
+        let changelog = Changelog::parse(KEEP_A_CHANGELOG);
+        assert_eq!(changelog.latest(), Some("1.2.0"));
+        assert_eq!(changelog.versions.len(), 2);
+        assert_eq!(changelog.unreleased, Some((3, true)));
+
+        let conventional = Changelog::parse(
+            "# [2.0.0](https://github.com/a/b/compare/v1.0.0...v2.0.0) (2024-05-01)\n\n### ⚠ BREAKING CHANGES\n\n### Bug Fixes\n",
+        );
+        assert_eq!(conventional.latest(), Some("2.0.0"));
+        assert_eq!(conventional.unreleased, None);
+    }
+
+    #[test]
+    fn test_version_key() {
+        assert_eq!(version_key("v1.2.3"), Some(vec![1, 2, 3]));
+        assert_eq!(version_key("2.0.0-rc.1"), Some(vec![2, 0, 0]));
+        assert!(version_key("1.10.0") > version_key("1.9.9"));
+        assert_eq!(version_key("release"), None);
+    }
+
+    #[tokio::test]
+    async fn test_applies_only_with_changelog() {
+        let tmp = TempDir::new().unwrap();
+        assert!(!ChangelogAnalyzer.applies_to(&make_project(&tmp)));
+        fs::write(tmp.path().join("CHANGELOG.md"), KEEP_A_CHANGELOG).unwrap();
+        assert!(ChangelogAnalyzer.applies_to(&make_project(&tmp)));
+        let issues = ChangelogAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap();
+        assert!(issues.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unstructured_changelog() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("CHANGELOG.md"),
+            "# Changes\n\nLots of things changed.\n",
+        )
+        .unwrap();
+        let issues = ChangelogAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "CHG-001");
+    }
+
+    #[tokio::test]
+    async fn test_nonstandard_sections() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("CHANGELOG.md"),
+            "## [1.0.0]\n\n### 🚀 Features\n- x\n\n### Misc stuff\n- y\n\n### Misc stuff\n- z\n",
+        )
+        .unwrap();
+        let issues = ChangelogAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "CHG-002");
+        assert_eq!(issues[0].line, Some(6));
+        assert!(issues[0]
+            .description
+            .starts_with("Section(s) Misc stuff are"));
+    }
+
+    #[tokio::test]
+    async fn test_manifest_version_mismatch() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("CHANGELOG.md"), KEEP_A_CHANGELOG).unwrap();
+        fs::write(
+            tmp.path().join("package.json"),
+            r#"{"name": "x", "version": "1.3.0"}"#,
+        )
+        .unwrap();
+        let issues = ChangelogAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].title,
+            "CHANGELOG.md has no entry for version 1.3.0"
+        );
+        assert_eq!(issues[0].line, Some(8));
+
+        // An older manifest (changelog written ahead of the bump) is fine
+        fs::write(tmp.path().join("package.json"), r#"{"version": "1.2.0"}"#).unwrap();
+        assert!(ChangelogAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_stale_unreleased() {
+        let changelog = Changelog::parse(KEEP_A_CHANGELOG);
+        let file = Path::new("CHANGELOG.md");
+        let tags = |names: &[&str]| names.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let mut issues = Vec::new();
+        check_stale_unreleased(
+            &changelog,
+            &tags(&["v1.1.0", "v1.2.0", "v1.3.0"]),
+            file,
+            &mut issues,
+        );
+        assert!(issues.is_empty());
+
+        check_stale_unreleased(
+            &changelog,
+            &tags(&["v1.2.0", "v1.3.0", "v1.4.0", "nightly"]),
+            file,
+            &mut issues,
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].title,
+            "Unreleased changelog section is 2 releases old"
+        );
+        assert_eq!(issues[0].line, Some(3));
+
+        // An empty Unreleased section is not stale
+        let empty = Changelog::parse("## [Unreleased]\n\n## [1.0.0]\n- x\n");
+        let mut issues = Vec::new();
+        check_stale_unreleased(&empty, &tags(&["v2.0.0", "v3.0.0"]), file, &mut issues);
+        assert!(issues.is_empty());
+    }
+}
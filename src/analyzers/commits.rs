@@ -0,0 +1,500 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::config::{CommitConventions, Config};
+use crate::core::project::Project;
+use crate::utils::git::{self, CommitMessage};
+use crate::utils::patterns;
+
+const DEFAULT_COUNT: usize = 50;
+const DEFAULT_MAX_UNREFERENCED_PERCENT: u8 = 50;
+
+/// Subjects quoted in issue descriptions.
+const MAX_EXAMPLES: usize = 3;
+
+/// Types accepted by `@commitlint/config-conventional`.
+static CONVENTIONAL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(build|chore|ci|docs|feat|fix|perf|refactor|revert|style|test)(\([^)]+\))?!?: \S")
+        .unwrap()
+});
+
+/// `#123`, `GH-123`, `ABC-123`
+static ISSUE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"#\d+\b|\b[A-Z][A-Z0-9]+-\d+\b").unwrap());
+
+/// Files that show the team already relies on commit messages for releases.
+const RELEASE_AUTOMATION_FILES: &[&str] = &[
+    "commitlint.config.js",
+    "commitlint.config.cjs",
+    "commitlint.config.mjs",
+    "commitlint.config.ts",
+    ".commitlintrc",
+    ".commitlintrc.json",
+    ".commitlintrc.yml",
+    ".commitlintrc.yaml",
+    ".commitlintrc.js",
+    ".releaserc",
+    ".releaserc.json",
+    ".releaserc.yml",
+    ".releaserc.yaml",
+    ".releaserc.js",
+    "release.config.js",
+    "release-please-config.json",
+    ".czrc",
+    ".versionrc",
+    ".versionrc.json",
+];
+
+static WIP_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)^(?:fixup!|squash!|amend!|\[?wip\b\]?)").unwrap());
+
+pub struct CommitsAnalyzer;
+
+fn uses_release_automation(path: &Path) -> bool {
+    RELEASE_AUTOMATION_FILES
+        .iter()
+        .any(|f| path.join(f).is_file())
+}
+
+#[async_trait]
+impl Analyzer for CommitsAnalyzer {
+    fn name(&self) -> &'static str {
+        "commits"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks recent commit messages against Conventional Commits or a configured pattern"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Configuration
+    }
+
+    /// Opt-in: a `commits:` config section or commitlint/semantic-release/
+    /// release-please configuration.
+    fn applies_to(&self, project: &Project) -> bool {
+        project.detected.has_git
+            && (Config::load(&project.path).commits.is_some()
+                || uses_release_automation(&project.path))
+    }
+
+    async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+        let conventions = Config::load(&project.path).commits.unwrap_or_default();
+        let default_branch = git::default_branch(&project.path);
+        let rev = default_branch.as_deref().unwrap_or("HEAD");
+        let count = conventions.count.unwrap_or(DEFAULT_COUNT);
+        let Some(commits) = git::recent_commits(&project.path, rev, count) else {
+            return Ok(issues);
+        };
+        if commits.is_empty() {
+            return Ok(issues);
+        }
+
+        check_subjects(&commits, &conventions, rev, &mut issues);
+        if default_branch.is_some() {
+            check_wip_commits(&commits, rev, &mut issues);
+        }
+        check_issue_references(&commits, &conventions, rev, &mut issues);
+
+        Ok(issues)
+    }
+}
+
+fn short(commit: &CommitMessage) -> String {
+    format!(
+        "{} {}",
+        &commit.hash[..commit.hash.len().min(7)],
+        commit.subject
+    )
+}
+
+fn examples(commits: &[&CommitMessage]) -> String {
+    let mut listed: Vec<String> = commits
+        .iter()
+        .take(MAX_EXAMPLES)
+        .map(|c| short(c))
+        .collect();
+    if commits.len() > MAX_EXAMPLES {
+        listed.push(format!("and {} more", commits.len() - MAX_EXAMPLES));
+    }
+    listed.join("; ")
+}
+
+/// The configured regex for `key`, or `default` when unset. An invalid
+/// pattern is reported as a configuration issue and skips the check.
+fn resolve_pattern(
+    configured: Option<&str>,
+    default: &Regex,
+    key: &str,
+    issues: &mut Vec<Issue>,
+) -> Option<Regex> {
+    let Some(pattern) = configured else {
+        return Some(default.clone());
+    };
+    match patterns::compile(pattern) {
+        Ok(regex) => Some(regex),
+        Err(e) => {
+            issues.push(Issue {
+                id: "COM-004".to_string(),
+                analyzer: "commits".to_string(),
+                category: AnalyzerCategory::Configuration,
+                severity: Severity::Medium,
+                title: format!("Invalid commits.{} in .repodoctor.yml", key),
+                description: format!(
+                    "commits.{} could not be compiled, so this check was skipped: {}",
+                    key, e
+                ),
+                file: Some(PathBuf::from(".repodoctor.yml")),
+                line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some(format!("Fix the `commits.{}` regex", key)),
+                auto_fixable: false,
+                references: vec![],
+            });
+            None
+        }
+    }
+}
+
+fn check_subjects(
+    commits: &[CommitMessage],
+    conventions: &CommitConventions,
+    rev: &str,
+    issues: &mut Vec<Issue>,
+) {
+    let custom = conventions.pattern.is_some();
+    let Some(pattern) = resolve_pattern(
+        conventions.pattern.as_deref(),
+        &CONVENTIONAL_RE,
+        "pattern",
+        issues,
+    ) else {
+        return;
+    };
+    let failing: Vec<&CommitMessage> = commits
+        .iter()
+        .filter(|c| !pattern.is_match(&c.subject) && !c.subject.starts_with("Revert \""))
+        .collect();
+    if failing.is_empty() {
+        return;
+    }
+
+    let convention = if custom {
+        "the configured pattern"
+    } else {
+        "Conventional Commits"
+    };
+    issues.push(Issue {
+        id: "COM-001".to_string(),
+        analyzer: "commits".to_string(),
+        category: AnalyzerCategory::Configuration,
+        severity: Severity::Medium,
+        title: format!(
+            "{} of the last {} commits do not follow {}",
+            failing.len(),
+            commits.len(),
+            convention
+        ),
+        description: format!(
+            "Release tooling cannot derive versions or changelog entries from these commits on {}: {}.",
+            rev,
+            examples(&failing)
+        ),
+        file: None,
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some(
+            "Enforce the format with a commit-msg hook (commitlint) or squash-merge with a conventional PR title"
+                .to_string(),
+        ),
+        auto_fixable: false,
+        references: vec!["https://www.conventionalcommits.org".to_string()],
+    });
+}
+
+fn check_wip_commits(commits: &[CommitMessage], branch: &str, issues: &mut Vec<Issue>) {
+    let wip: Vec<&CommitMessage> = commits
+        .iter()
+        .filter(|c| WIP_RE.is_match(c.subject.trim_start()))
+        .collect();
+    if wip.is_empty() {
+        return;
+    }
+
+    issues.push(Issue {
+        id: "COM-002".to_string(),
+        analyzer: "commits".to_string(),
+        category: AnalyzerCategory::Configuration,
+        severity: Severity::Medium,
+        title: format!("{} WIP/fixup commit(s) on {}", wip.len(), branch),
+        description: format!(
+            "Work-in-progress and fixup commits were merged to the default branch without being squashed: {}.",
+            examples(&wip)
+        ),
+        file: None,
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some(
+            "Run `git rebase -i --autosquash` before merging, or squash-merge pull requests"
+                .to_string(),
+        ),
+        auto_fixable: false,
+        references: vec![],
+    });
+}
+
+fn check_issue_references(
+    commits: &[CommitMessage],
+    conventions: &CommitConventions,
+    rev: &str,
+    issues: &mut Vec<Issue>,
+) {
+    let Some(pattern) = resolve_pattern(
+        conventions.issue_pattern.as_deref(),
+        &ISSUE_RE,
+        "issue_pattern",
+        issues,
+    ) else {
+        return;
+    };
+    let unreferenced = commits
+        .iter()
+        .filter(|c| !pattern.is_match(&c.subject) && !pattern.is_match(&c.body))
+        .count();
+    let percent = unreferenced * 100 / commits.len();
+    let max = conventions
+        .max_unreferenced_percent
+        .unwrap_or(DEFAULT_MAX_UNREFERENCED_PERCENT);
+    if percent <= max as usize {
+        return;
+    }
+
+    issues.push(Issue {
+        id: "COM-003".to_string(),
+        analyzer: "commits".to_string(),
+        category: AnalyzerCategory::Configuration,
+        severity: Severity::Low,
+        title: format!("{}% of recent commits have no issue reference", percent),
+        description: format!(
+            "{} of the last {} commits on {} reference no issue (more than the {}% allowed), so changes cannot be traced back to tickets.",
+            unreferenced,
+            commits.len(),
+            rev,
+            max
+        ),
+        file: None,
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some("Mention the issue (e.g. `Fixes #123`) in the subject or body".to_string()),
+        auto_fixable: false,
+        references: vec![],
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn commit(hash: &str, subject: &str, body: &str) -> CommitMessage {
+        CommitMessage {
+            hash: hash.to_string(),
+            subject: subject.to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    fn git(dir: &Path, args: &[&str]) -> bool {
+        Command::new("git")
+            .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+
+    /// Repository on `main` with one empty commit per subject, or `None` without git.
+    fn make_repo(subjects: &[&str]) -> Option<TempDir> {
+        let tmp = TempDir::new().unwrap();
+        if !git(tmp.path(), &["init", "-q", "-b", "main"]) {
+            return None;
+        }
+        for subject in subjects {
+            git(
+                tmp.path(),
+                &["commit", "-q", "--allow-empty", "-m", subject],
+            );
+        }
+        Some(tmp)
+    }
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::Unknown,
+                language: Language::Unknown,
+                version: None,
+                package_manager: None,
+                has_git: true,
+                has_ci: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_conventional_subjects() {
+        let commits = vec![
+            commit("aaaaaaaaaa", "feat(cli): add --stats", ""),
+            commit("bbbbbbbbbb", "fix!: drop old flag", ""),
+            commit("cccccccccc", "Revert \"feat: x\"", ""),
+            commit("dddddddddd", "Update stuff", ""),
+            commit("eeeeeeeeee", "feature: nope", ""),
+        ];
+        let mut issues = Vec::new();
+        check_subjects(&commits, &CommitConventions::default(), "main", &mut issues);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].title,
+            "2 of the last 5 commits do not follow Conventional Commits"
+        );
+        assert!(issues[0]
+            .description
+            .ends_with("ddddddd Update stuff; eeeeeee feature: nope."));
+    }
+
+    #[test]
+    fn test_custom_pattern() {
+        let commits = vec![
+            commit("a", "[API] Add endpoint", ""),
+            commit("b", "Add endpoint", ""),
+        ];
+        let conventions = CommitConventions {
+            pattern: Some(r"^\[[A-Z]+\] ".to_string()),
+            ..CommitConventions::default()
+        };
+        let mut issues = Vec::new();
+        check_subjects(&commits, &conventions, "main", &mut issues);
+        assert!(issues[0]
+            .title
+            .ends_with("do not follow the configured pattern"));
+
+        let invalid = CommitConventions {
+            pattern: Some("(".to_string()),
+            ..CommitConventions::default()
+        };
+        let mut issues = Vec::new();
+        check_subjects(&commits, &invalid, "main", &mut issues);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "COM-004");
+        assert_eq!(
+            issues[0].title,
+            "Invalid commits.pattern in .repodoctor.yml"
+        );
+    }
+
+    #[test]
+    fn test_wip_commits() {
+        let commits = vec![
+            commit("a", "fixup! feat: add x", ""),
+            commit("b", "WIP", ""),
+            commit("c", "[WIP] half done", ""),
+            commit("d", "feat: wipe cache", ""),
+        ];
+        let mut issues = Vec::new();
+        check_wip_commits(&commits, "main", &mut issues);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].title, "3 WIP/fixup commit(s) on main");
+    }
+
+    #[test]
+    fn test_issue_references() {
+        let commits = vec![
+            commit("a", "fix: crash (#12)", ""),
+            commit("b", "feat: export", "Refs PROJ-42"),
+            commit("c", "chore: bump", ""),
+            commit("d", "docs: typo", ""),
+        ];
+        let mut issues = Vec::new();
+        check_issue_references(&commits, &CommitConventions::default(), "main", &mut issues);
+        assert!(issues.is_empty()); // 50% is allowed
+
+        let strict = CommitConventions {
+            max_unreferenced_percent: Some(20),
+            ..CommitConventions::default()
+        };
+        check_issue_references(&commits, &strict, "main", &mut issues);
+        assert_eq!(
+            issues[0].title,
+            "50% of recent commits have no issue reference"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_opt_in_and_history() {
+        let Some(repo) = make_repo(&["feat: init (#1)", "wip", "Fix the thing", "fix: thing (#2)"])
+        else {
+            return;
+        };
+        let project = make_project(&repo);
+        assert!(!CommitsAnalyzer.applies_to(&project));
+
+        fs::write(repo.path().join(".commitlintrc.json"), "{}").unwrap();
+        assert!(CommitsAnalyzer.applies_to(&project));
+
+        let issues = CommitsAnalyzer.analyze(&project).await.unwrap();
+        let titles: Vec<_> = issues.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "2 of the last 4 commits do not follow Conventional Commits",
+                "1 WIP/fixup commit(s) on main",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_pattern_does_not_fail_the_scan() {
+        let Some(repo) = make_repo(&["feat: init (#1)", "Fix the thing"]) else {
+            return;
+        };
+        fs::write(
+            repo.path().join(".repodoctor.yml"),
+            "commits:\n  issue_pattern: '('\n",
+        )
+        .unwrap();
+        let project = make_project(&repo);
+
+        let issues = CommitsAnalyzer.analyze(&project).await.unwrap();
+        let ids: Vec<_> = issues.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["COM-001", "COM-004"]);
+        assert_eq!(
+            issues[1].title,
+            "Invalid commits.issue_pattern in .repodoctor.yml"
+        );
+    }
+}
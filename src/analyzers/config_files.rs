@@ -4,8 +4,8 @@ use std::path::Path;
 
 use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
 use crate::core::project::Project;
-use crate::frameworks::detector::Framework;
-use crate::utils::fs::path_exists;
+use crate::frameworks::detector::{DetectedProject, Framework};
+use crate::utils::fs::{detect_ci_provider, path_exists, read_to_string};
 
 pub struct ConfigAnalyzer;
 
@@ -35,11 +35,12 @@ impl Analyzer for ConfigAnalyzer {
         check_framework_config(path, &project.detected.framework, &mut issues);
 
         // Linter checks
-        check_linter_config(path, &project.detected.framework, &mut issues);
+        check_linter_config(path, &project.detected, &mut issues);
 
         // Generic checks
         check_editorconfig(path, &mut issues);
         check_env_committed(path, &mut issues);
+        check_ci_config(path, &project.detected.framework, &mut issues);
 
         Ok(issues)
     }
@@ -123,6 +124,11 @@ fn check_framework_config(path: &Path, framework: &Framework, issues: &mut Vec<I
             description: format!("{desc}. This file is recommended for {} projects.", framework),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some(format!("Create {file}")),
             auto_fixable: false,
             references: vec![],
@@ -131,15 +137,15 @@ fn check_framework_config(path: &Path, framework: &Framework, issues: &mut Vec<I
 }
 
 fn has_pyproject_tool_section(path: &Path) -> bool {
-    if let Ok(content) = std::fs::read_to_string(path.join("pyproject.toml")) {
+    if let Ok(content) = read_to_string(path.join("pyproject.toml")) {
         content.contains("[tool.")
     } else {
         false
     }
 }
 
-fn check_linter_config(path: &Path, framework: &Framework, issues: &mut Vec<Issue>) {
-    let has_linter = match framework {
+fn check_linter_config(path: &Path, detected: &DetectedProject, issues: &mut Vec<Issue>) {
+    let has_linter = match detected.generic_framework() {
         Framework::Flutter => path_exists(path, "analysis_options.yaml"),
         Framework::RustCargo => {
             path_exists(path, "clippy.toml") || path_exists(path, ".clippy.toml")
@@ -171,10 +177,15 @@ fn check_linter_config(path: &Path, framework: &Framework, issues: &mut Vec<Issu
             title: "Missing linter configuration".to_string(),
             description: format!(
                 "No linter or code style configuration found for {} project.",
-                framework
+                detected.kind()
             ),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Add a linter configuration file to enforce code quality".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -214,6 +225,11 @@ fn check_editorconfig(path: &Path, issues: &mut Vec<Issue>) {
             description: "No .editorconfig found. This file helps maintain consistent coding styles across editors.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create an .editorconfig file to define coding style rules".to_string()),
             auto_fixable: true,
             references: vec!["https://editorconfig.org".to_string()],
@@ -221,6 +237,31 @@ fn check_editorconfig(path: &Path, issues: &mut Vec<Issue>) {
     }
 }
 
+fn check_ci_config(path: &Path, framework: &Framework, issues: &mut Vec<Issue>) {
+    if detect_ci_provider(path).is_some() {
+        return;
+    }
+
+    issues.push(Issue {
+        id: "CFG-005".to_string(),
+        analyzer: "config_files".to_string(),
+        category: AnalyzerCategory::CiCd,
+        severity: Severity::Medium,
+        title: "Missing CI/CD configuration".to_string(),
+        description: "No CI configuration found (GitHub Actions, GitLab CI, CircleCI, Travis CI, Jenkins).".to_string(),
+        file: None,
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some("Add a CI workflow that builds and tests the project on every push".to_string()),
+        auto_fixable: *framework != Framework::Unknown,
+        references: vec![],
+    });
+}
+
 fn check_env_committed(path: &Path, issues: &mut Vec<Issue>) {
     if !path_exists(path, ".env") {
         return;
@@ -228,7 +269,7 @@ fn check_env_committed(path: &Path, issues: &mut Vec<Issue>) {
 
     // Check if .env is gitignored
     let gitignore_path = path.join(".gitignore");
-    let is_gitignored = if let Ok(content) = std::fs::read_to_string(&gitignore_path) {
+    let is_gitignored = if let Ok(content) = read_to_string(&gitignore_path) {
         content
             .lines()
             .any(|line| {
@@ -251,6 +292,11 @@ fn check_env_committed(path: &Path, issues: &mut Vec<Issue>) {
                     .to_string(),
             file: Some(path.join(".env")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Add .env to .gitignore to prevent committing secrets".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -261,7 +307,7 @@ fn check_env_committed(path: &Path, issues: &mut Vec<Issue>) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::frameworks::detector::{DetectedProject, Language};
+    use crate::frameworks::detector::Language;
     use std::fs as stdfs;
     use tempfile::TempDir;
 
@@ -357,6 +403,40 @@ mod tests {
         assert!(!issues.iter().any(|i| i.id == "CFG-004"));
     }
 
+    #[tokio::test]
+    async fn test_inferred_language_gets_linter_check() {
+        let tmp = TempDir::new().unwrap();
+        let mut project = make_project(&tmp, Framework::Unknown);
+        let issues = ConfigAnalyzer.analyze(&project).await.unwrap();
+        assert!(!issues.iter().any(|i| i.id == "CFG-004"));
+
+        project.detected.language = Language::Python;
+        let issues = ConfigAnalyzer.analyze(&project).await.unwrap();
+        let issue = issues.iter().find(|i| i.id == "CFG-004").unwrap();
+        assert!(issue.description.contains("Python project"));
+
+        stdfs::write(tmp.path().join(".flake8"), "[flake8]\n").unwrap();
+        let issues = ConfigAnalyzer.analyze(&project).await.unwrap();
+        assert!(!issues.iter().any(|i| i.id == "CFG-004"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_ci_config() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp, Framework::RustCargo);
+        let issues = ConfigAnalyzer.analyze(&project).await.unwrap();
+        assert!(issues.iter().any(|i| i.id == "CFG-005" && i.auto_fixable));
+    }
+
+    #[tokio::test]
+    async fn test_has_ci_config() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(tmp.path().join(".gitlab-ci.yml"), "test:\n  script: make").unwrap();
+        let project = make_project(&tmp, Framework::RustCargo);
+        let issues = ConfigAnalyzer.analyze(&project).await.unwrap();
+        assert!(!issues.iter().any(|i| i.id == "CFG-005"));
+    }
+
     #[tokio::test]
     async fn test_applies_to_all() {
         let tmp = TempDir::new().unwrap();
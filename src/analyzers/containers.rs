@@ -0,0 +1,558 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::config::{Config, ContainerScan};
+use crate::core::content::relative_path;
+use crate::core::project::Project;
+use crate::utils::fs::{find_executable, is_ignored_name, read_to_string, walk};
+
+/// `FROM [--platform=...] image [AS stage]`
+static FROM_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^\s*FROM\s+(?:--\S+\s+)*(\S+)(?:\s+AS\s+(\S+))?").unwrap()
+});
+
+/// `image: name:tag` in a compose service.
+static COMPOSE_IMAGE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^\s*image:\s*["']?([^"'\s#]+)"#).unwrap());
+
+/// External vulnerability scanners the analyzer can drive.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Tool {
+    Trivy,
+    Grype,
+}
+
+impl Tool {
+    pub(crate) fn program(self) -> &'static str {
+        match self {
+            Tool::Trivy => "trivy",
+            Tool::Grype => "grype",
+        }
+    }
+
+    fn args(self, image: &str, ignore_unfixed: bool) -> Vec<String> {
+        let mut args: Vec<String> = match self {
+            Tool::Trivy => ["image", "--quiet", "--format", "json", "--scanners", "vuln"]
+                .map(String::from)
+                .to_vec(),
+            Tool::Grype => ["--quiet", "--output", "json"].map(String::from).to_vec(),
+        };
+        if ignore_unfixed {
+            args.push(match self {
+                Tool::Trivy => "--ignore-unfixed".to_string(),
+                Tool::Grype => "--only-fixed".to_string(),
+            });
+        }
+        args.push(image.to_string());
+        args
+    }
+
+    /// Findings from the tool's JSON report.
+    fn parse(self, report: &Value) -> Vec<Finding> {
+        match self {
+            Tool::Trivy => report["Results"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .flat_map(|result| result["Vulnerabilities"].as_array().into_iter().flatten())
+                .filter_map(|v| {
+                    Some(Finding {
+                        id: v["VulnerabilityID"].as_str()?.to_string(),
+                        package: v["PkgName"].as_str()?.to_string(),
+                        installed: v["InstalledVersion"].as_str().unwrap_or_default().to_string(),
+                        fixed: non_empty(&v["FixedVersion"]),
+                        severity: map_severity(v["Severity"].as_str().unwrap_or_default()),
+                        title: non_empty(&v["Title"]),
+                        url: non_empty(&v["PrimaryURL"]),
+                    })
+                })
+                .collect(),
+            Tool::Grype => report["matches"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|m| {
+                    let vulnerability = &m["vulnerability"];
+                    let fixed = vulnerability["fix"]["versions"]
+                        .as_array()
+                        .map(|versions| {
+                            versions
+                                .iter()
+                                .filter_map(Value::as_str)
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        })
+                        .filter(|v| !v.is_empty());
+                    Some(Finding {
+                        id: vulnerability["id"].as_str()?.to_string(),
+                        package: m["artifact"]["name"].as_str()?.to_string(),
+                        installed: m["artifact"]["version"].as_str().unwrap_or_default().to_string(),
+                        fixed,
+                        severity: map_severity(vulnerability["severity"].as_str().unwrap_or_default()),
+                        title: non_empty(&vulnerability["description"]),
+                        url: non_empty(&vulnerability["dataSource"]),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A vulnerable package reported for an image.
+#[derive(Debug, Clone, PartialEq)]
+struct Finding {
+    id: String,
+    package: String,
+    installed: String,
+    fixed: Option<String>,
+    severity: Severity,
+    title: Option<String>,
+    url: Option<String>,
+}
+
+/// Where an image is first referenced.
+#[derive(Debug, Clone, PartialEq)]
+struct ImageRef {
+    image: String,
+    /// Project-relative path of the referencing file
+    file: String,
+    line: usize,
+    source_line: String,
+    start: usize,
+}
+
+fn non_empty(value: &Value) -> Option<String> {
+    value
+        .as_str()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+fn map_severity(severity: &str) -> Severity {
+    match severity.to_ascii_lowercase().as_str() {
+        "critical" => Severity::Critical,
+        "high" => Severity::High,
+        "medium" => Severity::Medium,
+        "low" => Severity::Low,
+        _ => Severity::Info,
+    }
+}
+
+fn is_dockerfile(name: &str) -> bool {
+    name == "Dockerfile"
+        || name == "Containerfile"
+        || name.starts_with("Dockerfile.")
+        || name.ends_with(".Dockerfile")
+        || name.ends_with(".dockerfile")
+}
+
+fn is_compose_file(name: &str) -> bool {
+    (name.starts_with("docker-compose") || name.starts_with("compose."))
+        && (name.ends_with(".yml") || name.ends_with(".yaml"))
+}
+
+/// Images referenced by `FROM` lines, skipping `scratch`, earlier build
+/// stages, and references built from `ARG`s.
+fn dockerfile_images(content: &str) -> Vec<(usize, String, usize)> {
+    let mut stages: HashSet<String> = HashSet::new();
+    let mut images = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let Some(caps) = FROM_RE.captures(line) else {
+            continue;
+        };
+        let image = caps.get(1).unwrap();
+        let name = image.as_str();
+        if !name.eq_ignore_ascii_case("scratch")
+            && !name.contains('$')
+            && !stages.contains(&name.to_lowercase())
+        {
+            images.push((index + 1, name.to_string(), image.start()));
+        }
+        if let Some(stage) = caps.get(2) {
+            stages.insert(stage.as_str().to_lowercase());
+        }
+    }
+    images
+}
+
+fn compose_images(content: &str) -> Vec<(usize, String, usize)> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let image = COMPOSE_IMAGE_RE.captures(line)?.get(1)?;
+            (!image.as_str().contains('$'))
+                .then(|| (index + 1, image.as_str().to_string(), image.start()))
+        })
+        .collect()
+}
+
+/// First reference to every distinct image in the project's Dockerfiles and
+/// compose files.
+fn find_images(base: &Path) -> Vec<ImageRef> {
+    let mut files: Vec<PathBuf> = walk(base)
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || !is_ignored_name(&e.file_name().to_string_lossy()))
+        .filter_map(|e| e.ok())
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy();
+            e.file_type().is_file() && (is_dockerfile(&name) || is_compose_file(&name))
+        })
+        .map(|e| e.into_path())
+        .collect();
+    files.sort();
+
+    let mut seen = HashSet::new();
+    let mut images = Vec::new();
+    for file in files {
+        let Ok(content) = read_to_string(&file) else {
+            continue;
+        };
+        let name = file.file_name().unwrap_or_default().to_string_lossy();
+        let found = if is_compose_file(&name) {
+            compose_images(&content)
+        } else {
+            dockerfile_images(&content)
+        };
+        for (line, image, start) in found {
+            if seen.insert(image.clone()) {
+                images.push(ImageRef {
+                    image,
+                    file: relative_path(base, &file),
+                    line,
+                    source_line: content.lines().nth(line - 1).unwrap_or_default().to_string(),
+                    start,
+                });
+            }
+        }
+    }
+    images
+}
+
+/// Runs trivy or grype, when installed, on the images referenced in
+/// Dockerfiles and compose files, and reports their vulnerabilities as issues.
+///
+/// `containers.scanner` in `.repodoctor.yml` (or `REPODOCTOR_CONTAINER_SCANNER`)
+/// picks the tool: `auto` prefers trivy over grype, and `none` turns the
+/// analyzer off.
+pub struct ContainerImagesAnalyzer;
+
+impl ContainerImagesAnalyzer {
+    pub(crate) fn settings(project: &Project) -> ContainerScan {
+        Config::load(&project.path).containers.unwrap_or_default()
+    }
+
+    /// The tool to run and its path; the path is `None` for a configured tool
+    /// that is not installed. `None` when scanning is off or no tool is found.
+    pub(crate) fn tool(settings: &ContainerScan) -> Option<(Tool, Option<PathBuf>)> {
+        match settings.scanner.as_deref().unwrap_or("auto") {
+            "none" | "off" | "false" => None,
+            "trivy" => Some((Tool::Trivy, find_executable("trivy"))),
+            "grype" => Some((Tool::Grype, find_executable("grype"))),
+            _ => [Tool::Trivy, Tool::Grype]
+                .into_iter()
+                .find_map(|tool| Some((tool, Some(find_executable(tool.program())?)))),
+        }
+    }
+
+    /// Run the scanner on one image and parse its report.
+    fn scan_image(program: &Path, tool: Tool, image: &str, ignore_unfixed: bool) -> Result<Vec<Finding>> {
+        let output = Command::new(program)
+            .args(tool.args(image, ignore_unfixed))
+            .output()
+            .with_context(|| format!("Failed to run {}", program.display()))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let reason = stderr.lines().rev().find(|l| !l.trim().is_empty()).unwrap_or("no output");
+            anyhow::bail!("{} exited with {}: {}", tool.program(), output.status, reason.trim());
+        }
+        let report: Value = serde_json::from_slice(&output.stdout)
+            .with_context(|| format!("{} did not produce a JSON report", tool.program()))?;
+        Ok(tool.parse(&report))
+    }
+
+    fn issue(
+        &self,
+        reference: &ImageRef,
+        id: &str,
+        severity: Severity,
+        title: String,
+        description: String,
+        suggestion: String,
+    ) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: self.name().to_string(),
+            // Vulnerabilities are security findings; scanner problems are setup issues
+            category: if id == "IMG-001" {
+                AnalyzerCategory::Security
+            } else {
+                AnalyzerCategory::Configuration
+            },
+            severity,
+            title,
+            description,
+            file: Some(PathBuf::from(&reference.file)),
+            line: Some(reference.line),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some(suggestion),
+            auto_fixable: false,
+            references: vec![],
+        }
+        .with_snippet(&reference.source_line, reference.start)
+    }
+
+    fn findings_to_issues(&self, reference: &ImageRef, findings: Vec<Finding>) -> Vec<Issue> {
+        // A CVE usually affects several binaries of one package; report it once per package
+        let mut unique: BTreeMap<(String, String), Finding> = BTreeMap::new();
+        for finding in findings {
+            unique
+                .entry((finding.id.clone(), finding.package.clone()))
+                .or_insert(finding);
+        }
+
+        unique
+            .into_values()
+            .map(|finding| {
+                let mut issue = self.issue(
+                    reference,
+                    "IMG-001",
+                    finding.severity,
+                    format!(
+                        "{} in {} {} ({})",
+                        finding.id, finding.package, finding.installed, reference.image
+                    ),
+                    format!(
+                        "Image '{}' contains {} {}, which is affected by {}.{}",
+                        reference.image,
+                        finding.package,
+                        finding.installed,
+                        finding.id,
+                        finding.title.map(|t| format!(" {}", t)).unwrap_or_default()
+                    ),
+                    match &finding.fixed {
+                        Some(fixed) => format!(
+                            "Update to an image that ships {} {} or later",
+                            finding.package, fixed
+                        ),
+                        None => "No fix is available yet; consider another base image or baseline the issue".to_string(),
+                    },
+                );
+                issue.references.extend(finding.url);
+                issue
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl Analyzer for ContainerImagesAnalyzer {
+    fn name(&self) -> &'static str {
+        "containers"
+    }
+
+    fn description(&self) -> &'static str {
+        "Scans referenced container images with trivy or grype"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Security
+    }
+
+    fn applies_to(&self, project: &Project) -> bool {
+        Self::tool(&Self::settings(project)).is_some() && !find_images(&project.path).is_empty()
+    }
+
+    async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
+        let settings = Self::settings(project);
+        let Some((tool, program)) = Self::tool(&settings) else {
+            return Ok(vec![]);
+        };
+        let images = find_images(&project.path);
+        let Some(first) = images.first() else {
+            return Ok(vec![]);
+        };
+
+        // IMG-003: Configured scanner is not installed
+        let Some(program) = program else {
+            return Ok(vec![self.issue(
+                first,
+                "IMG-003",
+                Severity::Low,
+                format!("Container scanner '{}' is not installed", tool.program()),
+                format!(
+                    "`containers.scanner` is set to {} but it was not found in PATH, so {} referenced image(s) were not scanned.",
+                    tool.program(),
+                    images.len()
+                ),
+                format!("Install {} or set `containers.scanner: none`", tool.program()),
+            )]);
+        };
+
+        let ignore_unfixed = settings.ignore_unfixed.unwrap_or(false);
+        let mut issues = Vec::new();
+        for reference in &images {
+            match Self::scan_image(&program, tool, &reference.image, ignore_unfixed) {
+                // IMG-001: Vulnerabilities in referenced images
+                Ok(findings) => issues.extend(self.findings_to_issues(reference, findings)),
+                // IMG-002: Image could not be scanned (pull failure, private registry, ...)
+                Err(e) => issues.push(self.issue(
+                    reference,
+                    "IMG-002",
+                    Severity::Info,
+                    format!("Container image could not be scanned: {}", reference.image),
+                    format!("{:#}", e),
+                    "Check that the image exists and that registry credentials are available to the scanner".to_string(),
+                )),
+            }
+        }
+        Ok(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+
+    #[test]
+    fn test_dockerfile_images_skip_stages_scratch_and_args() {
+        let content = "ARG BASE=alpine:3.19\n\
+            FROM --platform=$BUILDPLATFORM rust:1.77 AS build\n\
+            FROM build AS test\n\
+            FROM ${BASE}\n\
+            from gcr.io/distroless/cc-debian12@sha256:abc\n\
+            FROM scratch\n";
+        let images: Vec<String> = dockerfile_images(content).into_iter().map(|(_, i, _)| i).collect();
+        assert_eq!(images, ["rust:1.77", "gcr.io/distroless/cc-debian12@sha256:abc"]);
+    }
+
+    #[test]
+    fn test_find_images_in_dockerfiles_and_compose() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("Dockerfile"), "FROM node:20-alpine\nRUN npm ci\n").unwrap();
+        fs::write(
+            tmp.path().join("docker-compose.yml"),
+            "services:\n  db:\n    image: \"postgres:16\"\n  app:\n    build: .\n  cache:\n    image: node:20-alpine\n",
+        )
+        .unwrap();
+        fs::create_dir_all(tmp.path().join("node_modules/pkg")).unwrap();
+        fs::write(tmp.path().join("node_modules/pkg/Dockerfile"), "FROM ubuntu:18.04\n").unwrap();
+
+        let images = find_images(tmp.path());
+        let names: Vec<&str> = images.iter().map(|i| i.image.as_str()).collect();
+        assert_eq!(names, ["node:20-alpine", "postgres:16"]);
+        assert_eq!(images[0].file, "Dockerfile");
+        assert_eq!((images[1].file.as_str(), images[1].line), ("docker-compose.yml", 3));
+    }
+
+    #[test]
+    fn test_parse_trivy_report() {
+        let report = serde_json::json!({
+            "Results": [{
+                "Target": "alpine:3.18",
+                "Vulnerabilities": [{
+                    "VulnerabilityID": "CVE-2023-5363",
+                    "PkgName": "libcrypto3",
+                    "InstalledVersion": "3.1.3-r0",
+                    "FixedVersion": "3.1.4-r0",
+                    "Severity": "HIGH",
+                    "Title": "openssl: incorrect cipher key length",
+                    "PrimaryURL": "https://avd.aquasec.com/nvd/cve-2023-5363"
+                }]
+            }, { "Target": "app", "Class": "lang-pkgs" }]
+        });
+        let findings = Tool::Trivy.parse(&report);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity, Severity::High);
+        assert_eq!(findings[0].fixed.as_deref(), Some("3.1.4-r0"));
+    }
+
+    #[test]
+    fn test_parse_grype_report() {
+        let report = serde_json::json!({
+            "matches": [{
+                "vulnerability": {
+                    "id": "CVE-2023-5363",
+                    "severity": "Critical",
+                    "dataSource": "https://nvd.nist.gov/vuln/detail/CVE-2023-5363",
+                    "fix": { "versions": [], "state": "not-fixed" }
+                },
+                "artifact": { "name": "libcrypto3", "version": "3.1.3-r0" }
+            }, {
+                "vulnerability": { "id": "GHSA-xxxx", "severity": "Negligible" },
+                "artifact": { "name": "busybox", "version": "1.36" }
+            }]
+        });
+        let findings = Tool::Grype.parse(&report);
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].severity, Severity::Critical);
+        assert_eq!(findings[0].fixed, None);
+        assert_eq!(findings[1].severity, Severity::Info);
+    }
+
+    #[test]
+    fn test_tool_args() {
+        assert_eq!(
+            Tool::Trivy.args("alpine:3.18", true),
+            ["image", "--quiet", "--format", "json", "--scanners", "vuln", "--ignore-unfixed", "alpine:3.18"]
+        );
+        assert_eq!(Tool::Grype.args("alpine:3.18", false), ["--quiet", "--output", "json", "alpine:3.18"]);
+    }
+
+    #[test]
+    fn test_scanning_can_be_disabled() {
+        let settings = ContainerScan {
+            scanner: Some("none".to_string()),
+            ignore_unfixed: None,
+        };
+        assert!(ContainerImagesAnalyzer::tool(&settings).is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_image_runs_tool_and_maps_findings() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("Dockerfile"), "FROM alpine:3.18\n").unwrap();
+        let report = r#"{"Results":[{"Vulnerabilities":[
+            {"VulnerabilityID":"CVE-1","PkgName":"libssl3","InstalledVersion":"1","Severity":"CRITICAL"},
+            {"VulnerabilityID":"CVE-1","PkgName":"libssl3","InstalledVersion":"1","Severity":"CRITICAL"}]}]}"#;
+        let fake = tmp.path().join("fake-trivy");
+        fs::write(&fake, format!("#!/bin/sh\ncat <<'EOF'\n{}\nEOF\n", report)).unwrap();
+        fs::set_permissions(&fake, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let reference = &find_images(tmp.path())[0];
+        let findings =
+            ContainerImagesAnalyzer::scan_image(&fake, Tool::Trivy, &reference.image, false).unwrap();
+        let issues = ContainerImagesAnalyzer.findings_to_issues(reference, findings);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "IMG-001");
+        assert_eq!(issues[0].severity, Severity::Critical);
+        assert_eq!(issues[0].file, Some(PathBuf::from("Dockerfile")));
+        assert_eq!(issues[0].column, Some(6));
+        assert!(issues[0].suggestion.as_deref().unwrap().contains("No fix"));
+
+        let failing = tmp.path().join("failing-trivy");
+        fs::write(&failing, "#!/bin/sh\necho 'unable to pull image' >&2\nexit 1\n").unwrap();
+        fs::set_permissions(&failing, fs::Permissions::from_mode(0o755)).unwrap();
+        let err = ContainerImagesAnalyzer::scan_image(&failing, Tool::Trivy, "alpine:3.18", false)
+            .unwrap_err();
+        assert!(err.to_string().contains("unable to pull image"));
+    }
+}
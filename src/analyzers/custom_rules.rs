@@ -0,0 +1,408 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use glob::{MatchOptions, Pattern};
+use regex::Regex;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Effort, Impact, Issue, Severity};
+use crate::core::config::{Config, CustomRule};
+use crate::core::content::{ContentFile, ContentMatcher};
+use crate::core::project::Project;
+use crate::utils::patterns;
+
+#[derive(Default)]
+pub struct CustomRulesAnalyzer {
+    /// Rules of the last project seen, so `applies_to`, `analyze` and
+    /// `content_matchers` load and compile `.repodoctor.yml` once per scan.
+    loaded: Mutex<Option<Arc<LoadedRules>>>,
+}
+
+struct LoadedRules {
+    path: PathBuf,
+    compiled: Vec<CompiledRule>,
+    /// Rules that could not be compiled, as configuration issues
+    invalid: Vec<Issue>,
+}
+
+impl CustomRulesAnalyzer {
+    fn rules(&self, project: &Project) -> Arc<LoadedRules> {
+        let mut loaded = self.loaded.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(rules) = loaded.as_ref().filter(|r| r.path == project.path) {
+            return rules.clone();
+        }
+        let mut invalid = Vec::new();
+        let rules = Config::load(&project.path).custom_rules.unwrap_or_default();
+        let compiled = compile_rules(&rules, &mut invalid);
+        let rules = Arc::new(LoadedRules {
+            path: project.path.clone(),
+            compiled,
+            invalid,
+        });
+        *loaded = Some(rules.clone());
+        rules
+    }
+}
+
+const MAX_FILES: usize = 2000;
+
+/// Directories to skip when matching custom rule globs.
+const SKIP_DIRS: &[&str] = &[
+    ".git",
+    "node_modules",
+    "vendor",
+    "target",
+    ".next",
+    "dist",
+    "build",
+    "__pycache__",
+];
+
+/// A custom rule whose glob and regex compiled successfully.
+#[derive(Clone)]
+pub(crate) struct CompiledRule {
+    rule: CustomRule,
+    glob: Pattern,
+    regex: Regex,
+    severity: Severity,
+    category: AnalyzerCategory,
+    effort: Option<Effort>,
+    impact: Option<Impact>,
+}
+
+#[async_trait]
+impl Analyzer for CustomRulesAnalyzer {
+    fn name(&self) -> &'static str {
+        "custom_rules"
+    }
+
+    fn description(&self) -> &'static str {
+        "Runs user-defined regex rules from the custom_rules section of .repodoctor.yml"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Configuration
+    }
+
+    fn applies_to(&self, project: &Project) -> bool {
+        let rules = self.rules(project);
+        !rules.compiled.is_empty() || !rules.invalid.is_empty()
+    }
+
+    async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
+        // Matching runs in the content engine; only report rules that fail to compile
+        Ok(self.rules(project).invalid.clone())
+    }
+
+    fn content_matchers(&self, project: &Project) -> Vec<Box<dyn ContentMatcher>> {
+        let rules = self.rules(project);
+        if rules.compiled.is_empty() {
+            return Vec::new();
+        }
+        vec![Box::new(CustomRulesMatcher {
+            rules: rules.compiled.clone(),
+            current: Vec::new(),
+            files: 0,
+        })]
+    }
+}
+
+/// Dispatches file lines to every custom rule whose glob matches the file.
+struct CustomRulesMatcher {
+    rules: Vec<CompiledRule>,
+    /// Rules matching the file being read
+    current: Vec<usize>,
+    files: usize,
+}
+
+impl ContentMatcher for CustomRulesMatcher {
+    fn skips_dir(&self, name: &str) -> bool {
+        SKIP_DIRS.contains(&name)
+    }
+
+    fn wants(&self, relative: &str) -> bool {
+        self.rules
+            .iter()
+            .any(|c| c.glob.matches_with(relative, match_options()))
+    }
+
+    fn begin_file(&mut self, file: &ContentFile, _issues: &mut Vec<Issue>) -> bool {
+        self.files += 1;
+        self.current = (0..self.rules.len())
+            .filter(|&i| self.rules[i].glob.matches_with(file.relative, match_options()))
+            .collect();
+        true
+    }
+
+    fn check_line(
+        &mut self,
+        file: &ContentFile,
+        line_num: usize,
+        line: &str,
+        issues: &mut Vec<Issue>,
+    ) -> bool {
+        for &index in &self.current {
+            let compiled_rule = &self.rules[index];
+            if compiled_rule.regex.is_match(line) {
+                issues.push(Issue {
+                    id: compiled_rule.rule.id.clone(),
+                    analyzer: "custom_rules".to_string(),
+                    category: compiled_rule.category.clone(),
+                    severity: compiled_rule.severity,
+                    title: compiled_rule.rule.message.clone(),
+                    description: format!(
+                        "Custom rule {} matched in {}",
+                        compiled_rule.rule.id, file.relative
+                    ),
+                    file: Some(PathBuf::from(file.relative)),
+                    line: Some(line_num),
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: compiled_rule.effort,
+                    impact: compiled_rule.impact,
+                    suggestion: compiled_rule.rule.suggestion.clone(),
+                    auto_fixable: false,
+                    references: compiled_rule.rule.references.clone().unwrap_or_default(),
+                });
+            }
+        }
+        true
+    }
+
+    fn is_done(&self) -> bool {
+        self.files >= MAX_FILES
+    }
+}
+
+fn match_options() -> MatchOptions {
+    MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    }
+}
+
+/// Compile every rule, reporting invalid globs, regexes or unknown severity,
+/// category, effort and impact names as configuration issues.
+pub(crate) fn compile_rules(rules: &[CustomRule], issues: &mut Vec<Issue>) -> Vec<CompiledRule> {
+    let mut compiled = Vec::new();
+
+    for rule in rules {
+        let mut errors = Vec::new();
+        let glob = Pattern::new(&rule.files)
+            .map_err(|e| errors.push(format!("invalid `files` glob: {}", e)))
+            .ok();
+        let regex = patterns::compile(&rule.pattern)
+            .map_err(|e| errors.push(format!("invalid `pattern` regex: {}", e)))
+            .ok();
+        let severity = parse_name(
+            "severity",
+            rule.severity.as_deref(),
+            Severity::from_name,
+            "critical, high, medium, low, or info",
+            &mut errors,
+        );
+        let category = parse_name(
+            "category",
+            rule.category.as_deref(),
+            AnalyzerCategory::from_name,
+            "structure, dependencies, configuration, testing, security, documentation, performance, or cicd",
+            &mut errors,
+        );
+        let effort = parse_name(
+            "effort",
+            rule.effort.as_deref(),
+            Effort::from_name,
+            "trivial, small, medium, or large",
+            &mut errors,
+        );
+        let impact = parse_name(
+            "impact",
+            rule.impact.as_deref(),
+            Impact::from_name,
+            "low, medium, or high",
+            &mut errors,
+        );
+
+        match (glob, regex) {
+            (Some(glob), Some(regex)) if errors.is_empty() => compiled.push(CompiledRule {
+                rule: rule.clone(),
+                glob,
+                regex,
+                severity: severity.unwrap_or(Severity::Medium),
+                category: category.unwrap_or(AnalyzerCategory::Structure),
+                effort,
+                impact,
+            }),
+            _ => issues.push(Issue {
+                id: rule.id.clone(),
+                analyzer: "custom_rules".to_string(),
+                category: AnalyzerCategory::Configuration,
+                severity: Severity::Medium,
+                title: format!("Invalid custom rule: {}", rule.id),
+                description: format!(
+                    "Custom rule {} in .repodoctor.yml could not be compiled: {}",
+                    rule.id,
+                    errors.join("; ")
+                ),
+                file: Some(PathBuf::from(".repodoctor.yml")),
+                line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some("Fix the listed fields of this rule".to_string()),
+                auto_fixable: false,
+                references: vec![],
+            }),
+        }
+    }
+
+    compiled
+}
+
+/// Parse an optional `field` value, recording an error when it is set but unknown.
+fn parse_name<T>(
+    field: &str,
+    value: Option<&str>,
+    parse: fn(&str) -> Option<T>,
+    expected: &str,
+    errors: &mut Vec<String>,
+) -> Option<T> {
+    let value = value?;
+    let parsed = parse(value);
+    if parsed.is_none() {
+        errors.push(format!(
+            "unknown {} '{}' (expected {})",
+            field, value, expected
+        ));
+    }
+    parsed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::content::analyze_with_content;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs as stdfs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::Unknown,
+                language: Language::Unknown,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    const CONFIG: &str = r#"custom_rules:
+  - id: ACME-001
+    files: "src/**/*.ts"
+    pattern: "from ['\"]@acme/legacy-sdk"
+    severity: high
+    effort: small
+    impact: high
+    message: Deprecated internal SDK import
+    suggestion: Import from @acme/sdk instead
+    references: [https://wiki.acme.test/sdk-migration]
+"#;
+
+    #[tokio::test]
+    async fn test_custom_rule_matches() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(tmp.path().join(".repodoctor.yml"), CONFIG).unwrap();
+        stdfs::create_dir_all(tmp.path().join("src/api")).unwrap();
+        stdfs::write(
+            tmp.path().join("src/api/client.ts"),
+            "import { x } from 'lodash';\nimport { Client } from '@acme/legacy-sdk';\n",
+        )
+        .unwrap();
+        let project = make_project(&tmp);
+        let issues = analyze_with_content(&CustomRulesAnalyzer::default(), &project)
+            .await
+            .unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "ACME-001");
+        assert_eq!(issues[0].severity, Severity::High);
+        assert_eq!(issues[0].line, Some(2));
+        assert_eq!(issues[0].file, Some(PathBuf::from("src/api/client.ts")));
+        assert_eq!(issues[0].estimated_effort, Some(Effort::Small));
+        assert_eq!(issues[0].impact, Some(Impact::High));
+        assert_eq!(
+            issues[0].references,
+            vec!["https://wiki.acme.test/sdk-migration"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_custom_rule_respects_glob() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(tmp.path().join(".repodoctor.yml"), CONFIG).unwrap();
+        stdfs::create_dir_all(tmp.path().join("scripts")).unwrap();
+        stdfs::write(
+            tmp.path().join("scripts/legacy.ts"),
+            "import { Client } from '@acme/legacy-sdk';\n",
+        )
+        .unwrap();
+        let project = make_project(&tmp);
+        let issues = analyze_with_content(&CustomRulesAnalyzer::default(), &project)
+            .await
+            .unwrap();
+        assert!(issues.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_invalid_pattern_reported() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(
+            tmp.path().join(".repodoctor.yml"),
+            "custom_rules:\n  - id: BAD-001\n    files: \"**/*\"\n    pattern: \"(unclosed\"\n    message: Broken\n",
+        )
+        .unwrap();
+        let project = make_project(&tmp);
+        let issues = analyze_with_content(&CustomRulesAnalyzer::default(), &project)
+            .await
+            .unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].category, AnalyzerCategory::Configuration);
+        assert!(issues[0].title.contains("Invalid custom rule"));
+    }
+
+    #[tokio::test]
+    async fn test_applies_only_with_custom_rules() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+        assert!(!CustomRulesAnalyzer::default().applies_to(&project));
+
+        stdfs::write(tmp.path().join(".repodoctor.yml"), CONFIG).unwrap();
+        assert!(CustomRulesAnalyzer::default().applies_to(&project));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_names_reported() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(
+            tmp.path().join(".repodoctor.yml"),
+            "custom_rules:\n  - id: BAD-002\n    files: \"**/*\"\n    pattern: TODO\n    severity: hgih\n    category: style\n    message: Typo\n",
+        )
+        .unwrap();
+        stdfs::write(tmp.path().join("a.txt"), "TODO\n").unwrap();
+        let project = make_project(&tmp);
+        let issues = analyze_with_content(&CustomRulesAnalyzer::default(), &project)
+            .await
+            .unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].category, AnalyzerCategory::Configuration);
+        assert!(issues[0].description.contains("unknown severity 'hgih'"));
+        assert!(issues[0].description.contains("unknown category 'style'"));
+    }
+}
@@ -5,7 +5,7 @@ use std::path::Path;
 use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
 use crate::core::project::Project;
 use crate::frameworks::detector::{Framework, PackageManager};
-use crate::utils::fs::path_exists;
+use crate::utils::fs::{path_exists, read_to_string};
 
 pub struct DependenciesAnalyzer;
 
@@ -57,6 +57,11 @@ fn check_rust(path: &Path, issues: &mut Vec<Issue>) {
                 .to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Run `cargo build` to generate Cargo.lock".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -65,7 +70,7 @@ fn check_rust(path: &Path, issues: &mut Vec<Issue>) {
 
     // Parse Cargo.toml for dependencies
     let cargo_path = path.join("Cargo.toml");
-    if let Ok(content) = std::fs::read_to_string(&cargo_path) {
+    if let Ok(content) = read_to_string(&cargo_path) {
         let dep_count = count_cargo_dependencies(&content);
         if dep_count == 0 {
             issues.push(Issue {
@@ -77,6 +82,11 @@ fn check_rust(path: &Path, issues: &mut Vec<Issue>) {
                 description: "Cargo.toml has no [dependencies] entries.".to_string(),
                 file: Some(cargo_path.clone()),
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: None,
                 auto_fixable: false,
                 references: vec![],
@@ -93,6 +103,11 @@ fn check_rust(path: &Path, issues: &mut Vec<Issue>) {
                 ),
                 file: Some(cargo_path),
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some(
                     "Review dependencies and remove unused ones".to_string(),
                 ),
@@ -135,6 +150,11 @@ fn check_node(path: &Path, issues: &mut Vec<Issue>) {
             description: "No package-lock.json, yarn.lock, or pnpm-lock.yaml found.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Run `npm install` to generate a lock file".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -143,7 +163,7 @@ fn check_node(path: &Path, issues: &mut Vec<Issue>) {
 
     // Parse package.json
     let pkg_path = path.join("package.json");
-    if let Ok(content) = std::fs::read_to_string(&pkg_path) {
+    if let Ok(content) = read_to_string(&pkg_path) {
         if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
             let deps = json
                 .get("dependencies")
@@ -166,6 +186,11 @@ fn check_node(path: &Path, issues: &mut Vec<Issue>) {
                         .to_string(),
                     file: Some(pkg_path.clone()),
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: None,
                     auto_fixable: false,
                     references: vec![],
@@ -193,6 +218,11 @@ fn check_node(path: &Path, issues: &mut Vec<Issue>) {
                         ),
                         file: Some(pkg_path.clone()),
                         line: None,
+                        column: None,
+                        snippet: None,
+                        fingerprint: None,
+                        estimated_effort: None,
+                        impact: None,
                         suggestion: Some(
                             "Move development-only packages to devDependencies".to_string(),
                         ),
@@ -214,6 +244,11 @@ fn check_node(path: &Path, issues: &mut Vec<Issue>) {
                     ),
                     file: Some(pkg_path),
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some(
                         "Review dependencies and remove unused ones".to_string(),
                     ),
@@ -258,6 +293,11 @@ fn check_php(path: &Path, issues: &mut Vec<Issue>) {
                 .to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Run `composer install` to generate composer.lock".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -265,7 +305,7 @@ fn check_php(path: &Path, issues: &mut Vec<Issue>) {
     }
 
     let composer_path = path.join("composer.json");
-    if let Ok(content) = std::fs::read_to_string(&composer_path) {
+    if let Ok(content) = read_to_string(&composer_path) {
         if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
             let deps = json
                 .get("require")
@@ -289,6 +329,11 @@ fn check_php(path: &Path, issues: &mut Vec<Issue>) {
                         .to_string(),
                     file: Some(composer_path.clone()),
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: None,
                     auto_fixable: false,
                     references: vec![],
@@ -316,6 +361,11 @@ fn check_php(path: &Path, issues: &mut Vec<Issue>) {
                         ),
                         file: Some(composer_path.clone()),
                         line: None,
+                        column: None,
+                        snippet: None,
+                        fingerprint: None,
+                        estimated_effort: None,
+                        impact: None,
                         suggestion: Some(
                             "Move development-only packages to require-dev".to_string(),
                         ),
@@ -337,6 +387,11 @@ fn check_php(path: &Path, issues: &mut Vec<Issue>) {
                     ),
                     file: Some(composer_path),
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some(
                         "Review dependencies and remove unused ones".to_string(),
                     ),
@@ -374,6 +429,11 @@ fn check_flutter(path: &Path, issues: &mut Vec<Issue>) {
                 .to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Run `flutter pub get` to generate pubspec.lock".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -395,6 +455,11 @@ fn check_python(path: &Path, issues: &mut Vec<Issue>) {
             description: "No requirements.txt or pyproject.toml found.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: None,
             auto_fixable: false,
             references: vec![],
@@ -404,7 +469,7 @@ fn check_python(path: &Path, issues: &mut Vec<Issue>) {
     // Check for unpinned versions in requirements.txt
     if has_requirements {
         let req_path = path.join("requirements.txt");
-        if let Ok(content) = std::fs::read_to_string(&req_path) {
+        if let Ok(content) = read_to_string(&req_path) {
             let unpinned: Vec<String> = content
                 .lines()
                 .filter(|l| {
@@ -430,6 +495,11 @@ fn check_python(path: &Path, issues: &mut Vec<Issue>) {
                     ),
                     file: Some(req_path),
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some(
                         "Pin versions with == for reproducible builds (e.g., requests==2.28.0)"
                             .to_string(),
@@ -460,6 +530,11 @@ fn check_python(path: &Path, issues: &mut Vec<Issue>) {
                         .to_string(),
                     file: None,
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some(
                         "Run `poetry lock` to generate poetry.lock".to_string(),
                     ),
@@ -473,7 +548,7 @@ fn check_python(path: &Path, issues: &mut Vec<Issue>) {
 
 fn project_pm(path: &Path) -> Option<PackageManager> {
     if path.join("pyproject.toml").exists() {
-        if let Ok(content) = std::fs::read_to_string(path.join("pyproject.toml")) {
+        if let Ok(content) = read_to_string(path.join("pyproject.toml")) {
             if content.contains("[tool.poetry]") {
                 return Some(PackageManager::Poetry);
             }
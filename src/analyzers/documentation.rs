@@ -1,8 +1,13 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::{Path, PathBuf};
 
 use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
 use crate::core::project::Project;
+use crate::core::score::Grade;
+use crate::utils::fs::{find_file_ci, read_to_string};
 
 pub struct DocumentationAnalyzer;
 
@@ -29,9 +34,8 @@ impl Analyzer for DocumentationAnalyzer {
         let path = &project.path;
 
         // DOC-001: Check README exists and has minimum content
-        let readme_path = path.join("README.md");
-        if readme_path.exists() {
-            if let Ok(content) = std::fs::read_to_string(&readme_path) {
+        if let Some(readme_path) = find_file_ci(path, &["README.md"]) {
+            if let Ok(content) = read_to_string(&readme_path) {
                 let lines: Vec<&str> = content.lines().collect();
                 if lines.len() < 5 {
                     issues.push(Issue {
@@ -41,8 +45,13 @@ impl Analyzer for DocumentationAnalyzer {
                         severity: Severity::Medium,
                         title: "README.md is too short".to_string(),
                         description: "A good README should have at least a description, installation instructions, and usage examples.".to_string(),
-                        file: Some("README.md".into()),
+                        file: Some(readme_path.file_name().unwrap().into()),
                         line: None,
+                        column: None,
+                        snippet: None,
+                        fingerprint: None,
+                        estimated_effort: None,
+                        impact: None,
                         suggestion: Some("Add sections: Description, Installation, Usage".to_string()),
                         auto_fixable: false,
                         references: vec![],
@@ -62,20 +71,28 @@ impl Analyzer for DocumentationAnalyzer {
                                 severity: Severity::Low,
                                 title: format!("README.md missing {} section", section_name),
                                 description: format!("Consider adding a {} section to help users get started.", section_name),
-                                file: Some("README.md".into()),
+                                file: Some(readme_path.file_name().unwrap().into()),
                                 line: None,
+                                column: None,
+                                snippet: None,
+                                fingerprint: None,
+                                estimated_effort: None,
+                                impact: None,
                                 suggestion: Some(format!("Add a ## {} section", section_name)),
                                 auto_fixable: false,
                                 references: vec![],
                             });
                         }
                     }
+
+                    // DOC-010..DOC-012: README quality sub-score
+                    check_readme_quality(path, &readme_path, &content, &mut issues);
                 }
             }
         }
 
         // DOC-003: Check CONTRIBUTING.md exists
-        if !path.join("CONTRIBUTING.md").exists() {
+        if find_file_ci(path, &["CONTRIBUTING.md"]).is_none() {
             issues.push(Issue {
                 id: "DOC-003".to_string(),
                 analyzer: "documentation".to_string(),
@@ -85,6 +102,11 @@ impl Analyzer for DocumentationAnalyzer {
                 description: "A CONTRIBUTING.md helps new contributors understand how to participate.".to_string(),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Create a CONTRIBUTING.md with guidelines for contributors".to_string()),
                 auto_fixable: false,
                 references: vec![],
@@ -92,18 +114,8 @@ impl Analyzer for DocumentationAnalyzer {
         }
 
         // DOC-004: Check LICENSE file has content
-        let license_path = path.join("LICENSE");
-        let license_md_path = path.join("LICENSE.md");
-        let license_file = if license_path.exists() {
-            Some(license_path)
-        } else if license_md_path.exists() {
-            Some(license_md_path)
-        } else {
-            None
-        };
-
-        if let Some(lf) = license_file {
-            if let Ok(content) = std::fs::read_to_string(&lf) {
+        if let Some(lf) = find_file_ci(path, &["LICENSE", "LICENSE.md"]) {
+            if let Ok(content) = read_to_string(&lf) {
                 if content.trim().len() < 50 {
                     issues.push(Issue {
                         id: "DOC-004".to_string(),
@@ -114,6 +126,11 @@ impl Analyzer for DocumentationAnalyzer {
                         description: "The LICENSE file exists but has very little content.".to_string(),
                         file: Some(lf.file_name().unwrap().into()),
                         line: None,
+                        column: None,
+                        snippet: None,
+                        fingerprint: None,
+                        estimated_effort: None,
+                        impact: None,
                         suggestion: Some("Add a proper license text (MIT, Apache 2.0, etc.)".to_string()),
                         auto_fixable: false,
                         references: vec!["https://choosealicense.com".to_string()],
@@ -133,6 +150,11 @@ impl Analyzer for DocumentationAnalyzer {
                 description: "A code of conduct sets expectations for community behavior.".to_string(),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Add a CODE_OF_CONDUCT.md (e.g., Contributor Covenant)".to_string()),
                 auto_fixable: false,
                 references: vec!["https://www.contributor-covenant.org".to_string()],
@@ -143,6 +165,243 @@ impl Analyzer for DocumentationAnalyzer {
     }
 }
 
+// ---------------------------------------------------------------------------
+// README quality
+// ---------------------------------------------------------------------------
+
+static HEADING_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^#{1,6}\s+(.+)$").unwrap());
+
+/// Markdown links and images, and HTML `href`/`src` attributes.
+static LINK_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"\]\(\s*<?([^)\s>]+)>?(?:\s+"[^"]*")?\s*\)|(?:href|src)="([^"]+)""#).unwrap()
+});
+
+static BADGE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\[!\[[^\]]*\]\(|shields\.io|badge\.svg|/badge/").unwrap());
+
+static PLACEHOLDER_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"\b(?:TODO|TBD|FIXME|XXX)\b|(?i:lorem ipsum|your[-_ ]?project[-_ ]?name|<project[-_ ]?name>|coming soon|insert [a-z ]+ here)",
+    )
+    .unwrap()
+});
+
+/// Weighted README checks behind the DOC-010 grade.
+pub struct ReadmeQuality {
+    pub score: u8,
+    pub grade: Grade,
+    /// What cost points, for the issue description
+    pub findings: Vec<String>,
+    /// `(line, target)` of relative links that do not resolve
+    pub broken_links: Vec<(usize, String)>,
+    /// `(line, text)` of TODO/placeholder lines
+    pub placeholders: Vec<(usize, String)>,
+}
+
+/// Lines outside fenced code blocks, 1-based.
+fn prose_lines(content: &str) -> Vec<(usize, &str)> {
+    let mut in_fence = false;
+    let mut lines = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        if line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if !in_fence {
+            lines.push((i + 1, line));
+        }
+    }
+    lines
+}
+
+/// Where a relative link points, or `None` for external links and anchors.
+fn link_target(root: &Path, readme_dir: &Path, link: &str) -> Option<PathBuf> {
+    if link.contains("://")
+        || link.starts_with('#')
+        || link.starts_with("mailto:")
+        || link.starts_with("data:")
+        || link.starts_with("//")
+    {
+        return None;
+    }
+    let path = link.split(['#', '?']).next().unwrap_or(link).replace("%20", " ");
+    if path.is_empty() {
+        return None;
+    }
+    Some(match path.strip_prefix('/') {
+        Some(from_root) => root.join(from_root),
+        None => readme_dir.join(path),
+    })
+}
+
+pub fn assess_readme(root: &Path, readme: &Path, content: &str) -> ReadmeQuality {
+    let lines = prose_lines(content);
+    let headings: Vec<String> = lines
+        .iter()
+        .filter_map(|(_, line)| HEADING_RE.captures(line))
+        .map(|caps| caps[1].to_lowercase())
+        .collect();
+    let has_heading = |words: &[&str]| headings.iter().any(|h| words.iter().any(|w| h.contains(w)));
+    let lower = content.to_lowercase();
+
+    let mut score: u32 = 0;
+    let mut findings = Vec::new();
+
+    // Length (20)
+    let words = content.split_whitespace().count();
+    score += match words {
+        300.. => 20,
+        100..=299 => 10,
+        _ => 0,
+    };
+    if words < 300 {
+        findings.push(format!("only {} words", words));
+    }
+
+    // Sections (50)
+    let sections: [(&str, &[&str], u32); 3] = [
+        ("installation", &["install", "getting started", "setup"], 15),
+        ("usage", &["usage", "example", "quick start", "how to use"], 15),
+        ("license", &["license", "licence"], 10),
+    ];
+    for (name, words, points) in sections {
+        if has_heading(words) || (name == "license" && lower.contains("](license")) {
+            score += points;
+        } else {
+            findings.push(format!("no {} section", name));
+        }
+    }
+    if lines.iter().any(|(_, line)| BADGE_RE.is_match(line)) {
+        score += 10;
+    } else {
+        findings.push("no badges".to_string());
+    }
+
+    // Relative links (15)
+    let readme_dir = readme.parent().unwrap_or(root);
+    let mut broken_links = Vec::new();
+    for (line_num, line) in &lines {
+        for caps in LINK_RE.captures_iter(line) {
+            let link = caps.get(1).or(caps.get(2)).unwrap().as_str();
+            if let Some(target) = link_target(root, readme_dir, link) {
+                if !target.exists() {
+                    broken_links.push((*line_num, link.to_string()));
+                }
+            }
+        }
+    }
+    score += 15u32.saturating_sub(5 * broken_links.len() as u32);
+    if !broken_links.is_empty() {
+        findings.push(format!("{} broken relative link(s)", broken_links.len()));
+    }
+
+    // Placeholder text (15)
+    let placeholders: Vec<(usize, String)> = lines
+        .iter()
+        .filter(|(_, line)| PLACEHOLDER_RE.is_match(line))
+        .map(|(n, line)| (*n, line.trim().to_string()))
+        .collect();
+    score += 15u32.saturating_sub(5 * placeholders.len() as u32);
+    if !placeholders.is_empty() {
+        findings.push(format!("{} TODO/placeholder line(s)", placeholders.len()));
+    }
+
+    let score = score.min(100) as u8;
+    ReadmeQuality {
+        score,
+        grade: Grade::from_score(score),
+        findings,
+        broken_links,
+        placeholders,
+    }
+}
+
+fn check_readme_quality(root: &Path, readme: &Path, content: &str, issues: &mut Vec<Issue>) {
+    let quality = assess_readme(root, readme, content);
+    let file: PathBuf = readme.file_name().unwrap().into();
+
+    // The grade is the sub-score: its severity is what the Documentation category loses
+    let severity = match quality.grade {
+        Grade::A => None,
+        Grade::B => Some(Severity::Info),
+        Grade::C => Some(Severity::Low),
+        Grade::D => Some(Severity::Medium),
+        Grade::F => Some(Severity::High),
+    };
+    if let Some(severity) = severity {
+        issues.push(Issue {
+            id: "DOC-010".to_string(),
+            analyzer: "documentation".to_string(),
+            category: AnalyzerCategory::Documentation,
+            severity,
+            title: format!("README quality: {} ({}/100)", quality.grade, quality.score),
+            description: format!("README.md loses points for: {}.", quality.findings.join(", ")),
+            file: Some(file.clone()),
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Address the listed gaps to raise the README grade".to_string()),
+            auto_fixable: false,
+            references: vec!["https://www.makeareadme.com".to_string()],
+        });
+    }
+
+    // Details are Info so they do not count twice against the grade
+    let lines: Vec<&str> = content.lines().collect();
+    for (line_num, link) in &quality.broken_links {
+        let line = lines[line_num - 1];
+        issues.push(
+            Issue {
+                id: "DOC-011".to_string(),
+                analyzer: "documentation".to_string(),
+                category: AnalyzerCategory::Documentation,
+                severity: Severity::Info,
+                title: format!("Broken README link: {}", link),
+                description: format!("README.md links to {}, which does not exist.", link),
+                file: Some(file.clone()),
+                line: Some(*line_num),
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some("Fix the path or remove the link".to_string()),
+                auto_fixable: false,
+                references: vec![],
+            }
+            .with_snippet(line, line.find(link.as_str()).unwrap_or(0)),
+        );
+    }
+    for (line_num, text) in &quality.placeholders {
+        let line = lines[line_num - 1];
+        let start = PLACEHOLDER_RE.find(line).map_or(0, |m| m.start());
+        issues.push(
+            Issue {
+                id: "DOC-012".to_string(),
+                analyzer: "documentation".to_string(),
+                category: AnalyzerCategory::Documentation,
+                severity: Severity::Info,
+                title: "Placeholder text in README".to_string(),
+                description: format!("README.md still contains placeholder text: {}", text),
+                file: Some(file.clone()),
+                line: Some(*line_num),
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some("Replace the placeholder with real content".to_string()),
+                auto_fixable: false,
+                references: vec![],
+            }
+            .with_snippet(line, start),
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -254,4 +513,55 @@ mod tests {
         let issues = DocumentationAnalyzer.analyze(&project).await.unwrap();
         assert!(!issues.iter().any(|i| i.id == "DOC-005"));
     }
+
+    fn complete_readme() -> String {
+        let filler = "This tool checks repositories and reports problems with a score. ".repeat(30);
+        format!(
+            "# Tool\n\n[![CI](https://img.shields.io/badge/ci-passing-green)](https://ci)\n\n{}\n\n## Installation\n\n```sh\ncargo install tool # TODO in code is fine\n```\n\n## Usage\n\nSee [the guide](docs/guide.md#start).\n\n## License\n\nMIT\n",
+            filler
+        )
+    }
+
+    #[tokio::test]
+    async fn test_complete_readme_gets_grade_a() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("docs/guide.md"), "# Guide\n").unwrap();
+        let readme = tmp.path().join("README.md");
+        fs::write(&readme, complete_readme()).unwrap();
+
+        let quality = assess_readme(tmp.path(), &readme, &complete_readme());
+        assert_eq!(quality.score, 100, "{:?}", quality.findings);
+        let issues = DocumentationAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        assert!(!issues.iter().any(|i| i.id.starts_with("DOC-01")));
+    }
+
+    #[tokio::test]
+    async fn test_readme_quality_findings() {
+        let tmp = TempDir::new().unwrap();
+        let content = "# Your Project Name\n\nTODO: describe.\n\n## Usage\n\nSee [docs](docs/missing.md) and [site](https://example.com).\n\n![logo](/assets/logo.png)\n";
+        fs::write(tmp.path().join("README.md"), content).unwrap();
+
+        let quality = assess_readme(tmp.path(), &tmp.path().join("README.md"), content);
+        // usage 15 + links 15-10 + placeholders 15-10
+        assert_eq!(quality.score, 25);
+        assert_eq!(quality.grade, Grade::F);
+        assert_eq!(
+            quality.broken_links,
+            vec![(7, "docs/missing.md".to_string()), (9, "/assets/logo.png".to_string())]
+        );
+
+        let issues = DocumentationAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        let graded = issues.iter().find(|i| i.id == "DOC-010").unwrap();
+        assert_eq!(graded.title, "README quality: F (25/100)");
+        assert_eq!(graded.severity, Severity::High);
+        assert!(graded.description.contains("no installation section"));
+
+        let links: Vec<_> = issues.iter().filter(|i| i.id == "DOC-011").collect();
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].column, Some(12));
+        let placeholders: Vec<_> = issues.iter().filter(|i| i.id == "DOC-012").collect();
+        assert_eq!(placeholders.iter().map(|i| i.line).collect::<Vec<_>>(), vec![Some(1), Some(3)]);
+        assert!(placeholders.iter().all(|i| i.severity == Severity::Info));
+    }
 }
@@ -0,0 +1,423 @@
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::project::Project;
+use crate::utils::fs::{read_to_string, walk};
+use crate::utils::git;
+
+/// Config files whose key/value settings are compared against the template.
+const KEY_CONFIG_FILES: &[&str] = &[
+    ".editorconfig",
+    ".nvmrc",
+    ".node-version",
+    ".python-version",
+    ".tool-versions",
+    "rust-toolchain",
+    "rust-toolchain.toml",
+    "Cargo.toml",
+    "package.json",
+    "pyproject.toml",
+];
+
+/// `package.json` fields compared against the template; other fields are
+/// expected to differ between services.
+const PACKAGE_JSON_KEYS: &[&str] = &["engines", "packageManager", "type"];
+
+/// Compares a project against a reference template repository passed with
+/// `--template <PATH|URL>` and reports where it has drifted: missing files,
+/// missing or modified CI workflows, and changed key config values.
+///
+/// Only top-level files and the `.github/` tree of the template are compared;
+/// source code is expected to diverge.
+pub struct DriftAnalyzer {
+    root: PathBuf,
+    // Keeps a cloned template alive for as long as the analyzer.
+    _clone: Option<TempDir>,
+}
+
+impl DriftAnalyzer {
+    /// Use a local directory or shallow-clone a git URL as the template.
+    pub fn from_source(source: &str) -> Result<Self> {
+        if git::is_git_url(source) {
+            let clone = git::shallow_clone(source)?;
+            return Ok(Self {
+                root: clone.path().to_path_buf(),
+                _clone: Some(clone),
+            });
+        }
+        let root = PathBuf::from(source);
+        if !root.is_dir() {
+            bail!("Template '{}' is not a directory", source);
+        }
+        Ok(Self { root, _clone: None })
+    }
+
+    /// Hash of the template files the analyzer compares, so cached results
+    /// are tied to the template's content rather than its location.
+    pub fn fingerprint(&self) -> String {
+        let mut hasher = Sha256::new();
+        for file in self.template_files() {
+            hasher.update(file.as_bytes());
+            hasher.update([0]);
+            hasher.update(std::fs::read(self.root.join(&file)).unwrap_or_default());
+            hasher.update([0]);
+        }
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    /// Template files to check, relative to the template root.
+    fn template_files(&self) -> Vec<String> {
+        let mut files = Vec::new();
+        if let Ok(entries) = std::fs::read_dir(&self.root) {
+            for entry in entries.filter_map(|e| e.ok()) {
+                if entry.file_type().is_ok_and(|t| t.is_file()) {
+                    files.push(entry.file_name().to_string_lossy().to_string());
+                }
+            }
+        }
+        for entry in walk(&self.root.join(".github"))
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+        {
+            if let Ok(relative) = entry.path().strip_prefix(&self.root) {
+                files.push(relative.to_string_lossy().replace('\\', "/"));
+            }
+        }
+        files.sort();
+        files
+    }
+
+    fn issue(
+        &self,
+        id: &str,
+        severity: Severity,
+        title: String,
+        description: String,
+        file: &str,
+        suggestion: String,
+    ) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: self.name().to_string(),
+            // Missing files are structural, workflows are CI/CD, the rest is config drift
+            category: match id {
+                "DRF-001" => AnalyzerCategory::Structure,
+                "DRF-002" | "DRF-003" => AnalyzerCategory::CiCd,
+                _ => AnalyzerCategory::Configuration,
+            },
+            severity,
+            title,
+            description,
+            file: Some(PathBuf::from(file)),
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some(suggestion),
+            auto_fixable: false,
+            references: vec![],
+        }
+    }
+}
+
+fn is_workflow(relative: &str) -> bool {
+    relative.starts_with(".github/workflows/")
+        && (relative.ends_with(".yml") || relative.ends_with(".yaml"))
+}
+
+/// Flatten a config file into `key -> value` pairs for comparison.
+fn config_values(name: &str, content: &str) -> BTreeMap<String, String> {
+    match name {
+        "package.json" => json_values(content),
+        ".editorconfig" | "Cargo.toml" | "pyproject.toml" | "rust-toolchain.toml" => {
+            ini_values(content)
+        }
+        ".tool-versions" => content
+            .lines()
+            .filter_map(|line| line.split_once(char::is_whitespace))
+            .map(|(tool, version)| (tool.to_string(), version.trim().to_string()))
+            .collect(),
+        _ => BTreeMap::from([("version".to_string(), content.trim().to_string())]),
+    }
+}
+
+/// `key = value` lines, prefixed with their `[section]`. Good enough for
+/// `.editorconfig` and the scalar settings of TOML files.
+fn ini_values(content: &str) -> BTreeMap<String, String> {
+    let mut values = BTreeMap::new();
+    let mut section = String::new();
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line.trim_matches(|c| c == '[' || c == ']').to_string();
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            let key = key.trim();
+            let key = if section.is_empty() {
+                key.to_string()
+            } else {
+                format!("{}.{}", section, key)
+            };
+            values.insert(key, value.trim().trim_matches('"').to_string());
+        }
+    }
+    values
+}
+
+fn json_values(content: &str) -> BTreeMap<String, String> {
+    let mut values = BTreeMap::new();
+    let Ok(serde_json::Value::Object(root)) = serde_json::from_str(content) else {
+        return values;
+    };
+    for key in PACKAGE_JSON_KEYS {
+        match root.get(*key) {
+            Some(serde_json::Value::Object(nested)) => {
+                for (name, value) in nested {
+                    values.insert(format!("{}.{}", key, name), json_scalar(value));
+                }
+            }
+            Some(value) => {
+                values.insert(key.to_string(), json_scalar(value));
+            }
+            None => {}
+        }
+    }
+    values
+}
+
+fn json_scalar(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn normalize(content: &str) -> String {
+    content
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[async_trait]
+impl Analyzer for DriftAnalyzer {
+    fn name(&self) -> &'static str {
+        "drift"
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports drift from a reference template repository"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Structure
+    }
+
+    fn applies_to(&self, project: &Project) -> bool {
+        // Comparing a template with itself is never useful
+        project.path != self.root
+    }
+
+    async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+
+        for relative in self.template_files() {
+            let template_file = self.root.join(&relative);
+            let project_file = project.path.join(&relative);
+
+            // DRF-001 / DRF-002: Files and CI workflows missing from the project
+            if !project_file.exists() {
+                issues.push(if is_workflow(&relative) {
+                    self.issue(
+                        "DRF-002",
+                        Severity::High,
+                        format!("CI workflow missing: {}", relative),
+                        format!(
+                            "The template defines the '{}' workflow but this project does not.",
+                            relative
+                        ),
+                        &relative,
+                        format!("Copy '{}' from the template repository", relative),
+                    )
+                } else {
+                    self.issue(
+                        "DRF-001",
+                        Severity::Medium,
+                        format!("Template file missing: {}", relative),
+                        format!(
+                            "'{}' exists in the template repository but not in this project.",
+                            relative
+                        ),
+                        &relative,
+                        format!("Add '{}' based on the template", relative),
+                    )
+                });
+                continue;
+            }
+
+            let (Ok(expected), Ok(actual)) = (
+                read_to_string(&template_file),
+                read_to_string(&project_file),
+            ) else {
+                continue;
+            };
+
+            // DRF-003: CI workflow modified
+            if is_workflow(&relative) {
+                if normalize(&expected) != normalize(&actual) {
+                    issues.push(self.issue(
+                        "DRF-003",
+                        Severity::Low,
+                        format!("CI workflow differs from template: {}", relative),
+                        format!(
+                            "'{}' has been modified compared to the template repository.",
+                            relative
+                        ),
+                        &relative,
+                        "Review the changes and sync the workflow with the template".to_string(),
+                    ));
+                }
+                continue;
+            }
+
+            // DRF-004: Key config values changed
+            if KEY_CONFIG_FILES.contains(&relative.as_str()) {
+                let expected = config_values(&relative, &expected);
+                let actual = config_values(&relative, &actual);
+                for (key, value) in &expected {
+                    let found = actual.get(key);
+                    if found == Some(value) {
+                        continue;
+                    }
+                    issues.push(self.issue(
+                        "DRF-004",
+                        Severity::Medium,
+                        format!("Config value drift in {}: {}", relative, key),
+                        format!(
+                            "Template sets '{}' to '{}', project has '{}'.",
+                            key,
+                            value,
+                            found.map(String::as_str).unwrap_or("(unset)")
+                        ),
+                        &relative,
+                        format!("Set '{}' to '{}' in {}", key, value, relative),
+                    ));
+                }
+            }
+        }
+
+        Ok(issues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn make_template() -> TempDir {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join(".github/workflows")).unwrap();
+        fs::write(tmp.path().join(".github/workflows/ci.yml"), "on: push\njobs: {}\n").unwrap();
+        fs::write(tmp.path().join(".github/workflows/release.yml"), "on: tag\n").unwrap();
+        fs::write(tmp.path().join(".editorconfig"), "root = true\n[*]\nindent_size = 2\n").unwrap();
+        fs::write(tmp.path().join("CODEOWNERS"), "* @platform\n").unwrap();
+        fs::create_dir_all(tmp.path().join("src")).unwrap();
+        fs::write(tmp.path().join("src/main.rs"), "fn main() {}\n").unwrap();
+        tmp
+    }
+
+    async fn drift(template: &TempDir, project: &TempDir) -> Vec<Issue> {
+        let analyzer = DriftAnalyzer::from_source(&template.path().to_string_lossy()).unwrap();
+        let project = Project::new(project.path()).unwrap();
+        analyzer.analyze(&project).await.unwrap()
+    }
+
+    #[test]
+    fn test_fingerprint_follows_content() {
+        let fingerprint = |template: &TempDir| {
+            DriftAnalyzer::from_source(&template.path().to_string_lossy())
+                .unwrap()
+                .fingerprint()
+        };
+        let (first, second) = (make_template(), make_template());
+        assert_eq!(fingerprint(&first), fingerprint(&second));
+
+        fs::write(second.path().join("CODEOWNERS"), "* @security\n").unwrap();
+        assert_ne!(fingerprint(&first), fingerprint(&second));
+    }
+
+    #[tokio::test]
+    async fn test_identical_project_has_no_drift() {
+        let template = make_template();
+        let project = make_template();
+        assert!(drift(&template, &project).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reports_missing_files_and_workflows() {
+        let template = make_template();
+        let project = TempDir::new().unwrap();
+        let issues = drift(&template, &project).await;
+
+        let ids: Vec<&str> = issues.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids.iter().filter(|id| **id == "DRF-002").count(), 2);
+        assert!(issues
+            .iter()
+            .any(|i| i.id == "DRF-001" && i.file == Some(PathBuf::from("CODEOWNERS"))));
+        // Source files are not part of the comparison
+        assert!(!issues.iter().any(|i| i.title.contains("main.rs")));
+    }
+
+    #[tokio::test]
+    async fn test_reports_modified_workflow_and_config_values() {
+        let template = make_template();
+        let project = make_template();
+        fs::write(
+            project.path().join(".github/workflows/ci.yml"),
+            "on: [push, pull_request]\njobs: {}\n",
+        )
+        .unwrap();
+        fs::write(project.path().join(".editorconfig"), "root = true\n[*]\nindent_size = 4\n")
+            .unwrap();
+
+        let issues = drift(&template, &project).await;
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().any(|i| i.id == "DRF-003"));
+        let config = issues.iter().find(|i| i.id == "DRF-004").unwrap();
+        assert!(config.title.ends_with("*.indent_size"));
+        assert!(config.description.contains("'2'"));
+    }
+
+    #[test]
+    fn test_package_json_values() {
+        let values = json_values(
+            r#"{"name": "svc", "type": "module", "engines": {"node": ">=20"}, "packageManager": "pnpm@9.0.0"}"#,
+        );
+        assert_eq!(values.get("engines.node").map(String::as_str), Some(">=20"));
+        assert_eq!(values.get("type").map(String::as_str), Some("module"));
+        assert!(!values.contains_key("name"));
+    }
+
+    #[test]
+    fn test_missing_template_dir_fails() {
+        assert!(DriftAnalyzer::from_source("/nonexistent/template").is_err());
+    }
+}
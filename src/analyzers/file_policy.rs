@@ -0,0 +1,252 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::config::{Config, FilePolicy};
+use crate::core::project::Project;
+
+pub struct FilePolicyAnalyzer;
+
+#[async_trait]
+impl Analyzer for FilePolicyAnalyzer {
+    fn name(&self) -> &'static str {
+        "file_policy"
+    }
+
+    fn description(&self) -> &'static str {
+        "Enforces required and forbidden paths declared in the file_policies section of .repodoctor.yml"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Structure
+    }
+
+    fn applies_to(&self, project: &Project) -> bool {
+        Config::load(&project.path)
+            .file_policies
+            .is_some_and(|policies| !policies.is_empty())
+    }
+
+    async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+        let config = Config::load(&project.path);
+
+        for policy in config.file_policies.unwrap_or_default() {
+            check_policy(&project.path, &policy, &mut issues);
+        }
+
+        Ok(issues)
+    }
+}
+
+fn check_policy(path: &Path, policy: &FilePolicy, issues: &mut Vec<Issue>) {
+    let category = policy
+        .category
+        .as_deref()
+        .and_then(AnalyzerCategory::from_name)
+        .unwrap_or(AnalyzerCategory::Structure);
+    let severity = |default: Severity| {
+        policy
+            .severity
+            .as_deref()
+            .and_then(Severity::from_name)
+            .unwrap_or(default)
+    };
+    let references = policy.references.clone().unwrap_or_default();
+
+    if let Some(required) = &policy.require {
+        if matching_paths(path, required).is_empty() {
+            issues.push(Issue {
+                id: policy.id.clone(),
+                analyzer: "file_policy".to_string(),
+                category: category.clone(),
+                severity: severity(Severity::Medium),
+                title: policy
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| format!("Required path missing: {}", required)),
+                description: format!(
+                    "Policy {} requires '{}' to exist in the repository.",
+                    policy.id, required
+                ),
+                file: None,
+                line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some(format!("Create '{}'", required)),
+                auto_fixable: false,
+                references: references.clone(),
+            });
+        }
+    }
+
+    if let Some(forbidden) = &policy.forbid {
+        for found in matching_paths(path, forbidden) {
+            let relative = found.strip_prefix(path).unwrap_or(&found).to_path_buf();
+            issues.push(Issue {
+                id: policy.id.clone(),
+                analyzer: "file_policy".to_string(),
+                category: category.clone(),
+                severity: severity(Severity::High),
+                title: policy
+                    .message
+                    .clone()
+                    .unwrap_or_else(|| format!("Forbidden path present: {}", relative.display())),
+                description: format!(
+                    "Policy {} forbids '{}' in the repository.",
+                    policy.id, forbidden
+                ),
+                file: Some(relative.clone()),
+                line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some(format!("Remove '{}' from the repository", relative.display())),
+                auto_fixable: false,
+                references: references.clone(),
+            });
+        }
+    }
+
+    if let Some(alternatives) = &policy.require_one_of {
+        let satisfied = alternatives
+            .iter()
+            .any(|alt| !matching_paths(path, alt).is_empty());
+        if !satisfied && !alternatives.is_empty() {
+            issues.push(Issue {
+                id: policy.id.clone(),
+                analyzer: "file_policy".to_string(),
+                category,
+                severity: severity(Severity::Medium),
+                title: policy.message.clone().unwrap_or_else(|| {
+                    format!("None of the required paths exist: {}", alternatives.join(" | "))
+                }),
+                description: format!(
+                    "Policy {} requires at least one of: {}",
+                    policy.id,
+                    alternatives.join(", ")
+                ),
+                file: None,
+                line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some(format!("Create one of: {}", alternatives.join(", "))),
+                auto_fixable: false,
+                references: references.clone(),
+            });
+        }
+    }
+}
+
+/// Resolve a policy path, expanding glob patterns relative to the project root.
+fn matching_paths(base: &Path, pattern: &str) -> Vec<PathBuf> {
+    let pattern = pattern.trim_end_matches('/');
+    if !pattern.contains(['*', '?', '[']) {
+        let candidate = base.join(pattern);
+        return if candidate.exists() {
+            vec![candidate]
+        } else {
+            vec![]
+        };
+    }
+
+    let full = base.join(pattern);
+    match glob::glob(&full.to_string_lossy()) {
+        Ok(paths) => paths.filter_map(|p| p.ok()).collect(),
+        Err(_) => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs as stdfs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::Unknown,
+                language: Language::Unknown,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    const CONFIG: &str = r#"file_policies:
+  - id: ORG-001
+    require: docs/ADR-template.md
+  - id: ORG-002
+    forbid: config/secrets.json
+  - id: ORG-003
+    require_one_of: [Jenkinsfile, .github/workflows]
+    severity: high
+    message: CI configuration is required
+    references: [https://wiki.acme.test/ci]
+  - id: ORG-004
+    forbid: "**/*.pem"
+"#;
+
+    #[tokio::test]
+    async fn test_policies_report_violations() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(tmp.path().join(".repodoctor.yml"), CONFIG).unwrap();
+        stdfs::create_dir_all(tmp.path().join("config")).unwrap();
+        stdfs::write(tmp.path().join("config/secrets.json"), "{}").unwrap();
+        stdfs::create_dir_all(tmp.path().join("certs")).unwrap();
+        stdfs::write(tmp.path().join("certs/server.pem"), "key").unwrap();
+
+        let project = make_project(&tmp);
+        let issues = FilePolicyAnalyzer.analyze(&project).await.unwrap();
+
+        let required = issues.iter().find(|i| i.id == "ORG-001").unwrap();
+        assert!(required.references.is_empty());
+        let forbidden = issues.iter().find(|i| i.id == "ORG-002").unwrap();
+        assert_eq!(forbidden.severity, Severity::High);
+        assert_eq!(forbidden.file, Some(PathBuf::from("config/secrets.json")));
+        let ci = issues.iter().find(|i| i.id == "ORG-003").unwrap();
+        assert_eq!(ci.severity, Severity::High);
+        assert_eq!(ci.title, "CI configuration is required");
+        assert_eq!(ci.references, vec!["https://wiki.acme.test/ci"]);
+        assert!(issues
+            .iter()
+            .any(|i| i.id == "ORG-004" && i.file == Some(PathBuf::from("certs/server.pem"))));
+    }
+
+    #[tokio::test]
+    async fn test_policies_satisfied() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(tmp.path().join(".repodoctor.yml"), CONFIG).unwrap();
+        stdfs::create_dir_all(tmp.path().join("docs")).unwrap();
+        stdfs::write(tmp.path().join("docs/ADR-template.md"), "# ADR").unwrap();
+        stdfs::create_dir_all(tmp.path().join(".github/workflows")).unwrap();
+
+        let project = make_project(&tmp);
+        let issues = FilePolicyAnalyzer.analyze(&project).await.unwrap();
+        assert!(issues.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_applies_only_with_policies() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+        assert!(!FilePolicyAnalyzer.applies_to(&project));
+
+        stdfs::write(tmp.path().join(".repodoctor.yml"), CONFIG).unwrap();
+        assert!(FilePolicyAnalyzer.applies_to(&project));
+    }
+}
@@ -1,11 +1,12 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use std::path::Path;
-use walkdir::WalkDir;
 
 use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::content::{ContentMatcher, FirstMatchPerFile, MAX_FILE_BYTES};
 use crate::core::project::Project;
 use crate::frameworks::detector::Framework;
+use crate::utils::fs::{read_text, read_to_string, walk, TextFile};
 
 pub struct FlutterAnalyzer;
 
@@ -21,7 +22,7 @@ struct PubspecYaml {
 
 impl PubspecYaml {
     fn parse(path: &Path) -> Option<Self> {
-        let content = std::fs::read_to_string(path.join("pubspec.yaml")).ok()?;
+        let content = read_to_string(path.join("pubspec.yaml")).ok()?;
         let yaml: serde_yaml::Value = serde_yaml::from_str(&content).ok()?;
 
         let description = yaml
@@ -143,12 +144,13 @@ impl Analyzer for FlutterAnalyzer {
             check_missing_flutter_test(p, path, &mut issues);
         }
 
-        // Security checks
-        check_http_urls(path, &mut issues);
-        check_debug_prints(path, &mut issues);
-
         Ok(issues)
     }
+
+    fn content_matchers(&self, _project: &Project) -> Vec<Box<dyn ContentMatcher>> {
+        // Security checks
+        vec![Box::new(http_urls_matcher()), Box::new(debug_prints_matcher())]
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -157,7 +159,7 @@ impl Analyzer for FlutterAnalyzer {
 
 fn check_main_dart_too_large(path: &Path, issues: &mut Vec<Issue>) {
     let main_dart = path.join("lib/main.dart");
-    let content = match std::fs::read_to_string(&main_dart) {
+    let content = match read_to_string(&main_dart) {
         Ok(c) => c,
         Err(_) => return,
     };
@@ -176,6 +178,11 @@ fn check_main_dart_too_large(path: &Path, issues: &mut Vec<Issue>) {
             ),
             file: Some(main_dart),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Extract widgets and business logic into separate files under lib/".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -226,6 +233,11 @@ fn check_no_architecture(path: &Path, issues: &mut Vec<Issue>) {
             ),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create subdirectories like lib/screens/, lib/widgets/, lib/models/".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -254,6 +266,11 @@ fn check_missing_platform_icons(path: &Path, issues: &mut Vec<Issue>) {
                 ),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some(format!("Add proper icon assets for {} platform", platform)),
                 auto_fixable: false,
                 references: vec![],
@@ -264,7 +281,7 @@ fn check_missing_platform_icons(path: &Path, issues: &mut Vec<Issue>) {
 
 fn check_gitignore_entries(path: &Path, issues: &mut Vec<Issue>) {
     let gitignore_path = path.join(".gitignore");
-    let content = match std::fs::read_to_string(&gitignore_path) {
+    let content = match read_to_string(&gitignore_path) {
         Ok(c) => c,
         Err(_) => return,
     };
@@ -296,6 +313,11 @@ fn check_gitignore_entries(path: &Path, issues: &mut Vec<Issue>) {
             ),
             file: Some(gitignore_path),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some(format!("Add {} to .gitignore", missing.join(", "))),
             auto_fixable: true,
             references: vec![],
@@ -318,12 +340,17 @@ fn check_missing_description(pubspec: &PubspecYaml, path: &Path, issues: &mut Ve
         issues.push(Issue {
             id: "FLT-010".to_string(),
             analyzer: "flutter".to_string(),
-            category: AnalyzerCategory::Configuration,
+            category: AnalyzerCategory::Documentation,
             severity: Severity::Low,
             title: "Missing description in pubspec.yaml".to_string(),
             description: "pubspec.yaml is missing a description field.".to_string(),
             file: Some(path.join("pubspec.yaml")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Add a meaningful description field to pubspec.yaml".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -361,6 +388,11 @@ fn check_sdk_constraint(pubspec: &PubspecYaml, path: &Path, issues: &mut Vec<Iss
             ),
             file: Some(path.join("pubspec.yaml")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Update SDK constraint to '^3.0.0' or higher".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -370,7 +402,7 @@ fn check_sdk_constraint(pubspec: &PubspecYaml, path: &Path, issues: &mut Vec<Iss
 
 fn check_android_signing(path: &Path, issues: &mut Vec<Issue>) {
     let gradle_path = path.join("android/app/build.gradle");
-    let content = match std::fs::read_to_string(&gradle_path) {
+    let content = match read_to_string(&gradle_path) {
         Ok(c) => c,
         Err(_) => return,
     };
@@ -385,6 +417,11 @@ fn check_android_signing(path: &Path, issues: &mut Vec<Issue>) {
             description: "android/app/build.gradle exists but has no signingConfigs for release builds.".to_string(),
             file: Some(gradle_path),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Add signingConfigs for release builds in build.gradle".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -403,6 +440,11 @@ fn check_ios_info_plist(path: &Path, issues: &mut Vec<Issue>) {
             description: "ios/ directory exists but ios/Runner/Info.plist is missing.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Run `flutter create .` to regenerate iOS platform files".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -444,6 +486,11 @@ fn check_dev_deps_in_dependencies(pubspec: &PubspecYaml, path: &Path, issues: &m
             ),
             file: Some(path.join("pubspec.yaml")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Move these packages to dev_dependencies in pubspec.yaml".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -462,6 +509,11 @@ fn check_git_dependencies(pubspec: &PubspecYaml, path: &Path, issues: &mut Vec<I
             description: "Dependencies using git: source can be unstable and hard to reproduce.".to_string(),
             file: Some(path.join("pubspec.yaml")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Consider publishing packages to pub.dev or using path dependencies".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -479,7 +531,7 @@ fn check_no_widget_tests(path: &Path, issues: &mut Vec<Issue>) {
         return;
     }
 
-    let has_widget_test = WalkDir::new(&test_dir)
+    let has_widget_test = walk(&test_dir)
         .into_iter()
         .filter_map(|e| e.ok())
         .filter(|e| {
@@ -487,9 +539,10 @@ fn check_no_widget_tests(path: &Path, issues: &mut Vec<Issue>) {
                 && e.file_name().to_string_lossy().ends_with(".dart")
         })
         .any(|e| {
-            std::fs::read_to_string(e.path())
-                .map(|c| c.contains("testWidgets"))
-                .unwrap_or(false)
+            matches!(
+                read_text(e.path(), MAX_FILE_BYTES),
+                Ok(TextFile::Text(c)) if c.contains("testWidgets")
+            )
         });
 
     if !has_widget_test {
@@ -502,6 +555,11 @@ fn check_no_widget_tests(path: &Path, issues: &mut Vec<Issue>) {
             description: "test/ directory exists but no file contains testWidgets calls.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Add widget tests using testWidgets() for UI components".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -520,6 +578,11 @@ fn check_missing_integration_tests(path: &Path, issues: &mut Vec<Issue>) {
             description: "No integration_test/ directory found. Integration tests verify complete app flows.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create integration_test/ and add integration tests".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -538,6 +601,11 @@ fn check_missing_flutter_test(pubspec: &PubspecYaml, path: &Path, issues: &mut V
             description: "flutter_test is not in dependencies or dev_dependencies.".to_string(),
             file: Some(path.join("pubspec.yaml")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Add flutter_test to dev_dependencies in pubspec.yaml".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -557,113 +625,65 @@ fn is_local_http(line: &str, pos: usize) -> bool {
         || after.starts_with("10.")
 }
 
-fn check_http_urls(path: &Path, issues: &mut Vec<Issue>) {
-    let lib_dir = path.join("lib");
-    if !lib_dir.is_dir() {
-        return;
-    }
-
-    for entry in WalkDir::new(&lib_dir)
-        .into_iter()
-        .filter_entry(|e| {
-            if e.depth() == 0 {
-                return true;
-            }
-            if e.file_type().is_dir() {
-                let name = e.file_name().to_string_lossy();
-                return !SKIP_DIRS.iter().any(|d| name.as_ref() == *d);
-            }
-            true
-        })
-        .filter_map(|e| e.ok())
-    {
-        if !entry.file_type().is_file() {
-            continue;
-        }
-        if !entry.file_name().to_string_lossy().ends_with(".dart") {
-            continue;
-        }
-
-        let file_path = entry.into_path();
-        if let Ok(content) = std::fs::read_to_string(&file_path) {
-            for (line_num, line) in content.lines().enumerate() {
-                if let Some(pos) = line.find("http://") {
-                    if !is_local_http(line, pos) {
-                        issues.push(Issue {
-                            id: "FLT-041".to_string(),
-                            analyzer: "flutter".to_string(),
-                            category: AnalyzerCategory::Security,
-                            severity: Severity::High,
-                            title: "Insecure HTTP URL found".to_string(),
-                            description: format!(
-                                "http:// URL found in {}. Use https:// for secure communication.",
-                                file_path.display()
-                            ),
-                            file: Some(file_path.clone()),
-                            line: Some(line_num + 1),
-                            suggestion: Some("Replace http:// with https://".to_string()),
-                            auto_fixable: true,
-                            references: vec![],
-                        });
-                        break; // One issue per file
-                    }
-                }
-            }
-        }
-    }
+fn is_lib_dart(relative: &str) -> bool {
+    relative.starts_with("lib/") && relative.ends_with(".dart")
 }
 
-fn check_debug_prints(path: &Path, issues: &mut Vec<Issue>) {
-    let lib_dir = path.join("lib");
-    if !lib_dir.is_dir() {
-        return;
+fn http_urls_matcher() -> FirstMatchPerFile {
+    FirstMatchPerFile {
+        skips_dir: |name| SKIP_DIRS.contains(&name),
+        wants: is_lib_dart,
+        matches: |line| line.find("http://").filter(|&pos| !is_local_http(line, pos)),
+        issue: |file, line_num| Issue {
+            id: "FLT-041".to_string(),
+            analyzer: "flutter".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::High,
+            title: "Insecure HTTP URL found".to_string(),
+            description: format!(
+                "http:// URL found in {}. Use https:// for secure communication.",
+                file.path.display()
+            ),
+            file: Some(file.path.to_path_buf()),
+            line: Some(line_num),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Replace http:// with https://".to_string()),
+            auto_fixable: true,
+            references: vec![],
+        },
     }
+}
 
-    for entry in WalkDir::new(&lib_dir)
-        .into_iter()
-        .filter_entry(|e| {
-            if e.depth() == 0 {
-                return true;
-            }
-            if e.file_type().is_dir() {
-                let name = e.file_name().to_string_lossy();
-                return !SKIP_DIRS.iter().any(|d| name.as_ref() == *d);
-            }
-            true
-        })
-        .filter_map(|e| e.ok())
-    {
-        if !entry.file_type().is_file() {
-            continue;
-        }
-        if !entry.file_name().to_string_lossy().ends_with(".dart") {
-            continue;
-        }
-
-        let file_path = entry.into_path();
-        if let Ok(content) = std::fs::read_to_string(&file_path) {
-            for (line_num, line) in content.lines().enumerate() {
-                if line.contains("debugPrint(") {
-                    issues.push(Issue {
-                        id: "FLT-042".to_string(),
-                        analyzer: "flutter".to_string(),
-                        category: AnalyzerCategory::Security,
-                        severity: Severity::High,
-                        title: "debugPrint() found in lib/ code".to_string(),
-                        description: format!(
-                            "debugPrint() call found in {}. Debug output should not be in production code.",
-                            file_path.display()
-                        ),
-                        file: Some(file_path.clone()),
-                        line: Some(line_num + 1),
-                        suggestion: Some("Remove debugPrint() calls or use a proper logging framework".to_string()),
-                        auto_fixable: false,
-                        references: vec![],
-                    });
-                    break; // One issue per file
-                }
-            }
-        }
+fn debug_prints_matcher() -> FirstMatchPerFile {
+    FirstMatchPerFile {
+        skips_dir: |name| SKIP_DIRS.contains(&name),
+        wants: is_lib_dart,
+        matches: |line| line.find("debugPrint("),
+        issue: |file, line_num| Issue {
+            id: "FLT-042".to_string(),
+            analyzer: "flutter".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::High,
+            title: "debugPrint() found in lib/ code".to_string(),
+            description: format!(
+                "debugPrint() call found in {}. Debug output should not be in production code.",
+                file.path.display()
+            ),
+            file: Some(file.path.to_path_buf()),
+            line: Some(line_num),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Remove debugPrint() calls or use a proper logging framework".to_string()),
+            auto_fixable: false,
+            references: vec![],
+        },
     }
 }
 
@@ -674,6 +694,7 @@ fn check_debug_prints(path: &Path, issues: &mut Vec<Issue>) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::content::analyze_with_content;
     use crate::frameworks::detector::{DetectedProject, Language, PackageManager};
     use std::fs as stdfs;
     use tempfile::TempDir;
@@ -779,7 +800,7 @@ dev_dependencies:
         let tmp = TempDir::new().unwrap();
         scaffold_flutter(&tmp);
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(
             issues.is_empty(),
             "Expected no issues but got: {:?}",
@@ -795,7 +816,7 @@ dev_dependencies:
         let lines: String = (0..60).map(|i| format!("var x{} = {};\n", i, i)).collect();
         stdfs::write(tmp.path().join("lib/main.dart"), lines).unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-003"));
     }
 
@@ -813,7 +834,7 @@ dev_dependencies:
             .unwrap();
         }
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-004"));
     }
 
@@ -827,7 +848,7 @@ dev_dependencies:
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-010"));
     }
 
@@ -841,7 +862,7 @@ dev_dependencies:
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-011"));
     }
 
@@ -867,7 +888,7 @@ dev_dependencies:
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-021"));
     }
 
@@ -894,7 +915,7 @@ dev_dependencies:
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-022"));
     }
 
@@ -909,7 +930,7 @@ dev_dependencies:
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-030"));
     }
 
@@ -919,7 +940,7 @@ dev_dependencies:
         scaffold_flutter(&tmp);
         stdfs::remove_dir_all(tmp.path().join("integration_test")).unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-031"));
     }
 
@@ -933,7 +954,7 @@ dev_dependencies:
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-032"));
     }
 
@@ -947,7 +968,7 @@ dev_dependencies:
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-041"));
     }
 
@@ -961,7 +982,7 @@ dev_dependencies:
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(!issues.iter().any(|i| i.id == "FLT-041"));
     }
 
@@ -975,7 +996,7 @@ dev_dependencies:
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-042"));
     }
 
@@ -989,7 +1010,7 @@ dev_dependencies:
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-050"));
     }
 
@@ -999,7 +1020,7 @@ dev_dependencies:
         scaffold_flutter(&tmp);
         stdfs::remove_file(tmp.path().join("ios/Runner/Info.plist")).unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-051"));
     }
 
@@ -1009,7 +1030,7 @@ dev_dependencies:
         scaffold_flutter(&tmp);
         stdfs::write(tmp.path().join(".gitignore"), ".env\n").unwrap();
         let project = make_project(&tmp);
-        let issues = FlutterAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&FlutterAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "FLT-053"));
     }
 }
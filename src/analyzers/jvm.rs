@@ -0,0 +1,542 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::content::{relative_path, ContentFile, ContentMatcher};
+use crate::core::project::Project;
+use crate::utils::fs::{is_ignored_name, read_to_string, walk};
+
+const GRADLE_BUILD_FILES: &[&str] = &["build.gradle", "build.gradle.kts"];
+const GRADLE_MARKERS: &[&str] = &[
+    "build.gradle",
+    "build.gradle.kts",
+    "settings.gradle",
+    "settings.gradle.kts",
+];
+
+/// Build output directories never worth walking into.
+const SKIP_DIRS: &[&str] = &["build", "out", "bin"];
+
+pub struct JvmAnalyzer;
+
+fn is_gradle(path: &Path) -> bool {
+    GRADLE_MARKERS.iter().any(|m| path.join(m).is_file())
+}
+
+fn is_maven(path: &Path) -> bool {
+    path.join("pom.xml").is_file()
+}
+
+fn skips_dir(name: &str) -> bool {
+    is_ignored_name(name) || SKIP_DIRS.contains(&name)
+}
+
+#[async_trait]
+impl Analyzer for JvmAnalyzer {
+    fn name(&self) -> &'static str {
+        "jvm"
+    }
+
+    fn description(&self) -> &'static str {
+        "Gradle/Maven dependency hygiene: dynamic versions, locking, wrappers, and plugin versions"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Dependencies
+    }
+
+    fn applies_to(&self, project: &Project) -> bool {
+        is_gradle(&project.path) || is_maven(&project.path)
+    }
+
+    async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+        let path = &project.path;
+        let build_files = find_build_files(path);
+
+        // Dependency checks
+        if is_gradle(path) {
+            check_gradle_locking(path, &build_files, &mut issues);
+        }
+        if is_maven(path) {
+            check_maven_checksums(path, &mut issues);
+        }
+        check_scattered_plugin_versions(path, &build_files, &mut issues);
+
+        // Configuration checks
+        check_missing_wrapper(path, &mut issues);
+
+        Ok(issues)
+    }
+
+    fn content_matchers(&self, _project: &Project) -> Vec<Box<dyn ContentMatcher>> {
+        // Dependency checks
+        vec![Box::new(DynamicVersionMatcher)]
+    }
+}
+
+/// Gradle build scripts and Maven POMs anywhere in the project.
+fn find_build_files(base: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = walk(base)
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || !skips_dir(&e.file_name().to_string_lossy()))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy();
+            name == "pom.xml" || GRADLE_BUILD_FILES.contains(&name.as_ref())
+        })
+        .map(|e| e.into_path())
+        .collect();
+    files.sort();
+    files
+}
+
+// ---------------------------------------------------------------------------
+// Dependency checks
+// ---------------------------------------------------------------------------
+
+/// `"group:artifact:1.+"`, `'group:artifact:+'`, `"group:artifact:latest.release"`
+static GRADLE_DYNAMIC_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"["']([\w.\-]+:[\w.\-]+):([^"'\s]*\+|latest\.[a-z]+)["']"#).unwrap());
+
+/// `<version>LATEST</version>`, `<version>RELEASE</version>`, `<version>[1.0,)</version>`
+static MAVEN_DYNAMIC_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"<version>\s*(LATEST|RELEASE|[\[(][^<]*)\s*</version>").unwrap());
+
+/// `guava = "33.+"` in a version catalog
+static CATALOG_DYNAMIC_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^\s*([\w.\-]+)\s*=\s*"([^"]*\+|latest\.[a-z]+)""#).unwrap());
+
+/// Reports every dependency declared with a dynamic version.
+struct DynamicVersionMatcher;
+
+impl DynamicVersionMatcher {
+    /// `(dependency, version, byte offset)` of a dynamic version on `line`.
+    fn find<'a>(relative: &str, line: &'a str) -> Option<(&'a str, &'a str, usize)> {
+        let name = relative.rsplit('/').next().unwrap_or(relative);
+        let caps = if name == "pom.xml" {
+            MAVEN_DYNAMIC_RE.captures(line)?
+        } else if name.ends_with(".toml") {
+            CATALOG_DYNAMIC_RE.captures(line)?
+        } else {
+            GRADLE_DYNAMIC_RE.captures(line)?
+        };
+        let version = caps.get(caps.len() - 1).unwrap();
+        let dependency = if caps.len() > 2 {
+            caps.get(1).unwrap().as_str()
+        } else {
+            ""
+        };
+        Some((dependency, version.as_str(), caps.get(0).unwrap().start()))
+    }
+}
+
+impl ContentMatcher for DynamicVersionMatcher {
+    fn skips_dir(&self, name: &str) -> bool {
+        skips_dir(name)
+    }
+
+    fn wants(&self, relative: &str) -> bool {
+        let name = relative.rsplit('/').next().unwrap_or(relative);
+        name == "pom.xml" || name.ends_with("versions.toml") || GRADLE_BUILD_FILES.contains(&name)
+    }
+
+    fn check_line(
+        &mut self,
+        file: &ContentFile,
+        line_num: usize,
+        line: &str,
+        issues: &mut Vec<Issue>,
+    ) -> bool {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("//") || trimmed.starts_with('#') || trimmed.starts_with("<!--") {
+            return true;
+        }
+        let Some((dependency, version, start)) = Self::find(file.relative, line) else {
+            return true;
+        };
+
+        let subject = if dependency.is_empty() {
+            format!("version {}", version)
+        } else {
+            format!("{}:{}", dependency, version)
+        };
+        issues.push(
+            Issue {
+                id: "JVM-001".to_string(),
+                analyzer: "jvm".to_string(),
+                category: AnalyzerCategory::Dependencies,
+                severity: Severity::High,
+                title: format!("Dynamic dependency version: {}", subject),
+                description: format!(
+                    "{} in {} resolves to whatever is newest at build time, so two builds of the same commit can differ.",
+                    subject, file.relative
+                ),
+                file: Some(file.path.to_path_buf()),
+                line: Some(line_num),
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some("Pin an exact version".to_string()),
+                auto_fixable: false,
+                references: vec![
+                    "https://docs.gradle.org/current/userguide/dynamic_versions.html".to_string(),
+                ],
+            }
+            .with_snippet(line, start),
+        );
+        true
+    }
+}
+
+fn check_gradle_locking(path: &Path, build_files: &[PathBuf], issues: &mut Vec<Issue>) {
+    let has_lockfile = path.join("gradle.lockfile").is_file()
+        || path.join("gradle/dependency-locks").is_dir()
+        || build_files.iter().any(|f| {
+            f.parent()
+                .is_some_and(|dir| dir.join("gradle.lockfile").is_file())
+        });
+    let has_verification = path.join("gradle/verification-metadata.xml").is_file();
+    if has_lockfile || has_verification {
+        return;
+    }
+
+    issues.push(Issue {
+        id: "JVM-002".to_string(),
+        analyzer: "jvm".to_string(),
+        category: AnalyzerCategory::Dependencies,
+        severity: Severity::Medium,
+        title: "No dependency locking or verification".to_string(),
+        description: "Neither gradle.lockfile nor gradle/verification-metadata.xml exists, so transitive dependency versions and checksums are not recorded.".to_string(),
+        file: None,
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some("Run `./gradlew dependencies --write-locks` or `./gradlew --write-verification-metadata sha256 help`".to_string()),
+        auto_fixable: false,
+        references: vec!["https://docs.gradle.org/current/userguide/dependency_locking.html".to_string()],
+    });
+}
+
+fn check_maven_checksums(path: &Path, issues: &mut Vec<Issue>) {
+    let config = read_to_string(path.join(".mvn/maven.config")).unwrap_or_default();
+    if config
+        .split_whitespace()
+        .any(|arg| arg == "--strict-checksums" || arg == "-C")
+    {
+        return;
+    }
+
+    issues.push(Issue {
+        id: "JVM-002".to_string(),
+        analyzer: "jvm".to_string(),
+        category: AnalyzerCategory::Dependencies,
+        severity: Severity::Medium,
+        title: "No dependency checksum verification".to_string(),
+        description:
+            "Maven only warns on artifact checksum mismatches unless strict checksums are enabled."
+                .to_string(),
+        file: None,
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some("Add `--strict-checksums` to .mvn/maven.config".to_string()),
+        auto_fixable: false,
+        references: vec![],
+    });
+}
+
+/// `id("org.x") version "1.0"`, `id 'org.x' version '1.0'`, `kotlin("jvm") version "1.9"`
+static GRADLE_PLUGIN_VERSION_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?m)^\s*(?:id\s*\(?\s*["'][^"']+["']\s*\)?|kotlin\s*\(\s*"[^"]+"\s*\))\s+version\b"#,
+    )
+    .unwrap()
+});
+
+static MAVEN_PLUGIN_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?s)<plugin>(.*?)</plugin>").unwrap());
+
+fn declares_plugin_version(file: &Path) -> bool {
+    let Ok(content) = read_to_string(file) else {
+        return false;
+    };
+    if file.ends_with("pom.xml") {
+        MAVEN_PLUGIN_RE.captures_iter(&content).any(|caps| {
+            // Versions of the plugin's own dependencies do not count
+            let own = caps[1].split("<dependencies>").next().unwrap_or_default();
+            own.contains("<version>")
+        })
+    } else {
+        GRADLE_PLUGIN_VERSION_RE.is_match(&content)
+    }
+}
+
+fn check_scattered_plugin_versions(path: &Path, build_files: &[PathBuf], issues: &mut Vec<Issue>) {
+    let declaring: Vec<String> = build_files
+        .iter()
+        .filter(|f| declares_plugin_version(f))
+        .map(|f| relative_path(path, f))
+        .collect();
+    if declaring.len() < 2 {
+        return;
+    }
+
+    issues.push(Issue {
+        id: "JVM-003".to_string(),
+        analyzer: "jvm".to_string(),
+        category: AnalyzerCategory::Dependencies,
+        severity: Severity::Low,
+        title: "Plugin versions declared in several build files".to_string(),
+        description: format!(
+            "Plugin versions are set in {} files ({}), so modules can drift onto different versions.",
+            declaring.len(),
+            declaring.join(", ")
+        ),
+        file: None,
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some("Declare plugin versions once: pluginManagement in settings.gradle, gradle/libs.versions.toml, or <pluginManagement> in the parent POM".to_string()),
+        auto_fixable: false,
+        references: vec![],
+    });
+}
+
+// ---------------------------------------------------------------------------
+// Configuration checks
+// ---------------------------------------------------------------------------
+
+fn check_missing_wrapper(path: &Path, issues: &mut Vec<Issue>) {
+    let (tool, script, properties, command) = if is_gradle(path) {
+        (
+            "Gradle",
+            "gradlew",
+            "gradle/wrapper/gradle-wrapper.properties",
+            "gradle wrapper",
+        )
+    } else {
+        (
+            "Maven",
+            "mvnw",
+            ".mvn/wrapper/maven-wrapper.properties",
+            "mvn wrapper:wrapper",
+        )
+    };
+    if path.join(script).is_file() && path.join(properties).is_file() {
+        return;
+    }
+
+    issues.push(Issue {
+        id: "JVM-010".to_string(),
+        analyzer: "jvm".to_string(),
+        category: AnalyzerCategory::Configuration,
+        severity: Severity::Medium,
+        title: format!("Missing {} wrapper", tool),
+        description: format!(
+            "No {} and {} found. Builds use whichever {} version is installed locally.",
+            script, properties, tool
+        ),
+        file: None,
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some(format!("Run `{}` and commit the generated files", command)),
+        auto_fixable: false,
+        references: vec![],
+    });
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::content::analyze_with_content;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs as stdfs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::Unknown,
+                language: Language::Unknown,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    fn write(tmp: &TempDir, relative: &str, content: &str) {
+        let path = tmp.path().join(relative);
+        stdfs::create_dir_all(path.parent().unwrap()).unwrap();
+        stdfs::write(path, content).unwrap();
+    }
+
+    /// Gradle project with wrapper and lockfile, so only the checks under test fire.
+    fn scaffold_gradle(tmp: &TempDir) {
+        write(tmp, "settings.gradle", "rootProject.name = 'app'\n");
+        write(tmp, "gradlew", "#!/bin/sh\n");
+        write(
+            tmp,
+            "gradle/wrapper/gradle-wrapper.properties",
+            "distributionUrl=x\n",
+        );
+        write(tmp, "gradle.lockfile", "empty=\n");
+    }
+
+    #[test]
+    fn test_applies_to() {
+        let tmp = TempDir::new().unwrap();
+        assert!(!JvmAnalyzer.applies_to(&make_project(&tmp)));
+        write(&tmp, "pom.xml", "<project/>");
+        assert!(JvmAnalyzer.applies_to(&make_project(&tmp)));
+    }
+
+    #[tokio::test]
+    async fn test_dynamic_versions() {
+        let tmp = TempDir::new().unwrap();
+        scaffold_gradle(&tmp);
+        write(
+            &tmp,
+            "build.gradle",
+            "dependencies {\n    implementation 'com.google.guava:guava:33.0.0-jre'\n    implementation \"org.slf4j:slf4j-api:2.+\"\n    // implementation 'a:b:+'\n    testImplementation 'junit:junit:latest.release'\n}\n",
+        );
+        write(
+            &tmp,
+            "lib/pom.xml",
+            "<dependency>\n  <artifactId>x</artifactId>\n  <version>[1.0,2.0)</version>\n</dependency>\n",
+        );
+        write(
+            &tmp,
+            "gradle/libs.versions.toml",
+            "[versions]\nkotlin = \"1.9.+\"\nok = \"1.0\"\n",
+        );
+
+        let issues = analyze_with_content(&JvmAnalyzer, &make_project(&tmp))
+            .await
+            .unwrap();
+        let mut titles: Vec<_> = issues
+            .iter()
+            .filter(|i| i.id == "JVM-001")
+            .map(|i| i.title.as_str())
+            .collect();
+        titles.sort();
+        assert_eq!(
+            titles,
+            vec![
+                "Dynamic dependency version: junit:junit:latest.release",
+                "Dynamic dependency version: kotlin:1.9.+",
+                "Dynamic dependency version: org.slf4j:slf4j-api:2.+",
+                "Dynamic dependency version: version [1.0,2.0)",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_gradle_locking_and_wrapper() {
+        let tmp = TempDir::new().unwrap();
+        write(&tmp, "build.gradle.kts", "plugins { java }\n");
+        let issues = JvmAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        let ids: Vec<_> = issues.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["JVM-002", "JVM-010"]);
+        assert_eq!(issues[1].title, "Missing Gradle wrapper");
+
+        scaffold_gradle(&tmp);
+        assert!(JvmAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_maven_checksums_and_wrapper() {
+        let tmp = TempDir::new().unwrap();
+        write(&tmp, "pom.xml", "<project/>");
+        let issues = JvmAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        let ids: Vec<_> = issues.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["JVM-002", "JVM-010"]);
+        assert_eq!(issues[1].title, "Missing Maven wrapper");
+
+        write(&tmp, ".mvn/maven.config", "--strict-checksums\n");
+        write(&tmp, "mvnw", "#!/bin/sh\n");
+        write(
+            &tmp,
+            ".mvn/wrapper/maven-wrapper.properties",
+            "distributionUrl=x\n",
+        );
+        assert!(JvmAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scattered_plugin_versions() {
+        let tmp = TempDir::new().unwrap();
+        scaffold_gradle(&tmp);
+        write(&tmp, "build.gradle", "plugins {\n    id 'java'\n}\n");
+        write(
+            &tmp,
+            "app/build.gradle.kts",
+            "plugins {\n    id(\"org.springframework.boot\") version \"3.2.0\"\n}\n",
+        );
+        let issues = JvmAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        assert!(issues.is_empty());
+
+        write(
+            &tmp,
+            "lib/build.gradle.kts",
+            "plugins {\n    kotlin(\"jvm\") version \"1.9.22\"\n}\n",
+        );
+        let issues = JvmAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "JVM-003");
+        assert!(issues[0]
+            .description
+            .contains("app/build.gradle.kts, lib/build.gradle.kts"));
+    }
+
+    #[test]
+    fn test_maven_plugin_dependency_versions_ignored() {
+        let tmp = TempDir::new().unwrap();
+        write(
+            &tmp,
+            "pom.xml",
+            "<plugin>\n  <artifactId>p</artifactId>\n  <dependencies>\n    <dependency><version>1</version></dependency>\n  </dependencies>\n</plugin>\n",
+        );
+        assert!(!declares_plugin_version(&tmp.path().join("pom.xml")));
+        write(
+            &tmp,
+            "pom.xml",
+            "<plugin>\n  <artifactId>p</artifactId>\n  <version>3.1</version>\n</plugin>\n",
+        );
+        assert!(declares_plugin_version(&tmp.path().join("pom.xml")));
+    }
+}
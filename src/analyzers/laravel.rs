@@ -1,13 +1,15 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashMap;
 use std::path::Path;
 
 use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::content::{ContentFile, ContentMatcher, FirstMatchPerFile};
 use crate::core::project::Project;
 use crate::frameworks::detector::Framework;
-use crate::utils::fs::find_files_with_extension;
+use crate::utils::fs::{is_ignored_name, read_to_string};
 
 pub struct LaravelAnalyzer;
 
@@ -18,7 +20,7 @@ struct ComposerJson {
 
 impl ComposerJson {
     fn parse(path: &Path) -> Option<Self> {
-        let content = std::fs::read_to_string(path.join("composer.json")).ok()?;
+        let content = read_to_string(path.join("composer.json")).ok()?;
         let json: serde_json::Value = serde_json::from_str(&content).ok()?;
 
         let require = Self::parse_dep_map(json.get("require"));
@@ -82,15 +84,19 @@ impl Analyzer for LaravelAnalyzer {
         check_missing_phpunit_config(path, &mut issues);
         check_missing_tests_dir(path, &mut issues);
 
-        // Security checks
-        check_unguarded_models(path, &mut issues);
-        check_raw_sql_queries(path, &mut issues);
-
         // Best practices
         check_gitignore_entries(path, &mut issues);
 
         Ok(issues)
     }
+
+    fn content_matchers(&self, _project: &Project) -> Vec<Box<dyn ContentMatcher>> {
+        // Security checks
+        vec![
+            Box::new(UnguardedModelsMatcher),
+            Box::new(raw_sql_queries_matcher()),
+        ]
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -108,6 +114,11 @@ fn check_missing_controllers_dir(path: &Path, issues: &mut Vec<Issue>) {
             description: "Laravel projects should have an app/Http/Controllers/ directory for HTTP controllers.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create app/Http/Controllers/ and add your first controller".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -126,6 +137,11 @@ fn check_missing_routes_dir(path: &Path, issues: &mut Vec<Issue>) {
             description: "Laravel projects should have a routes/ directory for route definitions.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create routes/ directory with web.php and api.php".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -144,6 +160,11 @@ fn check_missing_views_dir(path: &Path, issues: &mut Vec<Issue>) {
             description: "Laravel projects should have a resources/views/ directory for Blade templates.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create resources/views/ for your Blade templates".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -157,7 +178,7 @@ fn check_missing_views_dir(path: &Path, issues: &mut Vec<Issue>) {
 
 fn check_default_app_key(path: &Path, issues: &mut Vec<Issue>) {
     let env_path = path.join(".env");
-    let content = match std::fs::read_to_string(&env_path) {
+    let content = match read_to_string(&env_path) {
         Ok(c) => c,
         Err(_) => return,
     };
@@ -178,6 +199,11 @@ fn check_default_app_key(path: &Path, issues: &mut Vec<Issue>) {
                 description: "APP_KEY in .env is empty or a known default. Run `php artisan key:generate`.".to_string(),
                 file: Some(env_path.clone()),
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Run `php artisan key:generate` to set a secure application key".to_string()),
                 auto_fixable: false,
                 references: vec![],
@@ -189,7 +215,7 @@ fn check_default_app_key(path: &Path, issues: &mut Vec<Issue>) {
 
 fn check_debug_mode(path: &Path, issues: &mut Vec<Issue>) {
     let env_path = path.join(".env");
-    let content = match std::fs::read_to_string(&env_path) {
+    let content = match read_to_string(&env_path) {
         Ok(c) => c,
         Err(_) => return,
     };
@@ -206,6 +232,11 @@ fn check_debug_mode(path: &Path, issues: &mut Vec<Issue>) {
                 description: "APP_DEBUG=true in .env. Ensure this is disabled in production.".to_string(),
                 file: Some(env_path.clone()),
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Set APP_DEBUG=false in production .env".to_string()),
                 auto_fixable: false,
                 references: vec![],
@@ -242,6 +273,11 @@ fn check_dev_deps_in_require(composer: &ComposerJson, path: &Path, issues: &mut
                 ),
                 file: Some(path.join("composer.json")),
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some(format!("Move {} to require-dev section", pkg)),
                 auto_fixable: false,
                 references: vec![],
@@ -266,6 +302,11 @@ fn check_missing_phpunit_config(path: &Path, issues: &mut Vec<Issue>) {
             description: "No phpunit.xml or phpunit.xml.dist found. Laravel ships with PHPUnit by default.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create phpunit.xml with your test configuration".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -284,6 +325,11 @@ fn check_missing_tests_dir(path: &Path, issues: &mut Vec<Issue>) {
             description: "No tests/ directory found. Laravel projects should have automated tests.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create a tests/ directory with Feature and Unit subdirectories".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -295,77 +341,98 @@ fn check_missing_tests_dir(path: &Path, issues: &mut Vec<Issue>) {
 // Security checks
 // ---------------------------------------------------------------------------
 
-fn check_unguarded_models(path: &Path, issues: &mut Vec<Issue>) {
-    let models_dir = path.join("app/Models");
-    if !models_dir.is_dir() {
-        return;
-    }
-
-    let extends_re = Regex::new(r"extends\s+Model").unwrap();
-    let guarded_re = Regex::new(r"\$(fillable|guarded)\s*=").unwrap();
-
-    for file_path in find_files_with_extension(&models_dir, "php") {
-        if let Ok(content) = std::fs::read_to_string(&file_path) {
-            if extends_re.is_match(&content) && !guarded_re.is_match(&content) {
-                issues.push(Issue {
-                    id: "LAR-040".to_string(),
-                    analyzer: "laravel".to_string(),
-                    category: AnalyzerCategory::Security,
-                    severity: Severity::High,
-                    title: "Unguarded model (mass assignment risk)".to_string(),
-                    description: format!(
-                        "Model {} extends Model without $fillable or $guarded property.",
-                        file_path.display()
-                    ),
-                    file: Some(file_path),
-                    line: None,
-                    suggestion: Some("Add $fillable or $guarded property to protect against mass assignment".to_string()),
-                    auto_fixable: false,
-                    references: vec![],
-                });
-            }
+static EXTENDS_MODEL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"extends\s+Model").unwrap());
+static MASS_ASSIGNMENT_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\$(fillable|guarded)\s*=").unwrap());
+
+/// LAR-040: Eloquent models without `$fillable` or `$guarded`.
+struct UnguardedModelsMatcher;
+
+impl ContentMatcher for UnguardedModelsMatcher {
+    fn skips_dir(&self, name: &str) -> bool {
+        is_ignored_name(name)
+    }
+
+    fn wants(&self, relative: &str) -> bool {
+        relative.starts_with("app/Models/") && is_php_source(relative)
+    }
+
+    fn begin_file(&mut self, file: &ContentFile, issues: &mut Vec<Issue>) -> bool {
+        if EXTENDS_MODEL_RE.is_match(file.content) && !MASS_ASSIGNMENT_RE.is_match(file.content) {
+            issues.push(Issue {
+                id: "LAR-040".to_string(),
+                analyzer: "laravel".to_string(),
+                category: AnalyzerCategory::Security,
+                severity: Severity::High,
+                title: "Unguarded model (mass assignment risk)".to_string(),
+                description: format!(
+                    "Model {} extends Model without $fillable or $guarded property.",
+                    file.path.display()
+                ),
+                file: Some(file.path.to_path_buf()),
+                line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some("Add $fillable or $guarded property to protect against mass assignment".to_string()),
+                auto_fixable: false,
+                references: vec![],
+            });
         }
+        false // Whole-file check; no lines needed
+    }
+
+    fn check_line(&mut self, _: &ContentFile, _: usize, _: &str, _: &mut Vec<Issue>) -> bool {
+        false
     }
 }
 
-fn check_raw_sql_queries(path: &Path, issues: &mut Vec<Issue>) {
-    let re = Regex::new(r"(DB::raw\(|->whereRaw\(|->selectRaw\()").unwrap();
-    let _ = SKIP_DIRS; // used conceptually via find_files_with_extension
-
-    for file_path in find_files_with_extension(path, "php") {
-        if let Ok(content) = std::fs::read_to_string(&file_path) {
-            for (line_num, line) in content.lines().enumerate() {
-                if re.is_match(line) {
-                    issues.push(Issue {
-                        id: "LAR-041".to_string(),
-                        analyzer: "laravel".to_string(),
-                        category: AnalyzerCategory::Security,
-                        severity: Severity::High,
-                        title: "Raw SQL query detected".to_string(),
-                        description: format!(
-                            "Raw SQL usage found in {}. This may be vulnerable to SQL injection.",
-                            file_path.display()
-                        ),
-                        file: Some(file_path.clone()),
-                        line: Some(line_num + 1),
-                        suggestion: Some("Use Eloquent query builder or parameterized queries instead of raw SQL".to_string()),
-                        auto_fixable: false,
-                        references: vec![],
-                    });
-                    break; // One issue per file
-                }
-            }
-        }
+static RAW_SQL_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(DB::raw\(|->whereRaw\(|->selectRaw\()").unwrap());
+
+fn raw_sql_queries_matcher() -> FirstMatchPerFile {
+    FirstMatchPerFile {
+        skips_dir: |name| is_ignored_name(name) || SKIP_DIRS.contains(&name),
+        wants: is_php_source,
+        matches: |line| RAW_SQL_RE.find(line).map(|m| m.start()),
+        issue: |file, line_num| Issue {
+            id: "LAR-041".to_string(),
+            analyzer: "laravel".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::High,
+            title: "Raw SQL query detected".to_string(),
+            description: format!(
+                "Raw SQL usage found in {}. This may be vulnerable to SQL injection.",
+                file.path.display()
+            ),
+            file: Some(file.path.to_path_buf()),
+            line: Some(line_num),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Use Eloquent query builder or parameterized queries instead of raw SQL".to_string()),
+            auto_fixable: false,
+            references: vec![],
+        },
     }
 }
 
+fn is_php_source(relative: &str) -> bool {
+    let name = relative.rsplit('/').next().unwrap_or(relative);
+    name.ends_with(".php") && !name.starts_with('.')
+}
+
 // ---------------------------------------------------------------------------
 // Best practices
 // ---------------------------------------------------------------------------
 
 fn check_gitignore_entries(path: &Path, issues: &mut Vec<Issue>) {
     let gitignore_path = path.join(".gitignore");
-    let content = match std::fs::read_to_string(&gitignore_path) {
+    let content = match read_to_string(&gitignore_path) {
         Ok(c) => c,
         Err(_) => return,
     };
@@ -400,6 +467,11 @@ fn check_gitignore_entries(path: &Path, issues: &mut Vec<Issue>) {
             ),
             file: Some(gitignore_path),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some(format!("Add {} to .gitignore", missing.join(" and "))),
             auto_fixable: true,
             references: vec![],
@@ -414,6 +486,7 @@ fn check_gitignore_entries(path: &Path, issues: &mut Vec<Issue>) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::content::analyze_with_content;
     use crate::frameworks::detector::{DetectedProject, Language, PackageManager};
     use std::fs as stdfs;
     use tempfile::TempDir;
@@ -491,7 +564,7 @@ mod tests {
         let tmp = TempDir::new().unwrap();
         scaffold_laravel(&tmp);
         let project = make_project(&tmp);
-        let issues = LaravelAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&LaravelAnalyzer, &project).await.unwrap();
         assert!(
             issues.is_empty(),
             "Expected no issues but got: {:?}",
@@ -504,7 +577,7 @@ mod tests {
         let tmp = TempDir::new().unwrap();
         // Empty project - no dirs at all
         let project = make_project(&tmp);
-        let issues = LaravelAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&LaravelAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "LAR-001"));
         assert!(issues.iter().any(|i| i.id == "LAR-002"));
         assert!(issues.iter().any(|i| i.id == "LAR-003"));
@@ -516,7 +589,7 @@ mod tests {
         scaffold_laravel(&tmp);
         stdfs::write(tmp.path().join(".env"), "APP_KEY=\nAPP_DEBUG=false\n").unwrap();
         let project = make_project(&tmp);
-        let issues = LaravelAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&LaravelAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "LAR-010"));
     }
 
@@ -530,7 +603,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = LaravelAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&LaravelAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "LAR-040"));
     }
 
@@ -545,7 +618,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = LaravelAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&LaravelAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "LAR-041"));
     }
 }
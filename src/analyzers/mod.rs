@@ -1,24 +1,50 @@
+pub mod bazel;
+pub mod changelog;
+pub mod commits;
 pub mod config_files;
+pub mod containers;
+pub mod custom_rules;
 pub mod dependencies;
 pub mod documentation;
+pub mod drift;
+pub mod file_policy;
 pub mod flutter;
+pub mod github_settings;
+pub mod jvm;
 pub mod laravel;
 pub mod nextjs;
+pub mod notebook;
+pub mod portability;
+pub mod ruby;
 pub mod rust_cargo;
 pub mod security;
+pub mod shell;
 pub mod structure;
 pub mod symfony;
 pub mod testing;
 pub mod traits;
 
+pub use bazel::BazelAnalyzer;
+pub use changelog::ChangelogAnalyzer;
+pub use commits::CommitsAnalyzer;
 pub use config_files::ConfigAnalyzer;
+pub use containers::ContainerImagesAnalyzer;
+pub use custom_rules::CustomRulesAnalyzer;
 pub use dependencies::DependenciesAnalyzer;
 pub use documentation::DocumentationAnalyzer;
+pub use drift::DriftAnalyzer;
+pub use file_policy::FilePolicyAnalyzer;
 pub use flutter::FlutterAnalyzer;
+pub use github_settings::GitHubSettingsAnalyzer;
+pub use jvm::JvmAnalyzer;
 pub use laravel::LaravelAnalyzer;
 pub use nextjs::NextJsAnalyzer;
+pub use notebook::NotebookAnalyzer;
+pub use portability::PortabilityAnalyzer;
+pub use ruby::RubyAnalyzer;
 pub use rust_cargo::RustCargoAnalyzer;
 pub use security::SecurityAnalyzer;
+pub use shell::ShellAnalyzer;
 pub use structure::StructureAnalyzer;
 pub use symfony::SymfonyAnalyzer;
 pub use testing::TestingAnalyzer;
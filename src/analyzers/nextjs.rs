@@ -1,13 +1,15 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
 
 use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::content::{ContentFile, ContentMatcher};
 use crate::core::project::Project;
 use crate::frameworks::detector::Framework;
+use crate::utils::fs::read_to_string;
 
 pub struct NextJsAnalyzer;
 
@@ -19,7 +21,7 @@ struct PackageJson {
 
 impl PackageJson {
     fn parse(path: &Path) -> Option<Self> {
-        let content = std::fs::read_to_string(path.join("package.json")).ok()?;
+        let content = read_to_string(path.join("package.json")).ok()?;
         let json: serde_json::Value = serde_json::from_str(&content).ok()?;
 
         let dependencies = Self::parse_dep_map(json.get("dependencies"));
@@ -62,7 +64,7 @@ impl PackageJson {
 fn read_next_config(path: &Path) -> Option<(PathBuf, String)> {
     for ext in &["js", "mjs", "ts"] {
         let config_path = path.join(format!("next.config.{}", ext));
-        if let Ok(content) = std::fs::read_to_string(&config_path) {
+        if let Ok(content) = read_to_string(&config_path) {
             return Some((config_path, content));
         }
     }
@@ -125,13 +127,18 @@ impl Analyzer for NextJsAnalyzer {
             check_missing_test_library(p, path, &mut issues);
         }
 
-        // Security checks
-        check_public_env_secrets(path, &mut issues);
+        // Security checks (NJS-040 and NJS-042 run in the content engine)
         check_next_config_headers(&next_config, &mut issues);
-        check_unsafe_inner_html(path, &mut issues);
 
         Ok(issues)
     }
+
+    fn content_matchers(&self, _project: &Project) -> Vec<Box<dyn ContentMatcher>> {
+        vec![
+            Box::new(PublicEnvMatcher::default()),
+            Box::new(InnerHtmlMatcher::default()),
+        ]
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -158,6 +165,11 @@ fn check_app_missing_layout(path: &Path, issues: &mut Vec<Issue>) {
             description: "app/ exists but no layout.tsx/jsx/js found. App Router requires a root layout.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create app/layout.tsx with a root layout component".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -176,6 +188,11 @@ fn check_router_mixing(path: &Path, issues: &mut Vec<Issue>) {
             description: "Mixing App Router and Pages Router can cause routing conflicts.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Migrate fully to App Router (app/) or keep only pages/".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -204,6 +221,11 @@ fn check_missing_error_page(path: &Path, issues: &mut Vec<Issue>) {
             description: "No error.tsx in app/ or _error.tsx in pages/. Custom error pages improve user experience.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create app/error.tsx or pages/_error.tsx for custom error handling".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -246,6 +268,11 @@ fn check_missing_app_utilities(path: &Path, issues: &mut Vec<Issue>) {
             ),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some(format!("Create {} in app/", missing.join(" and "))),
             auto_fixable: true,
             references: vec![],
@@ -264,6 +291,11 @@ fn check_missing_robots_txt(path: &Path, issues: &mut Vec<Issue>) {
             description: "No robots.txt found. Search engines need this for crawling instructions.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create public/robots.txt with appropriate crawling rules".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -292,6 +324,11 @@ fn check_missing_sitemap(path: &Path, issues: &mut Vec<Issue>) {
             description: "No sitemap.xml, app/sitemap.ts, or next-sitemap package found.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Add a sitemap via public/sitemap.xml, app/sitemap.ts, or next-sitemap package".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -322,6 +359,11 @@ fn check_next_config_empty(
                     ),
                     file: Some(path.clone()),
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some("Add meaningful configuration to next.config".to_string()),
                     auto_fixable: false,
                     references: vec![],
@@ -338,6 +380,11 @@ fn check_next_config_empty(
                 description: "No next.config.js, next.config.mjs, or next.config.ts found.".to_string(),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Create next.config.js with your project configuration".to_string()),
                 auto_fixable: true,
                 references: vec![],
@@ -348,7 +395,7 @@ fn check_next_config_empty(
 
 fn check_tsconfig_strict(path: &Path, issues: &mut Vec<Issue>) {
     let tsconfig_path = path.join("tsconfig.json");
-    let content = match std::fs::read_to_string(&tsconfig_path) {
+    let content = match read_to_string(&tsconfig_path) {
         Ok(c) => c,
         Err(_) => return,
     };
@@ -363,6 +410,11 @@ fn check_tsconfig_strict(path: &Path, issues: &mut Vec<Issue>) {
             description: "tsconfig.json exists but \"strict\": true is not set.".to_string(),
             file: Some(tsconfig_path),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Add \"strict\": true to compilerOptions in tsconfig.json".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -379,12 +431,17 @@ fn check_next_config_images(
             issues.push(Issue {
                 id: "NJS-012".to_string(),
                 analyzer: "nextjs".to_string(),
-                category: AnalyzerCategory::Configuration,
+                category: AnalyzerCategory::Performance,
                 severity: Severity::Low,
                 title: "next.config.* missing images config".to_string(),
                 description: "next.config does not configure images optimization.".to_string(),
                 file: Some(path.clone()),
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Add images configuration for optimized image handling".to_string()),
                 auto_fixable: false,
                 references: vec![],
@@ -408,6 +465,11 @@ fn check_next_config_strict_mode(
                 description: "reactStrictMode: true is not set in next.config. It helps catch common React bugs.".to_string(),
                 file: Some(path.clone()),
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Add reactStrictMode: true to next.config".to_string()),
                 auto_fixable: true,
                 references: vec![],
@@ -418,7 +480,7 @@ fn check_next_config_strict_mode(
 
 fn check_gitignore_env(path: &Path, issues: &mut Vec<Issue>) {
     let gitignore_path = path.join(".gitignore");
-    let content = match std::fs::read_to_string(&gitignore_path) {
+    let content = match read_to_string(&gitignore_path) {
         Ok(c) => c,
         Err(_) => return,
     };
@@ -438,6 +500,11 @@ fn check_gitignore_env(path: &Path, issues: &mut Vec<Issue>) {
             description: ".gitignore should include .env.local or .env*.local to prevent leaking secrets.".to_string(),
             file: Some(gitignore_path),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Add .env*.local to .gitignore".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -470,6 +537,11 @@ fn check_missing_core_deps(pkg: &PackageJson, path: &Path, issues: &mut Vec<Issu
             ),
             file: Some(path.join("package.json")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some(format!("Run `npm install {}`", missing.join(" "))),
             auto_fixable: false,
             references: vec![],
@@ -509,6 +581,11 @@ fn check_next_version(pkg: &PackageJson, path: &Path, issues: &mut Vec<Issue>) {
                     ),
                     file: Some(path.join("package.json")),
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some("Upgrade to Next.js 14+ for latest features and security fixes".to_string()),
                     auto_fixable: false,
                     references: vec![],
@@ -530,7 +607,7 @@ fn check_heavy_bundle_deps(pkg: &PackageJson, path: &Path, issues: &mut Vec<Issu
         issues.push(Issue {
             id: "NJS-022".to_string(),
             analyzer: "nextjs".to_string(),
-            category: AnalyzerCategory::Dependencies,
+            category: AnalyzerCategory::Performance,
             severity: Severity::Low,
             title: format!("Heavy bundle dependencies: {}", found.join(", ")),
             description: format!(
@@ -539,6 +616,11 @@ fn check_heavy_bundle_deps(pkg: &PackageJson, path: &Path, issues: &mut Vec<Issu
             ),
             file: Some(path.join("package.json")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Use date-fns instead of moment, lodash-es or individual lodash imports instead of lodash".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -575,6 +657,11 @@ fn check_missing_test_config(path: &Path, issues: &mut Vec<Issue>) {
             description: "No jest, vitest, or cypress config file found.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Set up a testing framework (Jest, Vitest, or Cypress)".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -596,6 +683,11 @@ fn check_missing_test_dirs(path: &Path, issues: &mut Vec<Issue>) {
             description: "No __tests__/, tests/, test/, or cypress/ directory found.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create a test directory and add automated tests".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -626,6 +718,11 @@ fn check_missing_test_library(pkg: &PackageJson, path: &Path, issues: &mut Vec<I
             description: "No testing library (jest, vitest, testing-library, cypress, playwright) found in package.json.".to_string(),
             file: Some(path.join("package.json")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Install a testing library: npm install --save-dev jest @testing-library/react".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -637,78 +734,80 @@ fn check_missing_test_library(pkg: &PackageJson, path: &Path, issues: &mut Vec<I
 // Security checks
 // ---------------------------------------------------------------------------
 
-fn check_public_env_secrets(path: &Path, issues: &mut Vec<Issue>) {
-    let sensitive_suffixes = ["SECRET", "PASSWORD", "KEY", "TOKEN"];
-    let re = Regex::new(r"process\.env\.NEXT_PUBLIC_(\w+)").unwrap();
+static NEXT_PUBLIC_ENV_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"process\.env\.NEXT_PUBLIC_(\w+)").unwrap());
 
-    let source_dirs: Vec<PathBuf> = ["app", "pages", "src", "components"]
-        .iter()
-        .map(|d| path.join(d))
-        .filter(|d| d.is_dir())
-        .collect();
+/// Source directories scanned for client-side security issues.
+const SOURCE_DIRS: &[&str] = &["app", "pages", "src", "components"];
 
-    for source_dir in &source_dirs {
-        for entry in WalkDir::new(source_dir)
-            .into_iter()
-            .filter_entry(|e| {
-                if e.depth() == 0 {
-                    return true;
-                }
-                if e.file_type().is_dir() {
-                    let name = e.file_name().to_string_lossy();
-                    return !SKIP_DIRS.iter().any(|d| name.as_ref() == *d);
-                }
-                true
-            })
-            .filter_map(|e| e.ok())
-        {
-            if !entry.file_type().is_file() {
-                continue;
-            }
-            let name = entry.file_name().to_string_lossy();
-            if !name.ends_with(".tsx")
-                && !name.ends_with(".jsx")
-                && !name.ends_with(".ts")
-                && !name.ends_with(".js")
-            {
-                continue;
-            }
+fn in_source_dir(relative: &str, extensions: &[&str]) -> bool {
+    relative
+        .split_once('/')
+        .is_some_and(|(dir, _)| SOURCE_DIRS.contains(&dir))
+        && extensions.iter().any(|ext| relative.ends_with(ext))
+}
 
-            let file_path = entry.into_path();
-            if let Ok(content) = std::fs::read_to_string(&file_path) {
-                for (line_num, line) in content.lines().enumerate() {
-                    for cap in re.captures_iter(line) {
-                        let env_name = &cap[1];
-                        if sensitive_suffixes
-                            .iter()
-                            .any(|s| env_name.to_uppercase().ends_with(s))
-                        {
-                            issues.push(Issue {
-                                id: "NJS-040".to_string(),
-                                analyzer: "nextjs".to_string(),
-                                category: AnalyzerCategory::Security,
-                                severity: Severity::High,
-                                title: format!(
-                                    "NEXT_PUBLIC_ env with sensitive suffix: {}",
-                                    env_name
-                                ),
-                                description: format!(
-                                    "NEXT_PUBLIC_{} in {} exposes a potentially sensitive value to the client.",
-                                    env_name,
-                                    file_path.display()
-                                ),
-                                file: Some(file_path.clone()),
-                                line: Some(line_num + 1),
-                                suggestion: Some("Remove NEXT_PUBLIC_ prefix for sensitive values; access them server-side only".to_string()),
-                                auto_fixable: false,
-                                references: vec![],
-                            });
-                            return; // One finding is enough
-                        }
-                    }
+/// NJS-040: `NEXT_PUBLIC_` env vars with sensitive names.
+#[derive(Default)]
+struct PublicEnvMatcher {
+    found: bool,
+}
+
+impl ContentMatcher for PublicEnvMatcher {
+    fn skips_dir(&self, name: &str) -> bool {
+        SKIP_DIRS.contains(&name)
+    }
+
+    fn wants(&self, relative: &str) -> bool {
+        in_source_dir(relative, &[".tsx", ".jsx", ".ts", ".js"])
+    }
+
+    fn check_line(
+        &mut self,
+        file: &ContentFile,
+        line_num: usize,
+        line: &str,
+        issues: &mut Vec<Issue>,
+    ) -> bool {
+        let sensitive_suffixes = ["SECRET", "PASSWORD", "KEY", "TOKEN"];
+        for cap in NEXT_PUBLIC_ENV_RE.captures_iter(line) {
+            let env_name = &cap[1];
+            if sensitive_suffixes
+                .iter()
+                .any(|s| env_name.to_uppercase().ends_with(s))
+            {
+                issues.push(Issue {
+                    id: "NJS-040".to_string(),
+                    analyzer: "nextjs".to_string(),
+                    category: AnalyzerCategory::Security,
+                    severity: Severity::High,
+                    title: format!("NEXT_PUBLIC_ env with sensitive suffix: {}", env_name),
+                    description: format!(
+                        "NEXT_PUBLIC_{} in {} exposes a potentially sensitive value to the client.",
+                        env_name,
+                        file.path.display()
+                    ),
+                    file: Some(file.path.to_path_buf()),
+                    line: Some(line_num),
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
+                    suggestion: Some("Remove NEXT_PUBLIC_ prefix for sensitive values; access them server-side only".to_string()),
+                    auto_fixable: false,
+                    references: vec![],
                 }
+                .with_snippet(line, cap.get(0).map_or(0, |m| m.start())));
+                self.found = true; // One finding is enough
+                return false;
             }
         }
+        true
+    }
+
+    fn is_done(&self) -> bool {
+        self.found
     }
 }
 
@@ -727,6 +826,11 @@ fn check_next_config_headers(
                 description: "next.config does not define custom headers. Security headers (CSP, HSTS, etc.) are important.".to_string(),
                 file: Some(path.clone()),
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Add a headers() function to next.config with security headers".to_string()),
                 auto_fixable: false,
                 references: vec![],
@@ -737,63 +841,58 @@ fn check_next_config_headers(
 
 /// Detect unsafe innerHTML usage in JSX/TSX files.
 // NJS-042: dangerously set inner HTML
-fn check_unsafe_inner_html(path: &Path, issues: &mut Vec<Issue>) {
-    let pattern = "dangerouslySetInner";
+#[derive(Default)]
+struct InnerHtmlMatcher {
+    found: bool,
+}
 
-    let source_dirs: Vec<PathBuf> = ["app", "pages", "src", "components"]
-        .iter()
-        .map(|d| path.join(d))
-        .filter(|d| d.is_dir())
-        .collect();
+impl ContentMatcher for InnerHtmlMatcher {
+    fn skips_dir(&self, name: &str) -> bool {
+        SKIP_DIRS.contains(&name)
+    }
 
-    for source_dir in &source_dirs {
-        for entry in WalkDir::new(source_dir)
-            .into_iter()
-            .filter_entry(|e| {
-                if e.depth() == 0 {
-                    return true;
-                }
-                if e.file_type().is_dir() {
-                    let name = e.file_name().to_string_lossy();
-                    return !SKIP_DIRS.iter().any(|d| name.as_ref() == *d);
-                }
-                true
-            })
-            .filter_map(|e| e.ok())
-        {
-            if !entry.file_type().is_file() {
-                continue;
-            }
-            let name = entry.file_name().to_string_lossy();
-            if !name.ends_with(".tsx") && !name.ends_with(".jsx") {
-                continue;
-            }
+    fn wants(&self, relative: &str) -> bool {
+        in_source_dir(relative, &[".tsx", ".jsx"])
+    }
 
-            let file_path = entry.into_path();
-            if let Ok(content) = std::fs::read_to_string(&file_path) {
-                for (line_num, line) in content.lines().enumerate() {
-                    if line.contains(pattern) {
-                        issues.push(Issue {
-                            id: "NJS-042".to_string(),
-                            analyzer: "nextjs".to_string(),
-                            category: AnalyzerCategory::Security,
-                            severity: Severity::High,
-                            title: "Unsafe innerHTML usage found".to_string(),
-                            description: format!(
-                                "Unsafe innerHTML usage in {} can lead to XSS vulnerabilities.",
-                                file_path.display()
-                            ),
-                            file: Some(file_path.clone()),
-                            line: Some(line_num + 1),
-                            suggestion: Some("Sanitize HTML content or use a safe rendering approach".to_string()),
-                            auto_fixable: false,
-                            references: vec![],
-                        });
-                        return; // One finding is enough
-                    }
-                }
-            }
+    fn check_line(
+        &mut self,
+        file: &ContentFile,
+        line_num: usize,
+        line: &str,
+        issues: &mut Vec<Issue>,
+    ) -> bool {
+        let Some(start) = line.find("dangerouslySetInner") else {
+            return true;
+        };
+        issues.push(Issue {
+            id: "NJS-042".to_string(),
+            analyzer: "nextjs".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::High,
+            title: "Unsafe innerHTML usage found".to_string(),
+            description: format!(
+                "Unsafe innerHTML usage in {} can lead to XSS vulnerabilities.",
+                file.path.display()
+            ),
+            file: Some(file.path.to_path_buf()),
+            line: Some(line_num),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Sanitize HTML content or use a safe rendering approach".to_string()),
+            auto_fixable: false,
+            references: vec![],
         }
+        .with_snippet(line, start));
+        self.found = true; // One finding is enough
+        false
+    }
+
+    fn is_done(&self) -> bool {
+        self.found
     }
 }
 
@@ -804,6 +903,7 @@ fn check_unsafe_inner_html(path: &Path, issues: &mut Vec<Issue>) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::content::analyze_with_content;
     use crate::frameworks::detector::{DetectedProject, Language, PackageManager};
     use std::fs as stdfs;
     use tempfile::TempDir;
@@ -910,7 +1010,7 @@ mod tests {
         let tmp = TempDir::new().unwrap();
         scaffold_nextjs(&tmp);
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(
             issues.is_empty(),
             "Expected no issues but got: {:?}",
@@ -924,7 +1024,7 @@ mod tests {
         scaffold_nextjs(&tmp);
         stdfs::remove_file(tmp.path().join("app/layout.tsx")).unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-001"));
     }
 
@@ -934,7 +1034,7 @@ mod tests {
         scaffold_nextjs(&tmp);
         stdfs::create_dir_all(tmp.path().join("pages")).unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-002"));
     }
 
@@ -944,7 +1044,7 @@ mod tests {
         scaffold_nextjs(&tmp);
         stdfs::remove_file(tmp.path().join("app/error.tsx")).unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-003"));
     }
 
@@ -954,7 +1054,7 @@ mod tests {
         scaffold_nextjs(&tmp);
         stdfs::remove_file(tmp.path().join("app/not-found.tsx")).unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-004"));
     }
 
@@ -964,7 +1064,7 @@ mod tests {
         scaffold_nextjs(&tmp);
         stdfs::write(tmp.path().join("next.config.mjs"), "{}").unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-010"));
     }
 
@@ -974,7 +1074,7 @@ mod tests {
         scaffold_nextjs(&tmp);
         stdfs::remove_file(tmp.path().join("next.config.mjs")).unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-010"));
     }
 
@@ -988,7 +1088,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-011"));
     }
 
@@ -1002,7 +1102,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-020"));
     }
 
@@ -1016,7 +1116,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-021"));
     }
 
@@ -1030,7 +1130,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-022"));
     }
 
@@ -1040,7 +1140,7 @@ mod tests {
         scaffold_nextjs(&tmp);
         stdfs::remove_file(tmp.path().join("jest.config.js")).unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-030"));
     }
 
@@ -1050,7 +1150,7 @@ mod tests {
         scaffold_nextjs(&tmp);
         stdfs::remove_dir_all(tmp.path().join("__tests__")).unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-031"));
     }
 
@@ -1064,7 +1164,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-032"));
     }
 
@@ -1078,7 +1178,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-040"));
     }
 
@@ -1095,7 +1195,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-042"));
     }
 
@@ -1105,7 +1205,7 @@ mod tests {
         scaffold_nextjs(&tmp);
         stdfs::write(tmp.path().join(".gitignore"), "node_modules\n").unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-050"));
     }
 
@@ -1115,7 +1215,7 @@ mod tests {
         scaffold_nextjs(&tmp);
         stdfs::remove_file(tmp.path().join("public/robots.txt")).unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-051"));
     }
 
@@ -1125,7 +1225,7 @@ mod tests {
         scaffold_nextjs(&tmp);
         stdfs::remove_file(tmp.path().join("public/sitemap.xml")).unwrap();
         let project = make_project(&tmp);
-        let issues = NextJsAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&NextJsAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "NJS-052"));
     }
 
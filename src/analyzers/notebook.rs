@@ -0,0 +1,457 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+use crate::analyzers::security::find_secret;
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::content::relative_path;
+use crate::core::project::Project;
+use crate::utils::fs::{is_ignored_name, read_text, read_to_string, walk, TextFile};
+
+/// Notebooks larger than this are not parsed; their size alone is reported.
+const MAX_NOTEBOOK_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Stored outputs above this size are reported as large.
+const LARGE_OUTPUT_BYTES: usize = 100 * 1024;
+
+/// Output MIME types that are typically rendered images or dataframes.
+const HEAVY_MIME_TYPES: &[&str] = &["image/png", "image/jpeg", "image/svg+xml", "text/html"];
+
+/// Virtualenvs and build output, whose notebooks are not the project's.
+const SKIP_DIRS: &[&str] = &["venv", "env", "build", "dist", "__pycache__"];
+
+pub struct NotebookAnalyzer;
+
+fn find_notebooks(base: &Path) -> Vec<PathBuf> {
+    let mut notebooks: Vec<PathBuf> = walk(base)
+        .into_iter()
+        .filter_entry(|e| {
+            let name = e.file_name().to_string_lossy();
+            e.depth() == 0 || !(is_ignored_name(&name) || SKIP_DIRS.contains(&name.as_ref()))
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && e.path().extension().is_some_and(|x| x == "ipynb"))
+        .map(|e| e.into_path())
+        .collect();
+    notebooks.sort();
+    notebooks
+}
+
+#[async_trait]
+impl Analyzer for NotebookAnalyzer {
+    fn name(&self) -> &'static str {
+        "notebook"
+    }
+
+    fn description(&self) -> &'static str {
+        "Jupyter notebooks: committed outputs, secrets in cells, output stripping, and placement"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Configuration
+    }
+
+    fn applies_to(&self, _project: &Project) -> bool {
+        true
+    }
+
+    async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+        let path = &project.path;
+        let notebooks = find_notebooks(path);
+        if notebooks.is_empty() {
+            return Ok(issues);
+        }
+
+        for notebook in &notebooks {
+            let relative = relative_path(path, notebook);
+            match read_text(notebook, MAX_NOTEBOOK_BYTES) {
+                Ok(TextFile::Text(content)) => {
+                    let Ok(json) = serde_json::from_str::<Value>(&content) else {
+                        continue;
+                    };
+                    let cells = json["cells"]
+                        .as_array()
+                        .map(Vec::as_slice)
+                        .unwrap_or_default();
+                    check_outputs(notebook, &relative, cells, &mut issues);
+                    check_cell_secrets(notebook, &relative, cells, &mut issues);
+                }
+                Ok(TextFile::TooLarge(size)) => {
+                    issues.push(outputs_issue(
+                        notebook,
+                        &relative,
+                        Severity::Medium,
+                        format!(
+                            "{} is {} MB, almost always because of stored outputs.",
+                            relative,
+                            size / (1024 * 1024)
+                        ),
+                    ));
+                }
+                Ok(TextFile::Binary) | Err(_) => {}
+            }
+            check_packaged_notebook(path, notebook, &relative, &mut issues);
+        }
+
+        check_output_stripping(path, &mut issues);
+
+        Ok(issues)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Output checks
+// ---------------------------------------------------------------------------
+
+fn outputs_issue(file: &Path, relative: &str, severity: Severity, description: String) -> Issue {
+    Issue {
+        id: "NB-001".to_string(),
+        analyzer: "notebook".to_string(),
+        category: AnalyzerCategory::Configuration,
+        severity,
+        title: format!("Notebook has committed outputs: {}", relative),
+        description,
+        file: Some(file.to_path_buf()),
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some(
+            "Clear outputs before committing (`jupyter nbconvert --clear-output --inplace`)"
+                .to_string(),
+        ),
+        auto_fixable: false,
+        references: vec!["https://github.com/kynan/nbstripout".to_string()],
+    }
+}
+
+fn check_outputs(file: &Path, relative: &str, cells: &[Value], issues: &mut Vec<Issue>) {
+    let outputs: Vec<&Value> = cells
+        .iter()
+        .filter_map(|cell| cell["outputs"].as_array())
+        .flatten()
+        .collect();
+    if outputs.is_empty() {
+        return;
+    }
+
+    let bytes: usize = outputs.iter().map(|o| o.to_string().len()).sum();
+    let heavy = outputs
+        .iter()
+        .filter(|o| {
+            o["data"]
+                .as_object()
+                .is_some_and(|data| HEAVY_MIME_TYPES.iter().any(|m| data.contains_key(*m)))
+        })
+        .count();
+
+    let severity = if heavy > 0 || bytes > LARGE_OUTPUT_BYTES {
+        Severity::Medium
+    } else {
+        Severity::Low
+    };
+    let mut description = format!(
+        "{} stores {} cell output(s) ({} KB)",
+        relative,
+        outputs.len(),
+        bytes.div_ceil(1024)
+    );
+    if heavy > 0 {
+        description.push_str(&format!(", including {} image or table render(s)", heavy));
+    }
+    description
+        .push_str(". Outputs bloat the repository, make diffs unreadable, and can leak data.");
+    issues.push(outputs_issue(file, relative, severity, description));
+}
+
+// ---------------------------------------------------------------------------
+// Security checks
+// ---------------------------------------------------------------------------
+
+fn cell_source(cell: &Value) -> String {
+    match &cell["source"] {
+        Value::String(source) => source.clone(),
+        Value::Array(lines) => lines.iter().filter_map(Value::as_str).collect(),
+        _ => String::new(),
+    }
+}
+
+fn check_cell_secrets(file: &Path, relative: &str, cells: &[Value], issues: &mut Vec<Issue>) {
+    for (index, cell) in cells.iter().enumerate() {
+        if cell["cell_type"] != "code" {
+            continue;
+        }
+        let source = cell_source(cell);
+        let Some((name, start, redacted)) = source.lines().find_map(find_secret) else {
+            continue;
+        };
+
+        issues.push(
+            Issue {
+                id: "NB-010".to_string(),
+                analyzer: "notebook".to_string(),
+                category: AnalyzerCategory::Security,
+                severity: Severity::Critical,
+                title: format!("Potential {} in notebook cell", name),
+                description: format!(
+                    "Code cell {} of {} contains what looks like a {}.",
+                    index + 1,
+                    relative,
+                    name
+                ),
+                file: Some(file.to_path_buf()),
+                line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some(
+                    "Load credentials from environment variables (os.environ) instead".to_string(),
+                ),
+                auto_fixable: false,
+                references: vec![],
+            }
+            .with_snippet(&redacted, start),
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Configuration checks
+// ---------------------------------------------------------------------------
+
+fn check_output_stripping(path: &Path, issues: &mut Vec<Issue>) {
+    let mentions = |file: &str, needle: &str| {
+        read_to_string(path.join(file)).is_ok_and(|c| c.contains(needle))
+    };
+    if mentions(".pre-commit-config.yaml", "nbstripout")
+        || mentions(".pre-commit-config.yaml", "nb-clean")
+        || mentions(".gitattributes", "filter=nbstripout")
+    {
+        return;
+    }
+
+    issues.push(Issue {
+        id: "NB-020".to_string(),
+        analyzer: "notebook".to_string(),
+        category: AnalyzerCategory::Configuration,
+        severity: Severity::Low,
+        title: "Notebook outputs are not stripped automatically".to_string(),
+        description: "The project has notebooks but neither a pre-commit hook (nbstripout, nb-clean) nor a .gitattributes nbstripout filter.".to_string(),
+        file: None,
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some("Add the nbstripout hook to .pre-commit-config.yaml".to_string()),
+        auto_fixable: false,
+        references: vec!["https://github.com/kynan/nbstripout".to_string()],
+    });
+}
+
+// ---------------------------------------------------------------------------
+// Structure checks
+// ---------------------------------------------------------------------------
+
+fn is_packaged(path: &Path) -> bool {
+    ["pyproject.toml", "setup.py", "setup.cfg"]
+        .iter()
+        .any(|f| path.join(f).is_file())
+}
+
+fn check_packaged_notebook(path: &Path, file: &Path, relative: &str, issues: &mut Vec<Issue>) {
+    if !relative.starts_with("src/") || !is_packaged(path) {
+        return;
+    }
+
+    issues.push(Issue {
+        id: "NB-030".to_string(),
+        analyzer: "notebook".to_string(),
+        category: AnalyzerCategory::Structure,
+        severity: Severity::Medium,
+        title: format!("Notebook inside package source: {}", relative),
+        description: "Notebooks under src/ of a packaged project can end up in built distributions and are not importable code.".to_string(),
+        file: Some(file.to_path_buf()),
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some("Move notebooks to a top-level notebooks/ or examples/ directory".to_string()),
+        auto_fixable: false,
+        references: vec![],
+    });
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use serde_json::json;
+    use std::fs as stdfs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::Python,
+                language: Language::Python,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    fn write_notebook(tmp: &TempDir, relative: &str, cells: Value) {
+        let path = tmp.path().join(relative);
+        stdfs::create_dir_all(path.parent().unwrap()).unwrap();
+        let notebook =
+            json!({ "cells": cells, "nbformat": 4, "nbformat_minor": 5, "metadata": {} });
+        stdfs::write(path, serde_json::to_string_pretty(&notebook).unwrap()).unwrap();
+    }
+
+    fn code_cell(source: &[&str], outputs: Value) -> Value {
+        json!({ "cell_type": "code", "source": source, "outputs": outputs, "metadata": {} })
+    }
+
+    fn strip_with_pre_commit(tmp: &TempDir) {
+        stdfs::write(
+            tmp.path().join(".pre-commit-config.yaml"),
+            "repos:\n  - repo: https://github.com/kynan/nbstripout\n    hooks:\n      - id: nbstripout\n",
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_no_notebooks_no_issues() {
+        let tmp = TempDir::new().unwrap();
+        let issues = NotebookAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        assert!(issues.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_clean_notebook_needs_stripping_config() {
+        let tmp = TempDir::new().unwrap();
+        write_notebook(
+            &tmp,
+            "notebooks/eda.ipynb",
+            json!([code_cell(&["x = 1\n"], json!([]))]),
+        );
+        let issues = NotebookAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        let ids: Vec<_> = issues.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["NB-020"]);
+
+        stdfs::write(
+            tmp.path().join(".gitattributes"),
+            "*.ipynb filter=nbstripout\n",
+        )
+        .unwrap();
+        assert!(NotebookAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_committed_outputs() {
+        let tmp = TempDir::new().unwrap();
+        strip_with_pre_commit(&tmp);
+        write_notebook(
+            &tmp,
+            "text.ipynb",
+            json!([code_cell(
+                &["print(1)\n"],
+                json!([{ "output_type": "stream", "text": ["1\n"] }])
+            )]),
+        );
+        write_notebook(
+            &tmp,
+            "plot.ipynb",
+            json!([code_cell(
+                &["df.plot()\n"],
+                json!([{ "output_type": "display_data", "data": { "image/png": "iVBORw0KGgo=" } }])
+            )]),
+        );
+        // Checkpoints are hidden and skipped
+        write_notebook(
+            &tmp,
+            ".ipynb_checkpoints/plot-checkpoint.ipynb",
+            json!([code_cell(
+                &["x\n"],
+                json!([{ "output_type": "stream", "text": ["x"] }])
+            )]),
+        );
+
+        let issues = NotebookAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(
+            issues[0].title,
+            "Notebook has committed outputs: plot.ipynb"
+        );
+        assert_eq!(issues[0].severity, Severity::Medium);
+        assert!(issues[0].description.contains("1 image or table render(s)"));
+        assert_eq!(
+            issues[1].title,
+            "Notebook has committed outputs: text.ipynb"
+        );
+        assert_eq!(issues[1].severity, Severity::Low);
+    }
+
+    #[tokio::test]
+    async fn test_secrets_in_code_cells() {
+        let tmp = TempDir::new().unwrap();
+        strip_with_pre_commit(&tmp);
+        write_notebook(
+            &tmp,
+            "api.ipynb",
+            json!([
+                { "cell_type": "markdown", "source": ["password = \"in docs only\"\n"], "metadata": {} },
+                code_cell(&["import requests\n", "api_key = \"abcd1234efgh5678ijkl\"\n"], json!([])),
+            ]),
+        );
+        let issues = NotebookAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "NB-010");
+        assert!(issues[0]
+            .description
+            .starts_with("Code cell 2 of api.ipynb"));
+        assert!(!issues[0].snippet.as_deref().unwrap().contains("abcd1234"));
+    }
+
+    #[tokio::test]
+    async fn test_notebook_in_packaged_src() {
+        let tmp = TempDir::new().unwrap();
+        strip_with_pre_commit(&tmp);
+        write_notebook(&tmp, "src/pkg/scratch.ipynb", json!([]));
+        let issues = NotebookAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        assert!(issues.is_empty());
+
+        stdfs::write(
+            tmp.path().join("pyproject.toml"),
+            "[project]\nname = \"pkg\"\n",
+        )
+        .unwrap();
+        let issues = NotebookAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].title,
+            "Notebook inside package source: src/pkg/scratch.ipynb"
+        );
+    }
+}
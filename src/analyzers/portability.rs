@@ -0,0 +1,398 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::content::relative_path;
+use crate::core::project::Project;
+use crate::utils::fs::{is_ignored_name, walk};
+
+/// MAX_PATH on Windows, counted in UTF-16 units like the Win32 API does.
+const MAX_WINDOWS_PATH: usize = 260;
+
+/// Characters that cannot appear in a file name on Windows.
+const WINDOWS_INVALID_CHARS: &[char] = &['<', '>', ':', '"', '\\', '|', '?', '*'];
+
+/// Device names Windows reserves regardless of extension (`nul.txt` included).
+const WINDOWS_RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+pub struct PortabilityAnalyzer;
+
+/// A file, directory, or symlink found in the project.
+struct Entry {
+    path: PathBuf,
+    relative: String,
+    name: String,
+    is_dir: bool,
+    is_symlink: bool,
+}
+
+fn find_entries(base: &Path) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = walk(base)
+        .into_iter()
+        .filter_entry(|e| {
+            e.depth() == 0
+                || !(e.file_type().is_dir() && is_ignored_name(&e.file_name().to_string_lossy()))
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.depth() > 0)
+        .map(|e| Entry {
+            relative: relative_path(base, e.path()),
+            name: e.file_name().to_string_lossy().into_owned(),
+            is_dir: e.file_type().is_dir(),
+            is_symlink: e.path_is_symlink(),
+            path: e.into_path(),
+        })
+        .collect();
+    entries.sort_by(|a, b| a.relative.cmp(&b.relative));
+    entries
+}
+
+#[async_trait]
+impl Analyzer for PortabilityAnalyzer {
+    fn name(&self) -> &'static str {
+        "portability"
+    }
+
+    fn description(&self) -> &'static str {
+        "Cross-platform file names: case collisions, Windows-invalid names, long paths, and symlinks leaving the repository"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Structure
+    }
+
+    fn applies_to(&self, _project: &Project) -> bool {
+        true
+    }
+
+    async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+        let entries = find_entries(&project.path);
+
+        check_case_collisions(&entries, &mut issues);
+        for entry in &entries {
+            check_windows_name(entry, &mut issues);
+            check_path_length(entry, &mut issues);
+            if entry.is_symlink {
+                check_symlink(entry, &mut issues);
+            }
+        }
+
+        Ok(issues)
+    }
+}
+
+fn issue(
+    id: &str,
+    severity: Severity,
+    entry: &Entry,
+    title: String,
+    description: String,
+    suggestion: &str,
+) -> Issue {
+    Issue {
+        id: id.to_string(),
+        analyzer: "portability".to_string(),
+        category: AnalyzerCategory::Structure,
+        severity,
+        title,
+        description,
+        file: Some(entry.path.clone()),
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some(suggestion.to_string()),
+        auto_fixable: false,
+        references: vec![],
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Case collisions
+// ---------------------------------------------------------------------------
+
+/// Entries of the same directory whose names only differ by case. Only the
+/// colliding entries are reported, not what they contain.
+fn check_case_collisions(entries: &[Entry], issues: &mut Vec<Issue>) {
+    let mut groups: BTreeMap<(&Path, String), Vec<&Entry>> = BTreeMap::new();
+    for entry in entries {
+        let parent = entry.path.parent().unwrap_or(Path::new(""));
+        groups
+            .entry((parent, entry.name.to_lowercase()))
+            .or_default()
+            .push(entry);
+    }
+
+    for group in groups.values().filter(|group| group.len() > 1) {
+        let names: Vec<&str> = group.iter().map(|e| e.relative.as_str()).collect();
+        issues.push(issue(
+            "PRT-001",
+            Severity::High,
+            group[0],
+            format!("Paths differ only by case: {}", names.join(", ")),
+            format!(
+                "{} only differ by case. Case-insensitive file systems (macOS and Windows by default) keep one of them, so checkouts there show the others as modified.",
+                names.join(" and ")
+            ),
+            "Rename or merge the entries so their names differ by more than case",
+        ));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Windows file names
+// ---------------------------------------------------------------------------
+
+/// Why `name` cannot be created on Windows, if it cannot.
+fn windows_name_problem(name: &str) -> Option<String> {
+    if let Some(c) = name.chars().find(|c| WINDOWS_INVALID_CHARS.contains(c)) {
+        return Some(format!("contains '{}'", c));
+    }
+    if name.chars().any(|c| c.is_ascii_control()) {
+        return Some("contains a control character".to_string());
+    }
+    if name.ends_with('.') || name.ends_with(' ') {
+        return Some("ends with a dot or space, which Windows strips".to_string());
+    }
+    let stem = name.split('.').next().unwrap_or_default().trim_end();
+    if WINDOWS_RESERVED_NAMES
+        .iter()
+        .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+    {
+        return Some(format!(
+            "uses the reserved device name {}",
+            stem.to_uppercase()
+        ));
+    }
+    None
+}
+
+fn check_windows_name(entry: &Entry, issues: &mut Vec<Issue>) {
+    let Some(problem) = windows_name_problem(&entry.name) else {
+        return;
+    };
+    issues.push(issue(
+        "PRT-002",
+        Severity::Medium,
+        entry,
+        format!("File name is invalid on Windows: {}", entry.relative),
+        format!(
+            "'{}' {}. Git cannot check it out on Windows, so the clone fails or stays incomplete.",
+            entry.name, problem
+        ),
+        "Rename it without <>:\"\\|?* or control characters, trailing dots or spaces, and reserved names such as CON or NUL",
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// Path length
+// ---------------------------------------------------------------------------
+
+/// Only files and links are reported; the directories above them are too
+/// long as well, but would repeat the same finding.
+fn check_path_length(entry: &Entry, issues: &mut Vec<Issue>) {
+    if entry.is_dir {
+        return;
+    }
+    let length = entry.relative.encode_utf16().count();
+    if length <= MAX_WINDOWS_PATH {
+        return;
+    }
+    issues.push(issue(
+        "PRT-003",
+        Severity::Medium,
+        entry,
+        format!("Path is longer than {} characters: {}", MAX_WINDOWS_PATH, entry.relative),
+        format!(
+            "The path is {} characters long before the checkout directory is prepended. Windows tools without long path support cannot create or open it.",
+            length
+        ),
+        "Shorten directory or file names, or flatten the directory structure",
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// Symlinks
+// ---------------------------------------------------------------------------
+
+/// Whether `target`, read from a symlink at the project-relative `link`, stays
+/// inside the project. Resolved lexically so dangling links are judged too.
+fn link_stays_inside(link: &str, target: &Path) -> bool {
+    let mut depth: usize = link.split('/').count() - 1;
+    for component in target.components() {
+        match component {
+            Component::Normal(_) => depth += 1,
+            Component::CurDir => {}
+            Component::ParentDir => match depth.checked_sub(1) {
+                Some(parent) => depth = parent,
+                None => return false,
+            },
+            Component::RootDir | Component::Prefix(_) => return false,
+        }
+    }
+    true
+}
+
+fn check_symlink(entry: &Entry, issues: &mut Vec<Issue>) {
+    let Ok(target) = std::fs::read_link(&entry.path) else {
+        return;
+    };
+    if link_stays_inside(&entry.relative, &target) {
+        return;
+    }
+    issues.push(issue(
+        "PRT-004",
+        Severity::Medium,
+        entry,
+        format!("Symlink points outside the repository: {}", entry.relative),
+        format!(
+            "{} links to {}, which is not part of the repository. The link is dangling on other machines, and on Windows symlinks are checked out as plain text files unless core.symlinks is enabled.",
+            entry.relative,
+            target.display()
+        ),
+        "Commit the target into the repository and link to it relatively, or replace the link with a copy",
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::Unknown,
+                language: Language::Unknown,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    fn ids(issues: &[Issue]) -> Vec<&str> {
+        issues.iter().map(|i| i.id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_portable_tree_has_no_issues() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("src/nested")).unwrap();
+        fs::write(tmp.path().join("README.md"), "# demo\n").unwrap();
+        fs::write(tmp.path().join("src/nested/lib.rs"), "").unwrap();
+        fs::write(tmp.path().join("src/console.rs"), "").unwrap();
+        let issues = PortabilityAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap();
+        assert!(issues.is_empty(), "{:?}", ids(&issues));
+    }
+
+    #[test]
+    fn test_windows_name_problem() {
+        assert!(windows_name_problem("notes.md").is_none());
+        assert!(windows_name_problem("console.log").is_none());
+        assert!(windows_name_problem(".gitignore").is_none());
+        assert!(windows_name_problem("what?.txt").unwrap().contains('?'));
+        assert!(windows_name_problem("10:30.log").unwrap().contains(':'));
+        assert!(windows_name_problem("bell\u{7}").is_some());
+        assert!(windows_name_problem("draft.").is_some());
+        assert!(windows_name_problem("draft ").is_some());
+        assert!(windows_name_problem("nul").unwrap().contains("NUL"));
+        assert!(windows_name_problem("Aux.h").unwrap().contains("AUX"));
+        assert!(windows_name_problem("com1.tar.gz").is_some());
+        assert!(windows_name_problem("com10").is_none());
+    }
+
+    #[test]
+    fn test_link_stays_inside() {
+        assert!(link_stays_inside("link", Path::new("src/lib.rs")));
+        assert!(link_stays_inside("docs/link", Path::new("../README.md")));
+        assert!(link_stays_inside("a/b/link", Path::new("./../../c")));
+        assert!(!link_stays_inside("link", Path::new("../other")));
+        assert!(!link_stays_inside("docs/link", Path::new("../../secret")));
+        assert!(!link_stays_inside("docs/link", Path::new("/etc/passwd")));
+    }
+
+    #[tokio::test]
+    async fn test_case_collisions_invalid_names_and_long_paths() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("Docs")).unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("Docs/index.md"), "").unwrap();
+        fs::write(tmp.path().join("docs/index.md"), "").unwrap();
+        fs::write(tmp.path().join("Makefile"), "").unwrap();
+        fs::write(tmp.path().join("makefile"), "").unwrap();
+        fs::write(tmp.path().join("aux.c"), "").unwrap();
+        let deep = (0..30)
+            .map(|i| format!("directory{:02}", i))
+            .collect::<Vec<_>>();
+        let deep = tmp.path().join(deep.join("/"));
+        fs::create_dir_all(&deep).unwrap();
+        fs::write(deep.join("file.txt"), "").unwrap();
+
+        let issues = PortabilityAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap();
+        let collisions: Vec<&Issue> = issues.iter().filter(|i| i.id == "PRT-001").collect();
+        assert_eq!(collisions.len(), 2);
+        assert!(collisions[0].title.contains("Docs, docs"));
+        assert!(collisions[1].title.contains("Makefile, makefile"));
+
+        let invalid: Vec<&Issue> = issues.iter().filter(|i| i.id == "PRT-002").collect();
+        assert_eq!(invalid.len(), 1);
+        assert!(invalid[0].title.ends_with("aux.c"));
+
+        let long: Vec<&Issue> = issues.iter().filter(|i| i.id == "PRT-003").collect();
+        assert_eq!(long.len(), 1);
+        assert!(long[0].title.ends_with("directory29/file.txt"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_symlinks_outside_repository() {
+        use std::os::unix::fs::symlink;
+
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir(tmp.path().join("docs")).unwrap();
+        fs::write(tmp.path().join("README.md"), "").unwrap();
+        symlink("../README.md", tmp.path().join("docs/README.md")).unwrap();
+        symlink("/etc/hosts", tmp.path().join("hosts")).unwrap();
+        symlink(
+            "../../shared/config.yml",
+            tmp.path().join("docs/config.yml"),
+        )
+        .unwrap();
+
+        let issues = PortabilityAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap();
+        let links: Vec<&str> = issues
+            .iter()
+            .filter(|i| i.id == "PRT-004")
+            .map(|i| i.title.as_str())
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                "Symlink points outside the repository: docs/config.yml",
+                "Symlink points outside the repository: hosts",
+            ]
+        );
+    }
+}
@@ -0,0 +1,424 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::content::{ContentMatcher, FirstMatchPerFile};
+use crate::core::project::Project;
+use crate::utils::fs::{is_ignored_name, read_to_string};
+
+pub struct RubyAnalyzer;
+
+fn has_gemspec(path: &Path) -> bool {
+    std::fs::read_dir(path).is_ok_and(|entries| {
+        entries
+            .filter_map(|e| e.ok())
+            .any(|e| e.file_name().to_string_lossy().ends_with(".gemspec"))
+    })
+}
+
+/// Rails apps have their own conventions (and their own analyzer someday).
+fn is_rails(path: &Path) -> bool {
+    if path.join("config/application.rb").is_file() || path.join("bin/rails").is_file() {
+        return true;
+    }
+    let gemfile = read_to_string(path.join("Gemfile")).unwrap_or_default();
+    GEMFILE_GEM_RE
+        .captures_iter(&gemfile)
+        .any(|caps| &caps[1] == "rails")
+}
+
+#[async_trait]
+impl Analyzer for RubyAnalyzer {
+    fn name(&self) -> &'static str {
+        "ruby"
+    }
+
+    fn description(&self) -> &'static str {
+        "Ruby gem and library projects: lockfile, RuboCop, specs, gem sources, and dynamic dispatch"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Configuration
+    }
+
+    fn applies_to(&self, project: &Project) -> bool {
+        let path = &project.path;
+        (path.join("Gemfile").is_file() || has_gemspec(path)) && !is_rails(path)
+    }
+
+    async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
+        let mut issues = Vec::new();
+        let path = &project.path;
+
+        // Dependency checks
+        check_missing_lockfile(path, &mut issues);
+        check_gem_sources(path, &mut issues);
+
+        // Configuration checks
+        check_missing_rubocop(path, &mut issues);
+
+        // Testing checks
+        check_missing_specs(path, &mut issues);
+
+        Ok(issues)
+    }
+
+    fn content_matchers(&self, _project: &Project) -> Vec<Box<dyn ContentMatcher>> {
+        // Security checks
+        vec![Box::new(dynamic_dispatch_matcher())]
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Dependency checks
+// ---------------------------------------------------------------------------
+
+static GEMFILE_GEM_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?m)^\s*gem\s+["']([\w.\-]+)["']"#).unwrap());
+
+static SOURCE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^\s*source\s+["'](http://[^"']+)["']"#).unwrap());
+
+static GIT_GEM_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"^\s*gem\s+["']([\w.\-]+)["'].*\b(?:git|github):?\s*(?:=>\s*)?["']"#).unwrap()
+});
+
+static GIT_PIN_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"\b(?:ref|tag):?\s*(?:=>\s*)?["']"#).unwrap());
+
+fn check_missing_lockfile(path: &Path, issues: &mut Vec<Issue>) {
+    if path.join("Gemfile").is_file() && !path.join("Gemfile.lock").is_file() {
+        issues.push(Issue {
+            id: "RB-001".to_string(),
+            analyzer: "ruby".to_string(),
+            category: AnalyzerCategory::Dependencies,
+            severity: Severity::Medium,
+            title: "Missing Gemfile.lock".to_string(),
+            description: "Gemfile exists but Gemfile.lock is not committed, so CI and contributors resolve different gem versions.".to_string(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Run `bundle install` and commit Gemfile.lock".to_string()),
+            auto_fixable: false,
+            references: vec!["https://bundler.io/guides/faq.html".to_string()],
+        });
+    }
+}
+
+fn check_gem_sources(path: &Path, issues: &mut Vec<Issue>) {
+    let gemfile = path.join("Gemfile");
+    let Ok(content) = read_to_string(&gemfile) else {
+        return;
+    };
+
+    for (i, line) in content.lines().enumerate() {
+        if line.trim_start().starts_with('#') {
+            continue;
+        }
+        let (title, description, start) = if let Some(caps) = SOURCE_RE.captures(line) {
+            let url = caps.get(1).unwrap();
+            (
+                format!("Insecure gem source: {}", url.as_str()),
+                "Gems fetched over plain HTTP can be tampered with in transit.".to_string(),
+                url.start(),
+            )
+        } else if let Some(caps) = GIT_GEM_RE.captures(line) {
+            if GIT_PIN_RE.is_match(line) {
+                continue;
+            }
+            let gem = caps.get(1).unwrap();
+            (
+                format!("Unpinned git gem: {}", gem.as_str()),
+                format!(
+                    "Gem '{}' tracks a git branch, so `bundle update` silently pulls whatever was pushed last.",
+                    gem.as_str()
+                ),
+                line.len() - line.trim_start().len(),
+            )
+        } else {
+            continue;
+        };
+
+        issues.push(
+            Issue {
+                id: "RB-002".to_string(),
+                analyzer: "ruby".to_string(),
+                category: AnalyzerCategory::Dependencies,
+                severity: Severity::High,
+                title,
+                description,
+                file: Some(gemfile.clone()),
+                line: Some(i + 1),
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
+                suggestion: Some(
+                    "Use an https:// source and pin git gems with `ref:` or `tag:`".to_string(),
+                ),
+                auto_fixable: false,
+                references: vec![],
+            }
+            .with_snippet(line, start),
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Configuration checks
+// ---------------------------------------------------------------------------
+
+fn check_missing_rubocop(path: &Path, issues: &mut Vec<Issue>) {
+    let configs = [".rubocop.yml", ".standard.yml"];
+    if !configs.iter().any(|c| path.join(c).is_file()) {
+        issues.push(Issue {
+            id: "RB-010".to_string(),
+            analyzer: "ruby".to_string(),
+            category: AnalyzerCategory::Configuration,
+            severity: Severity::Low,
+            title: "Missing RuboCop configuration".to_string(),
+            description: "No .rubocop.yml or .standard.yml found. Style and lint rules are not enforced consistently.".to_string(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Add a .rubocop.yml (or use standardrb with .standard.yml)".to_string()),
+            auto_fixable: false,
+            references: vec!["https://docs.rubocop.org/rubocop/configuration.html".to_string()],
+        });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Testing checks
+// ---------------------------------------------------------------------------
+
+fn check_missing_specs(path: &Path, issues: &mut Vec<Issue>) {
+    if !path.join("spec").is_dir() && !path.join("test").is_dir() {
+        issues.push(Issue {
+            id: "RB-020".to_string(),
+            analyzer: "ruby".to_string(),
+            category: AnalyzerCategory::Testing,
+            severity: Severity::Medium,
+            title: "Missing spec/ directory".to_string(),
+            description: "No spec/ (RSpec) or test/ (Minitest) directory found.".to_string(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some(
+                "Create spec/ and add RSpec tests (`bundle exec rspec --init`)".to_string(),
+            ),
+            auto_fixable: true,
+            references: vec![],
+        });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Security checks
+// ---------------------------------------------------------------------------
+
+/// `eval(params[:x])`, `obj.send(params[:m])`, `instance_eval ARGV[0]`, ...
+static DYNAMIC_DISPATCH_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"\b(?:eval|instance_eval|class_eval|module_eval|send|public_send|__send__|constantize)\b\s*\(?\s*(?:params|ARGV|ENV|gets|STDIN|\$stdin)\b",
+    )
+    .unwrap()
+});
+
+fn is_ruby_source(relative: &str) -> bool {
+    let name = relative.rsplit('/').next().unwrap_or(relative);
+    name.ends_with(".rb") || name == "Rakefile"
+}
+
+fn dynamic_dispatch_matcher() -> FirstMatchPerFile {
+    FirstMatchPerFile {
+        skips_dir: is_ignored_name,
+        wants: is_ruby_source,
+        matches: |line| {
+            if line.trim_start().starts_with('#') {
+                return None;
+            }
+            DYNAMIC_DISPATCH_RE.find(line).map(|m| m.start())
+        },
+        issue: |file, line_num| {
+            Issue {
+            id: "RB-030".to_string(),
+            analyzer: "ruby".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::High,
+            title: "eval/send on user input".to_string(),
+            description: format!(
+                "{} passes external input to eval or dynamic dispatch, which allows arbitrary code or method execution.",
+                file.relative
+            ),
+            file: Some(file.path.to_path_buf()),
+            line: Some(line_num),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Map input to an allowlist of methods or values instead of calling it directly".to_string()),
+            auto_fixable: false,
+            references: vec![
+                "https://guides.rubyonrails.org/security.html#command-line-injection".to_string(),
+            ],
+        }
+        },
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::content::analyze_with_content;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs as stdfs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::Unknown,
+                language: Language::Unknown,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    /// Gem with a lockfile, RuboCop config, and specs.
+    fn scaffold_gem(tmp: &TempDir) {
+        stdfs::write(
+            tmp.path().join("widget.gemspec"),
+            "Gem::Specification.new\n",
+        )
+        .unwrap();
+        stdfs::write(
+            tmp.path().join("Gemfile"),
+            "source \"https://rubygems.org\"\ngemspec\n",
+        )
+        .unwrap();
+        stdfs::write(tmp.path().join("Gemfile.lock"), "").unwrap();
+        stdfs::write(tmp.path().join(".rubocop.yml"), "").unwrap();
+        stdfs::create_dir(tmp.path().join("spec")).unwrap();
+    }
+
+    #[test]
+    fn test_applies_to_gems_but_not_rails() {
+        let tmp = TempDir::new().unwrap();
+        assert!(!RubyAnalyzer.applies_to(&make_project(&tmp)));
+
+        stdfs::write(tmp.path().join("widget.gemspec"), "").unwrap();
+        assert!(RubyAnalyzer.applies_to(&make_project(&tmp)));
+
+        stdfs::write(tmp.path().join("Gemfile"), "gem 'rails', '~> 7.1'\n").unwrap();
+        assert!(!RubyAnalyzer.applies_to(&make_project(&tmp)));
+    }
+
+    #[tokio::test]
+    async fn test_clean_gem_has_no_issues() {
+        let tmp = TempDir::new().unwrap();
+        scaffold_gem(&tmp);
+        assert!(RubyAnalyzer
+            .analyze(&make_project(&tmp))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_missing_lockfile_rubocop_and_specs() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(
+            tmp.path().join("Gemfile"),
+            "source 'https://rubygems.org'\n",
+        )
+        .unwrap();
+        let issues = RubyAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        let ids: Vec<_> = issues.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["RB-001", "RB-010", "RB-020"]);
+
+        // Minitest layout counts as tests
+        stdfs::create_dir(tmp.path().join("test")).unwrap();
+        let issues = RubyAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        assert!(!issues.iter().any(|i| i.id == "RB-020"));
+    }
+
+    #[tokio::test]
+    async fn test_unpinned_gem_sources() {
+        let tmp = TempDir::new().unwrap();
+        scaffold_gem(&tmp);
+        stdfs::write(
+            tmp.path().join("Gemfile"),
+            r#"source "http://rubygems.org"
+gem "pinned", git: "https://github.com/acme/pinned.git", ref: "abc123"
+gem "tagged", github: "acme/tagged", tag: "v1.0"
+  gem "floating", git: "https://github.com/acme/floating.git"
+gem "old_style", :git => "https://github.com/acme/old.git"
+# gem "commented", git: "https://github.com/acme/x.git"
+gem "rake"
+"#,
+        )
+        .unwrap();
+        let issues = RubyAnalyzer.analyze(&make_project(&tmp)).await.unwrap();
+        let titles: Vec<_> = issues.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Insecure gem source: http://rubygems.org",
+                "Unpinned git gem: floating",
+                "Unpinned git gem: old_style",
+            ]
+        );
+        assert_eq!(issues[1].line, Some(4));
+        assert_eq!(issues[1].column, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_eval_and_send_on_user_input() {
+        let tmp = TempDir::new().unwrap();
+        scaffold_gem(&tmp);
+        stdfs::create_dir(tmp.path().join("lib")).unwrap();
+        stdfs::write(
+            tmp.path().join("lib/runner.rb"),
+            "class Runner\n  # eval(ARGV[0])\n  def run\n    target.public_send(ARGV[0])\n  end\nend\n",
+        )
+        .unwrap();
+        stdfs::write(tmp.path().join("lib/safe.rb"), "obj.send(:to_s)\n").unwrap();
+
+        let issues = analyze_with_content(&RubyAnalyzer, &make_project(&tmp))
+            .await
+            .unwrap();
+        let found: Vec<_> = issues.iter().filter(|i| i.id == "RB-030").collect();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].line, Some(4));
+        assert_eq!(
+            found[0].snippet.as_deref(),
+            Some("    target.public_send(ARGV[0])")
+        );
+    }
+}
@@ -1,12 +1,14 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use once_cell::sync::Lazy;
 use regex::Regex;
 use std::path::Path;
 
 use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::content::{ContentMatcher, FirstMatchPerFile};
 use crate::core::project::Project;
 use crate::frameworks::detector::Framework;
-use crate::utils::fs::find_files_with_extension;
+use crate::utils::fs::{is_ignored_name, read_to_string};
 
 pub struct RustCargoAnalyzer;
 
@@ -44,14 +46,16 @@ impl Analyzer for RustCargoAnalyzer {
         // Testing checks
         check_missing_tests_dir(path, &mut issues);
 
-        // Security checks
-        check_unsafe_blocks(path, &mut issues);
-
         // Best practices
         check_gitignore_entries(path, &mut issues);
 
         Ok(issues)
     }
+
+    fn content_matchers(&self, _project: &Project) -> Vec<Box<dyn ContentMatcher>> {
+        // Security checks
+        vec![Box::new(unsafe_blocks_matcher())]
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -72,6 +76,11 @@ fn check_missing_entry_point(path: &Path, issues: &mut Vec<Issue>) {
             description: "Rust projects need either src/main.rs (binary) or src/lib.rs (library) as an entry point.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create src/main.rs for a binary crate or src/lib.rs for a library crate".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -90,6 +99,11 @@ fn check_missing_clippy_config(path: &Path, issues: &mut Vec<Issue>) {
             description: "No clippy.toml or .clippy.toml found. Clippy configuration helps enforce consistent lint rules.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create clippy.toml to configure Clippy lints for your project".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -108,6 +122,11 @@ fn check_missing_rustfmt_config(path: &Path, issues: &mut Vec<Issue>) {
             description: "No rustfmt.toml or .rustfmt.toml found. A consistent code style helps readability.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create rustfmt.toml to configure code formatting rules".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -119,16 +138,16 @@ fn check_missing_rustfmt_config(path: &Path, issues: &mut Vec<Issue>) {
 // Configuration checks
 // ---------------------------------------------------------------------------
 
+static EDITION_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"edition\s*=\s*"(\d+)""#).unwrap());
+
 fn check_outdated_edition(path: &Path, issues: &mut Vec<Issue>) {
     let cargo_path = path.join("Cargo.toml");
-    let content = match std::fs::read_to_string(&cargo_path) {
+    let content = match read_to_string(&cargo_path) {
         Ok(c) => c,
         Err(_) => return,
     };
 
-    let edition_re = Regex::new(r#"edition\s*=\s*"(\d+)""#).unwrap();
-
-    if let Some(caps) = edition_re.captures(&content) {
+    if let Some(caps) = EDITION_RE.captures(&content) {
         if let Ok(year) = caps[1].parse::<u32>() {
             if year < 2021 {
                 issues.push(Issue {
@@ -143,6 +162,11 @@ fn check_outdated_edition(path: &Path, issues: &mut Vec<Issue>) {
                     ),
                     file: Some(cargo_path),
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some("Update edition to \"2021\" in Cargo.toml".to_string()),
                     auto_fixable: false,
                     references: vec![],
@@ -160,6 +184,11 @@ fn check_outdated_edition(path: &Path, issues: &mut Vec<Issue>) {
             description: "No edition specified in Cargo.toml. Without it, the 2015 edition is used by default.".to_string(),
             file: Some(cargo_path),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Add edition = \"2021\" to [package] in Cargo.toml".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -179,6 +208,11 @@ fn check_missing_cargo_lock(path: &Path, issues: &mut Vec<Issue>) {
             description: "Binary crates should commit Cargo.lock for reproducible builds.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Run `cargo build` and commit the generated Cargo.lock".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -201,6 +235,11 @@ fn check_missing_tests_dir(path: &Path, issues: &mut Vec<Issue>) {
             description: "No tests/ directory found. Consider adding integration tests.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create a tests/ directory for integration tests".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -212,38 +251,38 @@ fn check_missing_tests_dir(path: &Path, issues: &mut Vec<Issue>) {
 // Security checks
 // ---------------------------------------------------------------------------
 
-fn check_unsafe_blocks(path: &Path, issues: &mut Vec<Issue>) {
-    let src_dir = path.join("src");
-    if !src_dir.is_dir() {
-        return;
-    }
-
-    let re = Regex::new(r"unsafe\s*\{").unwrap();
-
-    for file_path in find_files_with_extension(&src_dir, "rs") {
-        if let Ok(content) = std::fs::read_to_string(&file_path) {
-            for (line_num, line) in content.lines().enumerate() {
-                if re.is_match(line) {
-                    issues.push(Issue {
-                        id: "RST-030".to_string(),
-                        analyzer: "rust_cargo".to_string(),
-                        category: AnalyzerCategory::Security,
-                        severity: Severity::High,
-                        title: "Unsafe code block detected".to_string(),
-                        description: format!(
-                            "unsafe block found in {}. Ensure unsafe code is justified and reviewed.",
-                            file_path.display()
-                        ),
-                        file: Some(file_path.clone()),
-                        line: Some(line_num + 1),
-                        suggestion: Some("Review unsafe code for soundness or replace with safe alternatives".to_string()),
-                        auto_fixable: false,
-                        references: vec![],
-                    });
-                    break; // One issue per file
-                }
-            }
-        }
+static UNSAFE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"unsafe\s*\{").unwrap());
+
+fn unsafe_blocks_matcher() -> FirstMatchPerFile {
+    FirstMatchPerFile {
+        skips_dir: is_ignored_name,
+        wants: |relative| {
+            relative.starts_with("src/")
+                && relative.ends_with(".rs")
+                && !relative.rsplit('/').next().is_some_and(|name| name.starts_with('.'))
+        },
+        matches: |line| UNSAFE_RE.find(line).map(|m| m.start()),
+        issue: |file, line_num| Issue {
+            id: "RST-030".to_string(),
+            analyzer: "rust_cargo".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::High,
+            title: "Unsafe code block detected".to_string(),
+            description: format!(
+                "unsafe block found in {}. Ensure unsafe code is justified and reviewed.",
+                file.path.display()
+            ),
+            file: Some(file.path.to_path_buf()),
+            line: Some(line_num),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Review unsafe code for soundness or replace with safe alternatives".to_string()),
+            auto_fixable: false,
+            references: vec![],
+        },
     }
 }
 
@@ -253,7 +292,7 @@ fn check_unsafe_blocks(path: &Path, issues: &mut Vec<Issue>) {
 
 fn check_gitignore_entries(path: &Path, issues: &mut Vec<Issue>) {
     let gitignore_path = path.join(".gitignore");
-    let content = match std::fs::read_to_string(&gitignore_path) {
+    let content = match read_to_string(&gitignore_path) {
         Ok(c) => c,
         Err(_) => return,
     };
@@ -273,6 +312,11 @@ fn check_gitignore_entries(path: &Path, issues: &mut Vec<Issue>) {
             description: ".gitignore should include target/ for Rust projects.".to_string(),
             file: Some(gitignore_path),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Add target/ to .gitignore".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -287,6 +331,7 @@ fn check_gitignore_entries(path: &Path, issues: &mut Vec<Issue>) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::content::analyze_with_content;
     use crate::frameworks::detector::{DetectedProject, Language, PackageManager};
     use std::fs as stdfs;
     use tempfile::TempDir;
@@ -351,7 +396,7 @@ mod tests {
         let tmp = TempDir::new().unwrap();
         scaffold_rust(&tmp);
         let project = make_project(&tmp);
-        let issues = RustCargoAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&RustCargoAnalyzer, &project).await.unwrap();
         assert!(
             issues.is_empty(),
             "Expected no issues but got: {:?}",
@@ -369,7 +414,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = RustCargoAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&RustCargoAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "RST-001"));
     }
 
@@ -383,7 +428,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = RustCargoAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&RustCargoAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "RST-010"));
     }
 
@@ -397,7 +442,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = RustCargoAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&RustCargoAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "RST-030"));
     }
 
@@ -408,7 +453,7 @@ mod tests {
         // Remove Cargo.lock
         stdfs::remove_file(tmp.path().join("Cargo.lock")).unwrap();
         let project = make_project(&tmp);
-        let issues = RustCargoAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&RustCargoAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "RST-011"));
     }
 }
@@ -0,0 +1,441 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::content::{ContentFile, ContentMatcher};
+use crate::core::project::Project;
+use crate::utils::fs::is_ignored_name;
+
+/// Commands that destroy or overwrite data when an argument splits or is empty.
+const FILE_COMMANDS: &[&str] = &["rm", "cp", "mv"];
+
+pub struct ShellAnalyzer;
+
+#[async_trait]
+impl Analyzer for ShellAnalyzer {
+    fn name(&self) -> &'static str {
+        "shell"
+    }
+
+    fn description(&self) -> &'static str {
+        "Shell scripts: strict mode, quoting, piped installers, and hardcoded credentials"
+    }
+
+    fn category(&self) -> AnalyzerCategory {
+        AnalyzerCategory::Security
+    }
+
+    fn applies_to(&self, _project: &Project) -> bool {
+        true
+    }
+
+    async fn analyze(&self, _project: &Project) -> Result<Vec<Issue>> {
+        // Everything is checked per file by the content matcher
+        Ok(Vec::new())
+    }
+
+    fn content_matchers(&self, _project: &Project) -> Vec<Box<dyn ContentMatcher>> {
+        vec![Box::new(ShellScriptMatcher)]
+    }
+}
+
+fn is_shell_script(relative: &str) -> bool {
+    relative.ends_with(".sh") || relative.ends_with(".bash")
+}
+
+// ---------------------------------------------------------------------------
+// Strict mode
+// ---------------------------------------------------------------------------
+
+/// Shell options enabled by `set` lines and shebang flags: `(errexit, nounset, pipefail)`.
+fn strict_options(content: &str) -> (bool, bool, bool) {
+    let (mut errexit, mut nounset, mut pipefail) = (false, false, false);
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+        let args = if i == 0 && line.starts_with("#!") {
+            line
+        } else if let Some(args) = line.strip_prefix("set ") {
+            args
+        } else {
+            continue;
+        };
+
+        let mut tokens = args.split_whitespace();
+        while let Some(token) = tokens.next() {
+            let Some(flags) = token.strip_prefix('-').filter(|f| !f.starts_with('-')) else {
+                continue;
+            };
+            errexit |= flags.contains('e');
+            nounset |= flags.contains('u');
+            if flags.contains('o') {
+                match tokens.next() {
+                    Some("errexit") => errexit = true,
+                    Some("nounset") => nounset = true,
+                    Some("pipefail") => pipefail = true,
+                    _ => {}
+                }
+            }
+        }
+    }
+    (errexit, nounset, pipefail)
+}
+
+fn check_strict_mode(file: &ContentFile, issues: &mut Vec<Issue>) {
+    let Some(shebang) = file.content.lines().next().filter(|l| l.starts_with("#!")) else {
+        // Without a shebang the file is usually sourced, and the caller sets options
+        return;
+    };
+    // pipefail is a bash/zsh/ksh option; plain POSIX sh scripts only need -eu
+    let posix = shebang.ends_with("/sh") || shebang.ends_with(" sh") || shebang.contains("dash");
+
+    let (errexit, nounset, pipefail) = strict_options(file.content);
+    let mut missing = Vec::new();
+    if !errexit {
+        missing.push("-e");
+    }
+    if !nounset {
+        missing.push("-u");
+    }
+    if !pipefail && !posix {
+        missing.push("-o pipefail");
+    }
+    if missing.is_empty() {
+        return;
+    }
+
+    let wanted = if posix {
+        "set -eu"
+    } else {
+        "set -euo pipefail"
+    };
+    issues.push(Issue {
+        id: "SH-001".to_string(),
+        analyzer: "shell".to_string(),
+        category: AnalyzerCategory::Configuration,
+        severity: Severity::Low,
+        title: format!("Script does not use `{}`", wanted),
+        description: format!(
+            "{} is missing {}, so failing commands and unset variables do not stop the script.",
+            file.relative,
+            missing.join(", ")
+        ),
+        file: Some(file.path.to_path_buf()),
+        line: None,
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some(format!("Add `{}` after the shebang", wanted)),
+        auto_fixable: false,
+        references: vec![],
+    });
+}
+
+// ---------------------------------------------------------------------------
+// Line checks
+// ---------------------------------------------------------------------------
+
+/// Characters of `line` outside quotes and comments, with their byte offsets.
+fn unquoted_chars(line: &str) -> Vec<(usize, char)> {
+    let mut chars = Vec::new();
+    let (mut single, mut double, mut escaped) = (false, false, false);
+    let mut previous = ' ';
+    for (i, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' && !single {
+            escaped = true;
+        } else if c == '\'' && !double {
+            single = !single;
+        } else if c == '"' && !single {
+            double = !double;
+        } else if !single && !double {
+            if c == '#' && previous.is_whitespace() {
+                break;
+            }
+            chars.push((i, c));
+        }
+        previous = c;
+    }
+    chars
+}
+
+/// Byte offset of the first unquoted `$VAR`/`${VAR}` passed to rm, cp, or mv.
+fn unquoted_file_argument(line: &str) -> Option<usize> {
+    let chars = unquoted_chars(line);
+    let mut segment_start = 0;
+    for (n, &(i, c)) in chars.iter().enumerate() {
+        let ends_segment = matches!(c, ';' | '|' | '&');
+        let is_last = n + 1 == chars.len();
+        if !ends_segment && !is_last {
+            continue;
+        }
+
+        let segment_end = if ends_segment { i } else { line.len() };
+        let segment = &line[segment_start..segment_end];
+        let command = segment.split_whitespace().find(|w| *w != "sudo");
+        if command.is_some_and(|cmd| FILE_COMMANDS.contains(&cmd)) {
+            let expansion = chars.iter().find(|&&(j, ch)| {
+                ch == '$'
+                    && (segment_start..segment_end).contains(&j)
+                    && line[j + 1..]
+                        .chars()
+                        .next()
+                        .is_some_and(|next| next == '{' || next == '_' || next.is_alphabetic())
+            });
+            if let Some(&(j, _)) = expansion {
+                return Some(j);
+            }
+        }
+        segment_start = i + c.len_utf8();
+    }
+    None
+}
+
+static PIPED_INSTALL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\b(?:curl|wget)\b[^|]*\|\s*(?:sudo\s+(?:-\S+\s+)*)?(?:ba|da|k|z)?sh\b").unwrap()
+});
+
+/// `DB_PASSWORD=hunter2`, `export API_TOKEN="abc123"`, `curl -u admin:secret`
+static CREDENTIAL_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?i)(?:^|\s)(?:export\s+)?(\w*(?:password|passwd|secret|token|api_?key|access_key)\w*)=["']?([^"'\s$`][^"'\s]{3,})|\s-u\s+["']?[^\s:"'$]+:([^\s"'$@]{3,})"#,
+    )
+    .unwrap()
+});
+
+fn issue_at(
+    file: &ContentFile,
+    line_num: usize,
+    id: &str,
+    severity: Severity,
+    title: String,
+    description: String,
+    suggestion: &str,
+) -> Issue {
+    Issue {
+        id: id.to_string(),
+        analyzer: "shell".to_string(),
+        category: AnalyzerCategory::Security,
+        severity,
+        title,
+        description,
+        file: Some(file.path.to_path_buf()),
+        line: Some(line_num),
+        column: None,
+        snippet: None,
+        fingerprint: None,
+        estimated_effort: None,
+        impact: None,
+        suggestion: Some(suggestion.to_string()),
+        auto_fixable: false,
+        references: vec![],
+    }
+}
+
+/// Whole-file strict mode check plus per-line quoting, installer, and
+/// credential checks for `*.sh`/`*.bash` files.
+struct ShellScriptMatcher;
+
+impl ContentMatcher for ShellScriptMatcher {
+    fn skips_dir(&self, name: &str) -> bool {
+        is_ignored_name(name)
+    }
+
+    fn wants(&self, relative: &str) -> bool {
+        is_shell_script(relative)
+    }
+
+    fn begin_file(&mut self, file: &ContentFile, issues: &mut Vec<Issue>) -> bool {
+        check_strict_mode(file, issues);
+        true
+    }
+
+    fn check_line(
+        &mut self,
+        file: &ContentFile,
+        line_num: usize,
+        line: &str,
+        issues: &mut Vec<Issue>,
+    ) -> bool {
+        if line.trim_start().starts_with('#') {
+            return true;
+        }
+
+        if let Some(start) = unquoted_file_argument(line) {
+            issues.push(
+                issue_at(
+                    file,
+                    line_num,
+                    "SH-010",
+                    Severity::Medium,
+                    "Unquoted variable in rm/cp/mv".to_string(),
+                    format!(
+                        "An unquoted expansion in {} is split on spaces and globbed; an empty value can turn `rm -rf $DIR/` into `rm -rf /`.",
+                        file.relative
+                    ),
+                    "Quote the expansion (\"$VAR\") and use ${VAR:?} for paths that must be set",
+                )
+                .with_snippet(line, start),
+            );
+        }
+
+        if let Some(m) = PIPED_INSTALL_RE.find(line) {
+            issues.push(
+                issue_at(
+                    file,
+                    line_num,
+                    "SH-011",
+                    Severity::High,
+                    "Remote script piped to shell".to_string(),
+                    format!(
+                        "{} runs a downloaded script without verifying it; a compromised or truncated download executes as-is.",
+                        file.relative
+                    ),
+                    "Download to a file, verify its checksum or signature, then run it",
+                )
+                .with_snippet(line, m.start()),
+            );
+        }
+
+        if let Some(caps) = CREDENTIAL_RE.captures(line) {
+            let start = caps.get(1).or(caps.get(3)).map_or(0, |m| m.start());
+            let what = caps.get(1).map_or("curl -u password", |m| m.as_str());
+            issues.push(
+                issue_at(
+                    file,
+                    line_num,
+                    "SH-012",
+                    Severity::Critical,
+                    format!("Hardcoded credential in shell script: {}", what),
+                    format!(
+                        "{} contains a literal credential that ends up in version control and process listings.",
+                        file.relative
+                    ),
+                    "Read the credential from the environment or a secrets manager",
+                )
+                .with_snippet(line, start),
+            );
+        }
+        true
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::content::analyze_with_content;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs as stdfs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::Unknown,
+                language: Language::Unknown,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    async fn scan(tmp: &TempDir, name: &str, content: &str) -> Vec<Issue> {
+        stdfs::write(tmp.path().join(name), content).unwrap();
+        analyze_with_content(&ShellAnalyzer, &make_project(tmp))
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_strict_options() {
+        assert_eq!(strict_options("set -euo pipefail\n"), (true, true, true));
+        assert_eq!(
+            strict_options("set -e\nset -o nounset\nset -o pipefail\n"),
+            (true, true, true)
+        );
+        assert_eq!(strict_options("#!/bin/bash -eu\n"), (true, true, false));
+        assert_eq!(strict_options("echo set -e\n"), (false, false, false));
+    }
+
+    #[tokio::test]
+    async fn test_strict_mode() {
+        let tmp = TempDir::new().unwrap();
+        let issues = scan(&tmp, "deploy.sh", "#!/usr/bin/env bash\nset -e\necho hi\n").await;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "SH-001");
+        assert!(issues[0].description.contains("-u, -o pipefail"));
+
+        // POSIX sh does not need pipefail
+        let tmp = TempDir::new().unwrap();
+        assert!(scan(&tmp, "run.sh", "#!/bin/sh\nset -eu\n")
+            .await
+            .is_empty());
+
+        // Sourced helpers without a shebang are left alone
+        let tmp = TempDir::new().unwrap();
+        assert!(scan(&tmp, "lib.sh", "greet() { echo hi; }\n")
+            .await
+            .is_empty());
+    }
+
+    #[test]
+    fn test_unquoted_file_argument() {
+        assert_eq!(unquoted_file_argument("rm -rf $BUILD_DIR/"), Some(7));
+        assert_eq!(
+            unquoted_file_argument("cd /tmp && sudo cp ${SRC} dest"),
+            Some(19)
+        );
+        assert_eq!(unquoted_file_argument("rm -rf \"$BUILD_DIR\""), None);
+        assert_eq!(unquoted_file_argument("echo $HOME; rm -f out.txt"), None);
+        assert_eq!(unquoted_file_argument("rm -f out.txt # $OLD"), None);
+        assert_eq!(unquoted_file_argument("mv a b"), None);
+    }
+
+    #[tokio::test]
+    async fn test_line_checks() {
+        let tmp = TempDir::new().unwrap();
+        let issues = scan(
+            &tmp,
+            "install.bash",
+            "#!/bin/bash\nset -euo pipefail\nrm -rf $TARGET\ncurl -fsSL https://get.example.com | sudo bash\nexport API_TOKEN=\"sk_live_abcdef\"\nDB_PASSWORD=$DB_PASSWORD\n# curl https://x | sh\n",
+        )
+        .await;
+        let found: Vec<_> = issues.iter().map(|i| (i.id.as_str(), i.line)).collect();
+        assert_eq!(
+            found,
+            vec![
+                ("SH-010", Some(3)),
+                ("SH-011", Some(4)),
+                ("SH-012", Some(5))
+            ]
+        );
+        assert_eq!(
+            issues[2].title,
+            "Hardcoded credential in shell script: API_TOKEN"
+        );
+        assert_eq!(issues[0].column, Some(8));
+    }
+
+    #[tokio::test]
+    async fn test_curl_basic_auth_credential() {
+        let tmp = TempDir::new().unwrap();
+        let issues = scan(&tmp, "fetch.sh", "curl -u admin:hunter22 https://api\n").await;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].id, "SH-012");
+        assert_eq!(
+            issues[0].title,
+            "Hardcoded credential in shell script: curl -u password"
+        );
+    }
+}
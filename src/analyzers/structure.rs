@@ -65,6 +65,11 @@ impl Analyzer for StructureAnalyzer {
                     ),
                     file: None,
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some(format!("Create the '{}' directory", dir)),
                     auto_fixable: true,
                     references: vec![],
@@ -73,18 +78,23 @@ impl Analyzer for StructureAnalyzer {
         }
 
         // STR-002: Check README.md
-        if !fs::path_exists(path, "README.md") {
+        if fs::find_file_ci(path, &["README.md"]).is_none() {
             issues.push(Issue {
                 id: "STR-002".to_string(),
                 analyzer: self.name().to_string(),
-                category: AnalyzerCategory::Structure,
+                category: AnalyzerCategory::Documentation,
                 severity: Severity::Medium,
                 title: "Missing README.md".to_string(),
                 description: "A README.md file is essential for project documentation.".to_string(),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Create a README.md with project description and usage instructions".to_string()),
-                auto_fixable: false,
+                auto_fixable: true,
                 references: vec![],
             });
         }
@@ -100,6 +110,11 @@ impl Analyzer for StructureAnalyzer {
                 description: "A .gitignore file prevents committing unwanted files.".to_string(),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Create a .gitignore appropriate for your framework".to_string()),
                 auto_fixable: true,
                 references: vec![],
@@ -107,18 +122,23 @@ impl Analyzer for StructureAnalyzer {
         }
 
         // STR-004: Check LICENSE
-        if !fs::path_exists(path, "LICENSE") && !fs::path_exists(path, "LICENSE.md") {
+        if fs::find_file_ci(path, &["LICENSE", "LICENSE.md"]).is_none() {
             issues.push(Issue {
                 id: "STR-004".to_string(),
                 analyzer: self.name().to_string(),
-                category: AnalyzerCategory::Structure,
+                category: AnalyzerCategory::Documentation,
                 severity: Severity::Low,
                 title: "Missing LICENSE file".to_string(),
                 description: "A LICENSE file clarifies how others can use your code.".to_string(),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Add a LICENSE file (MIT, Apache-2.0, etc.)".to_string()),
-                auto_fixable: false,
+                auto_fixable: true,
                 references: vec![],
             });
         }
@@ -135,6 +155,11 @@ impl Analyzer for StructureAnalyzer {
                 description: "Deep nesting makes code harder to navigate and maintain.".to_string(),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Consider flattening your directory structure (max recommended: 8 levels)".to_string()),
                 auto_fixable: false,
                 references: vec![],
@@ -156,6 +181,11 @@ impl Analyzer for StructureAnalyzer {
                     ),
                     file: Some(path.join(forbidden)),
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some(format!("Remove '{}' and add it to .gitignore", forbidden)),
                     auto_fixable: false,
                     references: vec![],
@@ -216,6 +246,18 @@ mod tests {
         assert!(!issues.iter().any(|i| i.id == "STR-004"));
     }
 
+    #[tokio::test]
+    async fn test_readme_and_license_match_case_insensitively() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::write(tmp.path().join("readme.md"), "# Hello").unwrap();
+        stdfs::write(tmp.path().join("License.md"), "MIT").unwrap();
+        let project = make_project(&tmp, Framework::Unknown);
+        let analyzer = StructureAnalyzer;
+        let issues = analyzer.analyze(&project).await.unwrap();
+        assert!(!issues.iter().any(|i| i.id == "STR-002"));
+        assert!(!issues.iter().any(|i| i.id == "STR-004"));
+    }
+
     #[tokio::test]
     async fn test_missing_gitignore() {
         let tmp = TempDir::new().unwrap();
@@ -1,13 +1,15 @@
 use anyhow::Result;
 use async_trait::async_trait;
+use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use walkdir::WalkDir;
 
 use crate::analyzers::traits::{Analyzer, AnalyzerCategory, Issue, Severity};
+use crate::core::content::{relative_path, ContentMatcher, FirstMatchPerFile, MAX_FILE_BYTES};
 use crate::core::project::Project;
 use crate::frameworks::detector::Framework;
+use crate::utils::fs::{read_text, read_to_string, walk, TextFile};
 
 pub struct SymfonyAnalyzer;
 
@@ -19,7 +21,7 @@ struct ComposerJson {
 
 impl ComposerJson {
     fn parse(path: &Path) -> Option<Self> {
-        let content = std::fs::read_to_string(path.join("composer.json")).ok()?;
+        let content = read_to_string(path.join("composer.json")).ok()?;
         let json: serde_json::Value = serde_json::from_str(&content).ok()?;
 
         let require = Self::parse_dep_map(json.get("require"));
@@ -105,7 +107,6 @@ impl Analyzer for SymfonyAnalyzer {
         if let Some(ref c) = composer {
             check_missing_cors_bundle(c, path, &mut issues);
         }
-        check_unserialize_calls(path, &mut issues);
 
         // Best practices checks
         check_gitignore_entries(path, &mut issues);
@@ -114,6 +115,11 @@ impl Analyzer for SymfonyAnalyzer {
 
         Ok(issues)
     }
+
+    fn content_matchers(&self, _project: &Project) -> Vec<Box<dyn ContentMatcher>> {
+        // Security checks
+        vec![Box::new(unserialize_calls_matcher())]
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -131,6 +137,11 @@ fn check_missing_controller_dir(path: &Path, issues: &mut Vec<Issue>) {
             description: "Symfony projects should have a src/Controller/ directory for HTTP controllers.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create src/Controller/ and add your first controller".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -149,6 +160,11 @@ fn check_missing_entity_dir(path: &Path, issues: &mut Vec<Issue>) {
             description: "Symfony projects typically use src/Entity/ for Doctrine entity classes.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create src/Entity/ if using Doctrine ORM".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -170,6 +186,11 @@ fn check_misplaced_controllers(path: &Path, issues: &mut Vec<Issue>) {
             ),
             file: Some(file),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Move controller files to src/Controller/".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -191,6 +212,11 @@ fn check_misplaced_services(path: &Path, issues: &mut Vec<Issue>) {
             ),
             file: Some(file),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Move service files to src/Service/".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -201,10 +227,12 @@ fn check_misplaced_services(path: &Path, issues: &mut Vec<Issue>) {
 /// Find PHP files ending with `suffix` that are NOT under `expected_dir`.
 /// Skips vendor/, var/, .git/, node_modules/.
 fn find_misplaced_php_files(base: &Path, suffix: &str, expected_dir: &str) -> Vec<PathBuf> {
-    let expected = base.join(expected_dir);
+    // Compare `/`-separated relative paths so mixed separators on Windows
+    // (`C:\app\src/Controller`) cannot defeat the check
+    let expected = format!("{}/", expected_dir.trim_end_matches('/'));
     let mut results = Vec::new();
 
-    for entry in WalkDir::new(base)
+    for entry in walk(base)
         .into_iter()
         .filter_entry(|e| {
             if e.depth() == 0 {
@@ -225,9 +253,8 @@ fn find_misplaced_php_files(base: &Path, suffix: &str, expected_dir: &str) -> Ve
         if !name.ends_with(suffix) {
             continue;
         }
-        let file_path = entry.into_path();
-        if !file_path.starts_with(&expected) {
-            results.push(file_path);
+        if !relative_path(base, entry.path()).starts_with(&expected) {
+            results.push(entry.into_path());
         }
     }
 
@@ -240,7 +267,7 @@ fn find_misplaced_php_files(base: &Path, suffix: &str, expected_dir: &str) -> Ve
 
 fn check_app_secret(path: &Path, issues: &mut Vec<Issue>) {
     let env_path = path.join(".env");
-    let content = match std::fs::read_to_string(&env_path) {
+    let content = match read_to_string(&env_path) {
         Ok(c) => c,
         Err(_) => return,
     };
@@ -272,6 +299,11 @@ fn check_app_secret(path: &Path, issues: &mut Vec<Issue>) {
                 description: "APP_SECRET in .env is a known default or shorter than 16 characters.".to_string(),
                 file: Some(env_path.clone()),
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Generate a strong random secret: `php -r \"echo bin2hex(random_bytes(16));\"`".to_string()),
                 auto_fixable: false,
                 references: vec![],
@@ -287,7 +319,7 @@ fn check_prod_debug(path: &Path, issues: &mut Vec<Issue>) {
         return;
     }
 
-    for entry in WalkDir::new(&prod_config)
+    for entry in walk(&prod_config)
         .max_depth(1)
         .into_iter()
         .filter_map(|e| e.ok())
@@ -300,7 +332,7 @@ fn check_prod_debug(path: &Path, issues: &mut Vec<Issue>) {
             continue;
         }
         let file_path = entry.path().to_path_buf();
-        if let Ok(content) = std::fs::read_to_string(&file_path) {
+        if let Ok(TextFile::Text(content)) = read_text(&file_path, MAX_FILE_BYTES) {
             for line in content.lines() {
                 let trimmed = line.trim();
                 if trimmed.starts_with("debug:") && trimmed.contains("true") {
@@ -316,6 +348,11 @@ fn check_prod_debug(path: &Path, issues: &mut Vec<Issue>) {
                         ),
                         file: Some(file_path.clone()),
                         line: None,
+                        column: None,
+                        snippet: None,
+                        fingerprint: None,
+                        estimated_effort: None,
+                        impact: None,
                         suggestion: Some("Remove or set debug: false in production configuration".to_string()),
                         auto_fixable: true,
                         references: vec![],
@@ -367,6 +404,11 @@ fn check_symfony_version(composer: &ComposerJson, path: &Path, issues: &mut Vec<
                     ),
                     file: Some(path.join("composer.json")),
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some("Upgrade to Symfony 6+ for long-term support and security fixes".to_string()),
                     auto_fixable: false,
                     references: vec![],
@@ -389,6 +431,11 @@ fn check_missing_runtime(composer: &ComposerJson, path: &Path, issues: &mut Vec<
             description: "symfony/runtime is not in require. It provides the Runtime component for better application bootstrapping.".to_string(),
             file: Some(path.join("composer.json")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Run `composer require symfony/runtime`".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -411,6 +458,11 @@ fn check_missing_phpunit_config(path: &Path, issues: &mut Vec<Issue>) {
             description: "No phpunit.xml.dist or phpunit.xml found.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create phpunit.xml.dist with your test configuration".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -429,6 +481,11 @@ fn check_missing_tests_dir(path: &Path, issues: &mut Vec<Issue>) {
             description: "No tests/ directory found. Symfony projects should have automated tests.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create a tests/ directory and add your first test case".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -452,6 +509,11 @@ fn check_missing_phpunit_dep(composer: &ComposerJson, path: &Path, issues: &mut
             description: "Neither phpunit/phpunit nor symfony/phpunit-bridge found in composer.json.".to_string(),
             file: Some(path.join("composer.json")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Run `composer require --dev symfony/phpunit-bridge`".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -463,21 +525,22 @@ fn check_missing_phpunit_dep(composer: &ComposerJson, path: &Path, issues: &mut
 // Security checks
 // ---------------------------------------------------------------------------
 
+static DATABASE_URL_CREDENTIALS_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"DATABASE_URL\s*=\s*\S+://\w+:.+@").unwrap());
+
 fn check_hardcoded_db_credentials(path: &Path, issues: &mut Vec<Issue>) {
     let env_path = path.join(".env");
-    let content = match std::fs::read_to_string(&env_path) {
+    let content = match read_to_string(&env_path) {
         Ok(c) => c,
         Err(_) => return,
     };
 
-    let re = Regex::new(r"DATABASE_URL\s*=\s*\S+://\w+:.+@").unwrap();
-
     for line in content.lines() {
         let trimmed = line.trim();
         if trimmed.starts_with('#') {
             continue;
         }
-        if re.is_match(trimmed) {
+        if DATABASE_URL_CREDENTIALS_RE.is_match(trimmed) {
             issues.push(Issue {
                 id: "SYM-040".to_string(),
                 analyzer: "symfony".to_string(),
@@ -487,6 +550,11 @@ fn check_hardcoded_db_credentials(path: &Path, issues: &mut Vec<Issue>) {
                 description: "DATABASE_URL contains inline credentials (user:pass@). Use environment variables in production.".to_string(),
                 file: Some(env_path.clone()),
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some("Use environment variables or a secrets vault for database credentials".to_string()),
                 auto_fixable: false,
                 references: vec![],
@@ -512,6 +580,11 @@ fn check_missing_cors_bundle(composer: &ComposerJson, path: &Path, issues: &mut
             description: "nelmio/cors-bundle is not installed. API projects need CORS configuration.".to_string(),
             file: Some(path.join("composer.json")),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Run `composer require nelmio/cors-bundle`".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -519,60 +592,34 @@ fn check_missing_cors_bundle(composer: &ComposerJson, path: &Path, issues: &mut
     }
 }
 
-fn check_unserialize_calls(path: &Path, issues: &mut Vec<Issue>) {
-    let src_dir = path.join("src");
-    if !src_dir.is_dir() {
-        return;
-    }
-
-    let re = Regex::new(r"unserialize\s*\(").unwrap();
-
-    for entry in WalkDir::new(&src_dir)
-        .into_iter()
-        .filter_entry(|e| {
-            if e.depth() == 0 {
-                return true;
-            }
-            if e.file_type().is_dir() {
-                let name = e.file_name().to_string_lossy();
-                return !SKIP_DIRS.iter().any(|d| name.as_ref() == *d);
-            }
-            true
-        })
-        .filter_map(|e| e.ok())
-    {
-        if !entry.file_type().is_file() {
-            continue;
-        }
-        let name = entry.file_name().to_string_lossy();
-        if !name.ends_with(".php") {
-            continue;
-        }
+static UNSERIALIZE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"unserialize\s*\(").unwrap());
 
-        let file_path = entry.into_path();
-        if let Ok(content) = std::fs::read_to_string(&file_path) {
-            for (line_num, line) in content.lines().enumerate() {
-                if re.is_match(line) {
-                    issues.push(Issue {
-                        id: "SYM-042".to_string(),
-                        analyzer: "symfony".to_string(),
-                        category: AnalyzerCategory::Security,
-                        severity: Severity::Critical,
-                        title: "Unsafe unserialize() call".to_string(),
-                        description: format!(
-                            "unserialize() found in {}. This can lead to object injection vulnerabilities.",
-                            file_path.display()
-                        ),
-                        file: Some(file_path.clone()),
-                        line: Some(line_num + 1),
-                        suggestion: Some("Use json_decode() or Symfony Serializer instead of unserialize()".to_string()),
-                        auto_fixable: false,
-                        references: vec![],
-                    });
-                    break; // One issue per file is enough
-                }
-            }
-        }
+fn unserialize_calls_matcher() -> FirstMatchPerFile {
+    FirstMatchPerFile {
+        skips_dir: |name| SKIP_DIRS.contains(&name),
+        wants: |relative| relative.starts_with("src/") && relative.ends_with(".php"),
+        matches: |line| UNSERIALIZE_RE.find(line).map(|m| m.start()),
+        issue: |file, line_num| Issue {
+            id: "SYM-042".to_string(),
+            analyzer: "symfony".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::Critical,
+            title: "Unsafe unserialize() call".to_string(),
+            description: format!(
+                "unserialize() found in {}. This can lead to object injection vulnerabilities.",
+                file.path.display()
+            ),
+            file: Some(file.path.to_path_buf()),
+            line: Some(line_num),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Use json_decode() or Symfony Serializer instead of unserialize()".to_string()),
+            auto_fixable: false,
+            references: vec![],
+        },
     }
 }
 
@@ -582,7 +629,7 @@ fn check_unserialize_calls(path: &Path, issues: &mut Vec<Issue>) {
 
 fn check_gitignore_entries(path: &Path, issues: &mut Vec<Issue>) {
     let gitignore_path = path.join(".gitignore");
-    let content = match std::fs::read_to_string(&gitignore_path) {
+    let content = match read_to_string(&gitignore_path) {
         Ok(c) => c,
         Err(_) => return, // No .gitignore is already flagged by StructureAnalyzer
     };
@@ -621,6 +668,11 @@ fn check_gitignore_entries(path: &Path, issues: &mut Vec<Issue>) {
             ),
             file: Some(gitignore_path),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some(format!("Add {} to .gitignore", missing.join(" and "))),
             auto_fixable: true,
             references: vec![],
@@ -639,6 +691,11 @@ fn check_missing_rector(path: &Path, issues: &mut Vec<Issue>) {
             description: "Rector automates code upgrades and refactoring for PHP/Symfony projects.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Run `composer require --dev rector/rector` and create rector.php".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -657,6 +714,11 @@ fn check_missing_phpstan(path: &Path, issues: &mut Vec<Issue>) {
             description: "No phpstan.neon or phpstan.neon.dist found. Static analysis catches bugs early.".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Run `composer require --dev phpstan/phpstan` and create phpstan.neon".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -671,6 +733,7 @@ fn check_missing_phpstan(path: &Path, issues: &mut Vec<Issue>) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::content::analyze_with_content;
     use crate::frameworks::detector::{DetectedProject, Language, PackageManager};
     use std::fs as stdfs;
     use tempfile::TempDir;
@@ -744,7 +807,7 @@ mod tests {
         let tmp = TempDir::new().unwrap();
         scaffold_symfony(&tmp);
         let project = make_project(&tmp);
-        let issues = SymfonyAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&SymfonyAnalyzer, &project).await.unwrap();
         // A well-scaffolded project should produce no issues
         assert!(
             issues.is_empty(),
@@ -758,7 +821,7 @@ mod tests {
         let tmp = TempDir::new().unwrap();
         stdfs::create_dir_all(tmp.path().join("src")).unwrap();
         let project = make_project(&tmp);
-        let issues = SymfonyAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&SymfonyAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "SYM-001"));
         assert!(issues.iter().any(|i| i.id == "SYM-002"));
     }
@@ -774,7 +837,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = SymfonyAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&SymfonyAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "SYM-003"));
     }
 
@@ -789,7 +852,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = SymfonyAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&SymfonyAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "SYM-004"));
     }
 
@@ -799,7 +862,7 @@ mod tests {
         scaffold_symfony(&tmp);
         stdfs::write(tmp.path().join(".env"), "APP_SECRET=change_me\n").unwrap();
         let project = make_project(&tmp);
-        let issues = SymfonyAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&SymfonyAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "SYM-012"));
     }
 
@@ -814,7 +877,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = SymfonyAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&SymfonyAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "SYM-013"));
     }
 
@@ -837,7 +900,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = SymfonyAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&SymfonyAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "SYM-020"));
     }
 
@@ -852,7 +915,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = SymfonyAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&SymfonyAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "SYM-030"));
         assert!(issues.iter().any(|i| i.id == "SYM-031"));
         assert!(issues.iter().any(|i| i.id == "SYM-032"));
@@ -868,7 +931,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = SymfonyAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&SymfonyAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "SYM-040"));
     }
 
@@ -882,7 +945,7 @@ mod tests {
         )
         .unwrap();
         let project = make_project(&tmp);
-        let issues = SymfonyAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&SymfonyAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "SYM-042"));
     }
 
@@ -893,7 +956,7 @@ mod tests {
         // Overwrite .gitignore without var/ and vendor/
         stdfs::write(tmp.path().join(".gitignore"), ".env\n").unwrap();
         let project = make_project(&tmp);
-        let issues = SymfonyAnalyzer.analyze(&project).await.unwrap();
+        let issues = analyze_with_content(&SymfonyAnalyzer, &project).await.unwrap();
         assert!(issues.iter().any(|i| i.id == "SYM-050"));
     }
 
@@ -63,7 +63,7 @@ impl TestingAnalyzer {
         for dir in &src_dirs {
             let dir_path = path.join(dir);
             if dir_path.is_dir() {
-                for entry in walkdir::WalkDir::new(&dir_path)
+                for entry in fs::walk(&dir_path)
                     .into_iter()
                     .filter_map(|e| e.ok())
                 {
@@ -96,7 +96,7 @@ impl TestingAnalyzer {
         for dir in &test_dirs {
             let dir_path = path.join(dir);
             if dir_path.is_dir() {
-                for entry in walkdir::WalkDir::new(&dir_path)
+                for entry in fs::walk(&dir_path)
                     .into_iter()
                     .filter_map(|e| e.ok())
                 {
@@ -135,7 +135,7 @@ impl Analyzer for TestingAnalyzer {
     async fn analyze(&self, project: &Project) -> Result<Vec<Issue>> {
         let mut issues = Vec::new();
         let path = &project.path;
-        let framework = &project.detected.framework;
+        let framework = &project.detected.generic_framework();
 
         // TST-001: Check for test directory
         let test_dirs = Self::test_dirs(framework);
@@ -154,6 +154,11 @@ impl Analyzer for TestingAnalyzer {
                 ),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: Some(format!("Create a {} directory with test files", test_dirs[0])),
                 auto_fixable: false,
                 references: vec![],
@@ -177,6 +182,11 @@ impl Analyzer for TestingAnalyzer {
                     ),
                     file: None,
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some("Add a test configuration file for your testing framework".to_string()),
                     auto_fixable: false,
                     references: vec![],
@@ -202,6 +212,11 @@ impl Analyzer for TestingAnalyzer {
                     ),
                     file: None,
                     line: None,
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
                     suggestion: Some("Add test files to cover your source code".to_string()),
                     auto_fixable: false,
                     references: vec![],
@@ -221,6 +236,11 @@ impl Analyzer for TestingAnalyzer {
                         ),
                         file: None,
                         line: None,
+                        column: None,
+                        snippet: None,
+                        fingerprint: None,
+                        estimated_effort: None,
+                        impact: None,
                         suggestion: Some("Aim for at least 1 test file per 3 source files".to_string()),
                         auto_fixable: false,
                         references: vec![],
@@ -268,6 +288,16 @@ mod tests {
         assert!(TestingAnalyzer.applies_to(&project));
     }
 
+    #[tokio::test]
+    async fn test_inferred_language_uses_its_test_layout() {
+        let tmp = TempDir::new().unwrap();
+        let mut project = make_project(&tmp, Framework::Unknown);
+        project.detected.language = Language::Python;
+        let issues = TestingAnalyzer.analyze(&project).await.unwrap();
+        let issue = issues.iter().find(|i| i.id == "TST-001").unwrap();
+        assert_eq!(issue.description, "Expected one of: tests, test");
+    }
+
     #[tokio::test]
     async fn test_no_test_dir() {
         let tmp = TempDir::new().unwrap();
@@ -3,6 +3,7 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+use crate::core::content::ContentMatcher;
 use crate::core::project::Project;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -13,6 +14,25 @@ pub enum AnalyzerCategory {
     Testing,
     Security,
     Documentation,
+    Performance,
+    CiCd,
+}
+
+impl AnalyzerCategory {
+    /// Parse a category name as used in config files (case-insensitive).
+    pub fn from_name(name: &str) -> Option<AnalyzerCategory> {
+        match name.trim().to_lowercase().as_str() {
+            "structure" => Some(AnalyzerCategory::Structure),
+            "dependencies" => Some(AnalyzerCategory::Dependencies),
+            "configuration" => Some(AnalyzerCategory::Configuration),
+            "testing" => Some(AnalyzerCategory::Testing),
+            "security" => Some(AnalyzerCategory::Security),
+            "documentation" => Some(AnalyzerCategory::Documentation),
+            "performance" => Some(AnalyzerCategory::Performance),
+            "cicd" | "ci/cd" | "ci-cd" | "ci" => Some(AnalyzerCategory::CiCd),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for AnalyzerCategory {
@@ -24,6 +44,8 @@ impl std::fmt::Display for AnalyzerCategory {
             AnalyzerCategory::Testing => write!(f, "Testing"),
             AnalyzerCategory::Security => write!(f, "Security"),
             AnalyzerCategory::Documentation => write!(f, "Documentation"),
+            AnalyzerCategory::Performance => write!(f, "Performance"),
+            AnalyzerCategory::CiCd => write!(f, "CI/CD"),
         }
     }
 }
@@ -38,6 +60,18 @@ pub enum Severity {
 }
 
 impl Severity {
+    /// Parse a lowercase/uppercase severity name as used in config files.
+    pub fn from_name(name: &str) -> Option<Severity> {
+        match name.trim().to_lowercase().as_str() {
+            "critical" => Some(Severity::Critical),
+            "high" => Some(Severity::High),
+            "medium" => Some(Severity::Medium),
+            "low" => Some(Severity::Low),
+            "info" => Some(Severity::Info),
+            _ => None,
+        }
+    }
+
     pub fn penalty(&self) -> u8 {
         match self {
             Severity::Critical => 25,
@@ -61,6 +95,103 @@ impl std::fmt::Display for Severity {
     }
 }
 
+/// Rough estimate of the work needed to resolve an issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Effort {
+    Trivial,
+    Small,
+    Medium,
+    Large,
+}
+
+impl Effort {
+    pub fn from_name(name: &str) -> Option<Effort> {
+        match name.trim().to_lowercase().as_str() {
+            "trivial" => Some(Effort::Trivial),
+            "small" => Some(Effort::Small),
+            "medium" => Some(Effort::Medium),
+            "large" => Some(Effort::Large),
+            _ => None,
+        }
+    }
+
+    /// Estimate effort from fixability and category.
+    pub fn estimate(issue: &Issue) -> Effort {
+        if issue.auto_fixable {
+            return Effort::Trivial;
+        }
+        match issue.category {
+            AnalyzerCategory::Documentation
+            | AnalyzerCategory::Configuration
+            | AnalyzerCategory::CiCd => Effort::Small,
+            AnalyzerCategory::Structure
+            | AnalyzerCategory::Dependencies
+            | AnalyzerCategory::Performance => Effort::Medium,
+            AnalyzerCategory::Security | AnalyzerCategory::Testing => Effort::Large,
+        }
+    }
+
+    pub fn cost(&self) -> f64 {
+        match self {
+            Effort::Trivial => 1.0,
+            Effort::Small => 2.0,
+            Effort::Medium => 3.0,
+            Effort::Large => 5.0,
+        }
+    }
+}
+
+impl std::fmt::Display for Effort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Effort::Trivial => write!(f, "trivial"),
+            Effort::Small => write!(f, "small"),
+            Effort::Medium => write!(f, "medium"),
+            Effort::Large => write!(f, "large"),
+        }
+    }
+}
+
+/// How much resolving an issue improves the project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Impact {
+    Low,
+    Medium,
+    High,
+}
+
+impl Impact {
+    pub fn from_name(name: &str) -> Option<Impact> {
+        match name.trim().to_lowercase().as_str() {
+            "low" => Some(Impact::Low),
+            "medium" => Some(Impact::Medium),
+            "high" => Some(Impact::High),
+            _ => None,
+        }
+    }
+
+    /// Estimate impact from severity.
+    pub fn estimate(issue: &Issue) -> Impact {
+        match issue.severity {
+            Severity::Critical | Severity::High => Impact::High,
+            Severity::Medium => Impact::Medium,
+            Severity::Low | Severity::Info => Impact::Low,
+        }
+    }
+}
+
+impl std::fmt::Display for Impact {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Impact::Low => write!(f, "low"),
+            Impact::Medium => write!(f, "medium"),
+            Impact::High => write!(f, "high"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Issue {
     pub id: String,
@@ -71,11 +202,70 @@ pub struct Issue {
     pub description: String,
     pub file: Option<PathBuf>,
     pub line: Option<usize>,
+    /// 1-based column of the match within `line`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    /// Source line the issue was found on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+    /// Stable identifier of this occurrence, assigned by the scanner
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    /// Work needed to resolve the issue, assigned by the scanner
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_effort: Option<Effort>,
+    /// Benefit of resolving the issue, assigned by the scanner
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impact: Option<Impact>,
     pub suggestion: Option<String>,
     pub auto_fixable: bool,
     pub references: Vec<String>,
 }
 
+/// Longest snippet kept on an issue, in characters.
+const MAX_SNIPPET_CHARS: usize = 200;
+
+impl Issue {
+    /// Assigned effort, or an estimate for issues that did not go through the scanner.
+    pub fn resolved_effort(&self) -> Effort {
+        self.estimated_effort.unwrap_or_else(|| Effort::estimate(self))
+    }
+
+    /// Assigned impact, or an estimate for issues that did not go through the scanner.
+    pub fn resolved_impact(&self) -> Impact {
+        self.impact.unwrap_or_else(|| Impact::estimate(self))
+    }
+
+    /// High-impact issues that take almost no work to resolve.
+    pub fn is_quick_win(&self) -> bool {
+        self.resolved_impact() == Impact::High && self.resolved_effort() == Effort::Trivial
+    }
+
+    /// Record the source `line` and the column of the match starting at byte `start`.
+    pub fn with_snippet(mut self, line: &str, start: usize) -> Self {
+        self.column = Some(line[..start].chars().count() + 1);
+        self.snippet = Some(line.trim_end().chars().take(MAX_SNIPPET_CHARS).collect());
+        self
+    }
+
+    /// Split the snippet into `(before, highlighted, after)`, highlighting from
+    /// the column to the end of that token.
+    pub fn snippet_parts(&self) -> Option<(&str, &str, &str)> {
+        let snippet = self.snippet.as_deref()?;
+        let start = match self.column {
+            Some(column) => snippet
+                .char_indices()
+                .nth(column.saturating_sub(1))
+                .map_or(snippet.len(), |(i, _)| i),
+            None => snippet.len(),
+        };
+        let end = snippet[start..]
+            .find(char::is_whitespace)
+            .map_or(snippet.len(), |i| start + i);
+        Some((&snippet[..start], &snippet[start..end], &snippet[end..]))
+    }
+}
+
 #[async_trait]
 pub trait Analyzer: Send + Sync {
     fn name(&self) -> &'static str;
@@ -85,6 +275,14 @@ pub trait Analyzer: Send + Sync {
     fn category(&self) -> AnalyzerCategory;
     fn applies_to(&self, project: &Project) -> bool;
     async fn analyze(&self, project: &Project) -> Result<Vec<Issue>>;
+
+    /// File-content checks run by the shared [`ContentEngine`], which reads
+    /// each file once for all analyzers.
+    ///
+    /// [`ContentEngine`]: crate::core::content::ContentEngine
+    fn content_matchers(&self, _project: &Project) -> Vec<Box<dyn ContentMatcher>> {
+        Vec::new()
+    }
 }
 
 #[cfg(test)]
@@ -108,6 +306,58 @@ mod tests {
         assert_eq!(Severity::Info.penalty(), 0);
     }
 
+    #[test]
+    fn test_severity_from_name() {
+        assert_eq!(Severity::from_name("high"), Some(Severity::High));
+        assert_eq!(Severity::from_name("CRITICAL"), Some(Severity::Critical));
+        assert_eq!(Severity::from_name("nope"), None);
+    }
+
+    #[test]
+    fn test_category_from_name() {
+        assert_eq!(
+            AnalyzerCategory::from_name("Security"),
+            Some(AnalyzerCategory::Security)
+        );
+        assert_eq!(AnalyzerCategory::from_name("unknown"), None);
+        assert_eq!(
+            AnalyzerCategory::from_name("performance"),
+            Some(AnalyzerCategory::Performance)
+        );
+        assert_eq!(AnalyzerCategory::from_name("CI/CD"), Some(AnalyzerCategory::CiCd));
+        assert_eq!(AnalyzerCategory::from_name("ci"), Some(AnalyzerCategory::CiCd));
+        assert_eq!(AnalyzerCategory::CiCd.to_string(), "CI/CD");
+    }
+
+    #[test]
+    fn test_issue_snippet() {
+        let issue = Issue {
+            id: "SEC-001".to_string(),
+            analyzer: "security".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::Critical,
+            title: "Test".to_string(),
+            description: "Test".to_string(),
+            file: None,
+            line: Some(3),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        }
+        .with_snippet("  let é = unsafe { x };   ", 11);
+        assert_eq!(issue.column, Some(11));
+        assert_eq!(issue.snippet.as_deref(), Some("  let é = unsafe { x };"));
+        assert_eq!(
+            issue.snippet_parts(),
+            Some(("  let é = ", "unsafe", " { x };"))
+        );
+    }
+
     #[test]
     fn test_issue_creation() {
         let issue = Issue {
@@ -119,6 +369,11 @@ mod tests {
             description: "A test issue".to_string(),
             file: Some(PathBuf::from("test.rs")),
             line: Some(42),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Fix it".to_string()),
             auto_fixable: false,
             references: vec!["https://example.com".to_string()],
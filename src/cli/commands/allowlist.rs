@@ -0,0 +1,209 @@
+use anyhow::{bail, Context, Result};
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::analyzers::security::match_secret;
+use crate::core::allowlist::{read_source, SecretAllowlist, ALLOWLIST_FILE};
+use crate::core::content::relative_path;
+use crate::core::project::Project;
+
+#[derive(Args, Debug)]
+pub struct AllowlistArgs {
+    #[command(subcommand)]
+    pub action: AllowlistAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AllowlistAction {
+    /// Accept the secret reported at FILE:LINE as a false positive
+    Add(AllowlistAddArgs),
+    /// Remove entries whose secret is no longer found in its file
+    Prune(AllowlistPathArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct AllowlistAddArgs {
+    /// Location of the finding, e.g. config/test.yml:12
+    pub location: String,
+
+    /// Why the finding is not a real secret
+    #[arg(long)]
+    pub reason: Option<String>,
+
+    /// Path to the project (defaults to current directory)
+    #[arg(long, default_value = ".")]
+    pub path: PathBuf,
+}
+
+#[derive(Args, Debug)]
+pub struct AllowlistPathArgs {
+    /// Path to the project (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+}
+
+pub async fn execute(args: &AllowlistArgs) -> Result<()> {
+    match &args.action {
+        AllowlistAction::Add(add_args) => {
+            add(&add_args.path, &add_args.location, add_args.reason.clone())
+        }
+        AllowlistAction::Prune(path_args) => prune(&path_args.path),
+    }
+}
+
+fn add(path: &Path, location: &str, reason: Option<String>) -> Result<()> {
+    let project = Project::new(path)?;
+    let (file, line_num) = location
+        .rsplit_once(':')
+        .and_then(|(file, line)| Some((file, line.parse::<usize>().ok()?)))
+        .with_context(|| format!("'{}' is not a FILE:LINE location", location))?;
+
+    let full_path = project
+        .path
+        .join(file)
+        .canonicalize()
+        .with_context(|| format!("Failed to find {}", file))?;
+    let relative = relative_path(&project.path, &full_path);
+    let content = read_source(&full_path)
+        .with_context(|| format!("Failed to read {} as text", relative))?;
+    let Some(line) = line_num.checked_sub(1).and_then(|i| content.lines().nth(i)) else {
+        bail!("{} has no line {}", relative, line_num);
+    };
+    let Some((name, range)) = match_secret(line) else {
+        bail!("No secret found at {}:{}", relative, line_num);
+    };
+
+    let mut allowlist = SecretAllowlist::load(&project.path)?.unwrap_or_default();
+    if allowlist.add(&relative, &line[range], reason) {
+        allowlist.save(&project.path)?;
+        println!(
+            "  {} {} at {}:{} added to {}",
+            "DONE".green(),
+            name,
+            relative,
+            line_num,
+            ALLOWLIST_FILE
+        );
+    } else {
+        println!(
+            "  {} {} at {}:{} is already allowed",
+            "SKIP".yellow(),
+            name,
+            relative,
+            line_num
+        );
+    }
+    Ok(())
+}
+
+fn prune(path: &Path) -> Result<()> {
+    let project = Project::new(path)?;
+    let Some(mut allowlist) = SecretAllowlist::load(&project.path)? else {
+        println!("  {} No {} found.", "SKIP".yellow(), ALLOWLIST_FILE);
+        return Ok(());
+    };
+
+    // Hash every secret of a file once, however many entries it has
+    let mut found: HashMap<String, HashSet<String>> = HashMap::new();
+    for entry in &allowlist.entries {
+        if found.contains_key(&entry.file) {
+            continue;
+        }
+        let content = read_source(&project.path.join(&entry.file)).unwrap_or_default();
+        let hashes = content
+            .lines()
+            .filter_map(|line| {
+                let (_, range) = match_secret(line)?;
+                Some(allowlist.hash(&entry.file, &line[range]))
+            })
+            .collect();
+        found.insert(entry.file.clone(), hashes);
+    }
+
+    let before = allowlist.entries.len();
+    allowlist
+        .entries
+        .retain(|entry| found[&entry.file].contains(&entry.hash));
+    let removed = before - allowlist.entries.len();
+    allowlist.save(&project.path)?;
+    println!(
+        "  {} {} stale entr{} removed, {} remaining",
+        "DONE".green(),
+        removed,
+        if removed == 1 { "y" } else { "ies" },
+        allowlist.entries.len()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_add_then_prune() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir(tmp.path().join("config")).unwrap();
+        fs::write(
+            tmp.path().join("config/test.yml"),
+            "name: demo\npassword: 'example-password'\n",
+        )
+        .unwrap();
+
+        add(tmp.path(), "config/test.yml:2", Some("fixture".to_string())).unwrap();
+        add(tmp.path(), "config/test.yml:2", None).unwrap();
+        let allowlist = SecretAllowlist::load(tmp.path()).unwrap().unwrap();
+        assert_eq!(allowlist.entries.len(), 1);
+        assert_eq!(allowlist.entries[0].file, "config/test.yml");
+        assert!(allowlist.contains("config/test.yml", "password: 'example-password'"));
+
+        prune(tmp.path()).unwrap();
+        assert_eq!(
+            SecretAllowlist::load(tmp.path())
+                .unwrap()
+                .unwrap()
+                .entries
+                .len(),
+            1
+        );
+
+        fs::write(
+            tmp.path().join("config/test.yml"),
+            "password: 'changed-it'\n",
+        )
+        .unwrap();
+        prune(tmp.path()).unwrap();
+        assert!(SecretAllowlist::load(tmp.path())
+            .unwrap()
+            .unwrap()
+            .entries
+            .is_empty());
+    }
+
+    #[test]
+    fn test_add_in_non_utf8_file() {
+        let tmp = TempDir::new().unwrap();
+        let mut content = b"# caf\xe9 settings\n".to_vec();
+        content.extend_from_slice(b"password: 'example-password'\n");
+        fs::write(tmp.path().join("legacy.yml"), content).unwrap();
+
+        add(tmp.path(), "legacy.yml:2", None).unwrap();
+        prune(tmp.path()).unwrap();
+        let allowlist = SecretAllowlist::load(tmp.path()).unwrap().unwrap();
+        assert_eq!(allowlist.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_add_rejects_lines_without_secret() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("app.yml"), "name: demo\n").unwrap();
+        assert!(add(tmp.path(), "app.yml:1", None).is_err());
+        assert!(add(tmp.path(), "app.yml:5", None).is_err());
+        assert!(add(tmp.path(), "app.yml", None).is_err());
+        assert!(!tmp.path().join(ALLOWLIST_FILE).exists());
+    }
+}
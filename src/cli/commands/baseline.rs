@@ -0,0 +1,223 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use std::path::PathBuf;
+
+use crate::core::baseline::{Baseline, BASELINE_FILE};
+use crate::core::project::Project;
+use crate::core::scanner::default_scanner;
+use crate::utils::date;
+
+#[derive(Args, Debug)]
+pub struct BaselineArgs {
+    #[command(subcommand)]
+    pub action: BaselineAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum BaselineAction {
+    /// Record all current issues in .repodoctor-baseline.json
+    Write(BaselineWriteArgs),
+    /// Remove baseline entries for issues that are no longer detected
+    Prune(BaselinePathArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct BaselineWriteArgs {
+    /// Path to the project (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Expiry date (YYYY-MM-DD) after which recorded issues are reported again
+    #[arg(long, value_parser = parse_expiry)]
+    pub expires: Option<String>,
+}
+
+fn parse_expiry(value: &str) -> Result<String, String> {
+    date::parse_date(value)
+        .map(|_| value.trim().to_string())
+        .ok_or_else(|| format!("'{}' is not a valid YYYY-MM-DD date", value))
+}
+
+#[derive(Args, Debug)]
+pub struct BaselinePathArgs {
+    /// Path to the project (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+}
+
+pub async fn execute(args: &BaselineArgs) -> Result<()> {
+    match &args.action {
+        BaselineAction::Write(write_args) => {
+            write(&write_args.path, write_args.expires.as_deref()).await
+        }
+        BaselineAction::Prune(path_args) => prune(&path_args.path).await,
+    }
+}
+
+async fn write(path: &std::path::Path, expires: Option<&str>) -> Result<()> {
+    let project = Project::new(path)?;
+    let result = default_scanner()
+        .with_baseline(false)
+        .scan(&project)
+        .await?;
+
+    let previous = Baseline::load(&project.path)?;
+    let mut baseline = Baseline::from_issues(&result.issues, &project.path);
+    match (expires, &previous) {
+        (Some(expires), _) => baseline.set_expiry(expires),
+        (None, Some(previous)) => baseline.keep_expiry_from(previous),
+        (None, None) => {}
+    }
+    baseline.save(&project.path)?;
+    if previous.is_some_and(|p| p.is_legacy()) {
+        println!(
+            "  {} {} upgraded from per-file to per-occurrence entries",
+            "NOTE".cyan(),
+            BASELINE_FILE
+        );
+    }
+    println!(
+        "  {} {} issue(s) recorded in {}",
+        "DONE".green(),
+        baseline.entries.len(),
+        BASELINE_FILE
+    );
+    Ok(())
+}
+
+async fn prune(path: &std::path::Path) -> Result<()> {
+    let project = Project::new(path)?;
+    let Some(mut baseline) = Baseline::load(&project.path)? else {
+        println!(
+            "  {} No {} found. Run {} first.",
+            "SKIP".yellow(),
+            BASELINE_FILE,
+            "repodoctor baseline write".bold()
+        );
+        return Ok(());
+    };
+
+    let result = default_scanner()
+        .with_baseline(false)
+        .scan(&project)
+        .await?;
+    let removed = baseline.prune(&result.issues, &project.path);
+    baseline.save(&project.path)?;
+    if baseline.is_legacy() {
+        println!(
+            "  {} {} still uses per-file entries; run {} to record each occurrence",
+            "NOTE".cyan(),
+            BASELINE_FILE,
+            "repodoctor baseline write".bold()
+        );
+    }
+    println!(
+        "  {} {} resolved entr{} removed, {} remaining",
+        "DONE".green(),
+        removed,
+        if removed == 1 { "y" } else { "ies" },
+        baseline.entries.len()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_write_then_scan_is_clean() {
+        let tmp = TempDir::new().unwrap();
+        write(tmp.path(), None).await.unwrap();
+        assert!(tmp.path().join(BASELINE_FILE).is_file());
+
+        let project = Project::new(tmp.path()).unwrap();
+        let result = default_scanner().scan(&project).await.unwrap();
+        assert!(result.issues.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_new_secret_next_to_a_baselined_one_is_reported() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("config.yml"),
+            "api_key: \"abcdefghijklmnop1234\"\n",
+        )
+        .unwrap();
+        write(tmp.path(), None).await.unwrap();
+
+        fs::write(
+            tmp.path().join("config.yml"),
+            "api_key: \"abcdefghijklmnop1234\"\napi_key: \"zyxwvutsrqponmlk9876\"\n",
+        )
+        .unwrap();
+        let project = Project::new(tmp.path()).unwrap();
+        let result = default_scanner().scan(&project).await.unwrap();
+        let secrets: Vec<_> = result.issues.iter().filter(|i| i.id == "SEC-001").collect();
+        assert_eq!(secrets.len(), 1);
+        assert_eq!(secrets[0].line, Some(2));
+    }
+
+    #[tokio::test]
+    async fn test_prune_drops_fixed_issues() {
+        let tmp = TempDir::new().unwrap();
+        write(tmp.path(), None).await.unwrap();
+        let before = Baseline::load(tmp.path()).unwrap().unwrap().entries.len();
+
+        fs::write(tmp.path().join("LICENSE"), "MIT License\n").unwrap();
+        prune(tmp.path()).await.unwrap();
+
+        let after = Baseline::load(tmp.path()).unwrap().unwrap();
+        assert!(after.entries.len() < before);
+        assert!(!after.entries.iter().any(|e| e.id == "STR-004"));
+    }
+
+    #[tokio::test]
+    async fn test_write_upgrades_a_version_1_baseline() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("config.yml"),
+            "api_key: \"abcdefghijklmnop1234\"\n",
+        )
+        .unwrap();
+        write(tmp.path(), Some("2030-01-01")).await.unwrap();
+        let mut legacy = Baseline::load(tmp.path()).unwrap().unwrap();
+        legacy.version = 1;
+        legacy.save(tmp.path()).unwrap();
+
+        write(tmp.path(), None).await.unwrap();
+        let upgraded = Baseline::load(tmp.path()).unwrap().unwrap();
+        assert!(!upgraded.is_legacy());
+        assert_eq!(upgraded.entries.len(), legacy.entries.len());
+        assert!(upgraded
+            .entries
+            .iter()
+            .all(|e| e.expires.as_deref() == Some("2030-01-01")));
+    }
+
+    #[tokio::test]
+    async fn test_write_with_expiry() {
+        let tmp = TempDir::new().unwrap();
+        write(tmp.path(), Some("2000-01-01")).await.unwrap();
+        let baseline = Baseline::load(tmp.path()).unwrap().unwrap();
+        assert!(baseline
+            .entries
+            .iter()
+            .all(|e| e.expires.as_deref() == Some("2000-01-01")));
+
+        // Expired entries no longer hide issues
+        let project = Project::new(tmp.path()).unwrap();
+        let result = default_scanner().scan(&project).await.unwrap();
+        assert_eq!(result.issues.len(), baseline.entries.len());
+        assert!(result.expiring.iter().all(|e| e.days_left < 0));
+    }
+
+    #[test]
+    fn test_parse_expiry() {
+        assert!(parse_expiry("2025-12-31").is_ok());
+        assert!(parse_expiry("31/12/2025").is_err());
+    }
+}
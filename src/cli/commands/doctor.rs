@@ -0,0 +1,392 @@
+use anyhow::Result;
+use clap::Args;
+use colored::Colorize;
+use std::ffi::OsString;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use crate::analyzers::traits::Issue;
+use crate::core::baseline::{Baseline, BASELINE_FILE};
+use crate::core::plan::RemediationPlan;
+use crate::core::profile::Profile;
+use crate::core::project::Project;
+use crate::core::scanner::{default_scanner, Scanner};
+use crate::fixers::default_registry;
+use crate::fixers::registry::FixOutcome;
+
+#[derive(Args, Debug)]
+pub struct DoctorArgs {
+    /// Path to the project to examine (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Number of top findings to walk through
+    #[arg(long, default_value_t = 10)]
+    pub limit: usize,
+
+    /// Strictness profile (overrides `profile` in .repodoctor.yml)
+    #[arg(long, value_parser = ["strict", "recommended", "relaxed"])]
+    pub profile: Option<String>,
+}
+
+/// What the user chose to do with a finding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Fix,
+    Suppress,
+    Open,
+    Next,
+    Quit,
+}
+
+impl Action {
+    fn parse(input: &str) -> Option<Action> {
+        match input.trim().to_lowercase().as_str() {
+            "f" | "fix" => Some(Action::Fix),
+            "s" | "suppress" => Some(Action::Suppress),
+            "o" | "open" => Some(Action::Open),
+            "" | "n" | "next" => Some(Action::Next),
+            "q" | "quit" => Some(Action::Quit),
+            _ => None,
+        }
+    }
+}
+
+pub async fn execute(args: &DoctorArgs) -> Result<()> {
+    let input = std::io::BufReader::new(std::io::stdin());
+    let mut output = std::io::stdout();
+    run_session(args, input, &mut output).await
+}
+
+/// Read one line off the runtime's worker threads. Returns the reader back
+/// with the line, or `None` at end of input.
+async fn read_line<R: BufRead + Send + 'static>(mut input: R) -> Result<(R, Option<String>)> {
+    tokio::task::spawn_blocking(move || {
+        let mut line = String::new();
+        let read = input.read_line(&mut line)?;
+        Ok((input, (read > 0).then_some(line)))
+    })
+    .await?
+}
+
+fn scanner_for(args: &DoctorArgs) -> Scanner {
+    default_scanner().with_profile(args.profile.as_deref().and_then(Profile::from_name))
+}
+
+async fn run_session<R: BufRead + Send + 'static, W: Write>(
+    args: &DoctorArgs,
+    mut input: R,
+    out: &mut W,
+) -> Result<()> {
+    let project = Project::new(&args.path)?;
+    let result = scanner_for(args).scan(&project).await?;
+    let before = result.score.total;
+
+    let plan = RemediationPlan::build(&result.issues);
+    if plan.steps.is_empty() {
+        writeln!(out, "{}", "No issues found. Your repository is healthy!".green())?;
+        return Ok(());
+    }
+
+    let registry = default_registry();
+    let total = plan.steps.len().min(args.limit);
+    writeln!(
+        out,
+        "\n  {} Health score {}/100, {} finding(s). Walking through the top {}.\n",
+        "DOCTOR".bold(),
+        before,
+        plan.steps.len(),
+        total
+    )?;
+
+    let mut changed = false;
+    'findings: for step in plan.steps.iter().take(total) {
+        let issue = &step.issue;
+        explain(out, step.step, total, issue, step.score_gain)?;
+
+        let fixer = registry.find_fixer(&issue.id).filter(|_| issue.auto_fixable);
+        let mut choices = Vec::new();
+        if let Some(fixer) = fixer {
+            choices.push(format!("[f]ix ({})", fixer.describe(issue, &project)));
+        }
+        choices.push("[s]uppress".to_string());
+        if issue.file.is_some() {
+            choices.push("[o]pen".to_string());
+        }
+        choices.push("[n]ext".to_string());
+        choices.push("[q]uit".to_string());
+
+        loop {
+            write!(out, "  {} ", format!("{} >", choices.join(" ")).cyan())?;
+            out.flush()?;
+
+            let (rest, line) = read_line(input).await?;
+            input = rest;
+            let Some(line) = line else {
+                writeln!(out)?;
+                break 'findings;
+            };
+
+            match Action::parse(&line) {
+                Some(Action::Fix) if fixer.is_some() => {
+                    for (_, outcome) in registry.apply_fixes(&[issue], &project, false) {
+                        match outcome {
+                            FixOutcome::Applied(desc) => {
+                                writeln!(out, "  {} {}", "FIXED".green(), desc)?;
+                                changed = true;
+                            }
+                            FixOutcome::Skipped(reason) => {
+                                writeln!(out, "  {} {}", "SKIP".yellow(), reason)?
+                            }
+                            FixOutcome::DryRun(desc) => writeln!(out, "  {}", desc)?,
+                            FixOutcome::Error(err) => {
+                                writeln!(out, "  {} {}", "ERROR".red(), err)?
+                            }
+                        }
+                    }
+                    break;
+                }
+                Some(Action::Suppress) => {
+                    let mut baseline = Baseline::load(&project.path)?
+                        .unwrap_or_else(|| Baseline::from_issues(&[], &project.path));
+                    if baseline.add(issue, &project.path)? {
+                        baseline.save(&project.path)?;
+                        writeln!(
+                            out,
+                            "  {} this {} finding was added to {}",
+                            "SUPPRESSED".yellow(),
+                            issue.id,
+                            BASELINE_FILE
+                        )?;
+                        changed = true;
+                    } else {
+                        writeln!(out, "  This finding is already in {}", BASELINE_FILE)?;
+                    }
+                    break;
+                }
+                Some(Action::Open) if issue.file.is_some() => {
+                    if let Err(err) = open_in_editor(&project.path, issue) {
+                        writeln!(out, "  {} {}", "ERROR".red(), err)?;
+                    }
+                }
+                Some(Action::Next) => break,
+                Some(Action::Quit) => {
+                    writeln!(out)?;
+                    break 'findings;
+                }
+                _ => writeln!(out, "  Unknown choice.")?,
+            }
+        }
+        writeln!(out)?;
+    }
+
+    if changed {
+        let after = scanner_for(args).scan(&project).await?.score.total;
+        let delta = after as i16 - before as i16;
+        let summary = format!("Health score {}/100 -> {}/100 ({:+})", before, after, delta);
+        if delta > 0 {
+            writeln!(out, "  {}", summary.green().bold())?;
+        } else {
+            writeln!(out, "  {}", summary.bold())?;
+        }
+    } else {
+        writeln!(out, "  Health score unchanged at {}/100", before)?;
+    }
+
+    Ok(())
+}
+
+fn explain<W: Write>(
+    out: &mut W,
+    index: usize,
+    total: usize,
+    issue: &Issue,
+    gain: u8,
+) -> std::io::Result<()> {
+    writeln!(out, "{}", "─".repeat(64))?;
+    writeln!(
+        out,
+        "  [{}/{}] {} {}  ({}, +{} pts)",
+        index,
+        total,
+        issue.id.bold(),
+        issue.title,
+        issue.severity,
+        gain
+    )?;
+    writeln!(out, "\n  {}", issue.description)?;
+    if let Some(file) = &issue.file {
+        writeln!(
+            out,
+            "  File: {}{}",
+            file.to_string_lossy(),
+            issue
+                .line
+                .map(|l| format!(" (line {})", l))
+                .unwrap_or_default()
+        )?;
+    }
+    if let Some(suggestion) = &issue.suggestion {
+        writeln!(out, "  Suggestion: {}", suggestion.dimmed())?;
+    }
+    for reference in &issue.references {
+        writeln!(out, "  See: {}", reference)?;
+    }
+    writeln!(out)
+}
+
+/// Open the issue's file in `$VISUAL`/`$EDITOR`, or the platform opener.
+fn open_in_editor(root: &Path, issue: &Issue) -> Result<()> {
+    let file = match &issue.file {
+        Some(f) if f.is_absolute() => f.clone(),
+        Some(f) => root.join(f),
+        None => return Ok(()),
+    };
+
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|e| !e.trim().is_empty());
+
+    let status = match editor {
+        Some(editor) => {
+            let mut parts = editor.split_whitespace();
+            let program = parts.next().unwrap_or_default();
+            std::process::Command::new(program)
+                .args(parts)
+                .args(editor_args(program, &file, issue.line))
+                .status()?
+        }
+        None => {
+            let opener = if cfg!(target_os = "macos") {
+                "open"
+            } else if cfg!(windows) {
+                "explorer"
+            } else {
+                "xdg-open"
+            };
+            std::process::Command::new(opener).arg(&file).status()?
+        }
+    };
+
+    if !status.success() {
+        anyhow::bail!("editor exited with {}", status);
+    }
+    Ok(())
+}
+
+/// Arguments opening `file` in `program`, at `line` for editors known to
+/// accept a line number. Other editors just get the file.
+fn editor_args(program: &str, file: &Path, line: Option<usize>) -> Vec<OsString> {
+    let name = Path::new(program)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match (name.as_str(), line) {
+        ("vi" | "vim" | "nvim" | "emacs" | "emacsclient" | "nano", Some(line)) => {
+            vec![format!("+{}", line).into(), file.into()]
+        }
+        ("code" | "code-insiders" | "codium", Some(line)) => {
+            let mut target = file.as_os_str().to_owned();
+            target.push(format!(":{}", line));
+            vec!["-g".into(), target]
+        }
+        _ => vec![file.into()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn args_for(tmp: &TempDir) -> DoctorArgs {
+        DoctorArgs {
+            path: tmp.path().to_path_buf(),
+            limit: 10,
+            profile: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_action() {
+        assert_eq!(Action::parse("f\n"), Some(Action::Fix));
+        assert_eq!(Action::parse("Suppress"), Some(Action::Suppress));
+        assert_eq!(Action::parse("\n"), Some(Action::Next));
+        assert_eq!(Action::parse("q"), Some(Action::Quit));
+        assert_eq!(Action::parse("x"), None);
+    }
+
+    #[tokio::test]
+    async fn test_fix_and_rescore() {
+        colored::control::set_override(false);
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("src")).unwrap();
+        fs::create_dir_all(tmp.path().join("tests")).unwrap();
+        fs::write(tmp.path().join("README.md"), "# Test\n\nA test project with enough words.").unwrap();
+
+        // Fix every finding that offers it, then quit.
+        let input = std::io::Cursor::new("f\n".repeat(10));
+        let mut out = Vec::new();
+        run_session(&args_for(&tmp), input, &mut out).await.unwrap();
+
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains("FIXED"));
+        assert!(output.contains("Health score"));
+        assert!(output.contains("->"));
+    }
+
+    #[tokio::test]
+    async fn test_suppress_records_the_finding_in_the_baseline() {
+        colored::control::set_override(false);
+        let tmp = TempDir::new().unwrap();
+        // Two findings with the same rule, file and title
+        fs::write(
+            tmp.path().join("config.yml"),
+            "api_key: \"abcdefghijklmnop1234\"\napi_key: \"zyxwvutsrqponmlk9876\"\n",
+        )
+        .unwrap();
+
+        let project = Project::new(tmp.path()).unwrap();
+        let result = scanner_for(&args_for(&tmp)).scan(&project).await.unwrap();
+        let steps = RemediationPlan::build(&result.issues).steps;
+        let first = steps.iter().position(|s| s.issue.id == "SEC-001").unwrap();
+
+        // Skip to the first secret and suppress it
+        let input = std::io::Cursor::new(format!("{}s\nq\n", "n\n".repeat(first)));
+        let args = DoctorArgs {
+            limit: steps.len(),
+            ..args_for(&tmp)
+        };
+        let mut out = Vec::new();
+        run_session(&args, input, &mut out).await.unwrap();
+
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains("SUPPRESSED this SEC-001 finding"));
+        let baseline = Baseline::load(tmp.path()).unwrap().unwrap();
+        assert_eq!(baseline.entries.len(), 1);
+
+        // Only this finding: the second occurrence in the file is still reported
+        let result = scanner_for(&args_for(&tmp)).scan(&project).await.unwrap();
+        let secrets: Vec<_> = result.issues.iter().filter(|i| i.id == "SEC-001").collect();
+        assert_eq!(secrets.len(), 1);
+        assert_eq!(secrets[0].line, Some(2));
+    }
+
+    #[test]
+    fn test_editor_args() {
+        let file = Path::new("src/main.rs");
+        let args = |program: &str, line: Option<usize>| -> Vec<String> {
+            editor_args(program, file, line)
+                .iter()
+                .map(|a| a.to_string_lossy().into_owned())
+                .collect()
+        };
+        assert_eq!(args("vim", Some(12)), ["+12", "src/main.rs"]);
+        assert_eq!(args("/usr/bin/nano", Some(3)), ["+3", "src/main.rs"]);
+        assert_eq!(args("code", Some(12)), ["-g", "src/main.rs:12"]);
+        assert_eq!(args("subl", Some(12)), ["src/main.rs"]);
+        assert_eq!(args("vim", None), ["src/main.rs"]);
+    }
+}
@@ -1,12 +1,16 @@
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use clap::Args;
 use colored::Colorize;
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
 
+use crate::analyzers::traits::Issue;
 use crate::core::project::Project;
 use crate::core::scanner::default_scanner;
 use crate::fixers::default_registry;
-use crate::fixers::registry::FixOutcome;
+use crate::fixers::registry::{FixOutcome, FixerRegistry};
+use crate::utils::{date, git};
 
 #[derive(Args, Debug)]
 pub struct FixArgs {
@@ -22,9 +26,21 @@ pub struct FixArgs {
     #[arg(long)]
     pub auto: bool,
 
+    /// Show each planned change and ask before applying it
+    #[arg(long, conflicts_with_all = ["auto", "dry_run"])]
+    pub confirm: bool,
+
+    /// Append each --confirm decision to FILE
+    #[arg(long, value_name = "FILE", requires = "confirm")]
+    pub log: Option<PathBuf>,
+
     /// Only fix issues matching these IDs (comma-separated, e.g. STR-001,STR-003)
     #[arg(long, value_delimiter = ',')]
     pub only: Option<Vec<String>>,
+
+    /// Only touch files staged in git (or created by a fix), then restage them
+    #[arg(long)]
+    pub staged: bool,
 }
 
 pub async fn execute(args: &FixArgs) -> Result<()> {
@@ -56,7 +72,45 @@ pub async fn execute(args: &FixArgs) -> Result<()> {
     );
 
     let registry = default_registry();
-    let results = registry.apply_fixes(&fixable_issues, &project, args.dry_run);
+    let mut results = Vec::new();
+    let mut staged_targets = Vec::new();
+    if args.staged {
+        let scope = StagedScope::load(&project)?;
+        fixable_issues.retain(|issue| match scope.exclusion(&registry, issue, &project) {
+            Some(reason) => {
+                results.push((issue.id.clone(), FixOutcome::Skipped(reason)));
+                false
+            }
+            None => true,
+        });
+        staged_targets = fixable_issues
+            .iter()
+            .filter_map(|issue| registry.find_fixer(&issue.id)?.target(issue, &project))
+            .collect();
+        staged_targets.sort();
+        staged_targets.dedup();
+    }
+
+    results.extend(if args.confirm {
+        let stdin = std::io::stdin();
+        let decisions = confirm_fixes(
+            &registry,
+            &fixable_issues,
+            &project,
+            &mut stdin.lock(),
+            &mut std::io::stdout(),
+        )?;
+        if let Some(log) = &args.log {
+            append_log(log, &decisions)?;
+        }
+        println!();
+        decisions
+            .into_iter()
+            .map(|(id, _, outcome)| (id, outcome))
+            .collect()
+    } else {
+        registry.apply_fixes(&fixable_issues, &project, args.dry_run)
+    });
 
     let mut applied = 0;
     let mut skipped = 0;
@@ -82,17 +136,390 @@ pub async fn execute(args: &FixArgs) -> Result<()> {
     }
 
     if !args.dry_run {
+        // Skipped fixes leave staged files as they were, so adding them is a no-op
+        staged_targets.retain(|target| project.path.join(target).exists());
+        git::stage(&project.path, &staged_targets)?;
+        if !staged_targets.is_empty() {
+            println!("  {} {}", "STAGED".green(), staged_targets.join(", "));
+        }
         println!("\n{} fixed, {} skipped.", applied, skipped);
     }
 
     Ok(())
 }
 
+/// Files `fix --staged` may touch: staged files without further unstaged
+/// changes, and files that do not exist yet.
+struct StagedScope {
+    staged: HashSet<String>,
+    unstaged: HashSet<String>,
+}
+
+impl StagedScope {
+    fn load(project: &Project) -> Result<Self> {
+        let (Some(staged), Some(unstaged)) = (
+            git::staged_paths(&project.path),
+            git::unstaged_paths(&project.path),
+        ) else {
+            bail!("--staged needs a git repository at {}", project.path.display());
+        };
+        Ok(Self {
+            staged: staged.into_iter().collect(),
+            unstaged: unstaged.into_iter().collect(),
+        })
+    }
+
+    /// Why the fix for `issue` has to be left out, if it does.
+    fn exclusion(
+        &self,
+        registry: &FixerRegistry,
+        issue: &Issue,
+        project: &Project,
+    ) -> Option<String> {
+        let fixer = registry.find_fixer(&issue.id)?;
+        let Some(target) = fixer.target(issue, project) else {
+            return Some("Does not write a file that can be staged".to_string());
+        };
+        if !project.path.join(&target).exists() {
+            None
+        } else if !self.staged.contains(&target) {
+            Some(format!("{} is not staged", target))
+        } else if self.unstaged.contains(&target) {
+            Some(format!("{} has unstaged changes", target))
+        } else {
+            None
+        }
+    }
+}
+
+/// Answer to a `--confirm` prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Decision {
+    Yes,
+    No,
+    All,
+    Quit,
+}
+
+impl Decision {
+    fn parse(input: &str) -> Option<Decision> {
+        match input.trim().to_lowercase().as_str() {
+            "y" | "yes" => Some(Decision::Yes),
+            "" | "n" | "no" => Some(Decision::No),
+            "a" | "all" => Some(Decision::All),
+            "q" | "quit" => Some(Decision::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// How each fix was decided on, for the `--log` file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Verdict {
+    Accepted,
+    AcceptedAll,
+    Declined,
+    NotReviewed,
+}
+
+impl Verdict {
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Accepted => "accepted",
+            Verdict::AcceptedAll => "accepted-all",
+            Verdict::Declined => "declined",
+            Verdict::NotReviewed => "not-reviewed",
+        }
+    }
+}
+
+/// Walk through the fixes one by one, asking y/N/a(ll)/q(uit) before each.
+///
+/// `all` applies the current fix and every remaining one without asking;
+/// `quit` (or end of input) leaves the remaining fixes unapplied.
+fn confirm_fixes<R: BufRead, W: Write>(
+    registry: &FixerRegistry,
+    issues: &[&Issue],
+    project: &Project,
+    input: &mut R,
+    out: &mut W,
+) -> Result<Vec<(String, Verdict, FixOutcome)>> {
+    let mut decisions = Vec::new();
+    let mut apply_all = false;
+    let mut stopped = false;
+
+    for (index, issue) in issues.iter().enumerate() {
+        let Some(fixer) = registry.find_fixer(&issue.id) else {
+            decisions.push((
+                issue.id.clone(),
+                Verdict::NotReviewed,
+                FixOutcome::Skipped("No fixer available".to_string()),
+            ));
+            continue;
+        };
+
+        let verdict = if stopped {
+            Verdict::NotReviewed
+        } else if apply_all {
+            Verdict::AcceptedAll
+        } else {
+            writeln!(
+                out,
+                "\n  [{}/{}] {} {}",
+                index + 1,
+                issues.len(),
+                format!("[{}]", issue.id).bold(),
+                issue.title
+            )?;
+            writeln!(out, "  Planned: {}", fixer.describe(issue, project))?;
+            loop {
+                write!(out, "  {} ", "Apply this fix? [y/N/a/q] >".cyan())?;
+                out.flush()?;
+
+                let mut line = String::new();
+                if input.read_line(&mut line)? == 0 {
+                    writeln!(out)?;
+                    stopped = true;
+                    break Verdict::NotReviewed;
+                }
+                match Decision::parse(&line) {
+                    Some(Decision::Yes) => break Verdict::Accepted,
+                    Some(Decision::No) => break Verdict::Declined,
+                    Some(Decision::All) => {
+                        apply_all = true;
+                        break Verdict::AcceptedAll;
+                    }
+                    Some(Decision::Quit) => {
+                        stopped = true;
+                        break Verdict::NotReviewed;
+                    }
+                    None => writeln!(out, "  Please answer y, n, a, or q.")?,
+                }
+            }
+        };
+
+        let outcome = match verdict {
+            Verdict::Accepted | Verdict::AcceptedAll => registry
+                .apply_fixes(&[*issue], project, false)
+                .pop()
+                .map(|(_, outcome)| outcome)
+                .unwrap_or_else(|| FixOutcome::Skipped("No fixer available".to_string())),
+            Verdict::Declined => FixOutcome::Skipped("Declined".to_string()),
+            Verdict::NotReviewed => FixOutcome::Skipped("Not reviewed".to_string()),
+        };
+        decisions.push((issue.id.clone(), verdict, outcome));
+    }
+
+    Ok(decisions)
+}
+
+/// Append `date<TAB>id<TAB>decision<TAB>detail` lines to the decision log.
+fn append_log(path: &Path, decisions: &[(String, Verdict, FixOutcome)]) -> Result<()> {
+    let today = date::format_date(date::today());
+    let mut log = String::new();
+    for (id, verdict, outcome) in decisions {
+        let detail = match outcome {
+            FixOutcome::Applied(desc) | FixOutcome::DryRun(desc) => desc.as_str(),
+            FixOutcome::Skipped(reason) => reason.as_str(),
+            FixOutcome::Error(err) => err.as_str(),
+        };
+        log.push_str(&format!("{}\t{}\t{}\t{}\n", today, id, verdict.as_str(), detail));
+    }
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(log.as_bytes()))
+        .with_context(|| format!("Failed to write fix log {}", path.display()))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::analyzers::traits::{AnalyzerCategory, Issue, Severity};
+    use super::*;
+    use crate::analyzers::traits::{AnalyzerCategory, Severity};
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::Unknown,
+                language: Language::Unknown,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    fn missing_dir(name: &str) -> Issue {
+        Issue {
+            id: "STR-001".to_string(),
+            analyzer: "structure".to_string(),
+            category: AnalyzerCategory::Structure,
+            severity: Severity::High,
+            title: format!("Missing required directory: {}", name),
+            description: String::new(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: true,
+            references: vec![],
+        }
+    }
+
+    #[test]
+    fn test_staged_scope() {
+        let tmp = TempDir::new().unwrap();
+        let git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(tmp.path())
+                .output()
+                .map(|o| o.status.success())
+                .unwrap_or(false)
+        };
+        if !git(&["init", "-q"]) {
+            return; // git not available
+        }
+        fs::write(tmp.path().join(".gitignore"), "target/\n").unwrap();
+        fs::write(tmp.path().join("README.md"), "# Demo\n").unwrap();
+        git(&["add", "."]);
+        git(&["-c", "user.name=t", "-c", "user.email=t@t", "commit", "-qm", "init"]);
+
+        // README.md is fully staged, .gitignore only partly, .editorconfig not at all
+        fs::write(tmp.path().join("README.md"), "# Demo\n\nUsage\n").unwrap();
+        fs::write(tmp.path().join(".gitignore"), "target/\n.env\n").unwrap();
+        git(&["add", "README.md", ".gitignore"]);
+        fs::write(tmp.path().join(".gitignore"), "target/\n.env\n*.log\n").unwrap();
+        fs::write(tmp.path().join(".editorconfig"), "root = true\n").unwrap();
+
+        let project = make_project(&tmp);
+        let scope = StagedScope::load(&project).unwrap();
+        let registry = default_registry();
+        let exclusion = |id: &str| {
+            let issue = Issue {
+                id: id.to_string(),
+                ..missing_dir("tests")
+            };
+            scope.exclusion(&registry, &issue, &project)
+        };
+
+        assert_eq!(exclusion("STR-002"), None);
+        assert_eq!(exclusion("STR-004"), None); // LICENSE would be a new file
+        assert_eq!(
+            exclusion("CFG-003").as_deref(),
+            Some(".gitignore has unstaged changes")
+        );
+        assert_eq!(
+            exclusion("CFG-002").as_deref(),
+            Some(".editorconfig is not staged")
+        );
+        assert_eq!(
+            exclusion("STR-001").as_deref(),
+            Some("Does not write a file that can be staged")
+        );
+
+        fs::write(tmp.path().join("LICENSE"), "MIT\n").unwrap();
+        git::stage(&project.path, &["LICENSE".to_string()]).unwrap();
+        assert!(git::staged_paths(&project.path)
+            .unwrap()
+            .contains(&"LICENSE".to_string()));
+    }
+
+    #[test]
+    fn test_staged_scope_needs_git() {
+        let tmp = TempDir::new().unwrap();
+        assert!(StagedScope::load(&make_project(&tmp)).is_err());
+    }
+
+    fn confirm(tmp: &TempDir, issues: &[Issue], answers: &str) -> (Vec<Verdict>, String) {
+        let project = make_project(tmp);
+        let refs: Vec<&Issue> = issues.iter().collect();
+        let mut out = Vec::new();
+        let decisions = confirm_fixes(
+            &default_registry(),
+            &refs,
+            &project,
+            &mut answers.as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+        (
+            decisions.into_iter().map(|(_, verdict, _)| verdict).collect(),
+            String::from_utf8(out).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_decision_parse() {
+        assert_eq!(Decision::parse("Y\n"), Some(Decision::Yes));
+        assert_eq!(Decision::parse("\n"), Some(Decision::No));
+        assert_eq!(Decision::parse("all"), Some(Decision::All));
+        assert_eq!(Decision::parse("q"), Some(Decision::Quit));
+        assert_eq!(Decision::parse("maybe"), None);
+    }
+
+    #[test]
+    fn test_confirm_applies_only_accepted_fixes() {
+        let tmp = TempDir::new().unwrap();
+        let issues = [missing_dir("src"), missing_dir("tests"), missing_dir("docs")];
+        let (verdicts, out) = confirm(&tmp, &issues, "y\nwhat\n\ny\n");
+
+        assert_eq!(verdicts, [Verdict::Accepted, Verdict::Declined, Verdict::Accepted]);
+        assert!(out.contains("Planned: "));
+        assert!(out.contains("Please answer"));
+        assert!(tmp.path().join("src").is_dir());
+        assert!(!tmp.path().join("tests").exists());
+        assert!(tmp.path().join("docs").is_dir());
+    }
+
+    #[test]
+    fn test_confirm_all_and_quit() {
+        let tmp = TempDir::new().unwrap();
+        let issues = [missing_dir("src"), missing_dir("tests")];
+        let (verdicts, _) = confirm(&tmp, &issues, "a\n");
+        assert_eq!(verdicts, [Verdict::AcceptedAll, Verdict::AcceptedAll]);
+        assert!(tmp.path().join("tests").is_dir());
+
+        let tmp = TempDir::new().unwrap();
+        let (verdicts, _) = confirm(&tmp, &issues, "q\n");
+        assert_eq!(verdicts, [Verdict::NotReviewed, Verdict::NotReviewed]);
+        assert!(!tmp.path().join("src").exists());
+
+        // End of input behaves like quit
+        let (verdicts, _) = confirm(&tmp, &issues, "y\n");
+        assert_eq!(verdicts, [Verdict::Accepted, Verdict::NotReviewed]);
+    }
+
+    #[test]
+    fn test_append_log() {
+        let tmp = TempDir::new().unwrap();
+        let log = tmp.path().join("fix.log");
+        let decisions = [
+            ("STR-001".to_string(), Verdict::Accepted, FixOutcome::Applied("Created src/".to_string())),
+            ("STR-003".to_string(), Verdict::Declined, FixOutcome::Skipped("Declined".to_string())),
+        ];
+        append_log(&log, &decisions).unwrap();
+        append_log(&log, &decisions[..1]).unwrap();
+
+        let content = fs::read_to_string(&log).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].ends_with("\tSTR-001\taccepted\tCreated src/"));
+        assert!(lines[1].ends_with("\tSTR-003\tdeclined\tDeclined"));
+    }
 
     #[test]
+    #[allow(clippy::useless_vec, clippy::manual_contains)]
     fn test_only_flag_filters_issues() {
         let issues = vec![
             Issue {
@@ -104,6 +531,11 @@ mod tests {
                 description: "No src/ directory".to_string(),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: None,
                 auto_fixable: true,
                 references: vec![],
@@ -117,6 +549,11 @@ mod tests {
                 description: "No .gitignore".to_string(),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: None,
                 auto_fixable: true,
                 references: vec![],
@@ -130,6 +567,11 @@ mod tests {
                 description: "No .editorconfig".to_string(),
                 file: None,
                 line: None,
+                column: None,
+                snippet: None,
+                fingerprint: None,
+                estimated_effort: None,
+                impact: None,
                 suggestion: None,
                 auto_fixable: true,
                 references: vec![],
@@ -0,0 +1,56 @@
+use anyhow::{bail, Result};
+use clap::Args;
+use colored::Colorize;
+use std::path::PathBuf;
+
+use crate::core::depgraph::DependencyGraph;
+use crate::core::project::Project;
+
+#[derive(Args, Debug)]
+pub struct GraphArgs {
+    /// Path to the project (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Graph format
+    #[arg(long, default_value = "dot", value_parser = ["dot", "mermaid"])]
+    pub format: String,
+
+    /// Only show the workspace packages and the dependencies between them
+    #[arg(long)]
+    pub workspace_only: bool,
+
+    /// Write to this file instead of stdout
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+}
+
+pub async fn execute(args: &GraphArgs) -> Result<()> {
+    let project = Project::new(&args.path)?;
+    let graph = DependencyGraph::build(&project.path);
+    if graph.is_empty() {
+        bail!(
+            "No package manifest found in {} (Cargo.toml, package.json, composer.json, pubspec.yaml, pyproject.toml)",
+            project.path.display()
+        );
+    }
+
+    let content = match args.format.as_str() {
+        "mermaid" => graph.to_mermaid(args.workspace_only),
+        _ => graph.to_dot(args.workspace_only),
+    };
+
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, &content)?;
+            println!(
+                "  {} Graph of {} package(s) written to {}",
+                "DONE".green(),
+                graph.packages.len(),
+                path.display()
+            );
+        }
+        None => print!("{}", content),
+    }
+    Ok(())
+}
@@ -3,6 +3,7 @@ use clap::Args;
 use colored::Colorize;
 use std::path::PathBuf;
 
+use crate::core::project_type::ProjectType;
 use crate::frameworks::detector::{Framework, FrameworkDetector};
 
 #[derive(Args, Debug)]
@@ -30,13 +31,15 @@ pub async fn execute(args: &InitArgs) -> Result<()> {
     }
 
     let detected = FrameworkDetector::detect(&path);
-    let config = generate_config(&detected.framework);
+    let project_type = ProjectType::guess(&path, &detected.framework);
+    let config = generate_config(&detected.framework, project_type);
 
     std::fs::write(&config_path, config)?;
     println!(
-        "  {} .repodoctor.yml created for {} project",
+        "  {} .repodoctor.yml created for {} {}",
         "DONE".green(),
-        detected.framework.to_string().cyan()
+        detected.framework.to_string().cyan(),
+        project_type
     );
     println!(
         "  Edit {} to customize rules and thresholds.",
@@ -46,7 +49,7 @@ pub async fn execute(args: &InitArgs) -> Result<()> {
     Ok(())
 }
 
-fn generate_config(framework: &Framework) -> String {
+fn generate_config(framework: &Framework, project_type: ProjectType) -> String {
     let ignore_paths = match framework {
         Framework::Symfony | Framework::Laravel => "    - vendor/\n    - var/\n    - node_modules/",
         Framework::Flutter => "    - build/\n    - .dart_tool/\n    - .flutter-plugins",
@@ -60,6 +63,9 @@ fn generate_config(framework: &Framework) -> String {
         r#"# RepoDoctor configuration
 # Docs: https://github.com/Jonathanlight/repodoctor
 
+# What the project ships (library, application, service); adjusts severities
+project_type: {project_type}
+
 # Minimum severity to report (info, low, medium, high, critical)
 severity_threshold: low
 
@@ -129,23 +135,38 @@ mod tests {
         assert!(content.contains("target/"));
     }
 
+    #[tokio::test]
+    async fn test_init_writes_guessed_project_type() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir(tmp.path().join("src")).unwrap();
+        fs::write(tmp.path().join("Cargo.toml"), "[package]\nversion = \"0.1.0\"\n").unwrap();
+        fs::write(tmp.path().join("src/lib.rs"), "").unwrap();
+        let args = InitArgs {
+            path: tmp.path().to_path_buf(),
+            force: false,
+        };
+        execute(&args).await.unwrap();
+        let config = crate::core::config::Config::load(tmp.path());
+        assert_eq!(config.project_type(), Some(ProjectType::Library));
+    }
+
     #[test]
     fn test_generate_config_symfony() {
-        let config = generate_config(&Framework::Symfony);
+        let config = generate_config(&Framework::Symfony, ProjectType::Application);
         assert!(config.contains("vendor/"));
         assert!(config.contains("var/"));
     }
 
     #[test]
     fn test_generate_config_flutter() {
-        let config = generate_config(&Framework::Flutter);
+        let config = generate_config(&Framework::Flutter, ProjectType::Application);
         assert!(config.contains("build/"));
         assert!(config.contains(".dart_tool/"));
     }
 
     #[test]
     fn test_generate_config_nextjs() {
-        let config = generate_config(&Framework::NextJs);
+        let config = generate_config(&Framework::NextJs, ProjectType::Application);
         assert!(config.contains("node_modules/"));
         assert!(config.contains(".next/"));
     }
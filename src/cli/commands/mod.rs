@@ -1,4 +1,13 @@
+pub mod allowlist;
+pub mod baseline;
+pub mod doctor;
 pub mod fix;
+pub mod graph;
 pub mod init;
+pub mod plan;
 pub mod report;
+pub mod rules;
+pub mod sbom;
 pub mod scan;
+pub mod self_update;
+pub mod selfcheck;
@@ -0,0 +1,115 @@
+use anyhow::Result;
+use clap::Args;
+use colored::Colorize;
+use std::path::PathBuf;
+
+use crate::core::plan::RemediationPlan;
+use crate::core::profile::Profile;
+use crate::core::project::Project;
+use crate::core::scanner::default_scanner;
+
+#[derive(Args, Debug)]
+pub struct PlanArgs {
+    /// Path to the project to plan for (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Output format
+    #[arg(long, default_value = "table", value_parser = ["table", "json"])]
+    pub format: String,
+
+    /// Maximum number of steps to show
+    #[arg(long)]
+    pub limit: Option<usize>,
+
+    /// Strictness profile (overrides `profile` in .repodoctor.yml)
+    #[arg(long, value_parser = ["strict", "recommended", "relaxed"])]
+    pub profile: Option<String>,
+}
+
+pub async fn execute(args: &PlanArgs) -> Result<()> {
+    let project = Project::new(&args.path)?;
+    let scanner =
+        default_scanner().with_profile(args.profile.as_deref().and_then(Profile::from_name));
+    let result = if args.format == "table" {
+        let progress = crate::cli::progress::ScanProgress::new();
+        let res = scanner
+            .scan_with_progress(&project, |name| {
+                progress.set_analyzer(name);
+            })
+            .await?;
+        progress.finish();
+        res
+    } else {
+        scanner.scan(&project).await?
+    };
+
+    let mut plan = RemediationPlan::build(&result.issues);
+    if let Some(limit) = args.limit {
+        plan.steps.truncate(limit);
+    }
+
+    if args.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&plan)?);
+        return Ok(());
+    }
+
+    if plan.steps.is_empty() {
+        println!("{}", "Nothing to do: no issues found.".green());
+        return Ok(());
+    }
+
+    println!();
+    println!(
+        "  {} {}/100 -> {}/100",
+        "REMEDIATION PLAN".bold(),
+        plan.current_score,
+        plan.steps
+            .last()
+            .map(|s| s.score_after)
+            .unwrap_or(plan.current_score)
+    );
+    println!("{}", "─".repeat(64));
+
+    for step in &plan.steps {
+        let gain = if step.score_gain > 0 {
+            format!("+{} pts", step.score_gain).green()
+        } else {
+            "+0 pts".dimmed()
+        };
+        println!(
+            "  {:>3}. {}  {}",
+            step.step,
+            step.issue.id.bold(),
+            step.issue.title
+        );
+        println!(
+            "       {} severity, {} effort{}  {} (score {})",
+            step.issue.severity,
+            step.effort,
+            if step.issue.auto_fixable {
+                ", auto-fixable".green().to_string()
+            } else {
+                String::new()
+            },
+            gain,
+            step.score_after
+        );
+        if let Some(suggestion) = &step.issue.suggestion {
+            println!("       {}", suggestion.dimmed());
+        }
+    }
+
+    let fixable = plan.steps.iter().filter(|s| s.issue.auto_fixable).count();
+    if fixable > 0 {
+        println!();
+        println!(
+            "  Run {} to apply {} step(s) automatically.",
+            "repodoctor fix --auto".cyan(),
+            fixable
+        );
+    }
+    println!();
+
+    Ok(())
+}
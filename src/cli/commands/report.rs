@@ -1,51 +1,169 @@
 use anyhow::Result;
+use clap::builder::BoolishValueParser;
 use clap::Args;
 use colored::Colorize;
 use std::path::PathBuf;
 
+use crate::analyzers::{DriftAnalyzer, GitHubSettingsAnalyzer};
+use crate::core::cache::ResultCache;
+use crate::core::depgraph::DependencyGraph;
+use crate::core::policy::OrgPolicy;
+use crate::core::profile::Profile;
 use crate::core::project::Project;
 use crate::core::scanner::default_scanner;
 use crate::reporters::badge::BadgeGenerator;
 use crate::reporters::html::HtmlReporter;
+use crate::reporters::jira::{AdfReporter, JiraWikiReporter};
 use crate::reporters::json::JsonReporter;
 use crate::reporters::markdown::MarkdownReporter;
+use crate::reporters::sarif::SarifReporter;
+use crate::reporters::summary::{PreviousScan, SummaryReporter};
+use crate::reporters::template::ReportTemplates;
 use crate::reporters::traits::Reporter;
+use crate::utils::archive::extract_source;
+use crate::utils::fs::follows_symlinks;
 
 #[derive(Args, Debug)]
 pub struct ReportArgs {
-    /// Path to the project to report on (defaults to current directory)
+    /// Path to the project, bare repository or archive (.tar.gz, .tgz, .tar, .zip) to report on (defaults to current directory)
     #[arg(default_value = ".")]
     pub path: PathBuf,
 
+    /// Read the files of a tag, branch or commit from the git object database instead of the working tree
+    #[arg(long = "ref", value_name = "REV")]
+    pub git_ref: Option<String>,
+
     /// Report format
-    #[arg(long, default_value = "html", value_parser = ["html", "markdown", "json"])]
+    #[arg(long, default_value = "html", value_parser = ["html", "markdown", "json", "sarif", "jira", "adf", "summary-md"])]
     pub format: String,
 
     /// Output file path (auto-generated if not specified)
     #[arg(long, short)]
     pub output: Option<PathBuf>,
 
+    /// Previous JSON report to compute the score delta and new issues against (summary-md)
+    #[arg(long, value_name = "FILE")]
+    pub compare: Option<PathBuf>,
+
+    /// Directory of Tera templates overriding report.html / report.md (html, markdown)
+    #[arg(long, env = "REPODOCTOR_REPORT_TEMPLATE", value_name = "DIR")]
+    pub report_template: Option<PathBuf>,
+
+    /// Strictness profile (overrides `profile` in .repodoctor.yml)
+    #[arg(long, value_parser = ["strict", "recommended", "relaxed"])]
+    pub profile: Option<String>,
+
+    /// Organization policy file (path or URL) whose mandatory rules cannot be disabled by project config
+    #[arg(long, env = "REPODOCTOR_POLICY", value_name = "PATH|URL")]
+    pub policy: Option<String>,
+
+    /// Reference template repository (path or git URL) to report drift against
+    #[arg(long, env = "REPODOCTOR_TEMPLATE", value_name = "PATH|URL")]
+    pub template: Option<String>,
+
+    /// Check repository settings through the GitHub API (needs GITHUB_TOKEN)
+    #[arg(long, env = "REPODOCTOR_ONLINE", value_parser = BoolishValueParser::new())]
+    pub online: bool,
+
+    /// Embed the dependency graph as a Mermaid diagram (html, markdown)
+    #[arg(long)]
+    pub graph: bool,
+
     /// Also generate a health badge SVG
     #[arg(long)]
     pub badge: bool,
+
+    /// Always rescan, even when a cached result exists for the current commit
+    #[arg(long, env = "REPODOCTOR_NO_CACHE", value_parser = BoolishValueParser::new())]
+    pub no_cache: bool,
 }
 
 pub async fn execute(args: &ReportArgs) -> Result<()> {
-    let project = Project::new(&args.path)?;
-    let scanner = default_scanner();
+    // Keep the extracted archive or git tree alive for the duration of the scan.
+    let (archive_path, git_ref) = (args.path.clone(), args.git_ref.clone());
+    let archive =
+        tokio::task::spawn_blocking(move || extract_source(&archive_path, git_ref.as_deref()))
+            .await??;
+    let project = Project::new(archive.as_ref().map_or(&args.path, |a| &a.root))?;
+    let policy = args.policy.as_deref().map(OrgPolicy::load).transpose()?;
+    // Broken templates should fail before a full scan, not after it
+    let templates = match &args.report_template {
+        Some(dir) => ReportTemplates::load(dir)?,
+        None => ReportTemplates::builtin(),
+    };
+    let graph = args
+        .graph
+        .then(|| DependencyGraph::build(&project.path))
+        .filter(|graph| !graph.is_empty());
+    let templates = templates.with_graph(graph.map(|graph| graph.to_mermaid(false)));
+
+    let drift = args
+        .template
+        .as_deref()
+        .map(DriftAnalyzer::from_source)
+        .transpose()?;
 
-    let progress = crate::cli::progress::ScanProgress::new();
-    let result = scanner
-        .scan_with_progress(&project, |name| {
-            progress.set_analyzer(name);
-        })
-        .await?;
-    progress.finish();
+    // Archives and git trees are extracted fresh every time and have no checkout to key on;
+    // repository settings can change without a new commit
+    let cache = if args.no_cache || archive.is_some() || args.online {
+        None
+    } else {
+        let options = format!(
+            "{:?}|{}|{:?}|{}",
+            args.profile,
+            serde_json::to_string(&policy)?,
+            drift.as_ref().map(DriftAnalyzer::fingerprint),
+            follows_symlinks()
+        );
+        ResultCache::for_project(&project.path, &options)
+    };
+
+    let result = match cache.as_ref().and_then(|c| c.load(&project).map(|r| (c, r))) {
+        Some((cache, result)) => {
+            println!(
+                "  {} Reusing scan of commit {}",
+                "CACHED".cyan(),
+                &cache.commit[..cache.commit.len().min(12)]
+            );
+            result
+        }
+        None => {
+            let mut scanner = default_scanner()
+                .with_profile(args.profile.as_deref().and_then(Profile::from_name))
+                .with_policy(policy);
+            if let Some(drift) = drift {
+                scanner = scanner.with_analyzer(Box::new(drift));
+            }
+            if args.online {
+                scanner = scanner.with_analyzer(Box::new(GitHubSettingsAnalyzer::from_env()?));
+            }
+
+            let progress = crate::cli::progress::ScanProgress::new();
+            let result = scanner
+                .scan_with_progress(&project, |name| {
+                    progress.set_analyzer(name);
+                })
+                .await?;
+            progress.finish();
+
+            if let Some(cache) = &cache {
+                // A failed write only costs the next run a rescan
+                let _ = cache.store(&result);
+            }
+            result
+        }
+    };
 
     let reporter: Box<dyn Reporter> = match args.format.as_str() {
-        "markdown" => Box::new(MarkdownReporter),
+        "markdown" => Box::new(MarkdownReporter::new(templates)),
         "json" => Box::new(JsonReporter),
-        _ => Box::new(HtmlReporter),
+        "sarif" => Box::new(SarifReporter),
+        "jira" => Box::new(JiraWikiReporter),
+        "adf" => Box::new(AdfReporter),
+        "summary-md" => Box::new(SummaryReporter::new(
+            args.compare.as_deref().map(PreviousScan::load).transpose()?,
+        )),
+        _ => Box::new(HtmlReporter::new(templates)),
     };
 
     let content = reporter.generate(&result)?;
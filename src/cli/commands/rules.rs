@@ -0,0 +1,57 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use std::path::PathBuf;
+
+use crate::core::catalog;
+
+#[derive(Args, Debug)]
+pub struct RulesArgs {
+    #[command(subcommand)]
+    pub action: RulesAction,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RulesAction {
+    /// Generate the rule reference from the built-in rule catalog
+    Export(RulesExportArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct RulesExportArgs {
+    /// Output format
+    #[arg(long, default_value = "md", value_parser = ["md", "json"])]
+    pub format: String,
+
+    /// Write to this file instead of stdout
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+}
+
+pub async fn execute(args: &RulesArgs) -> Result<()> {
+    match &args.action {
+        RulesAction::Export(export_args) => export(export_args),
+    }
+}
+
+fn export(args: &RulesExportArgs) -> Result<()> {
+    let entries = catalog::entries();
+    let content = match args.format.as_str() {
+        "json" => serde_json::to_string_pretty(&entries)? + "\n",
+        _ => catalog::to_markdown(&entries),
+    };
+
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, &content)?;
+            println!(
+                "  {} {} rule(s) written to {}",
+                "DONE".green(),
+                entries.len(),
+                path.display()
+            );
+        }
+        None => print!("{}", content),
+    }
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anyhow::{bail, Result};
+use clap::Args;
+use colored::Colorize;
+use std::path::PathBuf;
+
+use crate::core::lockfiles;
+use crate::core::project::Project;
+use crate::core::sbom::{self, Subject};
+use crate::frameworks::detector::FrameworkDetector;
+use crate::utils::date;
+
+#[derive(Args, Debug)]
+pub struct SbomArgs {
+    /// Path to the project (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// SBOM format
+    #[arg(long, default_value = "cyclonedx", value_parser = ["cyclonedx", "spdx"])]
+    pub format: String,
+
+    /// Write to this file instead of stdout
+    #[arg(long, short)]
+    pub output: Option<PathBuf>,
+}
+
+pub async fn execute(args: &SbomArgs) -> Result<()> {
+    let project = Project::new(&args.path)?;
+    if lockfiles::present(&project.path).is_empty() {
+        bail!(
+            "No lock file found in {} (Cargo.lock, package-lock.json, composer.lock, pubspec.lock, poetry.lock)",
+            project.path.display()
+        );
+    }
+    let packages = lockfiles::read(&project.path)?;
+
+    let subject = Subject {
+        name: project
+            .path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "project".to_string()),
+        version: FrameworkDetector::manifest_version(&project.path),
+    };
+    let timestamp = date::format_timestamp(date::now());
+    let document = match args.format.as_str() {
+        "spdx" => sbom::spdx(&subject, &packages, &timestamp),
+        _ => sbom::cyclonedx(&subject, &packages, &timestamp),
+    };
+    let content = serde_json::to_string_pretty(&document)? + "\n";
+
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, &content)?;
+            println!(
+                "  {} {} SBOM with {} package(s) written to {}",
+                "DONE".green(),
+                args.format,
+                packages.len(),
+                path.display()
+            );
+        }
+        None => print!("{}", content),
+    }
+    Ok(())
+}
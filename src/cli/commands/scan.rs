@@ -1,36 +1,88 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
+use clap::builder::BoolishValueParser;
 use clap::Args;
-use std::path::PathBuf;
+use colored::Colorize;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
 
-use crate::analyzers::traits::Severity;
-use crate::cli::output::OutputFormatter;
+use crate::analyzers::traits::{Issue, Severity};
+use crate::analyzers::{DriftAnalyzer, GitHubSettingsAnalyzer};
+use crate::cli::output::{stats_table, OutputFormatter};
+use crate::core::config::Config;
+use crate::core::policy::OrgPolicy;
+use crate::core::profile::Profile;
 use crate::core::project::Project;
-use crate::core::scanner::default_scanner;
+use crate::core::batch::{parse_batch_file, run_batch};
+use crate::core::scanner::{default_scanner, ScanResult, Scanner};
+use crate::core::update::{self, UpdateNotice};
+use crate::reporters::summary::{PreviousScan, SummaryReporter};
+use crate::reporters::traits::Reporter;
+use crate::utils::archive::extract_source;
 
 #[derive(Args, Debug)]
 pub struct ScanArgs {
-    /// Path to the project to scan (defaults to current directory)
+    /// Path to the project, bare repository or archive (.tar.gz, .tgz, .tar, .zip) to scan (defaults to current directory)
     #[arg(default_value = ".")]
     pub path: PathBuf,
 
+    /// Read the files of a tag, branch or commit from the git object database instead of the working tree
+    #[arg(long = "ref", value_name = "REV", conflicts_with = "batch")]
+    pub git_ref: Option<String>,
+
     /// Output format
-    #[arg(long, default_value = "table", value_parser = ["table", "json"])]
+    #[arg(long, env = "REPODOCTOR_FORMAT", default_value = "table", value_parser = ["table", "json", "summary-md"])]
     pub format: String,
 
+    /// Order issues by severity, or by impact with the least effort first
+    #[arg(long, env = "REPODOCTOR_SORT_BY", default_value = "severity", value_parser = ["severity", "impact"])]
+    pub sort_by: String,
+
+    /// Record wall time, files walked and read, and bytes read per analyzer
+    #[arg(long, env = "REPODOCTOR_STATS", value_parser = BoolishValueParser::new())]
+    pub stats: bool,
+
+    /// Write the report to FILE and print a JSON summary to stdout (json, summary-md)
+    #[arg(long, short, value_name = "FILE", conflicts_with = "batch")]
+    pub output: Option<PathBuf>,
+
+    /// Previous JSON report to compute the score delta and new issues against (summary-md)
+    #[arg(long, value_name = "FILE")]
+    pub compare: Option<PathBuf>,
+
     /// Minimum severity to display
-    #[arg(long, value_parser = ["info", "low", "medium", "high", "critical"])]
+    #[arg(long, env = "REPODOCTOR_SEVERITY", value_parser = ["info", "low", "medium", "high", "critical"])]
     pub severity: Option<String>,
 
     /// CI mode: exit with code 1 if issues at or above threshold are found
-    #[arg(long)]
+    #[arg(long, env = "REPODOCTOR_CI", value_parser = BoolishValueParser::new())]
     pub ci: bool,
 
     /// Severity threshold for CI failure (default: high)
-    #[arg(long, default_value = "high", value_parser = ["low", "medium", "high", "critical"])]
+    #[arg(long, env = "REPODOCTOR_FAIL_ON", default_value = "high", value_parser = ["low", "medium", "high", "critical"])]
     pub fail_on: String,
 
+    /// Strictness profile (overrides `profile` in .repodoctor.yml)
+    #[arg(long, value_parser = ["strict", "recommended", "relaxed"])]
+    pub profile: Option<String>,
+
+    /// Organization policy file (path or URL) whose mandatory rules cannot be disabled by project config
+    #[arg(long, env = "REPODOCTOR_POLICY", value_name = "PATH|URL")]
+    pub policy: Option<String>,
+
+    /// Reference template repository (path or git URL) to report drift against
+    #[arg(long, env = "REPODOCTOR_TEMPLATE", value_name = "PATH|URL")]
+    pub template: Option<String>,
+
+    /// Check repository settings through the GitHub API (needs GITHUB_TOKEN)
+    #[arg(long, env = "REPODOCTOR_ONLINE", value_parser = BoolishValueParser::new())]
+    pub online: bool,
+
+    /// Scan every local path or git URL listed in FILE (one per line) and print an aggregate report
+    #[arg(long, value_name = "FILE")]
+    pub batch: Option<PathBuf>,
+
     /// Only run specific analyzers (comma-separated: structure,deps,config,security,testing,docs)
-    #[arg(long, value_delimiter = ',')]
+    #[arg(long, env = "REPODOCTOR_ONLY", value_delimiter = ',')]
     pub only: Option<Vec<String>>,
 }
 
@@ -53,6 +105,128 @@ impl ScanArgs {
             _ => Severity::High,
         }
     }
+
+    fn scanner(&self) -> Result<Scanner> {
+        let policy = self.policy.as_deref().map(OrgPolicy::load).transpose()?;
+        let only = self
+            .only
+            .as_ref()
+            .map(|only| only.iter().map(|n| expand_analyzer_name(n).to_string()).collect());
+        let mut scanner = default_scanner()
+            .with_profile(self.profile.as_deref().and_then(Profile::from_name))
+            .with_policy(policy)
+            .with_only(only);
+        if let Some(template) = &self.template {
+            scanner = scanner.with_analyzer(Box::new(DriftAnalyzer::from_source(template)?));
+        }
+        if self.online {
+            scanner = scanner.with_analyzer(Box::new(GitHubSettingsAnalyzer::from_env()?));
+        }
+        Ok(scanner)
+    }
+
+    /// Apply `--severity`, `--only` and `--sort-by` to a finished scan.
+    /// Policy violations are exempt: the CLI cannot hide mandatory rules.
+    fn apply_filters(&self, result: &mut ScanResult) {
+        let mandatory: Vec<String> = result
+            .policy
+            .iter()
+            .flat_map(|p| &p.violations)
+            .map(|i| i.id.clone())
+            .collect();
+        let is_mandatory = |issue: &Issue| mandatory.contains(&issue.id);
+
+        let min_severity = self.min_severity();
+        result
+            .issues
+            .retain(|i| i.severity >= min_severity || is_mandatory(i));
+
+        if let Some(only) = &self.only {
+            let allowed: Vec<&str> = only.iter().map(|n| expand_analyzer_name(n)).collect();
+            result
+                .issues
+                .retain(|i| allowed.contains(&i.analyzer.as_str()) || is_mandatory(i));
+            // Recalculate score with filtered issues
+            result.score = crate::core::score::HealthScore::calculate(&result.issues);
+        }
+
+        if self.sort_by == "impact" {
+            sort_by_impact(&mut result.issues);
+        }
+    }
+
+    fn fails_ci(&self, result: &ScanResult) -> bool {
+        let threshold = self.fail_severity();
+        result.issues.iter().any(|i| i.severity >= threshold)
+    }
+
+    /// Exit code for a finished scan and what caused it.
+    fn exit_condition(&self, result: &ScanResult) -> (i32, Option<&'static str>) {
+        // Policy violations fail the run even outside CI mode
+        if has_policy_violations(result) {
+            (2, Some("policy_violations"))
+        } else if self.ci && self.fails_ci(result) {
+            (1, Some("fail_on"))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+/// What `scan --output` prints to stdout, for scripts that wrap repodoctor.
+#[derive(Debug, Serialize)]
+struct ScanSummary {
+    score: u8,
+    grade: String,
+    issues: IssueCounts,
+    policy_violations: usize,
+    exit_code: i32,
+    exit_reason: Option<&'static str>,
+    output: String,
+}
+
+#[derive(Debug, Serialize)]
+struct IssueCounts {
+    total: usize,
+    critical: usize,
+    high: usize,
+    medium: usize,
+    low: usize,
+    info: usize,
+    fixable: usize,
+}
+
+impl ScanSummary {
+    fn new(args: &ScanArgs, result: &ScanResult, output: &Path) -> Self {
+        let count = |severity: Severity| result.issues.iter().filter(|i| i.severity == severity).count();
+        let (exit_code, exit_reason) = args.exit_condition(result);
+        Self {
+            score: result.score.total,
+            grade: result.score.grade.to_string(),
+            issues: IssueCounts {
+                total: result.issues.len(),
+                critical: count(Severity::Critical),
+                high: count(Severity::High),
+                medium: count(Severity::Medium),
+                low: count(Severity::Low),
+                info: count(Severity::Info),
+                fixable: result.issues.iter().filter(|i| i.auto_fixable).count(),
+            },
+            policy_violations: result.policy.as_ref().map_or(0, |p| p.violations.len()),
+            exit_code,
+            exit_reason,
+            output: output.display().to_string(),
+        }
+    }
+}
+
+/// Highest impact first, then least effort; ties keep the severity order.
+fn sort_by_impact(issues: &mut [Issue]) {
+    issues.sort_by(|a, b| {
+        b.resolved_impact()
+            .cmp(&a.resolved_impact())
+            .then_with(|| a.resolved_effort().cmp(&b.resolved_effort()))
+    });
 }
 
 fn expand_analyzer_name(name: &str) -> &str {
@@ -60,6 +234,8 @@ fn expand_analyzer_name(name: &str) -> &str {
         "deps" | "dependencies" => "dependencies",
         "config" | "configuration" => "config_files",
         "docs" | "documentation" => "documentation",
+        "changelog" | "changes" => "changelog",
+        "commits" | "commit" => "commits",
         "struct" | "structure" => "structure",
         "sec" | "security" => "security",
         "test" | "testing" => "testing",
@@ -68,13 +244,44 @@ fn expand_analyzer_name(name: &str) -> &str {
         "nextjs" | "next" => "nextjs",
         "laravel" => "laravel",
         "rust" | "cargo" | "rust_cargo" => "rust_cargo",
+        "bazel" | "buck" => "bazel",
+        "jvm" | "gradle" | "maven" => "jvm",
+        "ruby" | "gem" => "ruby",
+        "shell" | "sh" | "bash" => "shell",
+        "notebook" | "notebooks" | "jupyter" => "notebook",
+        "portability" | "filenames" => "portability",
+        "containers" | "container" | "docker" | "images" => "containers",
+        "custom" | "custom_rules" => "custom_rules",
+        "policy" | "policies" | "file_policy" => "file_policy",
+        "drift" | "template" => "drift",
+        "github" | "gh" => "github",
         other => other,
     }
 }
 
 pub async fn execute(args: &ScanArgs) -> Result<()> {
-    let project = Project::new(&args.path)?;
-    let scanner = default_scanner();
+    if let Some(batch_file) = &args.batch {
+        return execute_batch(args, batch_file).await;
+    }
+    if args.output.is_some() && args.format == "table" {
+        bail!("--output needs --format json or summary-md");
+    }
+
+    // Keep the extracted archive or git tree alive for the duration of the scan.
+    let (archive_path, git_ref) = (args.path.clone(), args.git_ref.clone());
+    let archive =
+        tokio::task::spawn_blocking(move || extract_source(&archive_path, git_ref.as_deref()))
+            .await??;
+    let project = Project::new(archive.as_ref().map_or(&args.path, |a| &a.root))?;
+    let previous = args.compare.as_deref().map(PreviousScan::load).transpose()?;
+    let scanner = args.scanner()?;
+    // Interactive runs only; CI logs do not need the notice
+    let update_notice = (args.format == "table"
+        && !args.ci
+        && std::env::var_os("CI").is_none()
+        && Config::load(&project.path).update_check()
+        && Config::load_user().update_check())
+    .then(UpdateNotice::start);
     let mut result = if args.format == "table" {
         let progress = crate::cli::progress::ScanProgress::new();
         let res = scanner
@@ -88,24 +295,99 @@ pub async fn execute(args: &ScanArgs) -> Result<()> {
         scanner.scan(&project).await?
     };
 
-    let min_severity = args.min_severity();
-    result.issues.retain(|i| i.severity >= min_severity);
+    args.apply_filters(&mut result);
+
+    match &args.output {
+        // The report goes to the file, the summary to stdout, and messages for humans to stderr
+        Some(output) => {
+            let content = match args.format.as_str() {
+                "summary-md" => SummaryReporter::new(previous).generate(&result)?,
+                _ => OutputFormatter::new("json").with_stats(args.stats).json(&result),
+            };
+            std::fs::write(output, &content)?;
+            eprintln!(
+                "  {} {} report written to {}",
+                "DONE".green(),
+                args.format,
+                output.display()
+            );
+            let summary = ScanSummary::new(args, &result, output);
+            println!("{}", serde_json::to_string(&summary)?);
+        }
+        None if args.format == "summary-md" => {
+            print!("{}", SummaryReporter::new(previous).generate(&result)?);
+        }
+        None => OutputFormatter::new(&args.format)
+            .with_impact_groups(args.sort_by == "impact")
+            .with_stats(args.stats)
+            .display(&result),
+    }
+    // Markdown has no place for the profile; keep it off stdout
+    if args.stats && args.format == "summary-md" {
+        eprint!("{}", stats_table(&result.stats));
+    }
+    if let Some(version) = update_notice.and_then(UpdateNotice::newer_version) {
+        eprintln!(
+            "  {} repodoctor {} is available (installed: {}). Run {} to update.\n",
+            "UPDATE".cyan(),
+            version,
+            update::current_version(),
+            "repodoctor self-update".bold()
+        );
+    }
+
+    match args.exit_condition(&result) {
+        (0, _) => Ok(()),
+        (code, _) => exit(code),
+    }
+}
+
+/// Exit with `code`, sending buffered telemetry first.
+fn exit(code: i32) -> ! {
+    crate::core::telemetry::flush();
+    std::process::exit(code);
+}
+
+fn has_policy_violations(result: &ScanResult) -> bool {
+    result
+        .policy
+        .as_ref()
+        .is_some_and(|p| !p.violations.is_empty())
+}
+
+async fn execute_batch(args: &ScanArgs, batch_file: &std::path::Path) -> Result<()> {
+    let targets = parse_batch_file(batch_file)?;
+    let scanner = args.scanner()?;
 
-    if let Some(only) = &args.only {
-        let allowed: Vec<&str> = only.iter().map(|n| expand_analyzer_name(n)).collect();
-        result.issues.retain(|i| allowed.contains(&i.analyzer.as_str()));
-        // Recalculate score with filtered issues
-        result.score = crate::core::score::HealthScore::calculate(&result.issues);
+    let progress = (args.format == "table").then(crate::cli::progress::ScanProgress::new);
+    let mut entries = run_batch(&targets, &scanner, |source| {
+        if let Some(progress) = &progress {
+            progress.set_analyzer(source);
+        }
+    })
+    .await;
+    if let Some(progress) = progress {
+        progress.finish();
+    }
+    for result in entries.iter_mut().filter_map(|e| e.result.as_mut()) {
+        args.apply_filters(result);
     }
 
-    let formatter = OutputFormatter::new(&args.format);
-    formatter.display(&result);
+    OutputFormatter::new(&args.format).display_batch(&entries);
 
+    let policy_failed = entries
+        .iter()
+        .filter_map(|e| e.result.as_ref())
+        .any(has_policy_violations);
+    if policy_failed {
+        exit(2);
+    }
     if args.ci {
-        let threshold = args.fail_severity();
-        let failing_count = result.issues.iter().filter(|i| i.severity >= threshold).count();
-        if failing_count > 0 {
-            std::process::exit(1);
+        let failing = entries
+            .iter()
+            .any(|e| e.result.as_ref().is_none_or(|r| args.fails_ci(r)));
+        if failing {
+            exit(1);
         }
     }
 
@@ -120,11 +402,21 @@ mod tests {
     fn test_min_severity_default() {
         let args = ScanArgs {
             path: PathBuf::from("."),
+            git_ref: None,
             format: "table".to_string(),
+            sort_by: "severity".to_string(),
+            stats: false,
+            output: None,
+            compare: None,
             severity: None,
             ci: false,
             fail_on: "high".to_string(),
             only: None,
+            profile: None,
+            batch: None,
+            policy: None,
+            template: None,
+            online: false,
         };
         assert_eq!(args.min_severity(), Severity::Info);
     }
@@ -133,11 +425,21 @@ mod tests {
     fn test_min_severity_critical() {
         let args = ScanArgs {
             path: PathBuf::from("."),
+            git_ref: None,
             format: "table".to_string(),
+            sort_by: "severity".to_string(),
+            stats: false,
+            output: None,
+            compare: None,
             severity: Some("critical".to_string()),
             ci: false,
             fail_on: "high".to_string(),
             only: None,
+            profile: None,
+            batch: None,
+            policy: None,
+            template: None,
+            online: false,
         };
         assert_eq!(args.min_severity(), Severity::Critical);
     }
@@ -146,11 +448,21 @@ mod tests {
     fn test_fail_severity_default() {
         let args = ScanArgs {
             path: PathBuf::from("."),
+            git_ref: None,
             format: "table".to_string(),
+            sort_by: "severity".to_string(),
+            stats: false,
+            output: None,
+            compare: None,
             severity: None,
             ci: true,
             fail_on: "high".to_string(),
             only: None,
+            profile: None,
+            batch: None,
+            policy: None,
+            template: None,
+            online: false,
         };
         assert_eq!(args.fail_severity(), Severity::High);
     }
@@ -159,13 +471,272 @@ mod tests {
     fn test_fail_severity_critical() {
         let args = ScanArgs {
             path: PathBuf::from("."),
+            git_ref: None,
             format: "table".to_string(),
+            sort_by: "severity".to_string(),
+            stats: false,
+            output: None,
+            compare: None,
             severity: None,
             ci: true,
             fail_on: "critical".to_string(),
             only: None,
+            profile: None,
+            batch: None,
+            policy: None,
+            template: None,
+            online: false,
+        };
+        assert_eq!(args.fail_severity(), Severity::Critical);
+    }
+
+    /// Runs the ignored `env_precedence_probe` test in a child process with
+    /// `REPODOCTOR_*` variables set, so the parent's environment is untouched.
+    #[test]
+    fn test_cli_flags_take_precedence_over_env() {
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "cli::commands::scan::tests::env_precedence_probe",
+                "--ignored",
+                "--test-threads=1",
+            ])
+            .env("REPODOCTOR_CI", "1")
+            .env("REPODOCTOR_FAIL_ON", "low")
+            .env("REPODOCTOR_ONLY", "docs")
+            .env("REPODOCTOR_STATS", "no")
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(output.status.success(), "{}", stdout);
+        assert!(stdout.contains("1 passed"), "{}", stdout);
+    }
+
+    #[test]
+    #[ignore = "run by test_cli_flags_take_precedence_over_env"]
+    fn env_precedence_probe() {
+        use clap::Parser;
+
+        if std::env::var_os("REPODOCTOR_CI").is_none() {
+            return;
+        }
+        let parse = |extra: &[&str]| {
+            let argv = ["repodoctor", "scan", "."];
+            match crate::cli::Cli::try_parse_from(argv.iter().chain(extra))
+                .unwrap()
+                .command
+            {
+                crate::cli::Commands::Scan(args) => args,
+                _ => panic!("expected scan command"),
+            }
         };
+
+        let args = parse(&[]);
+        assert!(args.ci);
+        assert!(!args.stats);
+        assert_eq!(args.fail_severity(), Severity::Low);
+        assert_eq!(args.only, Some(vec!["docs".to_string()]));
+
+        let args = parse(&["--fail-on", "critical", "--only", "security,deps"]);
         assert_eq!(args.fail_severity(), Severity::Critical);
+        assert_eq!(
+            args.only,
+            Some(vec!["security".to_string(), "deps".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_sort_by_impact() {
+        use crate::analyzers::traits::{AnalyzerCategory, Effort, Impact};
+
+        let issue = |id: &str, effort: Effort, impact: Impact| Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Structure,
+            severity: Severity::Medium,
+            title: String::new(),
+            description: String::new(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: Some(effort),
+            impact: Some(impact),
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        };
+        let mut issues = vec![
+            issue("A", Effort::Trivial, Impact::Low),
+            issue("B", Effort::Large, Impact::High),
+            issue("C", Effort::Trivial, Impact::High),
+            issue("D", Effort::Small, Impact::Medium),
+        ];
+        sort_by_impact(&mut issues);
+        let ids: Vec<_> = issues.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, ["C", "B", "D", "A"]);
+    }
+
+    #[test]
+    fn test_output_summary_reports_exit_condition() {
+        use crate::analyzers::traits::AnalyzerCategory;
+        use crate::core::project::Project;
+        use clap::Parser;
+        use crate::core::score::HealthScore;
+        use crate::frameworks::detector::{DetectedProject, Framework, Language};
+        use std::time::Duration;
+
+        let issue = |id: &str, severity: Severity| Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Security,
+            severity,
+            title: String::new(),
+            description: String::new(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: severity == Severity::Low,
+            references: vec![],
+        };
+        let issues = vec![issue("SEC-001", Severity::High), issue("STR-004", Severity::Low)];
+        let result = ScanResult {
+            project: Project {
+                path: PathBuf::from("/tmp/test"),
+                detected: DetectedProject {
+                    framework: Framework::RustCargo,
+                    language: Language::Rust,
+                    version: None,
+                    package_manager: None,
+                    has_git: true,
+                    has_ci: None,
+                },
+            },
+            score: HealthScore::calculate(&issues),
+            issues,
+            duration: Duration::from_millis(1),
+            expiring: vec![],
+            policy: None,
+            skipped_files: vec![],
+            stats: vec![],
+            metrics: Default::default(),
+        };
+
+        let parse = |extra: &[&str]| {
+            let argv = ["repodoctor", "scan", ".", "--format", "json", "-o", "report.json"];
+            match crate::cli::Cli::try_parse_from(argv.iter().chain(extra)).unwrap().command {
+                crate::cli::Commands::Scan(args) => args,
+                _ => panic!("expected scan command"),
+            }
+        };
+
+        let args = parse(&[]);
+        assert_eq!(args.exit_condition(&result), (0, None));
+
+        let args = parse(&["--ci"]);
+        let summary = serde_json::to_value(ScanSummary::new(
+            &args,
+            &result,
+            args.output.as_deref().unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(summary["score"], result.score.total);
+        assert_eq!(summary["issues"]["total"], 2);
+        assert_eq!(summary["issues"]["high"], 1);
+        assert_eq!(summary["issues"]["fixable"], 1);
+        assert_eq!(summary["exit_code"], 1);
+        assert_eq!(summary["exit_reason"], "fail_on");
+        assert_eq!(summary["output"], "report.json");
+
+        let args = parse(&["--ci", "--fail-on", "critical"]);
+        assert_eq!(args.exit_condition(&result), (0, None));
+    }
+
+    #[test]
+    fn test_filters_keep_policy_violations() {
+        use crate::analyzers::traits::AnalyzerCategory;
+        use crate::core::policy::PolicyReport;
+        use crate::core::score::HealthScore;
+        use crate::frameworks::detector::{DetectedProject, Framework, Language};
+        use clap::Parser;
+        use std::time::Duration;
+
+        let issue = |id: &str, analyzer: &str| Issue {
+            id: id.to_string(),
+            analyzer: analyzer.to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::Low,
+            title: String::new(),
+            description: String::new(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        };
+        let issues = vec![issue("SEC-001", "security"), issue("STR-004", "structure")];
+        let mut result = ScanResult {
+            project: Project {
+                path: PathBuf::from("/tmp/test"),
+                detected: DetectedProject {
+                    framework: Framework::Unknown,
+                    language: Language::Unknown,
+                    version: None,
+                    package_manager: None,
+                    has_git: false,
+                    has_ci: None,
+                },
+            },
+            score: HealthScore::calculate(&issues),
+            policy: Some(PolicyReport {
+                name: "ACME".to_string(),
+                violations: vec![issues[0].clone()],
+            }),
+            issues,
+            duration: Duration::from_millis(1),
+            expiring: vec![],
+            skipped_files: vec![],
+            stats: vec![],
+            metrics: Default::default(),
+        };
+
+        let argv = [
+            "repodoctor",
+            "scan",
+            ".",
+            "--severity",
+            "high",
+            "--only",
+            "docs",
+        ];
+        let crate::cli::Commands::Scan(args) =
+            crate::cli::Cli::try_parse_from(argv).unwrap().command
+        else {
+            panic!("expected scan command");
+        };
+        args.apply_filters(&mut result);
+        let ids: Vec<&str> = result.issues.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, ["SEC-001"]);
+    }
+
+    #[test]
+    fn test_output_conflicts_with_batch() {
+        use clap::Parser;
+        let parsed = crate::cli::Cli::try_parse_from([
+            "repodoctor", "scan", "--batch", "repos.txt", "--output", "out.json",
+        ]);
+        assert!(parsed.is_err());
     }
 
     #[test]
@@ -180,6 +751,19 @@ mod tests {
         assert_eq!(expand_analyzer_name("laravel"), "laravel");
         assert_eq!(expand_analyzer_name("rust"), "rust_cargo");
         assert_eq!(expand_analyzer_name("cargo"), "rust_cargo");
+        assert_eq!(expand_analyzer_name("buck"), "bazel");
+        assert_eq!(expand_analyzer_name("maven"), "jvm");
+        assert_eq!(expand_analyzer_name("gem"), "ruby");
+        assert_eq!(expand_analyzer_name("bash"), "shell");
+        assert_eq!(expand_analyzer_name("jupyter"), "notebook");
+        assert_eq!(expand_analyzer_name("filenames"), "portability");
+        assert_eq!(expand_analyzer_name("docker"), "containers");
+        assert_eq!(expand_analyzer_name("changes"), "changelog");
+        assert_eq!(expand_analyzer_name("commit"), "commits");
         assert_eq!(expand_analyzer_name("rust_cargo"), "rust_cargo");
+        assert_eq!(expand_analyzer_name("custom"), "custom_rules");
+        assert_eq!(expand_analyzer_name("policies"), "file_policy");
+        assert_eq!(expand_analyzer_name("template"), "drift");
+        assert_eq!(expand_analyzer_name("gh"), "github");
     }
 }
@@ -0,0 +1,68 @@
+use anyhow::{bail, Context, Result};
+use clap::Args;
+use colored::Colorize;
+
+use crate::core::config::Config;
+use crate::core::update::{self, Release};
+
+#[derive(Args, Debug)]
+pub struct SelfUpdateArgs {
+    /// Only report whether a newer release exists
+    #[arg(long)]
+    pub check: bool,
+
+    /// Install even if the release publishes no checksum for its archive
+    #[arg(long)]
+    pub no_verify: bool,
+}
+
+pub async fn execute(args: &SelfUpdateArgs) -> Result<()> {
+    // A user setting: the project in the current directory has no say
+    if !Config::load_user().update_check() {
+        bail!(
+            "Update checks are disabled by `updates.check: false` in the user config file or REPODOCTOR_UPDATE_CHECK"
+        );
+    }
+    let (check_only, verify) = (args.check, !args.no_verify);
+    tokio::task::spawn_blocking(move || run(check_only, verify)).await?
+}
+
+fn run(check_only: bool, verify: bool) -> Result<()> {
+    let current = update::current_version();
+    let release = Release::latest()?;
+    if !update::is_newer(&release.version, current) {
+        println!("  {} repodoctor {} is up to date", "OK".green(), current);
+        return Ok(());
+    }
+    if check_only {
+        println!(
+            "  {} repodoctor {} is available (installed: {}). Run {} to install it.",
+            "UPDATE".cyan(),
+            release.version,
+            current,
+            "repodoctor self-update".bold()
+        );
+        return Ok(());
+    }
+
+    let asset = release.platform_asset().with_context(|| {
+        format!(
+            "Release {} has no archive for {}/{}",
+            release.tag,
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        )
+    })?;
+    println!("  Downloading {}...", asset.name);
+    let archive = release.download(asset, verify)?;
+    let binary = update::extract_binary(&asset.name, &archive)?;
+    let exe = update::replace_current_exe(&binary)?;
+    println!(
+        "  {} repodoctor {} -> {} ({})",
+        "DONE".green(),
+        current,
+        release.version,
+        exe.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anyhow::{bail, Result};
+use clap::Args;
+use colored::Colorize;
+use std::path::PathBuf;
+
+use crate::core::project::Project;
+use crate::core::selfcheck::{self, Status};
+
+#[derive(Args, Debug)]
+pub struct SelfcheckArgs {
+    /// Path to the project to check the environment for (defaults to current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Output format
+    #[arg(long, default_value = "table", value_parser = ["table", "json"])]
+    pub format: String,
+
+    /// Skip the checks that contact the network
+    #[arg(long)]
+    pub offline: bool,
+}
+
+pub async fn execute(args: &SelfcheckArgs) -> Result<()> {
+    let project = Project::new(&args.path)?;
+    let offline = args.offline;
+    let checks = tokio::task::spawn_blocking(move || selfcheck::run(&project, offline)).await?;
+
+    if args.format == "json" {
+        println!("{}", serde_json::to_string_pretty(&checks)?);
+    } else {
+        println!();
+        for check in &checks {
+            let status = match check.status {
+                Status::Ok => "  OK  ".green(),
+                Status::Warn => " WARN ".yellow(),
+                Status::Fail => " FAIL ".red().bold(),
+            };
+            println!("  {} {:<18} {}", status, check.name.bold(), check.detail);
+            if let Some(hint) = &check.hint {
+                println!("         {:<18} {}", "", hint.dimmed());
+            }
+        }
+        println!();
+    }
+
+    let failed = checks.iter().filter(|c| c.status == Status::Fail).count();
+    if failed > 0 {
+        bail!("{} check(s) failed", failed);
+    }
+    Ok(())
+}
@@ -2,6 +2,7 @@ pub mod commands;
 pub mod output;
 pub mod progress;
 
+use clap::builder::BoolishValueParser;
 use clap::{Parser, Subcommand};
 
 #[derive(Parser, Debug)]
@@ -9,6 +10,18 @@ use clap::{Parser, Subcommand};
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// Follow symbolic links while walking the project (loops are detected and skipped)
+    #[arg(long, global = true, env = "REPODOCTOR_FOLLOW_SYMLINKS", value_parser = BoolishValueParser::new())]
+    pub follow_symlinks: bool,
+
+    /// Export scan spans to this OTLP/HTTP endpoint (e.g. http://localhost:4318)
+    #[arg(long, global = true, env = "OTEL_EXPORTER_OTLP_ENDPOINT", value_name = "URL")]
+    pub otlp_endpoint: Option<String>,
+
+    /// Also export issue counts and health scores as OTLP metrics
+    #[arg(long, global = true, env = "REPODOCTOR_OTLP_METRICS", value_parser = BoolishValueParser::new(), requires = "otlp_endpoint")]
+    pub otlp_metrics: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -17,8 +30,26 @@ pub enum Commands {
     Scan(commands::scan::ScanArgs),
     /// Auto-fix detected issues
     Fix(commands::fix::FixArgs),
+    /// Walk through the top findings interactively
+    Doctor(commands::doctor::DoctorArgs),
+    /// Print a prioritized remediation plan
+    Plan(commands::plan::PlanArgs),
     /// Generate a health report
     Report(commands::report::ReportArgs),
+    /// Manage the baseline of accepted issues
+    Baseline(commands::baseline::BaselineArgs),
+    /// Manage the allowlist of secret findings known to be false positives
+    Allowlist(commands::allowlist::AllowlistArgs),
+    /// Export the built-in rule catalog
+    Rules(commands::rules::RulesArgs),
+    /// Print the dependency graph of the project and its workspace packages
+    Graph(commands::graph::GraphArgs),
+    /// Generate a software bill of materials from lock files
+    Sbom(commands::sbom::SbomArgs),
+    /// Check that tools, tokens, network access, and configuration are usable
+    Selfcheck(commands::selfcheck::SelfcheckArgs),
+    /// Replace this binary with the latest GitHub release
+    SelfUpdate(commands::self_update::SelfUpdateArgs),
     /// Initialize a .repodoctor.yml configuration file
     Init(commands::init::InitArgs),
 }
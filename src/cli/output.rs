@@ -1,20 +1,45 @@
 use colored::*;
 
-use crate::analyzers::traits::Severity;
-use crate::core::scanner::ScanResult;
+use crate::analyzers::traits::{Impact, Issue, Severity};
+use crate::core::batch::BatchEntry;
+use crate::core::scanner::{AnalyzerStats, ScanResult};
 use crate::core::score::Grade;
+use crate::core::themes;
+use crate::utils::fs::display_path;
+
+/// Skipped files listed in the table output before collapsing the rest.
+const MAX_SKIPPED_SHOWN: usize = 5;
+
+/// Quick wins listed in the table output before collapsing the rest.
+const MAX_QUICK_WINS_SHOWN: usize = 5;
 
 pub struct OutputFormatter {
     format: String,
+    by_impact: bool,
+    stats: bool,
 }
 
 impl OutputFormatter {
     pub fn new(format: &str) -> Self {
         Self {
             format: format.to_string(),
+            by_impact: false,
+            stats: false,
         }
     }
 
+    /// Include per-analyzer timing and I/O in the output.
+    pub fn with_stats(mut self, stats: bool) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    /// Group table output by impact instead of severity.
+    pub fn with_impact_groups(mut self, by_impact: bool) -> Self {
+        self.by_impact = by_impact;
+        self
+    }
+
     pub fn display(&self, result: &ScanResult) {
         match self.format.as_str() {
             "json" => self.display_json(result),
@@ -22,10 +47,118 @@ impl OutputFormatter {
         }
     }
 
-    fn display_json(&self, result: &ScanResult) {
+    /// Aggregate view of a batch scan: one row per repository.
+    pub fn display_batch(&self, entries: &[BatchEntry]) {
+        match self.format.as_str() {
+            "json" => self.display_batch_json(entries),
+            _ => self.display_batch_table(entries),
+        }
+    }
+
+    fn display_batch_json(&self, entries: &[BatchEntry]) {
+        let repos: Vec<_> = entries
+            .iter()
+            .map(|entry| match &entry.result {
+                Some(result) => serde_json::json!({
+                    "source": entry.source,
+                    "framework": result.project.detected.framework,
+                    "score": result.score.total,
+                    "grade": format!("{}", result.score.grade),
+                    "issues": result.issues.len(),
+                    "critical": result.issues.iter().filter(|i| i.severity == Severity::Critical).count(),
+                    "top_issues": result.issues.iter().take(3).map(|i| &i.id).collect::<Vec<_>>(),
+                }),
+                None => serde_json::json!({
+                    "source": entry.source,
+                    "error": entry.error,
+                }),
+            })
+            .collect();
         let output = serde_json::json!({
+            "repositories": repos,
+            "average_score": average_score(entries),
+        });
+        println!("{}", serde_json::to_string_pretty(&output).unwrap());
+    }
+
+    fn display_batch_table(&self, entries: &[BatchEntry]) {
+        println!();
+        println!("{}", "RepoDoctor v0.1.0 - Batch report".bold());
+        println!("{}", "─".repeat(96));
+        println!(
+            "  {:<40} {:<7} {:<6} {:<7} {:<9} {}",
+            "Repository".bold(),
+            "Score".bold(),
+            "Grade".bold(),
+            "Issues".bold(),
+            "Critical".bold(),
+            "Top issues".bold()
+        );
+        println!("  {}", "─".repeat(94));
+
+        for entry in entries {
+            let source = truncate_left(&entry.source, 40);
+            match &entry.result {
+                Some(result) => {
+                    let critical = result
+                        .issues
+                        .iter()
+                        .filter(|i| i.severity == Severity::Critical)
+                        .count();
+                    let top: Vec<&str> =
+                        result.issues.iter().take(3).map(|i| i.id.as_str()).collect();
+                    let score = format!("{}/100", result.score.total);
+                    let score = match result.score.grade {
+                        Grade::A | Grade::B => score.green(),
+                        Grade::C => score.yellow(),
+                        Grade::D | Grade::F => score.red(),
+                    };
+                    println!(
+                        "  {:<40} {:<7} {:<6} {:<7} {:<9} {}",
+                        source,
+                        score,
+                        result.score.grade.to_string(),
+                        result.issues.len(),
+                        critical,
+                        top.join(", ")
+                    );
+                }
+                None => println!(
+                    "  {:<40} {} {}",
+                    source,
+                    "ERROR".red(),
+                    entry.error.as_deref().unwrap_or_default()
+                ),
+            }
+        }
+
+        println!("  {}", "─".repeat(94));
+        let failed = entries.iter().filter(|e| e.result.is_none()).count();
+        match average_score(entries) {
+            Some(avg) => println!(
+                "  {} repositories scanned, average score {}/100{}",
+                entries.len() - failed,
+                avg,
+                if failed > 0 {
+                    format!(", {} failed", failed)
+                } else {
+                    String::new()
+                }
+            ),
+            None => println!("  No repositories scanned successfully."),
+        }
+        println!();
+    }
+
+    fn display_json(&self, result: &ScanResult) {
+        println!("{}", self.json(result));
+    }
+
+    /// The `--format json` document for a scan.
+    pub fn json(&self, result: &ScanResult) -> String {
+        let mut output = serde_json::json!({
             "project": {
-                "path": result.project.path.to_string_lossy(),
+                "path": display_path(&result.project.path),
                 "framework": result.project.detected.framework,
                 "language": result.project.detected.language,
                 "version": result.project.detected.version,
@@ -36,9 +169,17 @@ impl OutputFormatter {
                 "breakdown": result.score.breakdown,
             },
             "issues": result.issues,
+            "themes": result.themes(),
+            "expiring_suppressions": result.expiring,
+            "policy": result.policy,
+            "skipped_files": result.skipped_files,
+            "metrics": result.metrics,
             "duration_ms": result.duration.as_millis(),
         });
-        println!("{}", serde_json::to_string_pretty(&output).unwrap());
+        if self.stats {
+            output["stats"] = serde_json::json!(result.stats);
+        }
+        serde_json::to_string_pretty(&output).unwrap()
     }
 
     fn display_table(&self, result: &ScanResult) {
@@ -51,7 +192,7 @@ impl OutputFormatter {
         // Project info
         println!(
             "  Project:  {}",
-            result.project.path.to_string_lossy().cyan()
+            display_path(&result.project.path).cyan()
         );
         println!(
             "  Detected: {}{}",
@@ -64,6 +205,10 @@ impl OutputFormatter {
                 .map(|v| format!(" {}", v))
                 .unwrap_or_default()
         );
+        if result.metrics.files > 0 {
+            println!("  Size:     {}", result.metrics.summary());
+            println!("  Types:    {}", result.metrics.top_types(6).dimmed());
+        }
         println!(
             "  Scan completed in {:.1}s",
             result.duration.as_secs_f64()
@@ -120,22 +265,105 @@ impl OutputFormatter {
         println!();
         println!("{}", "─".repeat(64));
 
-        // Issues grouped by severity
-        let severity_groups = [
-            (Severity::Critical, "CRITICAL", Color::Red),
-            (Severity::High, "HIGH", Color::Yellow),
-            (Severity::Medium, "MEDIUM", Color::Blue),
-            (Severity::Low, "LOW", Color::White),
-            (Severity::Info, "INFO", Color::BrightBlack),
-        ];
-
-        for (severity, label, color) in &severity_groups {
-            let group: Vec<_> = result
-                .issues
-                .iter()
-                .filter(|i| i.severity == *severity)
-                .collect();
+        // Organization policy violations, shown ahead of regular issues
+        if let Some(policy) = result.policy.as_ref().filter(|p| !p.violations.is_empty()) {
+            println!();
+            println!(
+                "  {} - {} ({})",
+                "POLICY VIOLATIONS".on_red().white().bold(),
+                policy.name,
+                policy.violations.len()
+            );
+            println!();
+            for issue in &policy.violations {
+                println!(
+                    "    {}  {} [{}]",
+                    issue.id.red().bold(),
+                    issue.title,
+                    issue.severity
+                );
+            }
+            println!();
+            println!("{}", "─".repeat(64));
+        }
+
+        // High-impact issues that take almost no work
+        let quick_wins: Vec<&Issue> = result.issues.iter().filter(|i| i.is_quick_win()).collect();
+        if !quick_wins.is_empty() {
+            println!();
+            println!("  {} ({})", "QUICK WINS".green().bold(), quick_wins.len());
+            println!();
+            for issue in quick_wins.iter().take(MAX_QUICK_WINS_SHOWN) {
+                println!("    {}  {}", issue.id.green().bold(), issue.title);
+            }
+            if quick_wins.len() > MAX_QUICK_WINS_SHOWN {
+                println!("    ... and {} more", quick_wins.len() - MAX_QUICK_WINS_SHOWN);
+            }
+            println!();
+            println!("{}", "─".repeat(64));
+        }
+
+        // Related issues shown once, with a combined recommendation
+        let themes = result.themes();
+        if !themes.is_empty() {
+            println!();
+            println!("  {} ({})", "THEMES".bold(), themes.len());
+            for theme in &themes {
+                println!();
+                println!(
+                    "    {} {} ({} issues)",
+                    "▸".color(severity_color(theme.severity)).bold(),
+                    theme.title.bold(),
+                    theme.issues.len()
+                );
+                for issue in &theme.issues {
+                    println!(
+                        "      {}  {}",
+                        issue.id.color(severity_color(issue.severity)),
+                        issue.title
+                    );
+                }
+                println!("      Recommendation: {}", theme.recommendation.dimmed());
+            }
+            println!();
+            println!("{}", "─".repeat(64));
+        }
+        let issues = themes::unthemed(&result.issues, &themes);
 
+        // Issues grouped by severity, or by impact with --sort-by impact
+        let groups: Vec<(&str, Color, Vec<&Issue>)> = if self.by_impact {
+            [
+                (Impact::High, "HIGH IMPACT", Color::Red),
+                (Impact::Medium, "MEDIUM IMPACT", Color::Yellow),
+                (Impact::Low, "LOW IMPACT", Color::White),
+            ]
+            .into_iter()
+            .map(|(impact, label, color)| {
+                let group = issues
+                    .iter()
+                    .copied()
+                    .filter(|i| i.resolved_impact() == impact)
+                    .collect();
+                (label, color, group)
+            })
+            .collect()
+        } else {
+            [
+                (Severity::Critical, "CRITICAL"),
+                (Severity::High, "HIGH"),
+                (Severity::Medium, "MEDIUM"),
+                (Severity::Low, "LOW"),
+                (Severity::Info, "INFO"),
+            ]
+            .into_iter()
+            .map(|(severity, label)| {
+                let group = issues.iter().copied().filter(|i| i.severity == severity).collect();
+                (label, severity_color(severity), group)
+            })
+            .collect()
+        };
+
+        for (label, color, group) in &groups {
             if group.is_empty() {
                 continue;
             }
@@ -148,25 +376,49 @@ impl OutputFormatter {
             );
             println!();
 
-            for issue in &group {
+            for issue in group {
                 println!(
                     "    {}  {}",
-                    issue.id.color(*color).bold(),
+                    issue.id.color(severity_color(issue.severity)).bold(),
                     issue.title
                 );
                 if let Some(file) = &issue.file {
                     println!(
                         "           File: {}{}",
-                        file.to_string_lossy(),
+                        display_path(file),
                         issue
                             .line
-                            .map(|l| format!(" (line {})", l))
+                            .map(|l| match issue.column {
+                                Some(c) => format!(" (line {}, column {})", l, c),
+                                None => format!(" (line {})", l),
+                            })
                             .unwrap_or_default()
                     );
                 }
+                if let Some((before, highlighted, after)) = issue.snippet_parts() {
+                    println!(
+                        "           {} {}{}{}",
+                        "|".dimmed(),
+                        before.trim_start(),
+                        highlighted.red().bold(),
+                        after
+                    );
+                }
                 if let Some(suggestion) = &issue.suggestion {
                     println!("           Suggestion: {}", suggestion.dimmed());
                 }
+                if let Some(reference) = issue.references.first() {
+                    println!("           See: {}", reference.dimmed());
+                }
+                println!(
+                    "           {}",
+                    format!(
+                        "Effort: {}, impact: {}",
+                        issue.resolved_effort(),
+                        issue.resolved_impact()
+                    )
+                    .dimmed()
+                );
                 if issue.auto_fixable {
                     println!("           {}", "Auto-fixable: Yes".green());
                 }
@@ -208,6 +460,108 @@ impl OutputFormatter {
         if fixable > 0 {
             println!("    {} auto-fixable issues", fixable);
         }
+        if !quick_wins.is_empty() {
+            println!("    {} quick wins (high impact, trivial effort)", quick_wins.len());
+        }
         println!();
+
+        if !result.expiring.is_empty() {
+            println!("  {}", "SUPPRESSIONS EXPIRING".yellow().bold());
+            for suppression in &result.expiring {
+                println!(
+                    "    [{}] {} - {}",
+                    suppression.source,
+                    suppression.target(),
+                    suppression.status()
+                );
+            }
+            println!();
+        }
+
+        if !result.skipped_files.is_empty() {
+            println!("  {}", "FILES NOT FULLY CHECKED".dimmed().bold());
+            for skipped in result.skipped_files.iter().take(MAX_SKIPPED_SHOWN) {
+                println!("    {} - {}", skipped.file, skipped.reason.dimmed());
+            }
+            if result.skipped_files.len() > MAX_SKIPPED_SHOWN {
+                println!(
+                    "    ... and {} more (see --format json)",
+                    result.skipped_files.len() - MAX_SKIPPED_SHOWN
+                );
+            }
+            println!();
+        }
+
+        if self.stats {
+            print!("{}", stats_table(&result.stats));
+        }
+    }
+}
+
+/// Per-analyzer profile for `scan --stats`, slowest first.
+pub fn stats_table(stats: &[AnalyzerStats]) -> String {
+    let mut rows: Vec<&AnalyzerStats> = stats.iter().collect();
+    rows.sort_by_key(|row| std::cmp::Reverse(row.duration));
+
+    let mut out = format!("  {}\n", "PROFILE".bold());
+    out.push_str(&format!(
+        "    {:<16} {:>10} {:>8} {:>8} {:>10} {:>7}\n",
+        "Analyzer", "Time", "Walked", "Read", "Bytes", "Issues"
+    ));
+    for row in rows {
+        out.push_str(&format!(
+            "    {:<16} {:>7.1} ms {:>8} {:>8} {:>10} {:>7}\n",
+            row.analyzer,
+            row.duration.as_secs_f64() * 1000.0,
+            row.io.files_walked,
+            row.io.files_read,
+            format_bytes(row.io.bytes_read),
+            row.issues
+        ));
+    }
+    out.push_str(&format!(
+        "    {}\n\n",
+        "Walked: files seen by directory walks. Content checks run in the shared `content` pass."
+            .dimmed()
+    ));
+    out
+}
+
+fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1 << 20 => format!("{:.1} MiB", b as f64 / (1 << 20) as f64),
+        b if b >= 1 << 10 => format!("{:.1} KiB", b as f64 / (1 << 10) as f64),
+        b => format!("{} B", b),
+    }
+}
+
+fn severity_color(severity: Severity) -> Color {
+    match severity {
+        Severity::Critical => Color::Red,
+        Severity::High => Color::Yellow,
+        Severity::Medium => Color::Blue,
+        Severity::Low => Color::White,
+        Severity::Info => Color::BrightBlack,
+    }
+}
+
+fn average_score(entries: &[BatchEntry]) -> Option<u8> {
+    let scores: Vec<u32> = entries
+        .iter()
+        .filter_map(|e| e.result.as_ref().map(|r| r.score.total as u32))
+        .collect();
+    if scores.is_empty() {
+        return None;
+    }
+    Some((scores.iter().sum::<u32>() as f64 / scores.len() as f64).round() as u8)
+}
+
+/// Keep the end of long paths/URLs, where the repository name is.
+fn truncate_left(value: &str, width: usize) -> String {
+    let count = value.chars().count();
+    if count <= width {
+        return value.to_string();
     }
+    let tail: String = value.chars().skip(count - (width - 1)).collect();
+    format!("…{}", tail)
 }
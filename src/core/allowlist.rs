@@ -0,0 +1,260 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::analyzers::security::match_secret;
+use crate::analyzers::traits::Issue;
+use crate::core::content::{relative_path, MAX_FILE_BYTES};
+use crate::utils::fs::{read_text, TextFile};
+
+pub const ALLOWLIST_FILE: &str = ".repodoctor-allowlist.json";
+
+/// Format written by this version; version 1 used a single SHA-256 pass.
+const VERSION: u32 = 2;
+
+/// PBKDF2 rounds for new allowlists. Tests use far fewer to stay fast.
+const ITERATIONS: u32 = if cfg!(test) { 1_000 } else { 200_000 };
+
+/// Secret findings accepted as false positives, stored in
+/// `.repodoctor-allowlist.json`.
+///
+/// Each entry is a PBKDF2-HMAC-SHA256 of the project-relative file and the
+/// matched text, so the allowlist can be committed without repeating the
+/// value it allows. The salt is public, so the many rounds are what make
+/// guessing short values such as `hunter2` offline expensive. Editing the
+/// value or moving the file reports it again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecretAllowlist {
+    pub version: u32,
+    /// Random per-project salt mixed into every hash
+    pub salt: String,
+    /// PBKDF2 rounds behind every hash
+    pub iterations: u32,
+    pub entries: Vec<AllowedSecret>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllowedSecret {
+    pub hash: String,
+    /// Project-relative file the match was found in
+    pub file: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl Default for SecretAllowlist {
+    fn default() -> Self {
+        Self {
+            version: VERSION,
+            salt: new_salt(),
+            iterations: ITERATIONS,
+            entries: Vec::new(),
+        }
+    }
+}
+
+/// 128 bits from the clock, the process ID, and a stack address. The salt
+/// only has to differ between projects, not stay secret.
+fn new_salt() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let marker = 0u8;
+    let seed = format!("{}|{}|{:p}", nanos, std::process::id(), &marker);
+    hex(&Sha256::digest(seed.as_bytes())[..16])
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+impl SecretAllowlist {
+    /// Load the project's allowlist, if one exists.
+    pub fn load(project_path: &Path) -> Result<Option<Self>> {
+        let path = project_path.join(ALLOWLIST_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let version: serde_json::Value = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid allowlist file {}", path.display()))?;
+        if version["version"].as_u64() != Some(VERSION.into()) {
+            bail!(
+                "{} uses an older hash format; delete it and re-add its entries with `repodoctor allowlist add`",
+                path.display()
+            );
+        }
+        let allowlist = serde_json::from_value(version)
+            .with_context(|| format!("Invalid allowlist file {}", path.display()))?;
+        Ok(Some(allowlist))
+    }
+
+    pub fn save(&self, project_path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(project_path.join(ALLOWLIST_FILE), content + "\n")?;
+        Ok(())
+    }
+
+    /// Hash of `matched` found in the project-relative `file`.
+    pub fn hash(&self, file: &str, matched: &str) -> String {
+        let password = format!("{}\0{}", file, matched);
+        let mut key = [0u8; 32];
+        pbkdf2::pbkdf2_hmac::<Sha256>(
+            password.as_bytes(),
+            self.salt.as_bytes(),
+            self.iterations,
+            &mut key,
+        );
+        hex(&key)
+    }
+
+    /// Whether `matched` is allowed in `file`. Only files with entries pay
+    /// for hashing.
+    pub fn contains(&self, file: &str, matched: &str) -> bool {
+        if !self.entries.iter().any(|e| e.file == file) {
+            return false;
+        }
+        let hash = self.hash(file, matched);
+        self.entries.iter().any(|e| e.file == file && e.hash == hash)
+    }
+
+    /// Allow `matched` in `file`. Returns `false` if it was already allowed.
+    pub fn add(&mut self, file: &str, matched: &str, reason: Option<String>) -> bool {
+        if self.contains(file, matched) {
+            return false;
+        }
+        self.entries.push(AllowedSecret {
+            hash: self.hash(file, matched),
+            file: file.to_string(),
+            reason,
+        });
+        self.entries
+            .sort_by(|a, b| (&a.file, &a.hash).cmp(&(&b.file, &b.hash)));
+        true
+    }
+
+    /// Drop SEC-001 findings whose matched text is allowed in their file.
+    ///
+    /// Runs after organization policy violations have been split off, so a
+    /// mandatory SEC-001 cannot be allowlisted away.
+    pub fn filter(&self, issues: Vec<Issue>, project_path: &Path) -> Vec<Issue> {
+        let mut sources: HashMap<PathBuf, Option<String>> = HashMap::new();
+        issues
+            .into_iter()
+            .filter(|issue| {
+                let (Some(file), Some(line_num)) = (&issue.file, issue.line) else {
+                    return true;
+                };
+                if issue.id != "SEC-001" {
+                    return true;
+                }
+                let source = sources
+                    .entry(file.clone())
+                    .or_insert_with(|| read_source(file));
+                let Some(line) = source
+                    .as_deref()
+                    .and_then(|s| s.lines().nth(line_num.saturating_sub(1)))
+                else {
+                    return true;
+                };
+                let relative = relative_path(project_path, file);
+                !match_secret(line).is_some_and(|(_, range)| self.contains(&relative, &line[range]))
+            })
+            .collect()
+    }
+}
+
+/// A file decoded the way the content engine reads it, so findings in
+/// non-UTF-8 files can be matched too.
+pub fn read_source(path: &Path) -> Option<String> {
+    match read_text(path, MAX_FILE_BYTES) {
+        Ok(TextFile::Text(content)) => Some(content),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_hash_depends_on_salt_file_and_value() {
+        let allowlist = SecretAllowlist::default();
+        let hash = allowlist.hash("config/test.yml", "token: 'not-a-real-token'");
+        assert_eq!(hash.len(), 64);
+        assert!(!hash.contains("not-a-real-token"));
+        assert_eq!(
+            hash,
+            allowlist.hash("config/test.yml", "token: 'not-a-real-token'")
+        );
+        assert_ne!(
+            hash,
+            allowlist.hash("config/prod.yml", "token: 'not-a-real-token'")
+        );
+        assert_ne!(
+            hash,
+            allowlist.hash("config/test.yml", "token: 'another-token'")
+        );
+        assert_ne!(
+            hash,
+            SecretAllowlist::default().hash("config/test.yml", "token: 'not-a-real-token'")
+        );
+    }
+
+    #[test]
+    fn test_add_save_and_load() {
+        let tmp = TempDir::new().unwrap();
+        let mut allowlist = SecretAllowlist::default();
+        assert!(allowlist.add("a.yml", "password: 'example'", Some("docs".to_string())));
+        assert!(!allowlist.add("a.yml", "password: 'example'", None));
+        allowlist.save(tmp.path()).unwrap();
+
+        let content = std::fs::read_to_string(tmp.path().join(ALLOWLIST_FILE)).unwrap();
+        assert!(!content.contains("example"));
+
+        let loaded = SecretAllowlist::load(tmp.path()).unwrap().unwrap();
+        assert_eq!(loaded.salt, allowlist.salt);
+        assert!(loaded.contains("a.yml", "password: 'example'"));
+        assert_eq!(loaded.entries[0].reason.as_deref(), Some("docs"));
+    }
+
+    #[test]
+    fn test_load_missing_and_invalid() {
+        let tmp = TempDir::new().unwrap();
+        assert!(SecretAllowlist::load(tmp.path()).unwrap().is_none());
+        std::fs::write(tmp.path().join(ALLOWLIST_FILE), "{").unwrap();
+        assert!(SecretAllowlist::load(tmp.path()).is_err());
+    }
+
+    #[test]
+    fn test_load_rejects_single_pass_format() {
+        let tmp = TempDir::new().unwrap();
+        std::fs::write(
+            tmp.path().join(ALLOWLIST_FILE),
+            r#"{"version": 1, "salt": "ab", "entries": []}"#,
+        )
+        .unwrap();
+        let err = SecretAllowlist::load(tmp.path()).unwrap_err();
+        assert!(err.to_string().contains("older hash format"));
+    }
+
+    #[test]
+    fn test_hash_is_key_stretched() {
+        let mut allowlist = SecretAllowlist::default();
+        let hash = allowlist.hash("a.yml", "password: 'hunter2'");
+        let mut single = Sha256::new();
+        for part in [allowlist.salt.as_str(), "a.yml", "password: 'hunter2'"] {
+            single.update(part.as_bytes());
+            single.update([0]);
+        }
+        assert_ne!(hash, hex(&single.finalize()));
+
+        allowlist.iterations += 1;
+        assert_ne!(hash, allowlist.hash("a.yml", "password: 'hunter2'"));
+    }
+}
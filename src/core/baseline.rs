@@ -0,0 +1,427 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::analyzers::traits::Issue;
+use crate::core::config::{is_unexpired, ExpiringSuppression};
+
+pub const BASELINE_FILE: &str = ".repodoctor-baseline.json";
+
+/// Entries keyed on the per-occurrence fingerprint. Version 1 files keyed on
+/// the file-level [`fingerprint`] and are still read that way.
+const VERSION: u32 = 2;
+
+/// Known issues accepted at adoption time, stored in `.repodoctor-baseline.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub entries: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub fingerprint: String,
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    pub title: String,
+    /// `YYYY-MM-DD` after which the issue is reported again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+}
+
+/// Stable fingerprint of an issue: rule ID, project-relative file, and title.
+///
+/// Line numbers are deliberately left out so unrelated edits that shift code
+/// around do not invalidate the baseline. Issues sharing this key are told
+/// apart by [`assign_fingerprints`].
+pub fn fingerprint(issue: &Issue, project_path: &Path) -> String {
+    hash(&fingerprint_key(issue, project_path))
+}
+
+/// Give every issue a per-occurrence fingerprint.
+///
+/// Rules that fire several times in one file share a baseline fingerprint;
+/// occurrences are told apart by their position among those issues, so
+/// `issues` must already be in a deterministic order. The first occurrence
+/// keeps the plain baseline fingerprint.
+pub fn assign_fingerprints(issues: &mut [Issue], project_path: &Path) {
+    let mut occurrences: HashMap<String, usize> = HashMap::new();
+    for issue in issues {
+        let key = fingerprint_key(issue, project_path);
+        let count = occurrences.entry(key.clone()).or_default();
+        issue.fingerprint = Some(match *count {
+            0 => hash(&key),
+            n => hash(&format!("{}|{}", key, n)),
+        });
+        *count += 1;
+    }
+}
+
+fn fingerprint_key(issue: &Issue, project_path: &Path) -> String {
+    let file = relative_file(issue, project_path).unwrap_or_default();
+    format!("{}|{}|{}", issue.id, file, issue.title)
+}
+
+/// FNV-1a: stable across platforms and toolchain versions
+pub fn hash(key: &str) -> String {
+    let hash = key.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+fn relative_file(issue: &Issue, project_path: &Path) -> Option<String> {
+    let file = issue.file.as_ref()?;
+    let relative = file.strip_prefix(project_path).unwrap_or(file);
+    Some(
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"),
+    )
+}
+
+/// Per-occurrence fingerprint set by the scanner, or the file-level one for
+/// issues that did not go through it.
+fn occurrence(issue: &Issue, project_path: &Path) -> String {
+    issue
+        .fingerprint
+        .clone()
+        .unwrap_or_else(|| fingerprint(issue, project_path))
+}
+
+impl Baseline {
+    /// Build a baseline covering every issue in `issues`, one entry per occurrence.
+    pub fn from_issues(issues: &[Issue], project_path: &Path) -> Self {
+        let mut seen = HashSet::new();
+        let mut entries = Vec::new();
+        for issue in issues {
+            let fingerprint = occurrence(issue, project_path);
+            if seen.insert(fingerprint.clone()) {
+                entries.push(BaselineEntry {
+                    fingerprint,
+                    id: issue.id.clone(),
+                    file: relative_file(issue, project_path),
+                    title: issue.title.clone(),
+                    expires: None,
+                });
+            }
+        }
+        entries.sort_by(|a, b| (&a.id, &a.file, &a.title).cmp(&(&b.id, &b.file, &b.title)));
+        Baseline {
+            version: VERSION,
+            entries,
+        }
+    }
+
+    /// Whether entries use the file-level key of version 1 baselines, where
+    /// one entry covers every same-titled finding of a rule in the file.
+    pub fn is_legacy(&self) -> bool {
+        self.version < VERSION
+    }
+
+    /// The key `issue` is matched on in this baseline.
+    fn key(&self, issue: &Issue, project_path: &Path) -> String {
+        if self.is_legacy() {
+            fingerprint(issue, project_path)
+        } else {
+            occurrence(issue, project_path)
+        }
+    }
+
+    /// Record a single occurrence. Returns `false` if it was already in the baseline.
+    pub fn add(&mut self, issue: &Issue, project_path: &Path) -> Result<bool> {
+        if self.is_legacy() {
+            bail!(
+                "{} uses the old per-file format; run `repodoctor baseline write` to upgrade it",
+                BASELINE_FILE
+            );
+        }
+        let fingerprint = occurrence(issue, project_path);
+        if self.entries.iter().any(|e| e.fingerprint == fingerprint) {
+            return Ok(false);
+        }
+        self.entries.push(BaselineEntry {
+            fingerprint,
+            id: issue.id.clone(),
+            file: relative_file(issue, project_path),
+            title: issue.title.clone(),
+            expires: None,
+        });
+        self.entries
+            .sort_by(|a, b| (&a.id, &a.file, &a.title).cmp(&(&b.id, &b.file, &b.title)));
+        Ok(true)
+    }
+
+    /// Load the project's baseline, if one exists.
+    pub fn load(project_path: &Path) -> Result<Option<Self>> {
+        let path = project_path.join(BASELINE_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let baseline = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid baseline file {}", path.display()))?;
+        Ok(Some(baseline))
+    }
+
+    pub fn save(&self, project_path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(project_path.join(BASELINE_FILE), content + "\n")?;
+        Ok(())
+    }
+
+    /// Carry expiry dates over from `previous` for entries that are still present.
+    /// Entries of a version 1 `previous` cover every occurrence with their rule,
+    /// file and title.
+    pub fn keep_expiry_from(&mut self, previous: &Baseline) {
+        for entry in &mut self.entries {
+            if let Some(old) = previous.entries.iter().find(|e| {
+                if previous.is_legacy() {
+                    (&e.id, &e.file, &e.title) == (&entry.id, &entry.file, &entry.title)
+                } else {
+                    e.fingerprint == entry.fingerprint
+                }
+            }) {
+                entry.expires = old.expires.clone();
+            }
+        }
+    }
+
+    /// Set the same expiry date on every entry.
+    pub fn set_expiry(&mut self, expires: &str) {
+        for entry in &mut self.entries {
+            entry.expires = Some(expires.to_string());
+        }
+    }
+
+    fn active_fingerprints(&self, today: i64) -> HashSet<&str> {
+        self.entries
+            .iter()
+            .filter(|e| is_unexpired(e.expires.as_deref(), today))
+            .map(|e| e.fingerprint.as_str())
+            .collect()
+    }
+
+    /// Entries that have expired or expire within the warning window.
+    pub fn expiring(&self, today: i64) -> Vec<ExpiringSuppression> {
+        self.entries
+            .iter()
+            .filter_map(|e| {
+                ExpiringSuppression::check(
+                    "baseline",
+                    &e.id,
+                    e.file.as_deref(),
+                    e.expires.as_deref(),
+                    today,
+                )
+            })
+            .collect()
+    }
+
+    /// Remove issues recorded in the baseline whose entries have not expired.
+    pub fn filter(&self, issues: Vec<Issue>, project_path: &Path, today: i64) -> Vec<Issue> {
+        let known = self.active_fingerprints(today);
+        issues
+            .into_iter()
+            .filter(|i| !known.contains(self.key(i, project_path).as_str()))
+            .collect()
+    }
+
+    /// Drop entries whose issues are no longer detected. Returns how many were removed.
+    pub fn prune(&mut self, current: &[Issue], project_path: &Path) -> usize {
+        let detected: HashSet<String> = current.iter().map(|i| self.key(i, project_path)).collect();
+        let before = self.entries.len();
+        self.entries.retain(|e| detected.contains(&e.fingerprint));
+        before - self.entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::{AnalyzerCategory, Severity};
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn make_issue(id: &str, file: Option<&str>, line: Option<usize>) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::High,
+            title: "Test".to_string(),
+            description: "Test".to_string(),
+            file: file.map(PathBuf::from),
+            line,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        }
+    }
+
+    #[test]
+    fn test_assign_fingerprints_per_occurrence() {
+        let root = Path::new("/project");
+        let mut issues = vec![
+            make_issue("SEC-001", Some("/project/a.js"), Some(3)),
+            make_issue("SEC-001", Some("/project/a.js"), Some(9)),
+            make_issue("SEC-001", Some("/project/b.js"), Some(1)),
+        ];
+        assign_fingerprints(&mut issues, root);
+
+        let prints: Vec<_> = issues.iter().map(|i| i.fingerprint.clone().unwrap()).collect();
+        assert_eq!(prints[0], fingerprint(&issues[0], root));
+        assert_ne!(prints[0], prints[1]);
+        assert_eq!(prints[2], fingerprint(&issues[2], root));
+
+        // Shifting lines does not change the fingerprints
+        issues[0].line = Some(13);
+        issues[1].line = Some(19);
+        assign_fingerprints(&mut issues, root);
+        let shifted: Vec<_> = issues.iter().map(|i| i.fingerprint.clone().unwrap()).collect();
+        assert_eq!(prints, shifted);
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_line_and_root() {
+        let root = Path::new("/work/project");
+        let a = make_issue("SEC-001", Some("/work/project/src/config.rs"), Some(10));
+        let b = make_issue("SEC-001", Some("src/config.rs"), Some(42));
+        assert_eq!(fingerprint(&a, root), fingerprint(&b, root));
+
+        let c = make_issue("SEC-001", Some("src/other.rs"), Some(10));
+        assert_ne!(fingerprint(&a, root), fingerprint(&c, root));
+    }
+
+    #[test]
+    fn test_filter_hides_baselined_issues() {
+        let root = Path::new("/p");
+        let old = vec![make_issue("SEC-001", Some("a.rs"), Some(1))];
+        let baseline = Baseline::from_issues(&old, root);
+
+        let current = vec![
+            make_issue("SEC-001", Some("a.rs"), Some(3)),
+            make_issue("SEC-001", Some("b.rs"), Some(1)),
+        ];
+        let remaining = baseline.filter(current, root, 0);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].file, Some(PathBuf::from("b.rs")));
+    }
+
+    #[test]
+    fn test_baselined_secret_does_not_hide_a_new_one_in_the_same_file() {
+        let root = Path::new("/p");
+        let mut old = vec![make_issue("SEC-001", Some("a.rs"), Some(1))];
+        assign_fingerprints(&mut old, root);
+        let baseline = Baseline::from_issues(&old, root);
+
+        // Same rule, file and title, added below the accepted one
+        let mut current = vec![
+            make_issue("SEC-001", Some("a.rs"), Some(4)),
+            make_issue("SEC-001", Some("a.rs"), Some(20)),
+        ];
+        assign_fingerprints(&mut current, root);
+        let remaining = baseline.filter(current, root, 0);
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].line, Some(20));
+    }
+
+    #[test]
+    fn test_version_1_baselines_match_per_file() {
+        let root = Path::new("/p");
+        let legacy = Baseline {
+            version: 1,
+            entries: vec![BaselineEntry {
+                fingerprint: fingerprint(&make_issue("SEC-001", Some("a.rs"), None), root),
+                id: "SEC-001".to_string(),
+                file: Some("a.rs".to_string()),
+                title: "Test".to_string(),
+                expires: Some("2030-01-01".to_string()),
+            }],
+        };
+        let mut current = vec![
+            make_issue("SEC-001", Some("a.rs"), Some(1)),
+            make_issue("SEC-001", Some("a.rs"), Some(2)),
+        ];
+        assign_fingerprints(&mut current, root);
+        assert!(legacy.filter(current.clone(), root, 0).is_empty());
+        assert!(legacy.clone().add(&current[0], root).is_err());
+
+        // Rewriting upgrades to one entry per occurrence and keeps the expiry
+        let mut upgraded = Baseline::from_issues(&current, root);
+        upgraded.keep_expiry_from(&legacy);
+        assert!(!upgraded.is_legacy());
+        assert_eq!(upgraded.entries.len(), 2);
+        assert!(upgraded
+            .entries
+            .iter()
+            .all(|e| e.expires.as_deref() == Some("2030-01-01")));
+    }
+
+    #[test]
+    fn test_prune_removes_resolved_entries() {
+        let root = Path::new("/p");
+        let mut baseline = Baseline::from_issues(
+            &[
+                make_issue("SEC-001", Some("a.rs"), None),
+                make_issue("STR-004", None, None),
+            ],
+            root,
+        );
+        let removed = baseline.prune(&[make_issue("STR-004", None, None)], root);
+        assert_eq!(removed, 1);
+        assert_eq!(baseline.entries.len(), 1);
+        assert_eq!(baseline.entries[0].id, "STR-004");
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let tmp = TempDir::new().unwrap();
+        assert!(Baseline::load(tmp.path()).unwrap().is_none());
+
+        let baseline = Baseline::from_issues(&[make_issue("DOC-001", None, None)], tmp.path());
+        baseline.save(tmp.path()).unwrap();
+
+        let loaded = Baseline::load(tmp.path()).unwrap().unwrap();
+        assert_eq!(loaded.entries, baseline.entries);
+    }
+
+    #[test]
+    fn test_expired_entries_reactivate_issues() {
+        let root = Path::new("/p");
+        let issues = vec![make_issue("SEC-001", Some("a.rs"), None)];
+        let mut baseline = Baseline::from_issues(&issues, root);
+        baseline.set_expiry("2025-06-30");
+        let deadline = crate::utils::date::parse_date("2025-06-30").unwrap();
+
+        assert!(baseline.filter(issues.clone(), root, deadline).is_empty());
+        assert_eq!(baseline.filter(issues, root, deadline + 1).len(), 1);
+
+        let expiring = baseline.expiring(deadline - 10);
+        assert_eq!(expiring.len(), 1);
+        assert_eq!(expiring[0].days_left, 10);
+        assert_eq!(expiring[0].source, "baseline");
+        assert!(baseline.expiring(deadline - 90).is_empty());
+    }
+
+    #[test]
+    fn test_rewrite_keeps_expiry() {
+        let root = Path::new("/p");
+        let issues = vec![make_issue("SEC-001", Some("a.rs"), None)];
+        let mut old = Baseline::from_issues(&issues, root);
+        old.set_expiry("2030-01-01");
+
+        let mut new = Baseline::from_issues(&issues, root);
+        new.keep_expiry_from(&old);
+        assert_eq!(new.entries[0].expires.as_deref(), Some("2030-01-01"));
+    }
+}
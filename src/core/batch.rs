@@ -0,0 +1,142 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::core::project::Project;
+use crate::core::scanner::{ScanResult, Scanner};
+use crate::utils::git;
+
+/// A repository listed in a batch file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchTarget {
+    Local(PathBuf),
+    Git(String),
+}
+
+impl BatchTarget {
+    pub fn label(&self) -> String {
+        match self {
+            BatchTarget::Local(path) => path.display().to_string(),
+            BatchTarget::Git(url) => url.clone(),
+        }
+    }
+}
+
+/// Read a batch file: one local path or git URL per line. Blank lines and
+/// `#` comments are skipped; relative paths resolve against the file's directory.
+pub fn parse_batch_file(path: &Path) -> Result<Vec<BatchTarget>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read batch file {}", path.display()))?;
+    let base = path.parent().unwrap_or(Path::new("."));
+
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            if git::is_git_url(line) {
+                BatchTarget::Git(line.to_string())
+            } else {
+                BatchTarget::Local(base.join(line))
+            }
+        })
+        .collect())
+}
+
+/// Scan outcome for one batch target.
+#[derive(Debug)]
+pub struct BatchEntry {
+    pub source: String,
+    pub result: Option<ScanResult>,
+    pub error: Option<String>,
+}
+
+/// Scan every target in order, calling `on_target` before each one. Failures
+/// are recorded per entry instead of aborting the batch.
+pub async fn run_batch<F: Fn(&str)>(
+    targets: &[BatchTarget],
+    scanner: &Scanner,
+    on_target: F,
+) -> Vec<BatchEntry> {
+    let mut entries = Vec::new();
+    for target in targets {
+        let source = target.label();
+        on_target(&source);
+        match scan_target(target, scanner).await {
+            Ok(result) => {
+                entries.push(BatchEntry {
+                    source,
+                    result: Some(result),
+                    error: None,
+                });
+            }
+            Err(e) => entries.push(BatchEntry {
+                source,
+                result: None,
+                error: Some(format!("{:#}", e)),
+            }),
+        }
+    }
+    entries
+}
+
+async fn scan_target(target: &BatchTarget, scanner: &Scanner) -> Result<ScanResult> {
+    match target {
+        BatchTarget::Local(path) => {
+            let project = Project::new(path)
+                .with_context(|| format!("Cannot open {}", path.display()))?;
+            scanner.scan(&project).await
+        }
+        BatchTarget::Git(url) => {
+            // The clone lives until the scan finishes.
+            let url = url.clone();
+            let clone = tokio::task::spawn_blocking(move || git::shallow_clone(&url)).await??;
+            let project = Project::new(clone.path())?;
+            scanner.scan(&project).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::default_scanner;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_parse_batch_file() {
+        let tmp = TempDir::new().unwrap();
+        let list = tmp.path().join("repos.txt");
+        fs::write(
+            &list,
+            "# services\nsvc-a\n\n  https://github.com/acme/api.git  \n/abs/path\n",
+        )
+        .unwrap();
+
+        let targets = parse_batch_file(&list).unwrap();
+        assert_eq!(
+            targets,
+            vec![
+                BatchTarget::Local(tmp.path().join("svc-a")),
+                BatchTarget::Git("https://github.com/acme/api.git".to_string()),
+                BatchTarget::Local(PathBuf::from("/abs/path")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_batch_records_failures() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("svc-a/src")).unwrap();
+        let targets = vec![
+            BatchTarget::Local(tmp.path().join("svc-a")),
+            BatchTarget::Local(tmp.path().join("missing")),
+        ];
+
+        let entries = run_batch(&targets, &default_scanner(), |_| {}).await;
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].result.is_some());
+        assert!(entries[1].result.is_none());
+        assert!(entries[1].error.as_deref().unwrap().contains("Cannot open"));
+    }
+}
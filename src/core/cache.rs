@@ -0,0 +1,280 @@
+//! Scan results cached by git commit.
+//!
+//! A clean checkout of a given commit always produces the same result for the
+//! same options, so `report` can reuse it instead of rescanning. Gitignored
+//! files such as `.env` are read by analyzers too, so their content is part
+//! of the key. Entries live inside the repository's `.git` directory, which
+//! keeps the working tree clean.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::analyzers::traits::Issue;
+use crate::core::baseline;
+use crate::core::config::ExpiringSuppression;
+use crate::core::content::SkippedFile;
+use crate::core::metrics::RepoMetrics;
+use crate::core::policy::PolicyReport;
+use crate::core::project::Project;
+use crate::core::scanner::ScanResult;
+use crate::core::score::HealthScore;
+use crate::utils::fs::{is_ignored_name, walk};
+use crate::utils::git;
+
+const CACHE_DIR: &str = "repodoctor-cache";
+
+/// RepoDoctor's own default outputs, which do not make a tree dirty.
+fn is_own_output(path: &str) -> bool {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    name.starts_with("repodoctor-report.") || name == "repodoctor-badge.svg"
+}
+
+/// Hash of the gitignored files under `project`, which the commit does not
+/// cover. Ignored directories the analyzers never enter (`node_modules`,
+/// `target`, hidden directories) are left out.
+fn ignored_inputs(project: &Path) -> Option<String> {
+    let mut files = Vec::new();
+    for path in git::ignored_paths(project)? {
+        let Some(dir) = path.strip_suffix('/') else {
+            files.push(project.join(path));
+            continue;
+        };
+        let skipped = dir.rsplit('/').next().is_some_and(is_ignored_name);
+        if skipped {
+            continue;
+        }
+        files.extend(
+            walk(&project.join(dir))
+                .into_iter()
+                .filter_entry(|e| {
+                    !(e.file_type().is_dir() && is_ignored_name(&e.file_name().to_string_lossy()))
+                })
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file())
+                .map(|e| e.into_path()),
+        );
+    }
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for file in files {
+        hasher.update(file.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(std::fs::read(&file).unwrap_or_default());
+        hasher.update([0]);
+    }
+    Some(
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect(),
+    )
+}
+
+/// Directory holding cached results for the repository containing `project`.
+pub fn cache_dir(project: &Path) -> Option<PathBuf> {
+    Some(git::git_dir(project)?.join(CACHE_DIR))
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedScan {
+    key: String,
+    issues: Vec<Issue>,
+    score: HealthScore,
+    expiring: Vec<ExpiringSuppression>,
+    policy: Option<PolicyReport>,
+    skipped_files: Vec<SkippedFile>,
+    metrics: RepoMetrics,
+    duration_ms: u64,
+}
+
+pub struct ResultCache {
+    file: PathBuf,
+    key: String,
+    /// Commit the cached result belongs to
+    pub commit: String,
+}
+
+impl ResultCache {
+    /// Cache entry for scanning `project` with the options summarized in
+    /// `options`. `None` unless the project is a git checkout with no
+    /// uncommitted changes.
+    pub fn for_project(project: &Path, options: &str) -> Option<Self> {
+        let commit = git::head_commit(project)?;
+        let dirty = git::dirty_paths(project)?;
+        if dirty.iter().any(|path| !is_own_output(path)) {
+            return None;
+        }
+
+        // Anything besides the commit that changes the result: the tool
+        // version, the options, gitignored files, environment overrides, and
+        // today's date (suppression expiry)
+        let ignored = ignored_inputs(project)?;
+        let mut env: Vec<String> = std::env::vars()
+            .filter(|(name, _)| name.starts_with("REPODOCTOR_"))
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        env.sort();
+        let key = format!(
+            "{}|{}|{}|{}|{}|{}|{}",
+            env!("CARGO_PKG_VERSION"),
+            commit,
+            project.display(),
+            options,
+            ignored,
+            env.join(","),
+            crate::utils::date::today()
+        );
+
+        let file = cache_dir(project)?.join(format!("{}.json", baseline::hash(&key)));
+        Some(Self { file, key, commit })
+    }
+
+    /// The cached result, if one was stored for this exact key.
+    pub fn load(&self, project: &Project) -> Option<ScanResult> {
+        let content = std::fs::read_to_string(&self.file).ok()?;
+        let cached: CachedScan = serde_json::from_str(&content).ok()?;
+        if cached.key != self.key {
+            return None;
+        }
+        Some(ScanResult {
+            project: project.clone(),
+            issues: cached.issues,
+            score: cached.score,
+            duration: Duration::from_millis(cached.duration_ms),
+            expiring: cached.expiring,
+            policy: cached.policy,
+            skipped_files: cached.skipped_files,
+            // Nothing ran, so there is nothing to profile
+            stats: vec![],
+            metrics: cached.metrics,
+        })
+    }
+
+    /// Store `result`, replacing entries for older commits or options.
+    pub fn store(&self, result: &ScanResult) -> Result<()> {
+        let dir = self.file.parent().unwrap_or(Path::new("."));
+        if dir.is_dir() {
+            for entry in std::fs::read_dir(dir)?.filter_map(|e| e.ok()) {
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+        std::fs::create_dir_all(dir)?;
+
+        let cached = CachedScan {
+            key: self.key.clone(),
+            issues: result.issues.clone(),
+            score: result.score.clone(),
+            expiring: result.expiring.clone(),
+            policy: result.policy.clone(),
+            skipped_files: result.skipped_files.clone(),
+            metrics: result.metrics.clone(),
+            duration_ms: result.duration.as_millis() as u64,
+        };
+        std::fs::write(&self.file, serde_json::to_string(&cached)?)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::scanner::default_scanner;
+    use std::fs;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) -> bool {
+        Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+
+    /// A committed repository, or `None` when git is not available.
+    fn make_repo() -> Option<TempDir> {
+        let tmp = TempDir::new().unwrap();
+        if !git(tmp.path(), &["init", "-q"]) {
+            return None;
+        }
+        fs::write(tmp.path().join("README.md"), "# Test\n").unwrap();
+        git(tmp.path(), &["add", "."]);
+        git(
+            tmp.path(),
+            &["-c", "user.name=t", "-c", "user.email=t@t", "commit", "-qm", "init"],
+        );
+        Some(tmp)
+    }
+
+    #[tokio::test]
+    async fn test_roundtrip_on_clean_tree() {
+        let Some(repo) = make_repo() else { return };
+        let project = Project::new(repo.path()).unwrap();
+        let cache = ResultCache::for_project(&project.path, "default").unwrap();
+        assert!(cache.load(&project).is_none());
+
+        let result = default_scanner().scan(&project).await.unwrap();
+        cache.store(&result).unwrap();
+
+        let cached = ResultCache::for_project(&project.path, "default")
+            .unwrap()
+            .load(&project)
+            .unwrap();
+        assert_eq!(cached.score.total, result.score.total);
+        assert_eq!(cached.issues.len(), result.issues.len());
+
+        // Different options miss
+        let other = ResultCache::for_project(&project.path, "strict").unwrap();
+        assert!(other.load(&project).is_none());
+    }
+
+    #[test]
+    fn test_no_cache_for_dirty_tree() {
+        let Some(repo) = make_repo() else { return };
+        // Default report outputs do not count as changes
+        fs::write(repo.path().join("repodoctor-report.html"), "<html>").unwrap();
+        assert!(ResultCache::for_project(repo.path(), "").is_some());
+
+        fs::write(repo.path().join("README.md"), "# Changed\n").unwrap();
+        assert!(ResultCache::for_project(repo.path(), "").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_ignored_files_are_part_of_the_key() {
+        let Some(repo) = make_repo() else { return };
+        fs::write(repo.path().join(".gitignore"), ".env\nnode_modules/\n").unwrap();
+        git(repo.path(), &["add", "."]);
+        git(
+            repo.path(),
+            &["-c", "user.name=t", "-c", "user.email=t@t", "commit", "-qm", "ignore"],
+        );
+        fs::write(repo.path().join(".env"), "APP_DEBUG=false\n").unwrap();
+        let project = Project::new(repo.path()).unwrap();
+        let cache = ResultCache::for_project(&project.path, "").unwrap();
+        cache
+            .store(&default_scanner().scan(&project).await.unwrap())
+            .unwrap();
+
+        // Ignored dependency directories are not analyzed, so they do not count
+        fs::create_dir(repo.path().join("node_modules")).unwrap();
+        fs::write(repo.path().join("node_modules/index.js"), "x").unwrap();
+        let cache = ResultCache::for_project(&project.path, "").unwrap();
+        assert!(cache.load(&project).is_some());
+
+        fs::write(repo.path().join(".env"), "APP_DEBUG=true\n").unwrap();
+        let cache = ResultCache::for_project(&project.path, "").unwrap();
+        assert!(cache.load(&project).is_none());
+    }
+
+    #[test]
+    fn test_no_cache_outside_git() {
+        let tmp = TempDir::new().unwrap();
+        assert!(ResultCache::for_project(tmp.path(), "").is_none());
+    }
+}
@@ -1,20 +1,244 @@
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
 
 use crate::analyzers::traits::{Issue, Severity};
+use crate::core::profile::Profile;
+use crate::core::project_type::ProjectType;
+use crate::utils::date;
+
+/// Suppressions expiring within this many days are listed in reports.
+pub const EXPIRY_WARNING_DAYS: i64 = 30;
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Config {
     /// Preset to inherit from: strict, balanced, relaxed
     pub extends: Option<String>,
+    /// Strictness profile: strict, recommended, relaxed
+    pub profile: Option<String>,
+    /// What the project ships: library, application, service (guessed when unset)
+    pub project_type: Option<String>,
     pub severity_threshold: Option<String>,
     pub ignore: Option<IgnoreConfig>,
+    /// User-defined regex rules run by the custom rules analyzer
+    pub custom_rules: Option<Vec<CustomRule>>,
+    /// Declarative file presence/absence rules run by the file policy analyzer
+    pub file_policies: Option<Vec<FilePolicy>>,
+    /// Directory whose files override the built-in fixer templates
+    pub templates_dir: Option<String>,
+    /// Commit message conventions checked by the commits analyzer
+    pub commits: Option<CommitConventions>,
+    /// External scanner used for images referenced in Dockerfiles and compose files
+    pub containers: Option<ContainerScan>,
+    /// Release checks by `scan` and `self-update`
+    pub updates: Option<UpdateSettings>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct IgnoreConfig {
     pub paths: Option<Vec<String>>,
-    pub rules: Option<Vec<String>>,
+    pub rules: Option<Vec<IgnoreRule>>,
+}
+
+/// An entry of `ignore.rules`: either a bare rule ID or a rule with an expiry date.
+///
+/// ```yaml
+/// rules:
+///   - DOC-003
+///   - rule: SEC-001
+///     expires: 2025-12-31
+///     reason: Rotating credentials in Q4
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IgnoreRule {
+    Id(String),
+    Detailed {
+        rule: String,
+        expires: Option<String>,
+        reason: Option<String>,
+    },
+}
+
+impl IgnoreRule {
+    pub fn rule(&self) -> &str {
+        match self {
+            IgnoreRule::Id(id) => id,
+            IgnoreRule::Detailed { rule, .. } => rule,
+        }
+    }
+
+    pub fn expires(&self) -> Option<&str> {
+        match self {
+            IgnoreRule::Id(_) => None,
+            IgnoreRule::Detailed { expires, .. } => expires.as_deref(),
+        }
+    }
+
+    /// Whether the suppression still applies on `today` (days since epoch).
+    pub fn is_active(&self, today: i64) -> bool {
+        is_unexpired(self.expires(), today)
+    }
+}
+
+impl From<&str> for IgnoreRule {
+    fn from(id: &str) -> Self {
+        IgnoreRule::Id(id.to_string())
+    }
+}
+
+impl PartialEq<String> for IgnoreRule {
+    fn eq(&self, other: &String) -> bool {
+        self.rule() == other
+    }
+}
+
+impl PartialEq<&str> for IgnoreRule {
+    fn eq(&self, other: &&str) -> bool {
+        self.rule() == *other
+    }
+}
+
+/// An expiry date is honored up to and including that day. Unparseable dates
+/// count as expired so a typo never hides an issue forever.
+pub fn is_unexpired(expires: Option<&str>, today: i64) -> bool {
+    match expires {
+        None => true,
+        Some(value) => date::parse_date(value).is_some_and(|d| today <= d),
+    }
+}
+
+/// A suppression that has expired or will expire soon.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpiringSuppression {
+    /// `config` for `ignore.rules` entries, `baseline` for baseline entries
+    pub source: String,
+    pub rule: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    pub expires: String,
+    /// Negative once the suppression has expired
+    pub days_left: i64,
+}
+
+impl ExpiringSuppression {
+    /// Build an entry when `expires` is within the warning window (or past).
+    pub fn check(
+        source: &str,
+        rule: &str,
+        file: Option<&str>,
+        expires: Option<&str>,
+        today: i64,
+    ) -> Option<Self> {
+        let expires = expires?;
+        let days_left = date::parse_date(expires).map_or(-1, |d| d - today);
+        (days_left <= EXPIRY_WARNING_DAYS).then(|| ExpiringSuppression {
+            source: source.to_string(),
+            rule: rule.to_string(),
+            file: file.map(String::from),
+            expires: expires.to_string(),
+            days_left,
+        })
+    }
+
+    /// Human-readable status, e.g. "expires in 5 day(s)".
+    pub fn status(&self) -> String {
+        match self.days_left {
+            d if d < 0 => format!("expired ({})", self.expires),
+            0 => "expires today".to_string(),
+            d => format!("expires in {} day(s) ({})", d, self.expires),
+        }
+    }
+
+    /// Rule ID, with the file for baseline entries.
+    pub fn target(&self) -> String {
+        match &self.file {
+            Some(file) => format!("{} in {}", self.rule, file),
+            None => self.rule.clone(),
+        }
+    }
+}
+
+/// A regex check declared in `.repodoctor.yml` under `custom_rules:`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CustomRule {
+    pub id: String,
+    /// Glob matched against paths relative to the project root (e.g. `src/**/*.ts`)
+    pub files: String,
+    pub pattern: String,
+    pub severity: Option<String>,
+    pub category: Option<String>,
+    /// trivial, small, medium or large
+    pub effort: Option<String>,
+    /// low, medium or high
+    pub impact: Option<String>,
+    pub message: String,
+    pub suggestion: Option<String>,
+    /// Links listed with each finding, such as an internal wiki page
+    pub references: Option<Vec<String>>,
+}
+
+/// A file-existence rule declared in `.repodoctor.yml` under `file_policies:`.
+///
+/// Exactly one of `require`, `forbid`, or `require_one_of` is expected; paths
+/// may contain glob patterns (e.g. `**/*.pem`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FilePolicy {
+    pub id: String,
+    pub require: Option<String>,
+    pub forbid: Option<String>,
+    pub require_one_of: Option<Vec<String>>,
+    pub severity: Option<String>,
+    pub category: Option<String>,
+    pub message: Option<String>,
+    /// Links listed with each finding, such as an internal wiki page
+    pub references: Option<Vec<String>>,
+}
+
+/// Commit message rules declared in `.repodoctor.yml` under `commits:`.
+///
+/// ```yaml
+/// commits:
+///   count: 100
+///   pattern: '^(feat|fix|chore)(\(.+\))?: '
+///   issue_pattern: '[A-Z]+-\d+'
+///   max_unreferenced_percent: 20
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommitConventions {
+    /// How many recent commits to check (default 50)
+    pub count: Option<usize>,
+    /// Regex every subject must match; defaults to Conventional Commits
+    pub pattern: Option<String>,
+    /// Regex for issue references; defaults to `#123` and `ABC-123`
+    pub issue_pattern: Option<String>,
+    /// Share of commits without an issue reference tolerated before reporting (default 50)
+    pub max_unreferenced_percent: Option<u8>,
+}
+
+/// Container image scanning settings declared under `containers:`.
+///
+/// ```yaml
+/// containers:
+///   scanner: grype        # auto (default), trivy, grype, or none
+///   ignore_unfixed: true  # Skip vulnerabilities without a fixed version
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContainerScan {
+    pub scanner: Option<String>,
+    pub ignore_unfixed: Option<bool>,
+}
+
+/// Release check settings declared under `updates:`.
+///
+/// ```yaml
+/// updates:
+///   check: false  # Air-gapped: no new-version notice, no self-update
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateSettings {
+    pub check: Option<bool>,
 }
 
 impl Config {
@@ -28,19 +252,61 @@ impl Config {
         }
     }
 
+    /// Whether GitHub may be contacted for new releases (default true).
+    pub fn update_check(&self) -> bool {
+        self.updates
+            .as_ref()
+            .and_then(|u| u.check)
+            .unwrap_or(true)
+    }
+
+    pub fn profile(&self) -> Option<Profile> {
+        self.profile.as_deref().and_then(Profile::from_name)
+    }
+
+    pub fn project_type(&self) -> Option<ProjectType> {
+        self.project_type.as_deref().and_then(ProjectType::from_name)
+    }
+
     pub fn is_rule_ignored(&self, rule_id: &str) -> bool {
+        self.is_rule_ignored_on(rule_id, date::today())
+    }
+
+    fn is_rule_ignored_on(&self, rule_id: &str, today: i64) -> bool {
         self.ignore
             .as_ref()
             .and_then(|ig| ig.rules.as_ref())
-            .map(|rules| rules.iter().any(|r| r == rule_id))
+            .map(|rules| rules.iter().any(|r| r.rule() == rule_id && r.is_active(today)))
             .unwrap_or(false)
     }
 
+    /// `ignore.rules` entries that have expired or expire within the warning window.
+    pub fn expiring_suppressions(&self, today: i64) -> Vec<ExpiringSuppression> {
+        self.ignore
+            .as_ref()
+            .and_then(|ig| ig.rules.as_ref())
+            .map(|rules| {
+                rules
+                    .iter()
+                    .filter_map(|r| {
+                        ExpiringSuppression::check("config", r.rule(), None, r.expires(), today)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn is_path_ignored(&self, file_path: &str) -> bool {
+        // Windows paths use `\`, ignore patterns are written with `/`
+        let file_path = file_path.replace('\\', "/");
         self.ignore
             .as_ref()
             .and_then(|ig| ig.paths.as_ref())
-            .map(|paths| paths.iter().any(|p| file_path.starts_with(p.trim_end_matches('/'))))
+            .map(|paths| {
+                paths.iter().any(|p| {
+                    file_path.starts_with(p.replace('\\', "/").trim_end_matches('/'))
+                })
+            })
             .unwrap_or(false)
     }
 
@@ -68,16 +334,79 @@ impl Config {
 
 impl Config {
     pub fn load(project_path: &Path) -> Self {
+        Self::load_file(Some(&project_path.join(".repodoctor.yml")))
+    }
+
+    /// Settings of the user rather than a project, from the user config file
+    /// (see [`user_config_path`]). Only `updates` is read from it.
+    pub fn load_user() -> Self {
+        Self::load_file(user_config_path(|key| std::env::var_os(key)).as_deref())
+    }
+
+    fn load_file(config_path: Option<&Path>) -> Self {
+        let mut config = config_path
+            .filter(|path| path.exists())
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_yaml::from_str::<Config>(&content).ok())
+            .unwrap_or_default();
+        config.apply_env_overrides(|key| std::env::var(key).ok());
+        config.apply_preset();
+        config
+    }
+
+    /// Parse `.repodoctor.yml` without falling back to defaults, so errors
+    /// can be reported. `None` when the project has no config file.
+    pub fn validate(project_path: &Path) -> Result<Option<Self>> {
         let config_path = project_path.join(".repodoctor.yml");
-        if config_path.exists() {
-            if let Ok(content) = std::fs::read_to_string(&config_path) {
-                if let Ok(mut config) = serde_yaml::from_str::<Config>(&content) {
-                    config.apply_preset();
-                    return config;
-                }
-            }
+        if !config_path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&config_path)?;
+        Ok(Some(serde_yaml::from_str(&content)?))
+    }
+
+    /// Override file values with `REPODOCTOR_*` environment variables.
+    ///
+    /// List values (`REPODOCTOR_IGNORE_RULES`, `REPODOCTOR_IGNORE_PATHS`) are
+    /// comma-separated and replace the lists from the file.
+    fn apply_env_overrides<F: Fn(&str) -> Option<String>>(&mut self, var: F) {
+        let non_empty = |key: &str| var(key).filter(|v| !v.trim().is_empty());
+        let split = |value: String| -> Vec<String> {
+            value
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        };
+
+        if let Some(extends) = non_empty("REPODOCTOR_EXTENDS") {
+            self.extends = Some(extends.trim().to_lowercase());
+        }
+        if let Some(profile) = non_empty("REPODOCTOR_PROFILE") {
+            self.profile = Some(profile.trim().to_lowercase());
+        }
+        if let Some(project_type) = non_empty("REPODOCTOR_PROJECT_TYPE") {
+            self.project_type = Some(project_type.trim().to_lowercase());
+        }
+        if let Some(threshold) = non_empty("REPODOCTOR_SEVERITY_THRESHOLD") {
+            self.severity_threshold = Some(threshold.trim().to_lowercase());
+        }
+        if let Some(rules) = non_empty("REPODOCTOR_IGNORE_RULES") {
+            self.ignore.get_or_insert_with(IgnoreConfig::default).rules =
+                Some(split(rules).into_iter().map(IgnoreRule::Id).collect());
+        }
+        if let Some(paths) = non_empty("REPODOCTOR_IGNORE_PATHS") {
+            self.ignore.get_or_insert_with(IgnoreConfig::default).paths = Some(split(paths));
+        }
+        if let Some(scanner) = non_empty("REPODOCTOR_CONTAINER_SCANNER") {
+            self.containers.get_or_insert_with(ContainerScan::default).scanner =
+                Some(scanner.trim().to_lowercase());
+        }
+        if let Some(check) = non_empty("REPODOCTOR_UPDATE_CHECK") {
+            let check = check.trim().to_lowercase();
+            self.updates.get_or_insert_with(UpdateSettings::default).check =
+                Some(!matches!(check.as_str(), "false" | "0" | "no" | "off"));
         }
-        Config::default()
     }
 
     fn apply_preset(&mut self) {
@@ -101,44 +430,53 @@ impl Config {
 
     fn preset_strict() -> Config {
         Config {
-            extends: None,
             severity_threshold: Some("info".to_string()),
-            ignore: None,
+            ..Config::default()
         }
     }
 
     fn preset_balanced() -> Config {
         Config {
-            extends: None,
             severity_threshold: Some("low".to_string()),
             ignore: Some(IgnoreConfig {
                 paths: None,
                 rules: Some(vec![
-                    "DOC-003".to_string(),
-                    "DOC-005".to_string(),
+                    "DOC-003".into(),
+                    "DOC-005".into(),
                 ]),
             }),
+            ..Config::default()
         }
     }
 
     fn preset_relaxed() -> Config {
         Config {
-            extends: None,
             severity_threshold: Some("medium".to_string()),
             ignore: Some(IgnoreConfig {
                 paths: None,
                 rules: Some(vec![
-                    "DOC-003".to_string(),
-                    "DOC-005".to_string(),
-                    "DOC-006".to_string(),
-                    "STR-005".to_string(),
-                    "CFG-004".to_string(),
+                    "DOC-003".into(),
+                    "DOC-005".into(),
+                    "DOC-006".into(),
+                    "STR-005".into(),
+                    "CFG-004".into(),
                 ]),
             }),
+            ..Config::default()
         }
     }
 }
 
+/// `$XDG_CONFIG_HOME`, `%APPDATA%`, or `~/.config`, followed by
+/// `repodoctor/config.yml`.
+pub fn user_config_path<F: Fn(&str) -> Option<OsString>>(var: F) -> Option<PathBuf> {
+    let var = |key: &str| var(key).filter(|v| !v.is_empty()).map(PathBuf::from);
+    let base = var("XDG_CONFIG_HOME")
+        .or_else(|| var("APPDATA"))
+        .or_else(|| var("HOME").map(|home| home.join(".config")))?;
+    Some(base.join("repodoctor").join("config.yml"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -156,6 +494,11 @@ mod tests {
             description: "Test".to_string(),
             file: file.map(|f| f.into()),
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: None,
             auto_fixable: false,
             references: vec![],
@@ -190,9 +533,9 @@ mod tests {
     #[test]
     fn test_min_severity_high() {
         let config = Config {
-            extends: None,
             severity_threshold: Some("high".to_string()),
             ignore: None,
+            ..Config::default()
         };
         assert_eq!(config.min_severity(), Severity::High);
     }
@@ -200,12 +543,12 @@ mod tests {
     #[test]
     fn test_is_rule_ignored() {
         let config = Config {
-            extends: None,
             severity_threshold: None,
             ignore: Some(IgnoreConfig {
                 paths: None,
-                rules: Some(vec!["DOC-003".to_string(), "STR-005".to_string()]),
+                rules: Some(vec!["DOC-003".into(), "STR-005".into()]),
             }),
+            ..Config::default()
         };
         assert!(config.is_rule_ignored("DOC-003"));
         assert!(config.is_rule_ignored("STR-005"));
@@ -215,24 +558,25 @@ mod tests {
     #[test]
     fn test_is_path_ignored() {
         let config = Config {
-            extends: None,
             severity_threshold: None,
             ignore: Some(IgnoreConfig {
                 paths: Some(vec!["vendor/".to_string(), "node_modules/".to_string()]),
                 rules: None,
             }),
+            ..Config::default()
         };
         assert!(config.is_path_ignored("vendor/autoload.php"));
         assert!(config.is_path_ignored("node_modules/package/index.js"));
         assert!(!config.is_path_ignored("src/main.rs"));
+        assert!(config.is_path_ignored("vendor\\autoload.php"));
     }
 
     #[test]
     fn test_filter_issues_by_severity() {
         let config = Config {
-            extends: None,
             severity_threshold: Some("medium".to_string()),
             ignore: None,
+            ..Config::default()
         };
         let issues = vec![
             make_issue("A", Severity::Critical, None),
@@ -251,12 +595,12 @@ mod tests {
     #[test]
     fn test_filter_issues_by_rule() {
         let config = Config {
-            extends: None,
             severity_threshold: None,
             ignore: Some(IgnoreConfig {
                 paths: None,
-                rules: Some(vec!["STR-005".to_string()]),
+                rules: Some(vec!["STR-005".into()]),
             }),
+            ..Config::default()
         };
         let issues = vec![
             make_issue("STR-001", Severity::High, None),
@@ -270,12 +614,12 @@ mod tests {
     #[test]
     fn test_filter_issues_by_path() {
         let config = Config {
-            extends: None,
             severity_threshold: None,
             ignore: Some(IgnoreConfig {
                 paths: Some(vec!["vendor/".to_string()]),
                 rules: None,
             }),
+            ..Config::default()
         };
         let issues = vec![
             make_issue("A", Severity::High, Some("vendor/autoload.php")),
@@ -318,6 +662,114 @@ mod tests {
         assert!(config.is_rule_ignored("CFG-004"));
     }
 
+    #[test]
+    fn test_env_overrides_file_values() {
+        let mut config: Config =
+            serde_yaml::from_str("severity_threshold: low\nignore:\n  rules:\n    - DOC-003\n").unwrap();
+        config.apply_env_overrides(|key| match key {
+            "REPODOCTOR_SEVERITY_THRESHOLD" => Some("HIGH".to_string()),
+            "REPODOCTOR_IGNORE_RULES" => Some("STR-005, CFG-004".to_string()),
+            _ => None,
+        });
+        assert_eq!(config.min_severity(), Severity::High);
+        assert!(config.is_rule_ignored("STR-005"));
+        assert!(config.is_rule_ignored("CFG-004"));
+        assert!(!config.is_rule_ignored("DOC-003"));
+    }
+
+    #[test]
+    fn test_profile_from_config_and_env() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join(".repodoctor.yml"), "profile: strict\n").unwrap();
+        let mut config = Config::load(tmp.path());
+        assert_eq!(config.profile(), Some(Profile::Strict));
+
+        config.apply_env_overrides(|key| match key {
+            "REPODOCTOR_PROFILE" => Some("Relaxed".to_string()),
+            _ => None,
+        });
+        assert_eq!(config.profile(), Some(Profile::Relaxed));
+    }
+
+    #[test]
+    fn test_project_type_from_config_and_env() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join(".repodoctor.yml"), "project_type: library\n").unwrap();
+        let mut config = Config::load(tmp.path());
+        assert_eq!(config.project_type(), Some(ProjectType::Library));
+
+        config.apply_env_overrides(|key| match key {
+            "REPODOCTOR_PROJECT_TYPE" => Some("Service".to_string()),
+            _ => None,
+        });
+        assert_eq!(config.project_type(), Some(ProjectType::Service));
+    }
+
+    #[test]
+    fn test_update_check_from_config_and_env() {
+        assert!(Config::default().update_check());
+
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join(".repodoctor.yml"), "updates:\n  check: false\n").unwrap();
+        assert!(!Config::load(tmp.path()).update_check());
+
+        let mut config = Config::default();
+        config.apply_env_overrides(|key| match key {
+            "REPODOCTOR_UPDATE_CHECK" => Some("False".to_string()),
+            _ => None,
+        });
+        assert!(!config.update_check());
+    }
+
+    #[test]
+    fn test_user_config_path() {
+        let path = user_config_path(|key| match key {
+            "XDG_CONFIG_HOME" => Some(OsString::new()),
+            "HOME" => Some(OsString::from("/home/dev")),
+            _ => None,
+        });
+        assert_eq!(
+            path,
+            Some(PathBuf::from("/home/dev/.config/repodoctor/config.yml"))
+        );
+        assert_eq!(user_config_path(|_| None), None);
+
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("config.yml");
+        fs::write(&path, "updates:\n  check: false\n").unwrap();
+        assert!(!Config::load_file(Some(&path)).update_check());
+    }
+
+    #[test]
+    fn test_env_overrides_ignore_empty_values() {
+        let mut config = Config {
+            severity_threshold: Some("medium".to_string()),
+            ignore: None,
+            ..Config::default()
+        };
+        config.apply_env_overrides(|key| match key {
+            "REPODOCTOR_SEVERITY_THRESHOLD" => Some("  ".to_string()),
+            _ => None,
+        });
+        assert_eq!(config.min_severity(), Severity::Medium);
+        assert!(config.ignore.is_none());
+    }
+
+    #[test]
+    fn test_env_extends_applies_preset() {
+        let mut config = Config::default();
+        config.apply_env_overrides(|key| match key {
+            "REPODOCTOR_EXTENDS" => Some("relaxed".to_string()),
+            "REPODOCTOR_IGNORE_PATHS" => Some("vendor/,dist/".to_string()),
+            _ => None,
+        });
+        config.apply_preset();
+        assert_eq!(config.min_severity(), Severity::Medium);
+        assert!(config.is_path_ignored("dist/app.js"));
+        // Env-provided ignore list takes precedence over the preset's list
+        assert!(!config.is_rule_ignored("DOC-003"));
+    }
+
     #[test]
     fn test_preset_user_override() {
         let tmp = TempDir::new().unwrap();
@@ -327,4 +779,34 @@ mod tests {
         // User override takes precedence over preset
         assert_eq!(config.min_severity(), Severity::High);
     }
+
+    #[test]
+    fn test_ignore_rule_with_expiry() {
+        let yaml = "ignore:\n  rules:\n    - DOC-003\n    - rule: SEC-001\n      expires: 2025-12-31\n      reason: Rotating keys\n";
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let deadline = date::parse_date("2025-12-31").unwrap();
+
+        assert!(config.is_rule_ignored_on("DOC-003", deadline + 1000));
+        assert!(config.is_rule_ignored_on("SEC-001", deadline));
+        assert!(!config.is_rule_ignored_on("SEC-001", deadline + 1));
+    }
+
+    #[test]
+    fn test_expiring_suppressions_window() {
+        let yaml = "ignore:\n  rules:\n    - rule: SEC-001\n      expires: 2025-12-31\n    - rule: SEC-002\n      expires: not-a-date\n    - DOC-003\n";
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        let deadline = date::parse_date("2025-12-31").unwrap();
+
+        let soon = config.expiring_suppressions(deadline - 5);
+        assert_eq!(soon.len(), 2);
+        assert_eq!(soon[0].rule, "SEC-001");
+        assert_eq!(soon[0].days_left, 5);
+        // Invalid dates count as expired
+        assert!(soon[1].days_left < 0);
+        assert!(!config.is_rule_ignored_on("SEC-002", deadline - 5));
+
+        let later = config.expiring_suppressions(deadline - EXPIRY_WARNING_DAYS - 1);
+        assert_eq!(later.len(), 1);
+        assert_eq!(later[0].rule, "SEC-002");
+    }
 }
@@ -0,0 +1,439 @@
+//! Single-pass content scanning.
+//!
+//! Analyzers that inspect file contents register [`ContentMatcher`]s instead
+//! of walking the tree themselves. The [`ContentEngine`] walks the project
+//! once, reads each file at most once, and dispatches its lines to every
+//! matcher interested in that file.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::analyzers::traits::Issue;
+use crate::utils::fs::{read_text, walk, TextFile};
+
+/// Files larger than this are not read by content checks.
+pub const MAX_FILE_BYTES: u64 = 2 * 1024 * 1024;
+
+/// Lines longer than this, typically minified bundles, are not handed to matchers.
+pub const MAX_LINE_BYTES: usize = 4096;
+
+/// A file handed to matchers by the engine.
+pub struct ContentFile<'a> {
+    /// Absolute path
+    pub path: &'a Path,
+    /// Project-relative path with `/` separators
+    pub relative: &'a str,
+    pub content: &'a str,
+}
+
+/// A line-oriented content check registered by an analyzer.
+pub trait ContentMatcher: Send {
+    /// Directory names this matcher never looks inside.
+    fn skips_dir(&self, _name: &str) -> bool {
+        false
+    }
+
+    /// Whether the matcher wants to read `relative` (project-relative, `/`-separated).
+    fn wants(&self, relative: &str) -> bool;
+
+    /// Called with the whole file before its lines. Return `false` to skip the lines.
+    fn begin_file(&mut self, _file: &ContentFile, _issues: &mut Vec<Issue>) -> bool {
+        true
+    }
+
+    /// Called for each line (1-based). Return `false` to stop receiving lines of this file.
+    fn check_line(
+        &mut self,
+        file: &ContentFile,
+        line_num: usize,
+        line: &str,
+        issues: &mut Vec<Issue>,
+    ) -> bool;
+
+    /// Whether the matcher has seen enough for this scan.
+    fn is_done(&self) -> bool {
+        false
+    }
+}
+
+/// A file content checks could not fully read, reported as a scan diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkippedFile {
+    /// Project-relative path with `/` separators
+    pub file: String,
+    pub reason: String,
+}
+
+/// What a [`ContentEngine`] run produced.
+#[derive(Debug, Default)]
+pub struct ContentScan {
+    pub issues: Vec<Issue>,
+    pub skipped: Vec<SkippedFile>,
+}
+
+#[derive(Default)]
+pub struct ContentEngine {
+    matchers: Vec<Box<dyn ContentMatcher>>,
+}
+
+impl ContentEngine {
+    pub fn register(&mut self, matchers: Vec<Box<dyn ContentMatcher>>) {
+        self.matchers.extend(matchers);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.matchers.is_empty()
+    }
+
+    /// Walk `root` once and run every registered matcher.
+    pub fn run(mut self, root: &Path) -> ContentScan {
+        let mut issues = Vec::new();
+        let mut skipped = Vec::new();
+        if self.matchers.is_empty() {
+            return ContentScan { issues, skipped };
+        }
+
+        let mut walker = walk(root).into_iter();
+        while let Some(entry) = walker.next() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => {
+                    if let (Some(path), Some(_)) = (err.path(), err.loop_ancestor()) {
+                        skipped.push(SkippedFile {
+                            file: relative_path(root, path),
+                            reason: "symlink loop".to_string(),
+                        });
+                    }
+                    continue;
+                }
+            };
+            if entry.depth() == 0 {
+                continue;
+            }
+
+            if entry.file_type().is_dir() {
+                let name = entry.file_name().to_string_lossy();
+                if self
+                    .matchers
+                    .iter()
+                    .all(|m| m.is_done() || m.skips_dir(&name))
+                {
+                    walker.skip_current_dir();
+                }
+                continue;
+            }
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let relative = relative_path(root, entry.path());
+            let interested: Vec<usize> = (0..self.matchers.len())
+                .filter(|&i| {
+                    let matcher = &self.matchers[i];
+                    !matcher.is_done()
+                        && !in_skipped_dir(matcher.as_ref(), &relative)
+                        && matcher.wants(&relative)
+                })
+                .collect();
+            if interested.is_empty() {
+                continue;
+            }
+
+            let content = match read_text(entry.path(), MAX_FILE_BYTES) {
+                Ok(TextFile::Text(content)) => content,
+                Ok(TextFile::TooLarge(size)) => {
+                    skipped.push(SkippedFile {
+                        file: relative,
+                        reason: format!(
+                            "larger than {} MiB ({:.1} MiB)",
+                            MAX_FILE_BYTES / (1024 * 1024),
+                            size as f64 / (1024.0 * 1024.0)
+                        ),
+                    });
+                    continue;
+                }
+                Ok(TextFile::Binary) => continue,
+                Err(err) => {
+                    skipped.push(SkippedFile {
+                        file: relative,
+                        reason: format!("unreadable: {}", err),
+                    });
+                    continue;
+                }
+            };
+            let file = ContentFile {
+                path: entry.path(),
+                relative: &relative,
+                content: &content,
+            };
+
+            let mut active: Vec<usize> = interested
+                .into_iter()
+                .filter(|&i| self.matchers[i].begin_file(&file, &mut issues))
+                .collect();
+            let mut long_lines = 0;
+            for (index, line) in content.lines().enumerate() {
+                if active.is_empty() {
+                    break;
+                }
+                if line.len() > MAX_LINE_BYTES {
+                    long_lines += 1;
+                    continue;
+                }
+                active.retain(|&i| {
+                    self.matchers[i].check_line(&file, index + 1, line, &mut issues)
+                });
+            }
+            if long_lines > 0 {
+                skipped.push(SkippedFile {
+                    file: relative,
+                    reason: format!(
+                        "{} line(s) longer than {} bytes not checked (minified?)",
+                        long_lines, MAX_LINE_BYTES
+                    ),
+                });
+            }
+
+            if self.matchers.iter().all(|m| m.is_done()) {
+                break;
+            }
+        }
+
+        ContentScan { issues, skipped }
+    }
+}
+
+/// Reports the first matching line of every wanted file, the shape of most
+/// "find X in source files" checks.
+pub struct FirstMatchPerFile {
+    pub skips_dir: fn(&str) -> bool,
+    pub wants: fn(&str) -> bool,
+    /// Byte offset of the match within the line, if any
+    pub matches: fn(&str) -> Option<usize>,
+    pub issue: fn(&ContentFile, usize) -> Issue,
+}
+
+impl ContentMatcher for FirstMatchPerFile {
+    fn skips_dir(&self, name: &str) -> bool {
+        (self.skips_dir)(name)
+    }
+
+    fn wants(&self, relative: &str) -> bool {
+        (self.wants)(relative)
+    }
+
+    fn check_line(
+        &mut self,
+        file: &ContentFile,
+        line_num: usize,
+        line: &str,
+        issues: &mut Vec<Issue>,
+    ) -> bool {
+        if let Some(start) = (self.matches)(line) {
+            issues.push((self.issue)(file, line_num).with_snippet(line, start));
+            return false; // One issue per file
+        }
+        true
+    }
+}
+
+fn in_skipped_dir(matcher: &dyn ContentMatcher, relative: &str) -> bool {
+    relative
+        .rsplit_once('/')
+        .is_some_and(|(dir, _)| dir.split('/').any(|name| matcher.skips_dir(name)))
+}
+
+/// Project-relative path using `/` separators so globs behave the same on every OS.
+pub fn relative_path(base: &Path, file: &Path) -> String {
+    file.strip_prefix(base)
+        .unwrap_or(file)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Run one analyzer together with its content matchers, as the scanner would.
+#[cfg(test)]
+pub async fn analyze_with_content(
+    analyzer: &dyn crate::analyzers::traits::Analyzer,
+    project: &crate::core::project::Project,
+) -> anyhow::Result<Vec<Issue>> {
+    let mut issues = analyzer.analyze(project).await?;
+    let mut engine = ContentEngine::default();
+    engine.register(analyzer.content_matchers(project));
+    issues.extend(engine.run(&project.path).issues);
+    Ok(issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::{AnalyzerCategory, Severity};
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    /// Reports every line containing `needle` in `.txt` files and counts reads.
+    struct Needle {
+        needle: &'static str,
+        files_seen: Arc<AtomicUsize>,
+        limit: Option<usize>,
+        found: usize,
+    }
+
+    impl Needle {
+        fn new(needle: &'static str, files_seen: Arc<AtomicUsize>) -> Self {
+            Self {
+                needle,
+                files_seen,
+                limit: None,
+                found: 0,
+            }
+        }
+    }
+
+    impl ContentMatcher for Needle {
+        fn skips_dir(&self, name: &str) -> bool {
+            name == "skipped"
+        }
+
+        fn wants(&self, relative: &str) -> bool {
+            relative.ends_with(".txt")
+        }
+
+        fn begin_file(&mut self, _file: &ContentFile, _issues: &mut Vec<Issue>) -> bool {
+            self.files_seen.fetch_add(1, Ordering::SeqCst);
+            true
+        }
+
+        fn check_line(
+            &mut self,
+            file: &ContentFile,
+            line_num: usize,
+            line: &str,
+            issues: &mut Vec<Issue>,
+        ) -> bool {
+            if line.contains(self.needle) {
+                self.found += 1;
+                issues.push(Issue {
+                    id: self.needle.to_string(),
+                    analyzer: "test".to_string(),
+                    category: AnalyzerCategory::Security,
+                    severity: Severity::Low,
+                    title: "Found".to_string(),
+                    description: "Found".to_string(),
+                    file: Some(PathBuf::from(file.relative)),
+                    line: Some(line_num),
+                    column: None,
+                    snippet: None,
+                    fingerprint: None,
+                    estimated_effort: None,
+                    impact: None,
+                    suggestion: None,
+                    auto_fixable: false,
+                    references: vec![],
+                });
+            }
+            !self.is_done()
+        }
+
+        fn is_done(&self) -> bool {
+            self.limit.is_some_and(|limit| self.found >= limit)
+        }
+    }
+
+    fn make_tree() -> TempDir {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("a/skipped")).unwrap();
+        fs::write(tmp.path().join("a/one.txt"), "foo\nbar\nfoo bar\n").unwrap();
+        fs::write(tmp.path().join("a/skipped/two.txt"), "foo\n").unwrap();
+        fs::write(tmp.path().join("three.md"), "foo\n").unwrap();
+        tmp
+    }
+
+    #[test]
+    fn test_dispatches_lines_to_all_matchers() {
+        let tmp = make_tree();
+        let seen = Arc::new(AtomicUsize::new(0));
+        let mut engine = ContentEngine::default();
+        engine.register(vec![
+            Box::new(Needle::new("foo", seen.clone())),
+            Box::new(Needle::new("bar", seen.clone())),
+        ]);
+
+        let issues = engine.run(tmp.path()).issues;
+        let lines = |id: &str| {
+            issues
+                .iter()
+                .filter(|i| i.id == id)
+                .map(|i| i.line.unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(lines("foo"), vec![1, 3]);
+        assert_eq!(lines("bar"), vec![2, 3]);
+        assert!(issues.iter().all(|i| i.file == Some(PathBuf::from("a/one.txt"))));
+        // Skipped directories and unwanted files are never handed to matchers
+        assert_eq!(seen.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_done_matchers_stop_receiving_files() {
+        let tmp = make_tree();
+        fs::write(tmp.path().join("a/four.txt"), "foo\n").unwrap();
+        let seen = Arc::new(AtomicUsize::new(0));
+        let mut matcher = Needle::new("foo", seen.clone());
+        matcher.limit = Some(1);
+        let mut engine = ContentEngine::default();
+        engine.register(vec![Box::new(matcher)]);
+
+        let issues = engine.run(tmp.path()).issues;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(seen.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_empty_engine() {
+        let tmp = make_tree();
+        let engine = ContentEngine::default();
+        assert!(engine.is_empty());
+        assert!(engine.run(tmp.path()).issues.is_empty());
+    }
+
+    #[test]
+    fn test_tolerates_encodings_and_records_skipped_files() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("latin1.txt"), b"caf\xe9\nfoo\n").unwrap();
+        fs::write(tmp.path().join("bom.txt"), b"\xef\xbb\xbffoo\n").unwrap();
+        let minified = format!("{}foo\nfoo\n", "x".repeat(MAX_LINE_BYTES));
+        fs::write(tmp.path().join("bundle.min.txt"), minified).unwrap();
+        let huge = std::fs::File::create(tmp.path().join("huge.txt")).unwrap();
+        huge.set_len(MAX_FILE_BYTES + 1).unwrap();
+
+        let seen = Arc::new(AtomicUsize::new(0));
+        let mut engine = ContentEngine::default();
+        engine.register(vec![Box::new(Needle::new("foo", seen))]);
+        let scan = engine.run(tmp.path());
+
+        let mut found: Vec<_> = scan
+            .issues
+            .iter()
+            .map(|i| (i.file.clone().unwrap(), i.line.unwrap()))
+            .collect();
+        found.sort();
+        assert_eq!(
+            found,
+            vec![
+                (PathBuf::from("bom.txt"), 1),
+                (PathBuf::from("bundle.min.txt"), 2),
+                (PathBuf::from("latin1.txt"), 2),
+            ]
+        );
+
+        let mut skipped: Vec<_> = scan.skipped.iter().map(|s| s.file.as_str()).collect();
+        skipped.sort();
+        assert_eq!(skipped, vec!["bundle.min.txt", "huge.txt"]);
+    }
+}
@@ -0,0 +1,577 @@
+//! Direct-dependency graph of a project and its workspace packages.
+//!
+//! Each package found in a manifest (Cargo.toml, package.json, composer.json,
+//! pubspec.yaml, pyproject.toml) becomes a node, with an edge to every
+//! dependency it declares directly. Cargo and npm workspaces contribute one
+//! node per member, so coupling between the packages of a monorepo shows up
+//! as edges between workspace nodes.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::utils::fs::read_to_string;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DepKind {
+    Normal,
+    /// Tests, tooling and other development-only dependencies
+    Dev,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    pub name: String,
+    pub kind: DepKind,
+}
+
+/// A package declared by a manifest in the project.
+#[derive(Debug, Clone)]
+pub struct GraphPackage {
+    pub name: String,
+    /// Directory relative to the project root (`""` for the root)
+    pub path: String,
+    pub dependencies: Vec<Dependency>,
+}
+
+#[derive(Debug, Default)]
+pub struct DependencyGraph {
+    pub packages: Vec<GraphPackage>,
+}
+
+type ManifestParser = fn(&str) -> Option<(String, Vec<Dependency>)>;
+type MembersParser = fn(&str) -> Vec<String>;
+
+/// Manifests, their parsers, and the workspace member globs they declare.
+const MANIFESTS: &[(&str, ManifestParser, MembersParser)] = &[
+    ("Cargo.toml", parse_cargo, cargo_members),
+    ("package.json", parse_package_json, npm_members),
+    ("composer.json", parse_composer, no_members),
+    ("pubspec.yaml", parse_pubspec, no_members),
+    ("pyproject.toml", parse_pyproject, no_members),
+];
+
+impl DependencyGraph {
+    /// Read the manifests at `root` and in its workspace members.
+    pub fn build(root: &Path) -> Self {
+        let mut packages = Vec::new();
+        let mut seen = BTreeSet::new();
+        for (file, parse, members) in MANIFESTS {
+            let Ok(content) = read_to_string(root.join(file)) else {
+                continue;
+            };
+            let mut dirs = vec![root.to_path_buf()];
+            dirs.extend(expand_members(root, file, &members(&content)));
+            for dir in dirs {
+                if !seen.insert((dir.clone(), *file)) {
+                    continue;
+                }
+                let Some((name, dependencies)) = read_to_string(dir.join(file))
+                    .ok()
+                    .and_then(|content| parse(&content))
+                else {
+                    continue;
+                };
+                let path = dir.strip_prefix(root).unwrap_or(&dir);
+                packages.push(GraphPackage {
+                    name,
+                    path: path.to_string_lossy().replace('\\', "/"),
+                    dependencies: dedup(dependencies),
+                });
+            }
+        }
+        Self { packages }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+
+    /// Edges to render: (from, to, kind), optionally limited to edges
+    /// between workspace packages.
+    fn edges(&self, workspace_only: bool) -> Vec<(&str, &str, DepKind)> {
+        let internal: BTreeSet<&str> = self.packages.iter().map(|p| p.name.as_str()).collect();
+        self.packages
+            .iter()
+            .flat_map(|package| {
+                package
+                    .dependencies
+                    .iter()
+                    .map(move |dep| (package.name.as_str(), dep.name.as_str(), dep.kind))
+            })
+            .filter(|(_, to, _)| !workspace_only || internal.contains(to))
+            .collect()
+    }
+
+    /// Node names in render order: workspace packages first, then external
+    /// dependencies alphabetically.
+    fn nodes(&self, workspace_only: bool) -> (Vec<&str>, Vec<&str>) {
+        let internal: Vec<&str> = self.packages.iter().map(|p| p.name.as_str()).collect();
+        let external: BTreeSet<&str> = self
+            .edges(workspace_only)
+            .into_iter()
+            .map(|(_, to, _)| to)
+            .filter(|to| !internal.contains(to))
+            .collect();
+        (internal, external.into_iter().collect())
+    }
+
+    /// Graphviz DOT source.
+    pub fn to_dot(&self, workspace_only: bool) -> String {
+        let quote = |name: &str| format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""));
+        let (_, external) = self.nodes(workspace_only);
+
+        let mut out = String::from("digraph dependencies {\n");
+        out.push_str("  rankdir=LR;\n");
+        out.push_str("  node [shape=box, style=\"rounded,filled\", fillcolor=\"#e3f2fd\", color=\"#1976d2\"];\n");
+        for package in &self.packages {
+            match package.path.as_str() {
+                "" => out.push_str(&format!("  {};\n", quote(&package.name))),
+                path => out.push_str(&format!(
+                    "  {} [tooltip={}];\n",
+                    quote(&package.name),
+                    quote(path)
+                )),
+            }
+        }
+        for name in &external {
+            out.push_str(&format!(
+                "  {} [shape=ellipse, style=solid, color=\"#9e9e9e\"];\n",
+                quote(name)
+            ));
+        }
+        for (from, to, kind) in self.edges(workspace_only) {
+            let style = match kind {
+                DepKind::Normal => "",
+                DepKind::Dev => " [style=dashed]",
+            };
+            out.push_str(&format!("  {} -> {}{};\n", quote(from), quote(to), style));
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Mermaid flowchart source.
+    pub fn to_mermaid(&self, workspace_only: bool) -> String {
+        let (internal, external) = self.nodes(workspace_only);
+        // Mermaid ids must be plain identifiers, so number the nodes
+        let ids: BTreeMap<&str, String> = internal
+            .iter()
+            .chain(&external)
+            .enumerate()
+            .map(|(i, name)| (*name, format!("n{}", i)))
+            .collect();
+        let label = |name: &str| name.replace('"', "#quot;");
+
+        let mut out = String::from("graph LR\n");
+        for name in &internal {
+            out.push_str(&format!("  {}[\"{}\"]\n", ids[name], label(name)));
+        }
+        for name in &external {
+            out.push_str(&format!("  {}([\"{}\"])\n", ids[name], label(name)));
+        }
+        for (from, to, kind) in self.edges(workspace_only) {
+            let arrow = match kind {
+                DepKind::Normal => "-->",
+                DepKind::Dev => "-.->",
+            };
+            out.push_str(&format!("  {} {} {}\n", ids[from], arrow, ids[to]));
+        }
+        if !internal.is_empty() {
+            out.push_str("  classDef workspace fill:#e3f2fd,stroke:#1976d2\n");
+            let workspace: Vec<&str> = internal.iter().map(|name| ids[name].as_str()).collect();
+            out.push_str(&format!("  class {} workspace\n", workspace.join(",")));
+        }
+        out
+    }
+}
+
+/// One entry per dependency; a dependency declared for both normal and
+/// development use is a normal dependency.
+fn dedup(mut dependencies: Vec<Dependency>) -> Vec<Dependency> {
+    dependencies.sort_by(|a, b| (&a.name, a.kind).cmp(&(&b.name, b.kind)));
+    dependencies.dedup_by(|a, b| a.name == b.name);
+    dependencies
+}
+
+/// Directories matched by workspace member globs that contain `manifest`.
+fn expand_members(root: &Path, manifest: &str, patterns: &[String]) -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    for pattern in patterns {
+        let full = root.join(pattern.trim_end_matches('/'));
+        let Ok(paths) = glob::glob(&full.to_string_lossy()) else {
+            continue;
+        };
+        dirs.extend(
+            paths
+                .filter_map(|p| p.ok())
+                .filter(|dir| dir.join(manifest).is_file()),
+        );
+    }
+    dirs.sort();
+    dirs
+}
+
+fn no_members(_: &str) -> Vec<String> {
+    Vec::new()
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches('"').trim_matches('\'')
+}
+
+/// Quoted strings of a TOML array that may span several lines, starting at
+/// the `key = [` line.
+fn toml_array(content: &str, section: &str, key: &str) -> Vec<String> {
+    let mut current = "";
+    let mut collecting = false;
+    let mut items = Vec::new();
+    for line in content.lines().map(str::trim) {
+        if !collecting && line.starts_with('[') {
+            current = line;
+            continue;
+        }
+        let rest = if collecting {
+            line
+        } else if current == section {
+            match line.split_once('=') {
+                Some((k, v)) if k.trim() == key && v.trim().starts_with('[') => {
+                    collecting = true;
+                    v.trim().trim_start_matches('[')
+                }
+                _ => continue,
+            }
+        } else {
+            continue;
+        };
+        let (values, done) = match rest.split_once(']') {
+            Some((values, _)) => (values, true),
+            None => (rest, false),
+        };
+        items.extend(
+            values
+                .split(',')
+                .map(|v| unquote(v.split('#').next().unwrap_or_default()))
+                .filter(|v| !v.is_empty())
+                .map(str::to_string),
+        );
+        if done {
+            break;
+        }
+    }
+    items
+}
+
+fn cargo_members(content: &str) -> Vec<String> {
+    toml_array(content, "[workspace]", "members")
+}
+
+fn parse_cargo(content: &str) -> Option<(String, Vec<Dependency>)> {
+    let mut name = None;
+    let mut section: Option<DepKind> = None;
+    let mut in_package = false;
+    let mut dependencies = Vec::new();
+
+    let dep_kind = |table: &str| {
+        // `[dependencies]`, `[target.'cfg(unix)'.dev-dependencies]`, ...
+        let last = table.rsplit('.').next().unwrap_or(table);
+        match last {
+            "dependencies" | "build-dependencies" => Some(DepKind::Normal),
+            "dev-dependencies" => Some(DepKind::Dev),
+            _ => None,
+        }
+    };
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            let table = line.split('#').next().unwrap_or(line).trim();
+            let table = table.trim_start_matches('[').trim_end_matches(']').trim();
+            in_package = table == "package";
+            section = dep_kind(table);
+            // `[dependencies.serde]` declares one dependency as a table
+            if section.is_none() {
+                if let Some((parent, dep)) = table.rsplit_once('.') {
+                    if let Some(kind) = dep_kind(parent) {
+                        dependencies.push(Dependency {
+                            name: unquote(dep).to_string(),
+                            kind,
+                        });
+                    }
+                }
+            }
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        if in_package && key.trim() == "name" {
+            name = Some(unquote(value).to_string());
+        } else if let Some(kind) = section {
+            // `serde = "1"`, `serde.workspace = true`
+            let dep = unquote(key.split('.').next().unwrap_or(key));
+            if !dep.is_empty() && !dep.starts_with('#') {
+                dependencies.push(Dependency {
+                    name: dep.to_string(),
+                    kind,
+                });
+            }
+        }
+    }
+    // A virtual workspace manifest has no package of its own
+    Some((name?, dependencies))
+}
+
+fn npm_members(content: &str) -> Vec<String> {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+    let workspaces = json.get("workspaces");
+    workspaces
+        .and_then(|w| w.as_array())
+        .or_else(|| workspaces?.get("packages")?.as_array())
+        .map(|patterns| {
+            patterns
+                .iter()
+                .filter_map(|p| p.as_str())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Dependency names under each JSON object key.
+fn json_dependencies(json: &serde_json::Value, keys: &[(&str, DepKind)]) -> Vec<Dependency> {
+    keys.iter()
+        .filter_map(|(key, kind)| Some((json.get(*key)?.as_object()?, *kind)))
+        .flat_map(|(deps, kind)| {
+            deps.keys().map(move |name| Dependency {
+                name: name.clone(),
+                kind,
+            })
+        })
+        .collect()
+}
+
+fn parse_package_json(content: &str) -> Option<(String, Vec<Dependency>)> {
+    let json: serde_json::Value = serde_json::from_str(content).ok()?;
+    let name = json.get("name")?.as_str()?.to_string();
+    let dependencies = json_dependencies(
+        &json,
+        &[
+            ("dependencies", DepKind::Normal),
+            ("peerDependencies", DepKind::Normal),
+            ("optionalDependencies", DepKind::Normal),
+            ("devDependencies", DepKind::Dev),
+        ],
+    );
+    Some((name, dependencies))
+}
+
+fn parse_composer(content: &str) -> Option<(String, Vec<Dependency>)> {
+    let json: serde_json::Value = serde_json::from_str(content).ok()?;
+    let name = json.get("name")?.as_str()?.to_string();
+    let dependencies = json_dependencies(
+        &json,
+        &[("require", DepKind::Normal), ("require-dev", DepKind::Dev)],
+    )
+    .into_iter()
+    // Platform requirements are not packages
+    .filter(|dep| dep.name.contains('/'))
+    .collect();
+    Some((name, dependencies))
+}
+
+fn parse_pubspec(content: &str) -> Option<(String, Vec<Dependency>)> {
+    let yaml: serde_yaml::Value = serde_yaml::from_str(content).ok()?;
+    let name = yaml.get("name")?.as_str()?.to_string();
+    let mut dependencies = Vec::new();
+    for (key, kind) in [
+        ("dependencies", DepKind::Normal),
+        ("dev_dependencies", DepKind::Dev),
+    ] {
+        let Some(deps) = yaml.get(key).and_then(|d| d.as_mapping()) else {
+            continue;
+        };
+        dependencies.extend(
+            deps.iter()
+                // `flutter: { sdk: flutter }` ships with the toolchain
+                .filter(|(_, spec)| spec.get("sdk").is_none())
+                .filter_map(|(name, _)| name.as_str())
+                .map(|name| Dependency {
+                    name: name.to_string(),
+                    kind,
+                }),
+        );
+    }
+    Some((name, dependencies))
+}
+
+fn parse_pyproject(content: &str) -> Option<(String, Vec<Dependency>)> {
+    let mut name = None;
+    let mut table = "";
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            table = line;
+        } else if let Some((key, value)) = line.split_once('=') {
+            if key.trim() == "name" && matches!(table, "[project]" | "[tool.poetry]") {
+                name.get_or_insert_with(|| unquote(value).to_string());
+            }
+        }
+    }
+
+    // PEP 508 requirements: the name is everything before the version specifier
+    let requirement = |spec: &str| {
+        spec.split(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+            .next()
+            .filter(|n| !n.is_empty())
+            .map(str::to_string)
+    };
+    let dependencies = toml_array(content, "[project]", "dependencies")
+        .iter()
+        .filter_map(|spec| requirement(spec))
+        .map(|name| Dependency {
+            name,
+            kind: DepKind::Normal,
+        })
+        .collect();
+    Some((name?, dependencies))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn names(deps: &[Dependency]) -> Vec<&str> {
+        deps.iter().map(|d| d.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_parse_cargo_dependency_forms() {
+        let (name, deps) = parse_cargo(
+            r#"[package]
+name = "api"
+version = "0.1.0"
+
+[dependencies]
+serde = { version = "1", features = ["derive"] }
+core.workspace = true
+# tokio = "1"
+
+[dependencies.reqwest]
+version = "0.12"
+
+[target.'cfg(unix)'.dependencies]
+nix = "0.29"
+
+[dev-dependencies]
+insta = "1"
+serde = "1"
+"#,
+        )
+        .unwrap();
+        assert_eq!(name, "api");
+        let deps = dedup(deps);
+        assert_eq!(names(&deps), ["core", "insta", "nix", "reqwest", "serde"]);
+        assert_eq!(deps[1].kind, DepKind::Dev);
+        // Also a normal dependency
+        assert_eq!(deps[4].kind, DepKind::Normal);
+
+        assert!(parse_cargo("[workspace]\nmembers = [\"a\"]\n").is_none());
+    }
+
+    #[test]
+    fn test_cargo_workspace_graph() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\n  \"crates/*\", # all crates\n]\n",
+        )
+        .unwrap();
+        for (name, deps) in [
+            ("api", "core = { path = \"../core\" }\naxum = \"0.7\"\n"),
+            ("core", ""),
+        ] {
+            let dir = tmp.path().join("crates").join(name);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\n\n[dependencies]\n{}", name, deps),
+            )
+            .unwrap();
+        }
+
+        let graph = DependencyGraph::build(tmp.path());
+        assert_eq!(graph.packages.len(), 2);
+        assert_eq!(graph.packages[0].path, "crates/api");
+
+        let dot = graph.to_dot(false);
+        assert!(dot.contains("\"api\" [tooltip=\"crates/api\"];"));
+        assert!(dot.contains("\"api\" -> \"core\";"));
+        assert!(dot.contains("\"axum\" [shape=ellipse"));
+
+        let internal = graph.to_dot(true);
+        assert!(internal.contains("\"api\" -> \"core\";"));
+        assert!(!internal.contains("axum"));
+    }
+
+    #[test]
+    fn test_npm_workspaces_and_mermaid() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("package.json"),
+            r#"{"name": "monorepo", "private": true, "workspaces": {"packages": ["packages/*"]},
+                "devDependencies": {"turbo": "^2.0.0"}}"#,
+        )
+        .unwrap();
+        let web = tmp.path().join("packages/web");
+        fs::create_dir_all(&web).unwrap();
+        fs::write(
+            web.join("package.json"),
+            r#"{"name": "@acme/web", "dependencies": {"@acme/ui": "*", "react": "^18"}}"#,
+        )
+        .unwrap();
+        let ui = tmp.path().join("packages/ui");
+        fs::create_dir_all(&ui).unwrap();
+        fs::write(ui.join("package.json"), r#"{"name": "@acme/ui"}"#).unwrap();
+
+        let graph = DependencyGraph::build(tmp.path());
+        assert_eq!(graph.packages.len(), 3);
+
+        let mermaid = graph.to_mermaid(false);
+        assert!(mermaid.starts_with("graph LR\n"));
+        assert!(mermaid.contains("[\"@acme/web\"]"));
+        assert!(mermaid.contains("([\"react\"])"));
+        assert!(mermaid.contains(" -.-> "));
+        assert!(mermaid.contains("class n0,n1,n2 workspace"));
+    }
+
+    #[test]
+    fn test_other_manifests() {
+        let (_, deps) = parse_composer(
+            r#"{"name": "acme/shop", "require": {"php": ">=8.2", "ext-json": "*", "symfony/console": "^7"},
+                "require-dev": {"phpunit/phpunit": "^10"}}"#,
+        )
+        .unwrap();
+        assert_eq!(names(&deps), ["symfony/console", "phpunit/phpunit"]);
+
+        let (_, deps) = parse_pubspec(
+            "name: app\ndependencies:\n  flutter:\n    sdk: flutter\n  http: ^1.2.0\ndev_dependencies:\n  mocktail: ^1.0.0\n",
+        )
+        .unwrap();
+        assert_eq!(names(&deps), ["http", "mocktail"]);
+
+        let (name, deps) = parse_pyproject(
+            "[project]\nname = \"svc\"\ndependencies = [\n  \"requests>=2.31\",\n  \"pydantic[email]~=2.0\",\n]\n",
+        )
+        .unwrap();
+        assert_eq!(name, "svc");
+        assert_eq!(names(&deps), ["requests", "pydantic"]);
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let tmp = TempDir::new().unwrap();
+        assert!(DependencyGraph::build(tmp.path()).is_empty());
+    }
+}
@@ -0,0 +1,440 @@
+//! Resolved dependencies read from lock files.
+//!
+//! Lock files pin the exact version of every direct and transitive
+//! dependency, which makes them the source of truth for what a build ships.
+//! Supported: Cargo.lock, package-lock.json, composer.lock, pubspec.lock and
+//! poetry.lock at the project root.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::path::Path;
+
+use crate::utils::fs::read_to_string;
+
+/// Package ecosystem, named after its package URL type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Ecosystem {
+    Cargo,
+    Npm,
+    Composer,
+    Pub,
+    Pypi,
+}
+
+impl Ecosystem {
+    /// Package URL (purl) type.
+    pub fn purl_type(self) -> &'static str {
+        match self {
+            Ecosystem::Cargo => "cargo",
+            Ecosystem::Npm => "npm",
+            Ecosystem::Composer => "composer",
+            Ecosystem::Pub => "pub",
+            Ecosystem::Pypi => "pypi",
+        }
+    }
+}
+
+/// Digest of a package archive as recorded in the lock file (hex encoded).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Checksum {
+    /// `SHA-1` or `SHA-256`
+    pub algorithm: &'static str,
+    pub value: String,
+}
+
+/// One resolved package.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LockedPackage {
+    pub ecosystem: Ecosystem,
+    pub name: String,
+    pub version: String,
+    /// Lock file the package was read from
+    pub lockfile: &'static str,
+    /// Only needed for development (tests, tooling)
+    pub dev: bool,
+    pub checksum: Option<Checksum>,
+}
+
+impl LockedPackage {
+    /// Package URL, e.g. `pkg:npm/%40types/node@20.1.0`.
+    pub fn purl(&self) -> String {
+        let name = match self.ecosystem {
+            Ecosystem::Npm => self.name.replacen('@', "%40", 1),
+            // PyPI names are case-insensitive and treat `_` like `-`
+            Ecosystem::Pypi => self.name.to_lowercase().replace('_', "-"),
+            _ => self.name.clone(),
+        };
+        format!(
+            "pkg:{}/{}@{}",
+            self.ecosystem.purl_type(),
+            name,
+            self.version.replace('+', "%2B")
+        )
+    }
+}
+
+type Parser = fn(&str) -> Result<Vec<LockedPackage>>;
+
+/// Lock files and their parsers, in report order.
+const LOCKFILES: &[(&str, Parser)] = &[
+    ("Cargo.lock", parse_cargo_lock),
+    ("package-lock.json", parse_package_lock),
+    ("composer.lock", parse_composer_lock),
+    ("pubspec.lock", parse_pubspec_lock),
+    ("poetry.lock", parse_poetry_lock),
+];
+
+/// Names of the supported lock files present in `path`.
+pub fn present(path: &Path) -> Vec<&'static str> {
+    LOCKFILES
+        .iter()
+        .map(|(name, _)| *name)
+        .filter(|name| path.join(name).is_file())
+        .collect()
+}
+
+/// Every package pinned by the lock files in `path`, sorted and deduplicated.
+pub fn read(path: &Path) -> Result<Vec<LockedPackage>> {
+    let mut packages = Vec::new();
+    for (name, parse) in LOCKFILES {
+        let file = path.join(name);
+        if !file.is_file() {
+            continue;
+        }
+        let content =
+            read_to_string(&file).with_context(|| format!("Failed to read {}", file.display()))?;
+        packages.extend(parse(&content).with_context(|| format!("Failed to parse {}", name))?);
+    }
+    packages.sort_by(|a, b| {
+        (a.ecosystem, &a.name, &a.version).cmp(&(b.ecosystem, &b.name, &b.version))
+    });
+    packages.dedup_by(|a, b| {
+        // A package needed at runtime anywhere is not a dev dependency
+        let same = a.ecosystem == b.ecosystem && a.name == b.name && a.version == b.version;
+        if same {
+            b.dev &= a.dev;
+        }
+        same
+    });
+    Ok(packages)
+}
+
+/// Top-level `key = "value"` pairs of every `[[package]]` table in a TOML
+/// lock file. Keys of nested tables such as `[package.dependencies]` are
+/// ignored.
+fn toml_packages(content: &str) -> Vec<Vec<(&str, &str)>> {
+    let mut packages = Vec::new();
+    let mut current: Option<Vec<(&str, &str)>> = None;
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            packages.extend(current.take());
+            if line == "[[package]]" {
+                current = Some(Vec::new());
+            }
+            continue;
+        }
+        if let (Some(fields), Some((key, value))) = (current.as_mut(), line.split_once('=')) {
+            let value = value.trim();
+            if let Some(value) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                fields.push((key.trim(), value));
+            }
+        }
+    }
+    packages.extend(current);
+    packages
+}
+
+fn field<'a>(fields: &[(&str, &'a str)], key: &str) -> Option<&'a str> {
+    fields.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+}
+
+fn parse_cargo_lock(content: &str) -> Result<Vec<LockedPackage>> {
+    Ok(toml_packages(content)
+        .iter()
+        // Workspace members and path dependencies have no source; they are
+        // the project itself rather than something it depends on
+        .filter(|fields| field(fields, "source").is_some())
+        .filter_map(|fields| {
+            Some(LockedPackage {
+                ecosystem: Ecosystem::Cargo,
+                name: field(fields, "name")?.to_string(),
+                version: field(fields, "version")?.to_string(),
+                lockfile: "Cargo.lock",
+                dev: false,
+                checksum: field(fields, "checksum").map(|value| Checksum {
+                    algorithm: "SHA-256",
+                    value: value.to_string(),
+                }),
+            })
+        })
+        .collect())
+}
+
+fn parse_poetry_lock(content: &str) -> Result<Vec<LockedPackage>> {
+    Ok(toml_packages(content)
+        .iter()
+        .filter_map(|fields| {
+            Some(LockedPackage {
+                ecosystem: Ecosystem::Pypi,
+                name: field(fields, "name")?.to_string(),
+                version: field(fields, "version")?.to_string(),
+                lockfile: "poetry.lock",
+                // Only written by Poetry before 1.5
+                dev: field(fields, "category") == Some("dev"),
+                checksum: None,
+            })
+        })
+        .collect())
+}
+
+fn parse_package_lock(content: &str) -> Result<Vec<LockedPackage>> {
+    let json: serde_json::Value = serde_json::from_str(content)?;
+    let package = |name: &str, entry: &serde_json::Value| {
+        Some(LockedPackage {
+            ecosystem: Ecosystem::Npm,
+            name: name.to_string(),
+            version: entry.get("version")?.as_str()?.to_string(),
+            lockfile: "package-lock.json",
+            dev: entry.get("dev").and_then(|d| d.as_bool()).unwrap_or(false),
+            checksum: None,
+        })
+    };
+
+    // Lockfile v2 and v3: a flat map keyed by install path
+    if let Some(packages) = json.get("packages").and_then(|p| p.as_object()) {
+        return Ok(packages
+            .iter()
+            .filter(|(_, entry)| entry.get("link").and_then(|l| l.as_bool()) != Some(true))
+            .filter_map(|(path, entry)| {
+                // The root project is keyed "", workspaces by their folder
+                let (_, installed) = path.rsplit_once("node_modules/")?;
+                let name = entry
+                    .get("name")
+                    .and_then(|n| n.as_str())
+                    .unwrap_or(installed);
+                package(name, entry)
+            })
+            .collect());
+    }
+
+    // Lockfile v1: nested `dependencies`
+    fn walk(
+        deps: &serde_json::Map<String, serde_json::Value>,
+        package: &dyn Fn(&str, &serde_json::Value) -> Option<LockedPackage>,
+        out: &mut Vec<LockedPackage>,
+    ) {
+        for (name, entry) in deps {
+            out.extend(package(name, entry));
+            if let Some(nested) = entry.get("dependencies").and_then(|d| d.as_object()) {
+                walk(nested, package, out);
+            }
+        }
+    }
+    let mut packages = Vec::new();
+    if let Some(deps) = json.get("dependencies").and_then(|d| d.as_object()) {
+        walk(deps, &package, &mut packages);
+    }
+    Ok(packages)
+}
+
+fn parse_composer_lock(content: &str) -> Result<Vec<LockedPackage>> {
+    let json: serde_json::Value = serde_json::from_str(content)?;
+    let mut packages = Vec::new();
+    for (key, dev) in [("packages", false), ("packages-dev", true)] {
+        let Some(entries) = json.get(key).and_then(|p| p.as_array()) else {
+            continue;
+        };
+        packages.extend(entries.iter().filter_map(|entry| {
+            let shasum = entry
+                .pointer("/dist/shasum")
+                .and_then(|s| s.as_str())
+                .filter(|s| !s.is_empty());
+            Some(LockedPackage {
+                ecosystem: Ecosystem::Composer,
+                name: entry.get("name")?.as_str()?.to_string(),
+                version: entry.get("version")?.as_str()?.to_string(),
+                lockfile: "composer.lock",
+                dev,
+                checksum: shasum.map(|value| Checksum {
+                    algorithm: "SHA-1",
+                    value: value.to_string(),
+                }),
+            })
+        }));
+    }
+    Ok(packages)
+}
+
+fn parse_pubspec_lock(content: &str) -> Result<Vec<LockedPackage>> {
+    let yaml: serde_yaml::Value = serde_yaml::from_str(content)?;
+    let Some(entries) = yaml.get("packages").and_then(|p| p.as_mapping()) else {
+        return Ok(Vec::new());
+    };
+    Ok(entries
+        .iter()
+        // SDK packages (flutter, flutter_test) ship with the toolchain
+        .filter(|(_, entry)| entry.get("source").and_then(|s| s.as_str()) != Some("sdk"))
+        .filter_map(|(name, entry)| {
+            let sha256 = entry
+                .get("description")
+                .and_then(|d| d.get("sha256"))
+                .and_then(|s| s.as_str());
+            Some(LockedPackage {
+                ecosystem: Ecosystem::Pub,
+                name: name.as_str()?.to_string(),
+                version: entry.get("version")?.as_str()?.to_string(),
+                lockfile: "pubspec.lock",
+                dev: entry.get("dependency").and_then(|d| d.as_str()) == Some("direct dev"),
+                checksum: sha256.map(|value| Checksum {
+                    algorithm: "SHA-256",
+                    value: value.to_string(),
+                }),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_cargo_lock_skips_workspace_members() {
+        let packages = parse_cargo_lock(
+            r#"version = 3
+
+[[package]]
+name = "anyhow"
+version = "1.0.86"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "b3d1d046238990b9cf5bcde22a3fb3584ee5cf65fb2765f454ed428c7a0063da"
+
+[[package]]
+name = "myapp"
+version = "0.1.0"
+dependencies = [
+ "anyhow",
+]
+"#,
+        )
+        .unwrap();
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].name, "anyhow");
+        assert_eq!(packages[0].purl(), "pkg:cargo/anyhow@1.0.86");
+        assert_eq!(packages[0].checksum.as_ref().unwrap().algorithm, "SHA-256");
+    }
+
+    #[test]
+    fn test_poetry_lock_ignores_nested_tables() {
+        let packages = parse_poetry_lock(
+            r#"[[package]]
+name = "Flask_Cors"
+version = "4.0.0"
+category = "main"
+
+[package.dependencies]
+name = "not-a-package"
+
+[[package]]
+name = "pytest"
+version = "8.0.0"
+category = "dev"
+
+[metadata]
+content-hash = "abc"
+"#,
+        )
+        .unwrap();
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].purl(), "pkg:pypi/flask-cors@4.0.0");
+        assert!(!packages[0].dev);
+        assert!(packages[1].dev);
+    }
+
+    #[test]
+    fn test_package_lock_v3_and_v1() {
+        let v3 = parse_package_lock(
+            r#"{"lockfileVersion": 3, "packages": {
+                "": {"name": "web", "version": "1.0.0"},
+                "node_modules/@types/node": {"version": "20.1.0", "dev": true},
+                "node_modules/a/node_modules/b": {"version": "2.0.0"},
+                "node_modules/local": {"resolved": "packages/local", "link": true}
+            }}"#,
+        )
+        .unwrap();
+        let purls: Vec<_> = v3.iter().map(|p| p.purl()).collect();
+        assert_eq!(purls, ["pkg:npm/%40types/node@20.1.0", "pkg:npm/b@2.0.0"]);
+        assert!(v3[0].dev);
+
+        let v1 = parse_package_lock(
+            r#"{"lockfileVersion": 1, "dependencies": {
+                "a": {"version": "1.0.0", "dependencies": {"b": {"version": "2.0.0"}}}
+            }}"#,
+        )
+        .unwrap();
+        assert_eq!(v1.len(), 2);
+    }
+
+    #[test]
+    fn test_composer_and_pubspec() {
+        let composer = parse_composer_lock(
+            r#"{"packages": [{"name": "symfony/console", "version": "v7.0.1",
+                "dist": {"shasum": ""}}],
+               "packages-dev": [{"name": "phpunit/phpunit", "version": "10.5.0",
+                "dist": {"shasum": "da39a3ee5e6b4b0d3255bfef95601890afd80709"}}]}"#,
+        )
+        .unwrap();
+        assert_eq!(composer[0].purl(), "pkg:composer/symfony/console@v7.0.1");
+        assert!(composer[0].checksum.is_none());
+        assert!(composer[1].dev);
+        assert_eq!(composer[1].checksum.as_ref().unwrap().algorithm, "SHA-1");
+
+        let pubspec = parse_pubspec_lock(
+            r#"packages:
+  flutter:
+    dependency: "direct main"
+    description: flutter
+    source: sdk
+    version: "0.0.0"
+  http:
+    dependency: "direct main"
+    description:
+      name: http
+      sha256: "5895291c13fa8a3bd82e76d5627f69e0d85ca6a30dcac95c4ea19a5d555879c2"
+      url: "https://pub.dev"
+    source: hosted
+    version: "1.2.0"
+"#,
+        )
+        .unwrap();
+        assert_eq!(pubspec.len(), 1);
+        assert_eq!(pubspec[0].purl(), "pkg:pub/http@1.2.0");
+        assert!(pubspec[0].checksum.is_some());
+    }
+
+    #[test]
+    fn test_read_merges_and_reports_parse_errors() {
+        let tmp = TempDir::new().unwrap();
+        assert!(read(tmp.path()).unwrap().is_empty());
+
+        fs::write(
+            tmp.path().join("package-lock.json"),
+            r#"{"packages": {
+                "node_modules/zod": {"version": "3.0.0"},
+                "node_modules/a/node_modules/zod": {"version": "3.0.0", "dev": true}
+            }}"#,
+        )
+        .unwrap();
+        let packages = read(tmp.path()).unwrap();
+        assert_eq!(packages.len(), 1);
+        assert!(!packages[0].dev);
+        assert_eq!(present(tmp.path()), ["package-lock.json"]);
+
+        fs::write(tmp.path().join("composer.lock"), "{ not json").unwrap();
+        let err = read(tmp.path()).unwrap_err();
+        assert!(format!("{:#}", err).contains("Failed to parse composer.lock"));
+    }
+}
@@ -0,0 +1,409 @@
+//! Repository size and composition.
+//!
+//! Counts the project's files and non-blank lines by file type and by
+//! top-level directory, skipping hidden entries and dependency/build
+//! directories. For git checkouts, one snapshot per commit is kept in
+//! `.git/repodoctor-history.json` so a scan can report growth since the
+//! previous commit that was scanned.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::core::content::{relative_path, MAX_FILE_BYTES};
+use crate::utils::date;
+use crate::utils::fs::{is_ignored_name, read_text, walk, TextFile};
+use crate::utils::git;
+
+const HISTORY_FILE: &str = "repodoctor-history.json";
+
+/// Snapshots kept per project in the history file, oldest dropped first.
+const HISTORY_LIMIT: usize = 100;
+
+/// Directories listed in [`RepoMetrics::largest_dirs`].
+const LARGEST_DIRS: usize = 5;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RepoMetrics {
+    pub files: usize,
+    /// Non-blank lines of text files
+    pub lines: usize,
+    pub bytes: u64,
+    /// Most files first
+    pub file_types: Vec<FileTypeMetrics>,
+    /// Top-level directories with the most lines
+    pub largest_dirs: Vec<DirMetrics>,
+    /// Change since the last scan of an earlier commit
+    pub growth: Option<Growth>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileTypeMetrics {
+    /// Lowercase extension, or the file name for files without one (`Dockerfile`)
+    pub kind: String,
+    pub files: usize,
+    pub lines: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DirMetrics {
+    pub path: String,
+    pub files: usize,
+    pub lines: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Growth {
+    /// Date of the earlier scan (`YYYY-MM-DD`)
+    pub since: String,
+    pub commit: String,
+    pub files: i64,
+    pub lines: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Snapshot {
+    /// Project directory relative to the repository root, empty for the root,
+    /// so subprojects of a monorepo keep separate histories
+    #[serde(default)]
+    path: String,
+    commit: String,
+    date: String,
+    files: usize,
+    lines: usize,
+}
+
+impl RepoMetrics {
+    /// Walk `root` and count its files and lines.
+    pub fn collect(root: &Path) -> Self {
+        let mut metrics = Self::default();
+        let mut types: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+        let mut dirs: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+
+        for entry in walk(root)
+            .into_iter()
+            .filter_entry(|e| e.depth() == 0 || !is_ignored_name(&e.file_name().to_string_lossy()))
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+        {
+            let lines = match read_text(entry.path(), MAX_FILE_BYTES) {
+                Ok(TextFile::Text(content)) => {
+                    content.lines().filter(|l| !l.trim().is_empty()).count()
+                }
+                _ => 0,
+            };
+            metrics.files += 1;
+            metrics.lines += lines;
+            metrics.bytes += entry.metadata().map(|m| m.len()).unwrap_or(0);
+
+            let name = entry.file_name().to_string_lossy();
+            let kind = match name.rsplit_once('.') {
+                Some((stem, ext)) if !stem.is_empty() => ext.to_lowercase(),
+                _ => name.to_string(),
+            };
+            let counts = types.entry(kind).or_default();
+            counts.0 += 1;
+            counts.1 += lines;
+
+            let relative = relative_path(root, entry.path());
+            if let Some((top, _)) = relative.split_once('/') {
+                let counts = dirs.entry(top.to_string()).or_default();
+                counts.0 += 1;
+                counts.1 += lines;
+            }
+        }
+
+        metrics.file_types = types
+            .into_iter()
+            .map(|(kind, (files, lines))| FileTypeMetrics { kind, files, lines })
+            .collect();
+        metrics
+            .file_types
+            .sort_by(|a, b| b.files.cmp(&a.files).then_with(|| b.lines.cmp(&a.lines)));
+
+        metrics.largest_dirs = dirs
+            .into_iter()
+            .map(|(path, (files, lines))| DirMetrics { path, files, lines })
+            .collect();
+        metrics
+            .largest_dirs
+            .sort_by(|a, b| b.lines.cmp(&a.lines).then_with(|| b.files.cmp(&a.files)));
+        metrics.largest_dirs.truncate(LARGEST_DIRS);
+        metrics
+    }
+
+    /// Compare with the latest snapshot of another commit in the history of
+    /// the repository containing `project`, then record this scan as the
+    /// snapshot of the current commit. Snapshots are kept per project
+    /// directory. Does nothing outside git checkouts.
+    pub fn track_history(&mut self, project: &Path) {
+        let (Some(commit), Some(git_dir), Some(path)) = (
+            git::head_commit(project),
+            git::git_dir(project),
+            git::path_in_repo(project),
+        ) else {
+            return;
+        };
+        let file = git_dir.join(HISTORY_FILE);
+        let mut history: Vec<Snapshot> = std::fs::read_to_string(&file)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
+        self.growth = history
+            .iter()
+            .rev()
+            .find(|snapshot| snapshot.path == path && snapshot.commit != commit)
+            .map(|previous| Growth {
+                since: previous.date.clone(),
+                commit: previous.commit.clone(),
+                files: self.files as i64 - previous.files as i64,
+                lines: self.lines as i64 - previous.lines as i64,
+            });
+
+        history.retain(|snapshot| !(snapshot.path == path && snapshot.commit == commit));
+        let kept = history.iter().filter(|snapshot| snapshot.path == path).count();
+        let mut excess = (kept + 1).saturating_sub(HISTORY_LIMIT);
+        history.retain(|snapshot| {
+            let drop = excess > 0 && snapshot.path == path;
+            excess -= usize::from(drop);
+            !drop
+        });
+        history.push(Snapshot {
+            path,
+            commit,
+            date: date::format_date(date::today()),
+            files: self.files,
+            lines: self.lines,
+        });
+        // A failed write only costs the next scan its growth figures
+        if let Ok(content) = serde_json::to_string(&history) {
+            let _ = std::fs::write(&file, content);
+        }
+    }
+
+    /// `312 files, 48,210 lines`, followed by the growth when known.
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "{} files, {} lines",
+            format_count(self.files),
+            format_count(self.lines)
+        );
+        if let Some(growth) = &self.growth {
+            summary.push_str(&format!(
+                " ({} files, {} lines since {})",
+                format_delta(growth.files),
+                format_delta(growth.lines),
+                growth.since
+            ));
+        }
+        summary
+    }
+
+    /// The `count` most common file types, e.g. `rs 120, md 30`.
+    pub fn top_types(&self, count: usize) -> String {
+        self.file_types
+            .iter()
+            .take(count)
+            .map(|t| format!("{} {}", t.kind, t.files))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// `12345` as `12,345`.
+pub fn format_count(value: usize) -> String {
+    let digits = value.to_string();
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// A signed change, e.g. `+1,204` or `-3`.
+pub fn format_delta(value: i64) -> String {
+    let sign = if value < 0 { "-" } else { "+" };
+    format!("{}{}", sign, format_count(value.unsigned_abs() as usize))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::process::Command;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) -> bool {
+        Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn test_collect_counts_types_and_dirs() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("src/bin")).unwrap();
+        fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        fs::create_dir_all(tmp.path().join("node_modules/x")).unwrap();
+        fs::write(
+            tmp.path().join("src/main.rs"),
+            "fn main() {\n\n    run();\n}\n",
+        )
+        .unwrap();
+        fs::write(tmp.path().join("src/bin/tool.rs"), "fn main() {}\n").unwrap();
+        fs::write(tmp.path().join("docs/guide.md"), "# Guide\n").unwrap();
+        fs::write(tmp.path().join("Dockerfile"), "FROM scratch\n").unwrap();
+        fs::write(tmp.path().join("logo.png"), [0u8, 1, 2, 3]).unwrap();
+        fs::write(tmp.path().join(".env"), "SECRET=1\n").unwrap();
+        fs::write(tmp.path().join("node_modules/x/index.js"), "x\n").unwrap();
+
+        let metrics = RepoMetrics::collect(tmp.path());
+        assert_eq!(metrics.files, 5);
+        assert_eq!(metrics.lines, 6);
+        assert_eq!(metrics.file_types[0].kind, "rs");
+        assert_eq!(metrics.file_types[0].files, 2);
+        assert_eq!(metrics.file_types[0].lines, 4);
+        assert!(metrics.file_types.iter().any(|t| t.kind == "Dockerfile"));
+        assert!(metrics
+            .file_types
+            .iter()
+            .any(|t| t.kind == "png" && t.lines == 0));
+
+        let dirs: Vec<_> = metrics
+            .largest_dirs
+            .iter()
+            .map(|d| d.path.as_str())
+            .collect();
+        assert_eq!(dirs, ["src", "docs"]);
+        assert!(metrics.growth.is_none());
+    }
+
+    #[test]
+    fn test_growth_since_previous_commit() {
+        let tmp = TempDir::new().unwrap();
+        if !git(tmp.path(), &["init", "-q"]) {
+            return; // git not available
+        }
+        let commit = |message: &str| {
+            git(tmp.path(), &["add", "-A"]);
+            git(
+                tmp.path(),
+                &[
+                    "-c",
+                    "user.name=t",
+                    "-c",
+                    "user.email=t@t",
+                    "commit",
+                    "-qm",
+                    message,
+                ],
+            );
+        };
+        fs::write(tmp.path().join("a.txt"), "one\n").unwrap();
+        commit("first");
+
+        let mut first = RepoMetrics::collect(tmp.path());
+        first.track_history(tmp.path());
+        assert!(first.growth.is_none());
+
+        fs::write(tmp.path().join("b.txt"), "two\nthree\n").unwrap();
+        commit("second");
+        let mut second = RepoMetrics::collect(tmp.path());
+        second.track_history(tmp.path());
+        // Rescanning the same commit still compares with the earlier one
+        second.track_history(tmp.path());
+        let growth = second.growth.unwrap();
+        assert_eq!((growth.files, growth.lines), (1, 2));
+        assert_eq!(growth.since, date::format_date(date::today()));
+    }
+
+    #[test]
+    fn test_growth_is_tracked_per_subproject() {
+        let tmp = TempDir::new().unwrap();
+        if !git(tmp.path(), &["init", "-q"]) {
+            return; // git not available
+        }
+        let commit = |message: &str| {
+            git(tmp.path(), &["add", "-A"]);
+            git(
+                tmp.path(),
+                &[
+                    "-c",
+                    "user.name=t",
+                    "-c",
+                    "user.email=t@t",
+                    "commit",
+                    "-qm",
+                    message,
+                ],
+            );
+        };
+        let (api, web) = (tmp.path().join("api"), tmp.path().join("web"));
+        fs::create_dir_all(&api).unwrap();
+        fs::create_dir_all(&web).unwrap();
+        fs::write(api.join("a.txt"), "one\n").unwrap();
+        fs::write(web.join("a.txt"), "one\ntwo\nthree\n").unwrap();
+        fs::write(web.join("b.txt"), "four\n").unwrap();
+        commit("first");
+        RepoMetrics::collect(&api).track_history(&api);
+        RepoMetrics::collect(&web).track_history(&web);
+
+        fs::write(api.join("b.txt"), "two\n").unwrap();
+        commit("second");
+        let mut metrics = RepoMetrics::collect(&api);
+        metrics.track_history(&api);
+        let growth = metrics.growth.unwrap();
+        assert_eq!((growth.files, growth.lines), (1, 1));
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut metrics = RepoMetrics {
+            files: 1234,
+            lines: 56789,
+            file_types: vec![
+                FileTypeMetrics {
+                    kind: "rs".to_string(),
+                    files: 900,
+                    lines: 50000,
+                },
+                FileTypeMetrics {
+                    kind: "md".to_string(),
+                    files: 300,
+                    lines: 6000,
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(metrics.summary(), "1,234 files, 56,789 lines");
+        assert_eq!(metrics.top_types(2), "rs 900, md 300");
+
+        metrics.growth = Some(Growth {
+            since: "2026-10-01".to_string(),
+            commit: "abc".to_string(),
+            files: 12,
+            lines: -40,
+        });
+        assert_eq!(
+            metrics.summary(),
+            "1,234 files, 56,789 lines (+12 files, -40 lines since 2026-10-01)"
+        );
+    }
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(7), "7");
+        assert_eq!(format_count(1234), "1,234");
+        assert_eq!(format_count(1234567), "1,234,567");
+        assert_eq!(format_delta(1204), "+1,204");
+        assert_eq!(format_delta(-3), "-3");
+        assert_eq!(format_delta(0), "+0");
+    }
+}
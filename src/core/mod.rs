@@ -1,4 +1,23 @@
+pub mod allowlist;
+pub mod baseline;
+pub mod batch;
+pub mod catalog;
+pub mod cache;
 pub mod config;
+pub mod content;
+pub mod depgraph;
+pub mod lockfiles;
+pub mod metrics;
+pub mod plan;
+pub mod policy;
+pub mod profile;
 pub mod project;
+pub mod project_type;
+pub mod rules;
+pub mod sbom;
 pub mod scanner;
+pub mod selfcheck;
 pub mod score;
+pub mod telemetry;
+pub mod themes;
+pub mod update;
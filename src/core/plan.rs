@@ -0,0 +1,133 @@
+use serde::Serialize;
+
+use crate::analyzers::traits::{Effort, Issue};
+use crate::core::score::HealthScore;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanStep {
+    pub step: usize,
+    pub issue: Issue,
+    pub effort: Effort,
+    pub priority: f64,
+    /// Health score points gained by resolving this step after all previous ones.
+    pub score_gain: u8,
+    /// Health score once this step and all previous ones are resolved.
+    pub score_after: u8,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RemediationPlan {
+    pub current_score: u8,
+    pub projected_score: u8,
+    pub steps: Vec<PlanStep>,
+}
+
+/// Priority of an issue: severity weighted by fixability and divided by effort.
+pub fn priority(issue: &Issue) -> f64 {
+    let fixability = if issue.auto_fixable { 2.0 } else { 1.0 };
+    issue.severity.penalty() as f64 * fixability / issue.resolved_effort().cost()
+}
+
+impl RemediationPlan {
+    /// Order issues by priority and compute the cumulative score gain of each step.
+    pub fn build(issues: &[Issue]) -> Self {
+        let mut ordered: Vec<&Issue> = issues.iter().collect();
+        ordered.sort_by(|a, b| {
+            priority(b)
+                .total_cmp(&priority(a))
+                .then_with(|| b.severity.cmp(&a.severity))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        let current_score = HealthScore::calculate(issues).total;
+        let mut remaining: Vec<Issue> = ordered.iter().map(|i| (*i).clone()).collect();
+        let mut score = current_score;
+        let mut steps = Vec::new();
+
+        for (index, issue) in ordered.into_iter().enumerate() {
+            remaining.remove(0);
+            let score_after = HealthScore::calculate(&remaining).total;
+            steps.push(PlanStep {
+                step: index + 1,
+                issue: issue.clone(),
+                effort: issue.resolved_effort(),
+                priority: priority(issue),
+                score_gain: score_after.saturating_sub(score),
+                score_after,
+            });
+            score = score_after;
+        }
+
+        RemediationPlan {
+            current_score,
+            projected_score: score,
+            steps,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::{AnalyzerCategory, Severity};
+
+    fn make_issue(
+        id: &str,
+        category: AnalyzerCategory,
+        severity: Severity,
+        auto_fixable: bool,
+    ) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category,
+            severity,
+            title: "Test".to_string(),
+            description: "Test".to_string(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable,
+            references: vec![],
+        }
+    }
+
+    #[test]
+    fn test_auto_fixable_ranks_above_manual_work() {
+        let issues = vec![
+            make_issue("SEC-001", AnalyzerCategory::Security, Severity::High, false),
+            make_issue("STR-003", AnalyzerCategory::Structure, Severity::Medium, true),
+        ];
+        let plan = RemediationPlan::build(&issues);
+        assert_eq!(plan.steps[0].issue.id, "STR-003");
+        assert_eq!(plan.steps[0].effort, Effort::Trivial);
+        assert_eq!(plan.steps[1].effort, Effort::Large);
+    }
+
+    #[test]
+    fn test_score_gains_add_up() {
+        let issues = vec![
+            make_issue("SEC-001", AnalyzerCategory::Security, Severity::Critical, false),
+            make_issue("TST-001", AnalyzerCategory::Testing, Severity::High, false),
+            make_issue("DOC-001", AnalyzerCategory::Documentation, Severity::Low, true),
+        ];
+        let plan = RemediationPlan::build(&issues);
+        let total_gain: u32 = plan.steps.iter().map(|s| s.score_gain as u32).sum();
+        assert_eq!(plan.projected_score, 100);
+        assert_eq!(plan.current_score as u32 + total_gain, 100);
+        assert_eq!(plan.steps.last().unwrap().score_after, 100);
+    }
+
+    #[test]
+    fn test_empty_plan() {
+        let plan = RemediationPlan::build(&[]);
+        assert!(plan.steps.is_empty());
+        assert_eq!(plan.current_score, 100);
+        assert_eq!(plan.projected_score, 100);
+    }
+}
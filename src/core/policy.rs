@@ -0,0 +1,220 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::analyzers::traits::{Issue, Severity};
+
+/// Organization-wide policy loaded from `--policy <PATH|URL>`.
+///
+/// ```yaml
+/// name: ACME Engineering
+/// mandatory_rules:
+///   - SEC-001
+///   - id: STR-004
+///     min_severity: high
+/// ```
+///
+/// Mandatory rules are enforced regardless of `.repodoctor.yml` and the
+/// command line: project ignores, severity thresholds, profiles, baselines,
+/// the secret allowlist, `--only`, and `--severity` cannot hide them or lower
+/// their severity below `min_severity`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrgPolicy {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub mandatory_rules: Vec<MandatoryRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MandatoryRule {
+    Id(String),
+    Detailed {
+        id: String,
+        min_severity: Option<String>,
+    },
+}
+
+impl MandatoryRule {
+    pub fn id(&self) -> &str {
+        match self {
+            MandatoryRule::Id(id) => id,
+            MandatoryRule::Detailed { id, .. } => id,
+        }
+    }
+
+    fn min_severity_name(&self) -> Option<&str> {
+        match self {
+            MandatoryRule::Id(_) => None,
+            MandatoryRule::Detailed { min_severity, .. } => min_severity.as_deref(),
+        }
+    }
+
+    fn min_severity(&self) -> Option<Severity> {
+        self.min_severity_name().and_then(Severity::from_name)
+    }
+}
+
+/// Mandatory-rule violations found by a scan, reported separately from other issues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyReport {
+    pub name: String,
+    pub violations: Vec<Issue>,
+}
+
+impl OrgPolicy {
+    /// Load a policy from a local path or an `https://` URL.
+    pub fn load(source: &str) -> Result<Self> {
+        if source.starts_with("http://") {
+            bail!(
+                "Refusing to fetch policy over plain HTTP: {} (use https://)",
+                source
+            );
+        }
+        let content = if source.starts_with("https://") {
+            ureq::get(source)
+                .timeout(std::time::Duration::from_secs(15))
+                .call()
+                .with_context(|| format!("Failed to fetch policy from {}", source))?
+                .into_string()?
+        } else {
+            std::fs::read_to_string(Path::new(source))
+                .with_context(|| format!("Failed to read policy file {}", source))?
+        };
+        let policy: Self = serde_yaml::from_str(&content)
+            .with_context(|| format!("Invalid policy file {}", source))?;
+        policy
+            .validate()
+            .with_context(|| format!("Invalid policy file {}", source))?;
+        Ok(policy)
+    }
+
+    fn validate(&self) -> Result<()> {
+        for rule in &self.mandatory_rules {
+            if let Some(name) = rule.min_severity_name() {
+                if Severity::from_name(name).is_none() {
+                    bail!(
+                        "Unknown min_severity '{}' for {} (expected critical, high, medium, low, or info)",
+                        name,
+                        rule.id()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or("organization policy")
+    }
+
+    fn rule_for(&self, id: &str) -> Option<&MandatoryRule> {
+        self.mandatory_rules.iter().find(|r| r.id() == id)
+    }
+
+    /// Whether issues with this rule ID are mandatory.
+    pub fn is_mandatory(&self, id: &str) -> bool {
+        self.rule_for(id).is_some()
+    }
+
+    /// Split off issues covered by mandatory rules, raising their severity to
+    /// the configured floor. Returns `(violations, others)`.
+    pub fn partition(&self, issues: Vec<Issue>) -> (Vec<Issue>, Vec<Issue>) {
+        let mut violations = Vec::new();
+        let mut others = Vec::new();
+        for mut issue in issues {
+            match self.rule_for(&issue.id) {
+                Some(rule) => {
+                    if let Some(floor) = rule.min_severity() {
+                        issue.severity = issue.severity.max(floor);
+                    }
+                    violations.push(issue);
+                }
+                None => others.push(issue),
+            }
+        }
+        (violations, others)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::AnalyzerCategory;
+    use tempfile::TempDir;
+
+    fn make_issue(id: &str, severity: Severity) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Security,
+            severity,
+            title: "Test".to_string(),
+            description: "Test".to_string(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        }
+    }
+
+    const POLICY: &str = "name: ACME\nmandatory_rules:\n  - SEC-001\n  - id: STR-004\n    min_severity: high\n";
+
+    #[test]
+    fn test_load_from_file() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("policy.yml");
+        std::fs::write(&path, POLICY).unwrap();
+
+        let policy = OrgPolicy::load(&path.to_string_lossy()).unwrap();
+        assert_eq!(policy.display_name(), "ACME");
+        assert!(policy.rule_for("SEC-001").is_some());
+        assert!(policy.rule_for("STR-004").is_some());
+        assert!(policy.rule_for("DOC-003").is_none());
+    }
+
+    #[test]
+    fn test_load_missing_file_fails() {
+        assert!(OrgPolicy::load("/nonexistent/policy.yml").is_err());
+    }
+
+    #[test]
+    fn test_load_rejects_plain_http() {
+        let err = OrgPolicy::load("http://example.com/policy.yml").unwrap_err();
+        assert!(err.to_string().contains("https://"));
+    }
+
+    #[test]
+    fn test_load_rejects_unknown_min_severity() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("policy.yml");
+        std::fs::write(
+            &path,
+            "mandatory_rules:\n  - id: SEC-001\n    min_severity: severe\n",
+        )
+        .unwrap();
+
+        let err = OrgPolicy::load(&path.to_string_lossy()).unwrap_err();
+        assert!(format!("{:#}", err).contains("Unknown min_severity 'severe' for SEC-001"));
+    }
+
+    #[test]
+    fn test_partition_applies_severity_floor() {
+        let policy: OrgPolicy = serde_yaml::from_str(POLICY).unwrap();
+        let (violations, others) = policy.partition(vec![
+            make_issue("SEC-001", Severity::Critical),
+            make_issue("STR-004", Severity::Low),
+            make_issue("DOC-003", Severity::Info),
+        ]);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[1].severity, Severity::High);
+        assert_eq!(others.len(), 1);
+        assert_eq!(others[0].id, "DOC-003");
+    }
+}
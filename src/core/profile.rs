@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+
+use crate::analyzers::traits::{AnalyzerCategory, Issue, Severity};
+
+/// Built-in strictness profile, selected with `--profile` or `profile:` in config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Profile {
+    Strict,
+    Recommended,
+    Relaxed,
+}
+
+impl std::fmt::Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Profile::Strict => write!(f, "strict"),
+            Profile::Recommended => write!(f, "recommended"),
+            Profile::Relaxed => write!(f, "relaxed"),
+        }
+    }
+}
+
+/// Severity adjustments applied by the strict profile.
+const STRICT_UPGRADES: &[(&str, Severity)] = &[
+    ("RST-030", Severity::Critical), // unsafe code blocks
+    ("FLT-041", Severity::Critical), // insecure http:// URLs
+    ("NJS-042", Severity::Critical), // dangerouslySetInnerHTML
+    ("LAR-041", Severity::Critical), // raw SQL queries
+    ("DEP-004", Severity::High),     // unpinned dependency versions
+    ("CFG-004", Severity::High),     // missing linter configuration
+    ("DOC-003", Severity::Low),      // missing CONTRIBUTING.md
+    ("DOC-005", Severity::Low),      // missing CODE_OF_CONDUCT.md
+];
+
+/// Severity adjustments applied by the relaxed profile.
+const RELAXED_DOWNGRADES: &[(&str, Severity)] = &[
+    ("CFG-004", Severity::Low),    // missing linter configuration
+    ("TST-004", Severity::Low),    // low test-to-source ratio
+    ("RST-030", Severity::Medium), // unsafe code blocks
+];
+
+/// Low-severity rules the relaxed profile keeps even though their category is
+/// otherwise dropped: licensing decides whether the code may be used at all.
+const RELAXED_KEEPS: &[&str] = &["STR-004"];
+
+impl Profile {
+    pub fn from_name(name: &str) -> Option<Profile> {
+        match name.trim().to_lowercase().as_str() {
+            "strict" => Some(Profile::Strict),
+            "recommended" | "default" => Some(Profile::Recommended),
+            "relaxed" => Some(Profile::Relaxed),
+            _ => None,
+        }
+    }
+
+    /// Adjust severities and drop rules according to the profile.
+    pub fn apply(&self, issues: Vec<Issue>) -> Vec<Issue> {
+        match self {
+            Profile::Recommended => issues,
+            Profile::Strict => issues
+                .into_iter()
+                .map(|mut issue| {
+                    if let Some((_, severity)) =
+                        STRICT_UPGRADES.iter().find(|(id, _)| *id == issue.id)
+                    {
+                        issue.severity = issue.severity.max(*severity);
+                    }
+                    issue
+                })
+                .collect(),
+            Profile::Relaxed => issues
+                .into_iter()
+                .filter(|issue| !is_nag(issue))
+                .map(|mut issue| {
+                    if let Some((_, severity)) =
+                        RELAXED_DOWNGRADES.iter().find(|(id, _)| *id == issue.id)
+                    {
+                        issue.severity = issue.severity.min(*severity);
+                    }
+                    issue
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Low-value structure and documentation findings dropped by the relaxed profile.
+fn is_nag(issue: &Issue) -> bool {
+    matches!(
+        issue.category,
+        AnalyzerCategory::Structure | AnalyzerCategory::Documentation
+    ) && issue.severity <= Severity::Low
+        && !RELAXED_KEEPS.contains(&issue.id.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_issue(id: &str, category: AnalyzerCategory, severity: Severity) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category,
+            severity,
+            title: "Test".to_string(),
+            description: "Test".to_string(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        }
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Profile::from_name("strict"), Some(Profile::Strict));
+        assert_eq!(Profile::from_name("Recommended"), Some(Profile::Recommended));
+        assert_eq!(Profile::from_name("relaxed"), Some(Profile::Relaxed));
+        assert_eq!(Profile::from_name("balanced"), None);
+    }
+
+    #[test]
+    fn test_recommended_is_identity() {
+        let issues = vec![make_issue("STR-004", AnalyzerCategory::Structure, Severity::Low)];
+        let result = Profile::Recommended.apply(issues);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].severity, Severity::Low);
+    }
+
+    #[test]
+    fn test_strict_upgrades_severity() {
+        let issues = vec![
+            make_issue("RST-030", AnalyzerCategory::Security, Severity::High),
+            make_issue("FLT-041", AnalyzerCategory::Security, Severity::High),
+            make_issue("STR-004", AnalyzerCategory::Structure, Severity::Low),
+        ];
+        let result = Profile::Strict.apply(issues);
+        assert_eq!(result[0].severity, Severity::Critical);
+        assert_eq!(result[1].severity, Severity::Critical);
+        assert_eq!(result[2].severity, Severity::Low);
+    }
+
+    #[test]
+    fn test_relaxed_drops_structure_nags() {
+        let issues = vec![
+            make_issue("STR-004", AnalyzerCategory::Documentation, Severity::Low),
+            make_issue("STR-007", AnalyzerCategory::Structure, Severity::Low),
+            make_issue("DOC-003", AnalyzerCategory::Documentation, Severity::Info),
+            make_issue("STR-001", AnalyzerCategory::Structure, Severity::High),
+            make_issue("DEP-002", AnalyzerCategory::Dependencies, Severity::Info),
+        ];
+        let result = Profile::Relaxed.apply(issues);
+        let ids: Vec<&str> = result.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["STR-004", "STR-001", "DEP-002"]);
+    }
+
+    /// Pins the built-in rules the relaxed profile drops at their default
+    /// severity, so moving a rule between categories cannot silently change it.
+    #[test]
+    fn test_relaxed_drops_exactly_these_rules() {
+        let issues: Vec<Issue> = crate::core::catalog::RULES
+            .iter()
+            .map(|rule| make_issue(rule.id, rule.category.clone(), rule.severity))
+            .collect();
+        let kept: Vec<String> = Profile::Relaxed
+            .apply(issues)
+            .into_iter()
+            .map(|i| i.id)
+            .collect();
+        let dropped: Vec<&str> = crate::core::catalog::RULES
+            .iter()
+            .map(|rule| rule.id)
+            .filter(|id| !kept.iter().any(|k| k == id))
+            .collect();
+        assert_eq!(
+            dropped,
+            vec![
+                "DOC-002", "DOC-003", "DOC-005", "DOC-006", "DOC-011", "DOC-012", "CHG-001",
+                "CHG-002", "CHG-011", "GH-005", "GH-006", "SYM-004", "FLT-010", "FLT-052",
+                "NJS-004", "NJS-051", "NJS-052",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_relaxed_downgrades_severity() {
+        let issues = vec![make_issue("CFG-004", AnalyzerCategory::Configuration, Severity::Medium)];
+        let result = Profile::Relaxed.apply(issues);
+        assert_eq!(result[0].severity, Severity::Low);
+    }
+}
@@ -0,0 +1,370 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::analyzers::traits::{Issue, Severity};
+use crate::frameworks::detector::Framework;
+
+/// What the repository ships, set with `project_type:` in config or guessed
+/// from its manifests. Severities are calibrated for applications; the other
+/// types adjust or drop rules that do not fit them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProjectType {
+    /// Published for other code to depend on (crate, npm package, gem, ...)
+    Library,
+    /// User-facing program: website, mobile or desktop app, CLI
+    Application,
+    /// Deployed backend or internal app: API, worker, back office
+    Service,
+}
+
+impl std::fmt::Display for ProjectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProjectType::Library => write!(f, "library"),
+            ProjectType::Application => write!(f, "application"),
+            ProjectType::Service => write!(f, "service"),
+        }
+    }
+}
+
+/// Rule adjustments for libraries; `None` drops the rule.
+const LIBRARY_ADJUSTMENTS: &[(&str, Option<Severity>)] = &[
+    ("DEP-001", None), // lock files are ignored by dependents
+    ("RST-011", None), // Cargo.lock for binaries
+    ("NJS-051", None), // robots.txt
+    ("NJS-052", None), // sitemap
+];
+
+/// Rule adjustments for published (not private) libraries.
+const PUBLISHED_LIBRARY_ADJUSTMENTS: &[(&str, Option<Severity>)] = &[
+    ("STR-004", Some(Severity::High)), // missing LICENSE
+    ("DOC-004", Some(Severity::High)), // incomplete LICENSE
+];
+
+/// Rule adjustments for services.
+const SERVICE_ADJUSTMENTS: &[(&str, Option<Severity>)] = &[
+    ("NJS-051", None),                 // robots.txt is irrelevant when not indexed
+    ("NJS-052", None),                 // sitemap
+    ("STR-004", Some(Severity::Info)), // missing LICENSE
+    ("DEP-001", Some(Severity::High)), // lock file keeps deploys reproducible
+    ("RST-011", Some(Severity::High)), // Cargo.lock for binaries
+];
+
+/// Backend dependencies that make a binary or package a service.
+const SERVER_DEPENDENCIES: &[&str] = &[
+    // Rust
+    "axum", "actix-web", "rocket", "warp", "tonic", "poem", "hyper",
+    // Node.js
+    "express", "fastify", "koa", "@nestjs/core", "@hapi/hapi",
+    // Python
+    "fastapi", "flask", "starlette", "celery", "aiohttp",
+];
+
+/// Files that mean the project is deployed somewhere.
+const DEPLOY_FILES: &[&str] = &[
+    "Dockerfile",
+    "docker-compose.yml",
+    "docker-compose.yaml",
+    "compose.yml",
+    "compose.yaml",
+    "Procfile",
+    "fly.toml",
+    "app.yaml",
+    "serverless.yml",
+    "k8s",
+    "kubernetes",
+    "helm",
+    "charts",
+];
+
+impl ProjectType {
+    pub fn from_name(name: &str) -> Option<ProjectType> {
+        match name.trim().to_lowercase().as_str() {
+            "library" | "lib" | "package" => Some(ProjectType::Library),
+            "application" | "app" => Some(ProjectType::Application),
+            "service" => Some(ProjectType::Service),
+            _ => None,
+        }
+    }
+
+    /// Guess the type from the manifests at the project root.
+    pub fn guess(path: &Path, framework: &Framework) -> ProjectType {
+        if is_library(path) {
+            return ProjectType::Library;
+        }
+        match framework {
+            // Frameworks that render pages or screens for users
+            Framework::NextJs | Framework::Flutter | Framework::Symfony | Framework::Laravel => {
+                ProjectType::Application
+            }
+            _ if has_server_dependency(path)
+                || DEPLOY_FILES.iter().any(|f| path.join(f).exists()) =>
+            {
+                ProjectType::Service
+            }
+            _ => ProjectType::Application,
+        }
+    }
+
+    /// Adjust severities and drop rules that do not apply to this project type.
+    pub fn apply(&self, issues: Vec<Issue>, path: &Path) -> Vec<Issue> {
+        let adjustments: Vec<&(&str, Option<Severity>)> = match self {
+            ProjectType::Application => return issues,
+            ProjectType::Library if is_private(path) => LIBRARY_ADJUSTMENTS.iter().collect(),
+            ProjectType::Library => LIBRARY_ADJUSTMENTS
+                .iter()
+                .chain(PUBLISHED_LIBRARY_ADJUSTMENTS)
+                .collect(),
+            ProjectType::Service => SERVICE_ADJUSTMENTS.iter().collect(),
+        };
+
+        issues
+            .into_iter()
+            .filter_map(|mut issue| {
+                match adjustments.iter().find(|(id, _)| *id == issue.id) {
+                    Some((_, None)) => return None,
+                    Some((_, Some(severity))) => issue.severity = *severity,
+                    None => {}
+                }
+                Some(issue)
+            })
+            .collect()
+    }
+}
+
+fn read(path: &Path, file: &str) -> String {
+    std::fs::read_to_string(path.join(file)).unwrap_or_default()
+}
+
+fn package_json(path: &Path) -> Option<serde_json::Value> {
+    serde_json::from_str(&read(path, "package.json")).ok()
+}
+
+fn is_library(path: &Path) -> bool {
+    // Rust: a lib target and no binary
+    if path.join("Cargo.toml").exists() {
+        let has_lib = path.join("src/lib.rs").exists() || read(path, "Cargo.toml").contains("[lib]");
+        let has_bin = path.join("src/main.rs").exists() || path.join("src/bin").is_dir();
+        if has_lib && !has_bin {
+            return true;
+        }
+    }
+    // Node.js: entry points for importers and nothing that runs a site
+    if let Some(pkg) = package_json(path) {
+        let exported = ["main", "module", "exports", "types"]
+            .iter()
+            .any(|key| pkg.get(key).is_some());
+        let is_app = ["next", "react-scripts", "vite"].iter().any(|dep| {
+            pkg["dependencies"].get(dep).is_some() || pkg["devDependencies"].get(dep).is_some()
+        }) && pkg.get("exports").is_none();
+        if exported && !is_app && pkg.get("bin").is_none() {
+            return true;
+        }
+    }
+    // PHP: any composer type other than the application "project"
+    if let Ok(composer) = serde_json::from_str::<serde_json::Value>(&read(path, "composer.json")) {
+        if let Some(kind) = composer["type"].as_str() {
+            return kind != "project";
+        }
+    }
+    // Dart/Flutter plugins and packages
+    let pubspec = read(path, "pubspec.yaml");
+    if pubspec.contains("\n  plugin:") {
+        return true;
+    }
+    // Ruby gems
+    if std::fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .any(|e| e.file_name().to_string_lossy().ends_with(".gemspec"))
+        })
+        .unwrap_or(false)
+    {
+        return true;
+    }
+    // Python: a build backend and no app entry point
+    let pyproject = read(path, "pyproject.toml");
+    pyproject.contains("[build-system]")
+        && !path.join("manage.py").exists()
+        && !has_server_dependency(path)
+}
+
+fn has_server_dependency(path: &Path) -> bool {
+    if let Some(pkg) = package_json(path) {
+        if SERVER_DEPENDENCIES
+            .iter()
+            .any(|dep| pkg["dependencies"].get(dep).is_some())
+        {
+            return true;
+        }
+    }
+
+    let manifests = [
+        read(path, "Cargo.toml"),
+        read(path, "pyproject.toml"),
+        read(path, "requirements.txt"),
+    ]
+    .join("\n")
+    .to_lowercase();
+
+    SERVER_DEPENDENCIES.iter().any(|dep| {
+        manifests.lines().any(|line| {
+            let line = line.trim().trim_start_matches('"');
+            line.strip_prefix(dep).is_some_and(|rest| {
+                rest.is_empty() || rest.starts_with(['"', ' ', '=', '>', '<', '~', '[', '\''])
+            })
+        })
+    })
+}
+
+/// Libraries that are not published: `"private": true` in package.json or
+/// `publish = false` in Cargo.toml.
+fn is_private(path: &Path) -> bool {
+    package_json(path).is_some_and(|pkg| pkg["private"] == true)
+        || read(path, "Cargo.toml")
+            .lines()
+            .any(|line| line.replace(' ', "") == "publish=false")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::AnalyzerCategory;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_issue(id: &str, severity: Severity) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Structure,
+            severity,
+            title: "Test".to_string(),
+            description: "Test".to_string(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        }
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(ProjectType::from_name("Library"), Some(ProjectType::Library));
+        assert_eq!(ProjectType::from_name("app"), Some(ProjectType::Application));
+        assert_eq!(ProjectType::from_name("service"), Some(ProjectType::Service));
+        assert_eq!(ProjectType::from_name("monorepo"), None);
+    }
+
+    #[test]
+    fn test_guess_rust_crates() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir(tmp.path().join("src")).unwrap();
+        fs::write(tmp.path().join("Cargo.toml"), "[package]\nname = \"x\"\n").unwrap();
+        fs::write(tmp.path().join("src/lib.rs"), "").unwrap();
+        assert_eq!(ProjectType::guess(tmp.path(), &Framework::RustCargo), ProjectType::Library);
+
+        fs::write(tmp.path().join("src/main.rs"), "fn main() {}").unwrap();
+        assert_eq!(ProjectType::guess(tmp.path(), &Framework::RustCargo), ProjectType::Application);
+
+        fs::write(
+            tmp.path().join("Cargo.toml"),
+            "[package]\nname = \"x\"\n\n[dependencies]\naxum = \"0.7\"\n",
+        )
+        .unwrap();
+        assert_eq!(ProjectType::guess(tmp.path(), &Framework::RustCargo), ProjectType::Service);
+    }
+
+    #[test]
+    fn test_guess_node_packages() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("package.json"),
+            r#"{"name": "x", "main": "dist/index.js", "types": "dist/index.d.ts"}"#,
+        )
+        .unwrap();
+        assert_eq!(ProjectType::guess(tmp.path(), &Framework::NodeJs), ProjectType::Library);
+
+        fs::write(
+            tmp.path().join("package.json"),
+            r#"{"name": "x", "main": "server.js", "dependencies": {"express": "^4"}, "bin": {"x": "cli.js"}}"#,
+        )
+        .unwrap();
+        assert_eq!(ProjectType::guess(tmp.path(), &Framework::NodeJs), ProjectType::Service);
+    }
+
+    #[test]
+    fn test_guess_web_frameworks_are_applications_even_when_deployed() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("package.json"),
+            r#"{"private": true, "dependencies": {"next": "14"}}"#,
+        )
+        .unwrap();
+        fs::write(tmp.path().join("Dockerfile"), "FROM node:20\n").unwrap();
+        assert_eq!(ProjectType::guess(tmp.path(), &Framework::NextJs), ProjectType::Application);
+        assert_eq!(ProjectType::guess(tmp.path(), &Framework::Unknown), ProjectType::Service);
+    }
+
+    #[test]
+    fn test_guess_composer_and_python_libraries() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("composer.json"), r#"{"type": "symfony-bundle"}"#).unwrap();
+        assert_eq!(ProjectType::guess(tmp.path(), &Framework::Symfony), ProjectType::Library);
+
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join("pyproject.toml"),
+            "[build-system]\nrequires = [\"hatchling\"]\n",
+        )
+        .unwrap();
+        assert_eq!(ProjectType::guess(tmp.path(), &Framework::Python), ProjectType::Library);
+
+        fs::write(tmp.path().join("requirements.txt"), "fastapi==0.110\n").unwrap();
+        assert_eq!(ProjectType::guess(tmp.path(), &Framework::Python), ProjectType::Service);
+    }
+
+    #[test]
+    fn test_library_adjustments() {
+        let tmp = TempDir::new().unwrap();
+        let issues = vec![
+            make_issue("DEP-001", Severity::Medium),
+            make_issue("NJS-051", Severity::Low),
+            make_issue("STR-004", Severity::Low),
+            make_issue("SEC-001", Severity::Critical),
+        ];
+
+        let result = ProjectType::Library.apply(issues.clone(), tmp.path());
+        let ids: Vec<_> = result.iter().map(|i| (i.id.as_str(), i.severity)).collect();
+        assert_eq!(ids, vec![("STR-004", Severity::High), ("SEC-001", Severity::Critical)]);
+
+        // Private packages keep the default LICENSE severity
+        fs::write(tmp.path().join("package.json"), r#"{"private": true}"#).unwrap();
+        let result = ProjectType::Library.apply(issues, tmp.path());
+        assert_eq!(result[0].id, "STR-004");
+        assert_eq!(result[0].severity, Severity::Low);
+    }
+
+    #[test]
+    fn test_service_and_application_adjustments() {
+        let tmp = TempDir::new().unwrap();
+        let issues = vec![
+            make_issue("DEP-001", Severity::Medium),
+            make_issue("NJS-052", Severity::Low),
+            make_issue("STR-004", Severity::Low),
+        ];
+
+        let result = ProjectType::Service.apply(issues.clone(), tmp.path());
+        let ids: Vec<_> = result.iter().map(|i| (i.id.as_str(), i.severity)).collect();
+        assert_eq!(ids, vec![("DEP-001", Severity::High), ("STR-004", Severity::Info)]);
+
+        assert_eq!(ProjectType::Application.apply(issues, tmp.path()).len(), 3);
+    }
+}
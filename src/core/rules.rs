@@ -0,0 +1,517 @@
+use crate::analyzers::traits::{Effort, Impact, Issue};
+
+const OWASP_SECRETS: &str =
+    "https://cheatsheetseries.owasp.org/cheatsheets/Secrets_Management_Cheat_Sheet.html";
+const OWASP_COMPONENTS: &str =
+    "https://owasp.org/Top10/A06_2021-Vulnerable_and_Outdated_Components/";
+const GITIGNORE: &str = "https://git-scm.com/docs/gitignore";
+const MAKE_A_README: &str = "https://www.makeareadme.com";
+
+/// Documentation for individual rules, listed before the family links.
+const RULE_REFERENCES: &[(&str, &[&str])] = &[
+    // Structure
+    ("STR-002", &["https://docs.github.com/en/repositories/managing-your-repositorys-settings-and-features/customizing-your-repository/about-readmes"]),
+    ("STR-003", &[GITIGNORE]),
+    ("STR-004", &["https://choosealicense.com"]),
+    // Dependencies
+    ("DEP-001", &["https://doc.rust-lang.org/cargo/guide/cargo-toml-vs-cargo-lock.html", "https://docs.npmjs.com/cli/configuring-npm/package-lock-json", "https://getcomposer.org/doc/01-basic-usage.md#commit-your-composer-lock-file-to-version-control"]),
+    ("DEP-003", &["https://docs.npmjs.com/cli/configuring-npm/package-json#devdependencies"]),
+    ("DEP-004", &["https://semver.org"]),
+    // Configuration
+    ("CFG-001", &["https://docs.github.com/en/actions"]),
+    ("CFG-002", &["https://editorconfig.org"]),
+    ("CFG-003", &["https://12factor.net/config", OWASP_SECRETS]),
+    ("CFG-005", &["https://docs.github.com/en/actions"]),
+    // Security
+    ("SEC-001", &[OWASP_SECRETS, "https://cwe.mitre.org/data/definitions/798.html"]),
+    ("SEC-002", &[OWASP_SECRETS, "https://docs.github.com/en/authentication/keeping-your-account-and-data-secure/removing-sensitive-data-from-a-repository"]),
+    ("SEC-003", &[GITIGNORE, OWASP_SECRETS]),
+    // Documentation
+    ("DOC-003", &["https://docs.github.com/en/communities/setting-up-your-project-for-healthy-contributions/setting-guidelines-for-repository-contributors"]),
+    ("DOC-004", &["https://choosealicense.com"]),
+    ("DOC-005", &["https://www.contributor-covenant.org"]),
+    // Changelog and commits
+    ("CHG-002", &["https://www.conventionalcommits.org/en/v1.0.0/"]),
+    ("CHG-010", &["https://semver.org"]),
+    ("COM-002", &["https://git-scm.com/docs/git-rebase#Documentation/git-rebase.txt---autosquash"]),
+    ("COM-003", &["https://docs.github.com/en/get-started/writing-on-github/working-with-advanced-formatting/autolinked-references-and-urls"]),
+    // Container images
+    ("IMG-001", &[OWASP_COMPONENTS]),
+    ("IMG-002", &["https://trivy.dev", "https://github.com/anchore/grype"]),
+    ("IMG-003", &["https://trivy.dev", "https://github.com/anchore/grype"]),
+    // GitHub settings
+    ("GH-001", &["https://docs.github.com/en/repositories/configuring-branches-and-merges-in-your-repository/managing-protected-branches/about-protected-branches"]),
+    ("GH-002", &["https://docs.github.com/en/repositories/configuring-branches-and-merges-in-your-repository/managing-protected-branches/about-protected-branches#require-pull-request-reviews-before-merging"]),
+    ("GH-003", &["https://docs.github.com/en/code-security/secret-scanning/about-secret-scanning"]),
+    ("GH-004", &["https://docs.github.com/en/code-security/dependabot/dependabot-alerts/about-dependabot-alerts"]),
+    ("GH-005", &["https://docs.github.com/en/repositories/managing-your-repositorys-settings-and-features/customizing-your-repository/classifying-your-repository-with-topics"]),
+    ("GH-006", &["https://docs.github.com/en/repositories/managing-your-repositorys-settings-and-features/customizing-your-repository/classifying-your-repository-with-topics"]),
+    ("GH-009", &["https://docs.github.com/en/rest/authentication/permissions-required-for-fine-grained-personal-access-tokens"]),
+    // Symfony
+    ("SYM-001", &["https://symfony.com/doc/current/best_practices.html"]),
+    ("SYM-002", &["https://symfony.com/doc/current/doctrine.html"]),
+    ("SYM-003", &["https://symfony.com/doc/current/best_practices.html"]),
+    ("SYM-004", &["https://symfony.com/doc/current/service_container.html"]),
+    ("SYM-012", &["https://symfony.com/doc/current/reference/configuration/framework.html#secret", "https://symfony.com/doc/current/configuration/secrets.html"]),
+    ("SYM-013", &["https://symfony.com/doc/current/deployment.html", "https://symfony.com/doc/current/configuration.html#configuration-environments"]),
+    ("SYM-020", &["https://symfony.com/releases"]),
+    ("SYM-022", &["https://symfony.com/doc/current/components/runtime.html"]),
+    ("SYM-030", &["https://symfony.com/doc/current/testing.html"]),
+    ("SYM-031", &["https://symfony.com/doc/current/testing.html"]),
+    ("SYM-032", &["https://symfony.com/doc/current/testing.html"]),
+    ("SYM-040", &["https://symfony.com/doc/current/configuration/secrets.html", OWASP_SECRETS]),
+    ("SYM-041", &["https://symfony.com/bundles/NelmioCorsBundle/current/index.html", "https://symfony.com/doc/current/security.html"]),
+    ("SYM-042", &["https://www.php.net/manual/en/function.unserialize.php", "https://cheatsheetseries.owasp.org/cheatsheets/Deserialization_Cheat_Sheet.html"]),
+    ("SYM-050", &[GITIGNORE]),
+    ("SYM-052", &["https://getrector.com/documentation"]),
+    ("SYM-053", &["https://phpstan.org/user-guide/getting-started"]),
+    // Laravel
+    ("LAR-010", &["https://laravel.com/docs/encryption#configuration"]),
+    ("LAR-011", &["https://laravel.com/docs/configuration#debug-mode"]),
+    ("LAR-030", &["https://laravel.com/docs/testing"]),
+    ("LAR-031", &["https://laravel.com/docs/testing"]),
+    ("LAR-040", &["https://laravel.com/docs/eloquent#mass-assignment"]),
+    ("LAR-041", &["https://laravel.com/docs/queries#raw-expressions", "https://cheatsheetseries.owasp.org/cheatsheets/SQL_Injection_Prevention_Cheat_Sheet.html"]),
+    ("LAR-050", &[GITIGNORE]),
+    // Next.js
+    ("NJS-001", &["https://nextjs.org/docs/app/api-reference/file-conventions/layout"]),
+    ("NJS-002", &["https://nextjs.org/docs/app/guides/migrating/app-router-migration"]),
+    ("NJS-003", &["https://nextjs.org/docs/app/api-reference/file-conventions/error"]),
+    ("NJS-004", &["https://nextjs.org/docs/app/getting-started/project-structure"]),
+    ("NJS-010", &["https://nextjs.org/docs/app/api-reference/config/next-config-js"]),
+    ("NJS-011", &["https://nextjs.org/docs/app/api-reference/config/typescript", "https://www.typescriptlang.org/tsconfig#strict"]),
+    ("NJS-012", &["https://nextjs.org/docs/app/api-reference/components/image"]),
+    ("NJS-013", &["https://nextjs.org/docs/app/api-reference/config/next-config-js/reactStrictMode"]),
+    ("NJS-021", &["https://nextjs.org/docs/app/guides/upgrading"]),
+    ("NJS-022", &["https://nextjs.org/docs/app/guides/package-bundling"]),
+    ("NJS-030", &["https://nextjs.org/docs/app/guides/testing"]),
+    ("NJS-031", &["https://nextjs.org/docs/app/guides/testing"]),
+    ("NJS-032", &["https://nextjs.org/docs/app/guides/testing"]),
+    ("NJS-040", &["https://nextjs.org/docs/app/guides/environment-variables#bundling-environment-variables-for-the-browser", OWASP_SECRETS]),
+    ("NJS-041", &["https://nextjs.org/docs/app/api-reference/config/next-config-js/headers", "https://owasp.org/www-project-secure-headers/"]),
+    ("NJS-042", &["https://react.dev/reference/react-dom/components/common#dangerously-setting-the-inner-html", "https://cheatsheetseries.owasp.org/cheatsheets/Cross_Site_Scripting_Prevention_Cheat_Sheet.html"]),
+    ("NJS-050", &["https://nextjs.org/docs/app/guides/environment-variables", GITIGNORE]),
+    ("NJS-051", &["https://nextjs.org/docs/app/api-reference/file-conventions/metadata/robots"]),
+    ("NJS-052", &["https://nextjs.org/docs/app/api-reference/file-conventions/metadata/sitemap"]),
+    // Flutter
+    ("FLT-003", &["https://docs.flutter.dev/app-architecture"]),
+    ("FLT-004", &["https://docs.flutter.dev/app-architecture"]),
+    ("FLT-010", &["https://dart.dev/tools/pub/pubspec#description"]),
+    ("FLT-011", &["https://dart.dev/resources/dart-3-migration"]),
+    ("FLT-021", &["https://dart.dev/tools/pub/dependencies#dev-dependencies"]),
+    ("FLT-022", &["https://dart.dev/tools/pub/dependencies#git-packages"]),
+    ("FLT-030", &["https://docs.flutter.dev/cookbook/testing/widget/introduction"]),
+    ("FLT-031", &["https://docs.flutter.dev/testing/integration-tests"]),
+    ("FLT-032", &["https://docs.flutter.dev/testing/overview"]),
+    ("FLT-041", &["https://docs.flutter.dev/release/breaking-changes/network-policy-ios-android"]),
+    ("FLT-042", &["https://docs.flutter.dev/testing/code-debugging#logging"]),
+    ("FLT-050", &["https://docs.flutter.dev/deployment/android#sign-the-app"]),
+    ("FLT-051", &["https://docs.flutter.dev/deployment/ios"]),
+    ("FLT-052", &["https://docs.flutter.dev/deployment/android#add-a-launcher-icon"]),
+    ("FLT-053", &[GITIGNORE]),
+    // Rust
+    ("RST-002", &["https://doc.rust-lang.org/clippy/configuration.html"]),
+    ("RST-003", &["https://rust-lang.github.io/rustfmt/"]),
+    ("RST-010", &["https://doc.rust-lang.org/edition-guide/"]),
+    ("RST-011", &["https://doc.rust-lang.org/cargo/guide/cargo-toml-vs-cargo-lock.html", "https://rustsec.org"]),
+    ("RST-020", &["https://doc.rust-lang.org/book/ch11-03-test-organization.html#integration-tests"]),
+    ("RST-030", &["https://doc.rust-lang.org/nomicon/", "https://rustsec.org"]),
+    ("RST-040", &[GITIGNORE]),
+    // Bazel and Buck
+    ("BZL-001", &["https://bazel.build/external/overview"]),
+    ("BZL-002", &["https://github.com/bazelbuild/bazelisk"]),
+    ("BZL-003", &["https://bazel.build/remote/caching"]),
+    ("BZL-004", &["https://bazel.build/concepts/build-files"]),
+    // JVM
+    ("JVM-001", &["https://docs.gradle.org/current/userguide/dynamic_versions.html"]),
+    ("JVM-002", &["https://docs.gradle.org/current/userguide/dependency_locking.html", "https://docs.gradle.org/current/userguide/dependency_verification.html"]),
+    ("JVM-003", &["https://docs.gradle.org/current/userguide/platforms.html"]),
+    ("JVM-010", &["https://docs.gradle.org/current/userguide/gradle_wrapper.html", "https://maven.apache.org/wrapper/"]),
+    // Ruby
+    ("RB-001", &["https://bundler.io/guides/faq.html"]),
+    ("RB-002", &["https://bundler.io/guides/git.html"]),
+    ("RB-010", &["https://docs.rubocop.org/rubocop/configuration.html"]),
+    ("RB-020", &["https://rspec.info/documentation/"]),
+    ("RB-030", &["https://guides.rubyonrails.org/security.html", "https://cwe.mitre.org/data/definitions/95.html"]),
+    // Shell
+    ("SH-001", &["https://www.gnu.org/software/bash/manual/bash.html#The-Set-Builtin"]),
+    ("SH-010", &["https://www.shellcheck.net/wiki/SC2086"]),
+    ("SH-011", &["https://cwe.mitre.org/data/definitions/494.html"]),
+    ("SH-012", &[OWASP_SECRETS, "https://cwe.mitre.org/data/definitions/798.html"]),
+    // Notebooks
+    ("NB-001", &["https://github.com/kynan/nbstripout"]),
+    ("NB-010", &[OWASP_SECRETS]),
+    ("NB-020", &["https://github.com/kynan/nbstripout"]),
+    ("NB-030", &["https://packaging.python.org/en/latest/guides/using-manifest-in/"]),
+    // Portability
+    ("PRT-001", &["https://git-scm.com/docs/git-config#Documentation/git-config.txt-coreignoreCase"]),
+    ("PRT-002", &["https://learn.microsoft.com/en-us/windows/win32/fileio/naming-a-file#naming-conventions"]),
+    ("PRT-003", &["https://learn.microsoft.com/en-us/windows/win32/fileio/maximum-file-path-limitation"]),
+    ("PRT-004", &["https://git-scm.com/docs/git-config#Documentation/git-config.txt-coresymlinks"]),
+];
+
+/// Documentation for every rule of a family, keyed by ID prefix.
+const FAMILY_REFERENCES: &[(&str, &[&str])] = &[
+    ("STR", &["https://docs.github.com/en/repositories/creating-and-managing-repositories/best-practices-for-repositories"]),
+    ("DEP", &[OWASP_COMPONENTS]),
+    ("CFG", &["https://12factor.net"]),
+    ("SEC", &["https://owasp.org/www-project-top-ten/"]),
+    ("TST", &["https://martinfowler.com/articles/practical-test-pyramid.html"]),
+    ("DOC", &[MAKE_A_README]),
+    ("CHG", &["https://keepachangelog.com/en/1.1.0/"]),
+    ("COM", &["https://www.conventionalcommits.org/en/v1.0.0/"]),
+    ("IMG", &["https://docs.docker.com/build/building/best-practices/"]),
+    ("DRF", &["https://docs.github.com/en/repositories/creating-and-managing-repositories/creating-a-template-repository"]),
+    ("GH", &["https://docs.github.com/en/rest/repos/repos"]),
+    ("SYM", &["https://symfony.com/doc/current/index.html"]),
+    ("LAR", &["https://laravel.com/docs"]),
+    ("NJS", &["https://nextjs.org/docs"]),
+    ("FLT", &["https://docs.flutter.dev"]),
+    ("RST", &["https://doc.rust-lang.org/cargo/"]),
+    ("BZL", &["https://bazel.build/docs"]),
+    ("JVM", &["https://docs.gradle.org/current/userguide/userguide.html"]),
+    ("RB", &["https://guides.rubygems.org/make-your-own-gem/", "https://bundler.io/guides/getting_started.html"]),
+    ("SH", &["https://www.shellcheck.net/wiki/"]),
+    ("NB", &["https://jupyter-notebook.readthedocs.io/"]),
+    ("PRT", &["https://learn.microsoft.com/en-us/windows/win32/fileio/naming-a-file"]),
+];
+
+/// Effort and impact of every built-in rule. Category and severity estimate
+/// them poorly: scaffolding fixes add little, and some findings are quick to
+/// resolve but matter a lot (or the reverse).
+const RULE_METADATA: &[(&str, Effort, Impact)] = &[
+    // Structure
+    ("STR-001", Effort::Trivial, Impact::Low),
+    ("STR-002", Effort::Trivial, Impact::Medium),
+    ("STR-003", Effort::Trivial, Impact::High),
+    ("STR-004", Effort::Trivial, Impact::Medium),
+    ("STR-005", Effort::Medium, Impact::Low),
+    ("STR-006", Effort::Small, Impact::High),
+    // Dependencies
+    ("DEP-001", Effort::Trivial, Impact::High),
+    ("DEP-002", Effort::Trivial, Impact::Low),
+    ("DEP-003", Effort::Small, Impact::Medium),
+    ("DEP-004", Effort::Small, Impact::Medium),
+    ("DEP-005", Effort::Large, Impact::Low),
+    // Configuration
+    ("CFG-001", Effort::Small, Impact::Low),
+    ("CFG-002", Effort::Trivial, Impact::Low),
+    ("CFG-003", Effort::Trivial, Impact::High),
+    ("CFG-004", Effort::Small, Impact::Medium),
+    ("CFG-005", Effort::Small, Impact::High),
+    // Security
+    ("SEC-001", Effort::Medium, Impact::High),
+    ("SEC-002", Effort::Large, Impact::High),
+    ("SEC-003", Effort::Trivial, Impact::High),
+    // Testing
+    ("TST-001", Effort::Large, Impact::High),
+    ("TST-002", Effort::Small, Impact::Medium),
+    ("TST-003", Effort::Large, Impact::High),
+    ("TST-004", Effort::Large, Impact::Medium),
+    // Documentation
+    ("DOC-001", Effort::Small, Impact::Medium),
+    ("DOC-002", Effort::Small, Impact::Low),
+    ("DOC-003", Effort::Small, Impact::Low),
+    ("DOC-004", Effort::Trivial, Impact::Medium),
+    ("DOC-005", Effort::Trivial, Impact::Low),
+    ("DOC-006", Effort::Small, Impact::Low),
+    ("DOC-010", Effort::Small, Impact::Medium),
+    ("DOC-011", Effort::Trivial, Impact::Low),
+    ("DOC-012", Effort::Trivial, Impact::Low),
+    // Changelog
+    ("CHG-001", Effort::Small, Impact::Low),
+    ("CHG-002", Effort::Trivial, Impact::Low),
+    ("CHG-010", Effort::Trivial, Impact::Medium),
+    ("CHG-011", Effort::Trivial, Impact::Low),
+    // Commit messages
+    ("COM-001", Effort::Medium, Impact::Medium),
+    ("COM-002", Effort::Small, Impact::Low),
+    ("COM-003", Effort::Small, Impact::Low),
+    ("COM-004", Effort::Trivial, Impact::Medium),
+    // Container images
+    ("IMG-001", Effort::Medium, Impact::High),
+    ("IMG-002", Effort::Small, Impact::Low),
+    ("IMG-003", Effort::Trivial, Impact::Low),
+    // Template drift
+    ("DRF-001", Effort::Trivial, Impact::Medium),
+    ("DRF-002", Effort::Small, Impact::High),
+    ("DRF-003", Effort::Small, Impact::Low),
+    ("DRF-004", Effort::Trivial, Impact::Medium),
+    // GitHub settings
+    ("GH-001", Effort::Trivial, Impact::High),
+    ("GH-002", Effort::Trivial, Impact::Medium),
+    ("GH-003", Effort::Trivial, Impact::Medium),
+    ("GH-004", Effort::Trivial, Impact::Medium),
+    ("GH-005", Effort::Trivial, Impact::Low),
+    ("GH-006", Effort::Trivial, Impact::Low),
+    ("GH-009", Effort::Small, Impact::Low),
+    // Symfony
+    ("SYM-001", Effort::Trivial, Impact::Low),
+    ("SYM-002", Effort::Trivial, Impact::Low),
+    ("SYM-003", Effort::Medium, Impact::Low),
+    ("SYM-004", Effort::Medium, Impact::Low),
+    ("SYM-012", Effort::Trivial, Impact::High),
+    ("SYM-013", Effort::Trivial, Impact::High),
+    ("SYM-020", Effort::Large, Impact::High),
+    ("SYM-022", Effort::Small, Impact::Low),
+    ("SYM-030", Effort::Trivial, Impact::Medium),
+    ("SYM-031", Effort::Trivial, Impact::Low),
+    ("SYM-032", Effort::Trivial, Impact::High),
+    ("SYM-040", Effort::Small, Impact::High),
+    ("SYM-041", Effort::Small, Impact::Medium),
+    ("SYM-042", Effort::Medium, Impact::High),
+    ("SYM-050", Effort::Trivial, Impact::Medium),
+    ("SYM-052", Effort::Small, Impact::Low),
+    ("SYM-053", Effort::Small, Impact::Medium),
+    // Flutter
+    ("FLT-003", Effort::Medium, Impact::Medium),
+    ("FLT-004", Effort::Large, Impact::Medium),
+    ("FLT-010", Effort::Trivial, Impact::Low),
+    ("FLT-011", Effort::Medium, Impact::High),
+    ("FLT-021", Effort::Trivial, Impact::Medium),
+    ("FLT-022", Effort::Small, Impact::Low),
+    ("FLT-030", Effort::Large, Impact::High),
+    ("FLT-031", Effort::Trivial, Impact::Low),
+    ("FLT-032", Effort::Trivial, Impact::High),
+    ("FLT-041", Effort::Small, Impact::High),
+    ("FLT-042", Effort::Trivial, Impact::Medium),
+    ("FLT-050", Effort::Small, Impact::Medium),
+    ("FLT-051", Effort::Small, Impact::Medium),
+    ("FLT-052", Effort::Small, Impact::Low),
+    ("FLT-053", Effort::Trivial, Impact::Medium),
+    // Next.js
+    ("NJS-001", Effort::Trivial, Impact::High),
+    ("NJS-002", Effort::Large, Impact::Medium),
+    ("NJS-003", Effort::Small, Impact::Medium),
+    ("NJS-004", Effort::Small, Impact::Low),
+    ("NJS-010", Effort::Trivial, Impact::Medium),
+    ("NJS-011", Effort::Medium, Impact::Medium),
+    ("NJS-012", Effort::Small, Impact::Low),
+    ("NJS-013", Effort::Trivial, Impact::Medium),
+    ("NJS-020", Effort::Trivial, Impact::High),
+    ("NJS-021", Effort::Large, Impact::High),
+    ("NJS-022", Effort::Medium, Impact::Low),
+    ("NJS-030", Effort::Small, Impact::High),
+    ("NJS-031", Effort::Trivial, Impact::Low),
+    ("NJS-032", Effort::Trivial, Impact::Medium),
+    ("NJS-040", Effort::Small, Impact::High),
+    ("NJS-041", Effort::Small, Impact::Medium),
+    ("NJS-042", Effort::Medium, Impact::High),
+    ("NJS-050", Effort::Trivial, Impact::High),
+    ("NJS-051", Effort::Trivial, Impact::Low),
+    ("NJS-052", Effort::Small, Impact::Low),
+    // Laravel
+    ("LAR-001", Effort::Trivial, Impact::Low),
+    ("LAR-002", Effort::Trivial, Impact::Low),
+    ("LAR-003", Effort::Trivial, Impact::Low),
+    ("LAR-010", Effort::Trivial, Impact::High),
+    ("LAR-011", Effort::Trivial, Impact::High),
+    ("LAR-020", Effort::Trivial, Impact::Medium),
+    ("LAR-030", Effort::Trivial, Impact::Medium),
+    ("LAR-031", Effort::Trivial, Impact::Low),
+    ("LAR-040", Effort::Small, Impact::High),
+    ("LAR-041", Effort::Medium, Impact::High),
+    ("LAR-050", Effort::Trivial, Impact::Medium),
+    // Rust/Cargo
+    ("RST-001", Effort::Small, Impact::High),
+    ("RST-002", Effort::Trivial, Impact::Low),
+    ("RST-003", Effort::Trivial, Impact::Low),
+    ("RST-010", Effort::Small, Impact::Medium),
+    ("RST-011", Effort::Trivial, Impact::Medium),
+    ("RST-020", Effort::Trivial, Impact::Low),
+    ("RST-030", Effort::Large, Impact::High),
+    ("RST-040", Effort::Trivial, Impact::Medium),
+    // Bazel/Buck
+    ("BZL-001", Effort::Small, Impact::High),
+    ("BZL-002", Effort::Trivial, Impact::Medium),
+    ("BZL-003", Effort::Small, Impact::Low),
+    ("BZL-004", Effort::Small, Impact::Medium),
+    // Gradle/Maven
+    ("JVM-001", Effort::Small, Impact::High),
+    ("JVM-002", Effort::Small, Impact::Medium),
+    ("JVM-003", Effort::Small, Impact::Low),
+    ("JVM-010", Effort::Trivial, Impact::Medium),
+    // Ruby
+    ("RB-001", Effort::Trivial, Impact::High),
+    ("RB-002", Effort::Small, Impact::High),
+    ("RB-010", Effort::Small, Impact::Low),
+    ("RB-020", Effort::Trivial, Impact::Low),
+    ("RB-030", Effort::Medium, Impact::High),
+    // Shell scripts
+    ("SH-001", Effort::Trivial, Impact::Low),
+    ("SH-010", Effort::Trivial, Impact::Medium),
+    ("SH-011", Effort::Small, Impact::High),
+    ("SH-012", Effort::Medium, Impact::High),
+    // Jupyter notebooks
+    ("NB-001", Effort::Trivial, Impact::Low),
+    ("NB-010", Effort::Medium, Impact::High),
+    ("NB-020", Effort::Small, Impact::Low),
+    ("NB-030", Effort::Small, Impact::Low),
+    // Portability
+    ("PRT-001", Effort::Small, Impact::High),
+    ("PRT-002", Effort::Small, Impact::Medium),
+    ("PRT-003", Effort::Medium, Impact::Low),
+    ("PRT-004", Effort::Small, Impact::Medium),
+];
+
+/// Authoritative links for a built-in rule: its own documentation first,
+/// then the documentation of its family. Unknown IDs (custom rules and file
+/// policies) get none; their links come from `.repodoctor.yml`.
+pub fn references(rule_id: &str) -> Vec<&'static str> {
+    let own = RULE_REFERENCES
+        .iter()
+        .find(|(id, _)| *id == rule_id)
+        .map(|(_, links)| *links)
+        .unwrap_or_default();
+    let family = rule_id
+        .split_once('-')
+        .and_then(|(prefix, _)| FAMILY_REFERENCES.iter().find(|(p, _)| *p == prefix))
+        .map(|(_, links)| *links)
+        .unwrap_or_default();
+    own.iter().chain(family).copied().collect()
+}
+
+/// Append the registry links to each issue, after any the analyzer set itself.
+pub fn attach_references(issues: &mut [Issue]) {
+    for issue in issues {
+        for link in references(&issue.id) {
+            if !issue.references.iter().any(|r| r == link) {
+                issue.references.push(link.to_string());
+            }
+        }
+    }
+}
+
+/// Effort and impact listed for a built-in rule, if any.
+pub fn metadata(rule_id: &str) -> Option<(Effort, Impact)> {
+    RULE_METADATA
+        .iter()
+        .find(|(id, _, _)| *id == rule_id)
+        .map(|(_, effort, impact)| (*effort, *impact))
+}
+
+/// Fill in effort and impact on issues that don't carry their own, from the
+/// registry or, for custom rules and file policies, estimated from category,
+/// fixability, and severity.
+pub fn attach_metadata(issues: &mut [Issue]) {
+    for issue in issues {
+        let listed = metadata(&issue.id);
+        if issue.estimated_effort.is_none() {
+            issue.estimated_effort = Some(listed.map_or_else(|| Effort::estimate(issue), |(e, _)| e));
+        }
+        if issue.impact.is_none() {
+            issue.impact = Some(listed.map_or_else(|| Impact::estimate(issue), |(_, i)| i));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::{AnalyzerCategory, Severity};
+
+    fn make_issue(id: &str, references: Vec<String>) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::High,
+            title: "Test issue".to_string(),
+            description: String::new(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references,
+        }
+    }
+
+    #[test]
+    fn test_rule_links_come_before_family_links() {
+        let links = references("NJS-042");
+        assert!(links[0].starts_with("https://react.dev/"));
+        assert_eq!(links.last(), Some(&"https://nextjs.org/docs"));
+    }
+
+    #[test]
+    fn test_family_fallback_and_unknown_ids() {
+        assert_eq!(references("TST-003"), vec!["https://martinfowler.com/articles/practical-test-pyramid.html"]);
+        assert_eq!(references("DRF-001").len(), 1);
+        assert!(references("ACME-001").is_empty());
+        assert!(references("README").is_empty());
+    }
+
+    #[test]
+    fn test_every_listed_rule_has_a_family() {
+        for (id, links) in RULE_REFERENCES {
+            assert!(!links.is_empty(), "{} has no links", id);
+            let prefix = id.split_once('-').unwrap().0;
+            assert!(
+                FAMILY_REFERENCES.iter().any(|(p, _)| *p == prefix),
+                "{} has no family links",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_metadata_rules_are_unique() {
+        for (index, (id, _, _)) in RULE_METADATA.iter().enumerate() {
+            assert!(
+                !RULE_METADATA[index + 1..].iter().any(|(other, _, _)| other == id),
+                "{} is listed twice",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_catalog_rule_has_metadata() {
+        for rule in crate::core::catalog::RULES {
+            assert!(
+                metadata(rule.id).is_some(),
+                "{} has no effort and impact",
+                rule.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_attach_metadata_prefers_issue_then_registry_then_estimate() {
+        let mut issues = vec![
+            make_issue("SEC-003", vec![]),
+            make_issue("SEC-003", vec![]),
+            make_issue("ACME-001", vec![]),
+        ];
+        issues[1].impact = Some(Impact::Low);
+        attach_metadata(&mut issues);
+
+        assert_eq!(issues[0].estimated_effort, Some(Effort::Trivial));
+        assert_eq!(issues[0].impact, Some(Impact::High));
+        assert!(issues[0].is_quick_win());
+        assert_eq!(issues[1].impact, Some(Impact::Low));
+        // Unlisted: High security issue that is not auto-fixable
+        assert_eq!(issues[2].estimated_effort, Some(Effort::Large));
+        assert_eq!(issues[2].impact, Some(Impact::High));
+    }
+
+    #[test]
+    fn test_attach_keeps_analyzer_links_first_without_duplicates() {
+        let mut issues = vec![
+            make_issue("CHG-001", vec!["https://keepachangelog.com/en/1.1.0/".to_string()]),
+            make_issue("RB-001", vec!["https://example.com/advisory".to_string()]),
+            make_issue("ACME-001", vec![]),
+        ];
+        attach_references(&mut issues);
+
+        assert_eq!(issues[0].references, vec!["https://keepachangelog.com/en/1.1.0/"]);
+        assert_eq!(issues[1].references[0], "https://example.com/advisory");
+        assert!(issues[1].references.contains(&"https://bundler.io/guides/faq.html".to_string()));
+        assert!(issues[2].references.is_empty());
+    }
+}
@@ -0,0 +1,231 @@
+//! Software bill of materials built from lock files.
+//!
+//! Both formats list the project as the described component and every locked
+//! package with its version, package URL and, where the lock file records
+//! one, its archive checksum. Licenses are not in lock files and are left as
+//! `NOASSERTION` (SPDX) or omitted (CycloneDX).
+
+use crate::core::baseline;
+use crate::core::lockfiles::LockedPackage;
+
+/// The project an SBOM describes.
+pub struct Subject {
+    pub name: String,
+    pub version: Option<String>,
+}
+
+/// CycloneDX 1.5 JSON document.
+pub fn cyclonedx(
+    subject: &Subject,
+    packages: &[LockedPackage],
+    timestamp: &str,
+) -> serde_json::Value {
+    let mut root = serde_json::json!({
+        "type": "application",
+        "bom-ref": "project",
+        "name": subject.name,
+    });
+    if let Some(version) = &subject.version {
+        root["version"] = version.as_str().into();
+    }
+
+    let components: Vec<_> = packages
+        .iter()
+        .map(|package| {
+            let mut component = serde_json::json!({
+                "type": "library",
+                "bom-ref": package.purl(),
+                "name": package.name,
+                "version": package.version,
+                "purl": package.purl(),
+                // Development dependencies do not ship with the application
+                "scope": if package.dev { "excluded" } else { "required" },
+                "properties": [{ "name": "repodoctor:lockfile", "value": package.lockfile }],
+            });
+            if let Some(checksum) = &package.checksum {
+                component["hashes"] = serde_json::json!([{
+                    "alg": checksum.algorithm,
+                    "content": checksum.value,
+                }]);
+            }
+            component
+        })
+        .collect();
+
+    serde_json::json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "metadata": {
+            "timestamp": timestamp,
+            "tools": {
+                "components": [{
+                    "type": "application",
+                    "name": "repodoctor",
+                    "version": env!("CARGO_PKG_VERSION"),
+                }],
+            },
+            "component": root,
+        },
+        "components": components,
+        "dependencies": [{
+            "ref": "project",
+            "dependsOn": packages.iter().map(|p| p.purl()).collect::<Vec<_>>(),
+        }],
+    })
+}
+
+/// SPDX 2.3 JSON document.
+pub fn spdx(subject: &Subject, packages: &[LockedPackage], timestamp: &str) -> serde_json::Value {
+    let mut root = serde_json::json!({
+        "SPDXID": "SPDXRef-Project",
+        "name": subject.name,
+        "downloadLocation": "NOASSERTION",
+        "filesAnalyzed": false,
+        "primaryPackagePurpose": "APPLICATION",
+    });
+    if let Some(version) = &subject.version {
+        root["versionInfo"] = version.as_str().into();
+    }
+
+    let mut spdx_packages = vec![root];
+    let mut relationships = vec![serde_json::json!({
+        "spdxElementId": "SPDXRef-DOCUMENT",
+        "relationshipType": "DESCRIBES",
+        "relatedSpdxElement": "SPDXRef-Project",
+    })];
+    for (i, package) in packages.iter().enumerate() {
+        let id = format!("SPDXRef-Package-{}", i + 1);
+        let mut entry = serde_json::json!({
+            "SPDXID": id,
+            "name": package.name,
+            "versionInfo": package.version,
+            "downloadLocation": "NOASSERTION",
+            "filesAnalyzed": false,
+            "licenseConcluded": "NOASSERTION",
+            "licenseDeclared": "NOASSERTION",
+            "copyrightText": "NOASSERTION",
+            "externalRefs": [{
+                "referenceCategory": "PACKAGE-MANAGER",
+                "referenceType": "purl",
+                "referenceLocator": package.purl(),
+            }],
+        });
+        if let Some(checksum) = &package.checksum {
+            entry["checksums"] = serde_json::json!([{
+                "algorithm": checksum.algorithm.replace('-', ""),
+                "checksumValue": checksum.value,
+            }]);
+        }
+        spdx_packages.push(entry);
+        relationships.push(if package.dev {
+            serde_json::json!({
+                "spdxElementId": id,
+                "relationshipType": "DEV_DEPENDENCY_OF",
+                "relatedSpdxElement": "SPDXRef-Project",
+            })
+        } else {
+            serde_json::json!({
+                "spdxElementId": "SPDXRef-Project",
+                "relationshipType": "DEPENDS_ON",
+                "relatedSpdxElement": id,
+            })
+        });
+    }
+
+    // Unique per project and generation time, as the specification requires
+    let namespace = format!(
+        "https://spdx.org/spdxdocs/{}-{}",
+        subject
+            .name
+            .replace(|c: char| !c.is_ascii_alphanumeric() && c != '-', "-"),
+        baseline::hash(&format!("{}|{}", subject.name, timestamp))
+    );
+
+    serde_json::json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": subject.name,
+        "documentNamespace": namespace,
+        "creationInfo": {
+            "created": timestamp,
+            "creators": [format!("Tool: repodoctor-{}", env!("CARGO_PKG_VERSION"))],
+        },
+        "packages": spdx_packages,
+        "relationships": relationships,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::lockfiles::{Checksum, Ecosystem};
+
+    fn make_package(name: &str, dev: bool) -> LockedPackage {
+        LockedPackage {
+            ecosystem: Ecosystem::Cargo,
+            name: name.to_string(),
+            version: "1.0.0".to_string(),
+            lockfile: "Cargo.lock",
+            dev,
+            checksum: Some(Checksum {
+                algorithm: "SHA-256",
+                value: "abc123".to_string(),
+            }),
+        }
+    }
+
+    fn subject() -> Subject {
+        Subject {
+            name: "billing api".to_string(),
+            version: Some("2.1.0".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_cyclonedx_document() {
+        let packages = [make_package("serde", false), make_package("insta", true)];
+        let bom = cyclonedx(&subject(), &packages, "2025-01-09T10:00:00Z");
+
+        assert_eq!(bom["bomFormat"], "CycloneDX");
+        assert_eq!(bom["metadata"]["component"]["version"], "2.1.0");
+        assert_eq!(bom["components"].as_array().unwrap().len(), 2);
+        assert_eq!(bom["components"][0]["purl"], "pkg:cargo/serde@1.0.0");
+        assert_eq!(bom["components"][0]["scope"], "required");
+        assert_eq!(bom["components"][1]["scope"], "excluded");
+        assert_eq!(bom["components"][0]["hashes"][0]["alg"], "SHA-256");
+        assert_eq!(
+            bom["dependencies"][0]["dependsOn"][1],
+            "pkg:cargo/insta@1.0.0"
+        );
+    }
+
+    #[test]
+    fn test_spdx_document() {
+        let packages = [make_package("serde", false), make_package("insta", true)];
+        let doc = spdx(&subject(), &packages, "2025-01-09T10:00:00Z");
+
+        assert_eq!(doc["spdxVersion"], "SPDX-2.3");
+        assert!(doc["documentNamespace"]
+            .as_str()
+            .unwrap()
+            .starts_with("https://spdx.org/spdxdocs/billing-api-"));
+        // The project plus one entry per package
+        assert_eq!(doc["packages"].as_array().unwrap().len(), 3);
+        assert_eq!(doc["packages"][1]["checksums"][0]["algorithm"], "SHA256");
+        assert_eq!(
+            doc["packages"][1]["externalRefs"][0]["referenceLocator"],
+            "pkg:cargo/serde@1.0.0"
+        );
+        assert_eq!(doc["relationships"][1]["relationshipType"], "DEPENDS_ON");
+        assert_eq!(
+            doc["relationships"][2]["relationshipType"],
+            "DEV_DEPENDENCY_OF"
+        );
+        assert_eq!(
+            doc["relationships"][2]["spdxElementId"],
+            "SPDXRef-Package-2"
+        );
+    }
+}
@@ -1,10 +1,23 @@
 use anyhow::Result;
+use serde::Serialize;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use crate::analyzers::traits::{Analyzer, Issue};
-use crate::core::config::Config;
+use crate::core::allowlist::SecretAllowlist;
+use crate::core::baseline::{self, Baseline};
+use crate::core::config::{Config, ExpiringSuppression};
+use crate::core::content::{ContentEngine, ContentFile, ContentMatcher, SkippedFile};
+use crate::core::metrics::RepoMetrics;
+use crate::core::policy::{OrgPolicy, PolicyReport};
+use crate::core::profile::Profile;
 use crate::core::project::Project;
+use crate::core::project_type::ProjectType;
+use crate::core::rules;
 use crate::core::score::HealthScore;
+use crate::core::telemetry::{self, Span};
+use crate::core::themes::{self, Theme};
+use crate::utils::fs::{measure_io, IoStats};
 
 #[derive(Debug, Clone)]
 pub struct ScanResult {
@@ -12,15 +25,102 @@ pub struct ScanResult {
     pub issues: Vec<Issue>,
     pub score: HealthScore,
     pub duration: Duration,
+    /// Suppressions that have expired or expire soon
+    pub expiring: Vec<ExpiringSuppression>,
+    /// Organization policy results, when a policy was supplied
+    pub policy: Option<PolicyReport>,
+    /// Files content checks skipped or only partially read
+    pub skipped_files: Vec<SkippedFile>,
+    /// Cost of each analyzer that ran, in run order; empty for cached results
+    pub stats: Vec<AnalyzerStats>,
+    /// Size and composition of the repository
+    pub metrics: RepoMetrics,
+}
+
+/// Wall time and file system work of one analyzer, or of the shared content
+/// pass (`content`) that runs the analyzers' file content checks.
+#[derive(Debug, Clone, Serialize)]
+pub struct AnalyzerStats {
+    pub analyzer: String,
+    #[serde(rename = "duration_ms", serialize_with = "serialize_millis")]
+    pub duration: Duration,
+    #[serde(flatten)]
+    pub io: IoStats,
+    /// Issues reported before filtering
+    pub issues: usize,
+}
+
+fn serialize_millis<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+}
+
+impl ScanResult {
+    /// Related issues grouped into themes for reporting.
+    pub fn themes(&self) -> Vec<Theme> {
+        themes::correlate(&self.issues)
+    }
 }
 
 pub struct Scanner {
-    analyzers: Vec<Box<dyn Analyzer>>,
+    analyzers: Vec<Arc<dyn Analyzer>>,
+    profile: Option<Profile>,
+    use_baseline: bool,
+    policy: Option<OrgPolicy>,
+    only: Option<Vec<String>>,
 }
 
 impl Scanner {
     pub fn new(analyzers: Vec<Box<dyn Analyzer>>) -> Self {
-        Self { analyzers }
+        Self {
+            analyzers: analyzers.into_iter().map(Arc::from).collect(),
+            profile: None,
+            use_baseline: true,
+            policy: None,
+            only: None,
+        }
+    }
+
+    /// Use `profile` instead of the one pinned in `.repodoctor.yml`.
+    pub fn with_profile(mut self, profile: Option<Profile>) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Whether to hide issues recorded in `.repodoctor-baseline.json` (on by default).
+    pub fn with_baseline(mut self, use_baseline: bool) -> Self {
+        self.use_baseline = use_baseline;
+        self
+    }
+
+    /// Enforce an organization policy on top of the project configuration.
+    pub fn with_policy(mut self, policy: Option<OrgPolicy>) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Run only the analyzers with these names (all of them by default).
+    pub fn with_only(mut self, only: Option<Vec<String>>) -> Self {
+        self.only = only;
+        self
+    }
+
+    fn selected(&self, analyzer: &dyn Analyzer) -> bool {
+        self.only
+            .as_ref()
+            .is_none_or(|only| only.iter().any(|name| name == analyzer.name()))
+    }
+
+    /// The policy, if it makes any rule mandatory.
+    fn mandatory_policy(&self) -> Option<&OrgPolicy> {
+        self.policy
+            .as_ref()
+            .filter(|policy| !policy.mandatory_rules.is_empty())
+    }
+
+    /// Run an extra analyzer after the default ones.
+    pub fn with_analyzer(mut self, analyzer: Box<dyn Analyzer>) -> Self {
+        self.analyzers.push(Arc::from(analyzer));
+        self
     }
 
     pub async fn scan(&self, project: &Project) -> Result<ScanResult> {
@@ -33,35 +133,290 @@ impl Scanner {
         on_analyzer: F,
     ) -> Result<ScanResult> {
         let start = Instant::now();
+        let mut root_span = telemetry::root_span("scan");
+        if let Some(span) = &mut root_span {
+            span.set_attribute(
+                "repodoctor.project",
+                crate::utils::fs::display_path(&project.path),
+            );
+            span.set_attribute("repodoctor.framework", project.detected.framework.to_string());
+        }
         let config = Config::load(&project.path);
         let mut all_issues: Vec<Issue> = Vec::new();
+        let mut content = ContentEngine::default();
+        let mut skipped_files = Vec::new();
+        let mut stats = Vec::new();
 
+        let analyze_span = child_span(&root_span, "analyze");
         for analyzer in &self.analyzers {
-            if analyzer.applies_to(project) {
+            // Analyzers left out by `--only` still run for mandatory policy rules
+            let selected = self.selected(analyzer.as_ref());
+            if (selected || self.mandatory_policy().is_some()) && analyzer.applies_to(project) {
                 on_analyzer(analyzer.name());
-                let issues = analyzer.analyze(project).await?;
+                let mut span = child_span(&analyze_span, &format!("analyzer {}", analyzer.name()));
+                let started = Instant::now();
+                let outcome = analyze_blocking(analyzer.clone(), project.clone()).await;
+                if let Some(span) = &mut span {
+                    span.set_attribute("repodoctor.analyzer", analyzer.name());
+                    match &outcome {
+                        Ok((issues, _, _)) => span.set_attribute("repodoctor.issues", issues.len()),
+                        Err(_) => span.set_error(),
+                    }
+                }
+                end_span(span);
+                let (mut issues, mut matchers, io) = outcome?;
+                if let (false, Some(policy)) = (selected, self.mandatory_policy()) {
+                    issues.retain(|issue| policy.is_mandatory(&issue.id));
+                    matchers = matchers
+                        .into_iter()
+                        .map(|matcher| {
+                            Box::new(MandatoryOnly {
+                                matcher,
+                                policy: policy.clone(),
+                            }) as Box<dyn ContentMatcher>
+                        })
+                        .collect();
+                }
+                stats.push(AnalyzerStats {
+                    analyzer: analyzer.name().to_string(),
+                    duration: started.elapsed(),
+                    io,
+                    issues: issues.len(),
+                });
                 all_issues.extend(issues);
+                content.register(matchers);
+            }
+        }
+        end_span(analyze_span);
+
+        // Read every file once for all content checks
+        if !content.is_empty() {
+            on_analyzer("content");
+            let mut span = child_span(&root_span, "content");
+            let root = project.path.clone();
+            let started = Instant::now();
+            let (scan, io) =
+                tokio::task::spawn_blocking(move || measure_io(|| content.run(&root))).await?;
+            stats.push(AnalyzerStats {
+                analyzer: "content".to_string(),
+                duration: started.elapsed(),
+                io,
+                issues: scan.issues.len(),
+            });
+            if let Some(span) = &mut span {
+                span.set_attribute("repodoctor.issues", scan.issues.len());
+                span.set_attribute("repodoctor.skipped_files", scan.skipped.len());
             }
+            end_span(span);
+            all_issues.extend(scan.issues);
+            skipped_files = scan.skipped;
+            skipped_files.sort_by(|a, b| a.file.cmp(&b.file));
+        }
+
+        // Size and composition, for context next to the issues
+        on_analyzer("metrics");
+        let root = project.path.clone();
+        let started = Instant::now();
+        let (metrics, io) = tokio::task::spawn_blocking(move || {
+            measure_io(|| {
+                let mut metrics = RepoMetrics::collect(&root);
+                metrics.track_history(&root);
+                metrics
+            })
+        })
+        .await?;
+        stats.push(AnalyzerStats {
+            analyzer: "metrics".to_string(),
+            duration: started.elapsed(),
+            io,
+            issues: 0,
+        });
+
+        rules::attach_references(&mut all_issues);
+
+        let filter_span = child_span(&root_span, "filter");
+
+        // Split off organization-mandated issues; profiles, project config and
+        // the baseline cannot hide or downgrade them
+        let mut violations = match &self.policy {
+            Some(policy) => {
+                let (violations, rest) = policy.partition(all_issues);
+                all_issues = rest;
+                violations
+            }
+            None => Vec::new(),
+        };
+
+        // Calibrate severities for what the project ships
+        let project_type = config
+            .project_type()
+            .unwrap_or_else(|| ProjectType::guess(&project.path, &project.detected.framework));
+        all_issues = project_type.apply(all_issues, &project.path);
+
+        // Adjust severities for the selected strictness profile
+        if let Some(profile) = self.profile.or_else(|| config.profile()) {
+            all_issues = profile.apply(all_issues);
         }
 
         // Apply config filters (severity threshold, ignored rules/paths)
         all_issues = config.filter_issues(all_issues);
 
-        // Sort issues by severity (Critical first)
-        all_issues.sort_by(|a, b| b.severity.cmp(&a.severity));
+        // Hide secrets accepted as false positives; an unreadable allowlist
+        // allows nothing
+        if let Some(allowlist) = SecretAllowlist::load(&project.path).ok().flatten() {
+            all_issues = allowlist.filter(all_issues, &project.path);
+        }
+
+        // Fingerprint before the baseline hides anything, so each occurrence
+        // keeps the number `baseline write` recorded for it
+        sort_issues(&mut all_issues);
+        baseline::assign_fingerprints(&mut all_issues, &project.path);
+
+        // Hide issues accepted in the baseline
+        let today = crate::utils::date::today();
+        let mut expiring = config.expiring_suppressions(today);
+        if self.use_baseline {
+            if let Some(baseline) = Baseline::load(&project.path)? {
+                all_issues = baseline.filter(all_issues, &project.path, today);
+                expiring.extend(baseline.expiring(today));
+            }
+        }
+        expiring.sort_by_key(|e| e.days_left);
+
+        sort_issues(&mut violations);
+        baseline::assign_fingerprints(&mut violations, &project.path);
+        all_issues.extend(violations.iter().cloned());
+        end_span(filter_span);
+
+        // Sort deterministically so output does not depend on directory walk order
+        let score_span = child_span(&root_span, "score");
+        sort_issues(&mut all_issues);
+        // Estimated from final severities, so after profiles and calibration
+        rules::attach_metadata(&mut all_issues);
 
         let score = HealthScore::calculate(&all_issues);
+        end_span(score_span);
         let duration = start.elapsed();
 
-        Ok(ScanResult {
+        let result = ScanResult {
             project: project.clone(),
             issues: all_issues,
             score,
             duration,
-        })
+            expiring,
+            policy: self.policy.as_ref().map(|p| PolicyReport {
+                name: p.display_name().to_string(),
+                violations,
+            }),
+            skipped_files,
+            stats,
+            metrics,
+        };
+        telemetry::record_scan(&result);
+        if let Some(span) = &mut root_span {
+            span.set_attribute("repodoctor.issues", result.issues.len());
+            span.set_attribute("repodoctor.health_score", usize::from(result.score.total));
+        }
+        end_span(root_span);
+        Ok(result)
+    }
+}
+
+/// Content checks of an analyzer left out by `--only`, reduced to the rules
+/// an organization policy makes mandatory.
+struct MandatoryOnly {
+    matcher: Box<dyn ContentMatcher>,
+    policy: OrgPolicy,
+}
+
+impl MandatoryOnly {
+    fn keep_mandatory(&self, issues: &mut Vec<Issue>, from: usize) {
+        let reported = issues.split_off(from);
+        issues.extend(
+            reported
+                .into_iter()
+                .filter(|issue| self.policy.is_mandatory(&issue.id)),
+        );
+    }
+}
+
+impl ContentMatcher for MandatoryOnly {
+    fn skips_dir(&self, name: &str) -> bool {
+        self.matcher.skips_dir(name)
+    }
+
+    fn wants(&self, relative: &str) -> bool {
+        self.matcher.wants(relative)
+    }
+
+    fn begin_file(&mut self, file: &ContentFile, issues: &mut Vec<Issue>) -> bool {
+        let from = issues.len();
+        let read_lines = self.matcher.begin_file(file, issues);
+        self.keep_mandatory(issues, from);
+        read_lines
+    }
+
+    fn check_line(
+        &mut self,
+        file: &ContentFile,
+        line_num: usize,
+        line: &str,
+        issues: &mut Vec<Issue>,
+    ) -> bool {
+        let from = issues.len();
+        let more = self.matcher.check_line(file, line_num, line, issues);
+        self.keep_mandatory(issues, from);
+        more
+    }
+
+    fn is_done(&self) -> bool {
+        self.matcher.is_done()
+    }
+}
+
+fn child_span(parent: &Option<Span>, name: &str) -> Option<Span> {
+    parent.as_ref().map(|p| p.child(name))
+}
+
+fn end_span(span: Option<Span>) {
+    if let Some(span) = span {
+        span.end();
     }
 }
 
+/// Order issues by severity (Critical first), then analyzer, file, line, and rule ID.
+fn sort_issues(issues: &mut [Issue]) {
+    issues.sort_by(|a, b| {
+        b.severity
+            .cmp(&a.severity)
+            .then_with(|| a.analyzer.cmp(&b.analyzer))
+            .then_with(|| a.file.cmp(&b.file))
+            .then_with(|| a.line.cmp(&b.line))
+            .then_with(|| a.id.cmp(&b.id))
+            .then_with(|| a.column.cmp(&b.column))
+            .then_with(|| a.title.cmp(&b.title))
+    });
+}
+
+/// Analyzers walk and read files synchronously; run them on the blocking pool
+/// so a huge repository doesn't stall the async runtime. The analyzer runs
+/// entirely on that thread, which is what its [`IoStats`] count.
+async fn analyze_blocking(
+    analyzer: Arc<dyn Analyzer>,
+    project: Project,
+) -> Result<(Vec<Issue>, Vec<Box<dyn ContentMatcher>>, IoStats)> {
+    let handle = tokio::runtime::Handle::current();
+    tokio::task::spawn_blocking(move || {
+        let (outcome, io) = measure_io(|| -> Result<_> {
+            let issues = handle.block_on(analyzer.analyze(&project))?;
+            Ok((issues, analyzer.content_matchers(&project)))
+        });
+        let (issues, matchers) = outcome?;
+        Ok((issues, matchers, io))
+    })
+    .await?
+}
+
 pub fn default_scanner() -> Scanner {
     let analyzers: Vec<Box<dyn Analyzer>> = vec![
         Box::new(crate::analyzers::StructureAnalyzer),
@@ -70,11 +425,22 @@ pub fn default_scanner() -> Scanner {
         Box::new(crate::analyzers::SecurityAnalyzer),
         Box::new(crate::analyzers::TestingAnalyzer),
         Box::new(crate::analyzers::DocumentationAnalyzer),
+        Box::new(crate::analyzers::ChangelogAnalyzer),
+        Box::new(crate::analyzers::CommitsAnalyzer),
         Box::new(crate::analyzers::SymfonyAnalyzer),
         Box::new(crate::analyzers::FlutterAnalyzer),
         Box::new(crate::analyzers::NextJsAnalyzer),
         Box::new(crate::analyzers::LaravelAnalyzer),
         Box::new(crate::analyzers::RustCargoAnalyzer),
+        Box::new(crate::analyzers::BazelAnalyzer),
+        Box::new(crate::analyzers::JvmAnalyzer),
+        Box::new(crate::analyzers::RubyAnalyzer),
+        Box::new(crate::analyzers::ShellAnalyzer),
+        Box::new(crate::analyzers::NotebookAnalyzer),
+        Box::new(crate::analyzers::PortabilityAnalyzer),
+        Box::new(crate::analyzers::ContainerImagesAnalyzer),
+        Box::new(crate::analyzers::CustomRulesAnalyzer::default()),
+        Box::new(crate::analyzers::FilePolicyAnalyzer),
     ];
     Scanner::new(analyzers)
 }
@@ -132,6 +498,64 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_scanner_attaches_rule_references() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+        let result = default_scanner().scan(&project).await.unwrap();
+        assert!(!result.issues.is_empty());
+        for issue in &result.issues {
+            assert!(!issue.references.is_empty(), "{} has no references", issue.id);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scanner_assigns_effort_and_impact() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+        let result = default_scanner().scan(&project).await.unwrap();
+        assert!(result
+            .issues
+            .iter()
+            .all(|i| i.estimated_effort.is_some() && i.impact.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_scanner_output_is_deterministic() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir(tmp.path().join("src")).unwrap();
+        fs::write(
+            tmp.path().join("src/config.js"),
+            "const api_key = \"abcdefghijklmnopqrstuvwxyz\";\n\
+             const secret = \"abcdefghijklmnopqrstuvwxyz\";\n",
+        )
+        .unwrap();
+        let project = make_project(&tmp);
+        let scanner = default_scanner();
+
+        let first = scanner.scan(&project).await.unwrap();
+        let second = scanner.scan(&project).await.unwrap();
+        let keys = |issues: &[Issue]| {
+            issues
+                .iter()
+                .map(|i| (i.id.clone(), i.line, i.fingerprint.clone().unwrap()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(keys(&first.issues), keys(&second.issues));
+
+        let mut prints: Vec<_> = first.issues.iter().map(|i| i.fingerprint.clone()).collect();
+        prints.sort();
+        prints.dedup();
+        assert_eq!(prints.len(), first.issues.len());
+
+        for window in first.issues.windows(2) {
+            let (a, b) = (&window[0], &window[1]);
+            if a.severity == b.severity && a.analyzer == b.analyzer && a.file == b.file {
+                assert!(a.line <= b.line);
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_scanner_respects_config_severity_threshold() {
         let tmp = TempDir::new().unwrap();
@@ -160,6 +584,44 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_scanner_records_analyzer_stats() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir(tmp.path().join("src")).unwrap();
+        let manifest = "[package]\nname = \"demo\"\n";
+        fs::write(tmp.path().join("Cargo.toml"), manifest).unwrap();
+        fs::write(tmp.path().join("src/main.rs"), "fn main() {}\n").unwrap();
+        let project = make_project(&tmp);
+        let result = default_scanner().scan(&project).await.unwrap();
+
+        let stats = |name: &str| result.stats.iter().find(|s| s.analyzer == name).unwrap();
+        assert!(stats("structure").io.files_walked >= 2);
+        assert!(stats("rust_cargo").io.bytes_read >= manifest.len() as u64);
+        // Content checks are counted once, in the shared pass, followed by
+        // the repository metrics
+        let order: Vec<&str> = result.stats.iter().rev().take(2).map(|s| s.analyzer.as_str()).collect();
+        assert_eq!(order, ["metrics", "content"]);
+        assert!(stats("content").io.files_read >= 2);
+        assert_eq!(stats("metrics").io.files_read, 2);
+        assert_eq!((result.metrics.files, result.metrics.lines), (2, 3));
+        let reported: usize = result.stats.iter().map(|s| s.issues).sum();
+        assert!(reported >= result.issues.len());
+    }
+
+    #[tokio::test]
+    async fn test_scanner_runs_only_selected_analyzers() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+        let result = default_scanner()
+            .with_only(Some(vec!["structure".to_string()]))
+            .scan(&project)
+            .await
+            .unwrap();
+        let ran: Vec<&str> = result.stats.iter().map(|s| s.analyzer.as_str()).collect();
+        assert_eq!(ran, ["structure", "metrics"]);
+        assert!(result.issues.iter().all(|i| i.analyzer == "structure"));
+    }
+
     #[tokio::test]
     async fn test_scan_with_progress() {
         let tmp = TempDir::new().unwrap();
@@ -179,6 +641,168 @@ mod tests {
         assert!(!collected.is_empty(), "Progress callback should have been called");
     }
 
+    #[tokio::test]
+    async fn test_scanner_applies_profile() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+
+        let baseline = default_scanner().scan(&project).await.unwrap();
+        assert!(baseline.issues.iter().any(|i| i.id == "DOC-003"));
+
+        let relaxed = default_scanner()
+            .with_profile(Some(Profile::Relaxed))
+            .scan(&project)
+            .await
+            .unwrap();
+        assert!(!relaxed.issues.iter().any(|i| i.id == "DOC-003"));
+        assert!(relaxed.issues.iter().any(|i| i.id == "STR-004"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_scanner_on_multi_thread_runtime() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+        std::fs::write(tmp.path().join("config.yml"), "api_key: \"abcdefghijklmnop1234\"\n")
+            .unwrap();
+
+        let result = default_scanner().scan(&project).await.unwrap();
+        assert!(result.issues.iter().any(|i| i.id == "SEC-001"));
+    }
+
+    #[tokio::test]
+    async fn test_scanner_with_drift_analyzer() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+        let template = TempDir::new().unwrap();
+        std::fs::write(template.path().join("CODEOWNERS"), "* @platform\n").unwrap();
+
+        let analyzer =
+            crate::analyzers::DriftAnalyzer::from_source(&template.path().to_string_lossy())
+                .unwrap();
+        let result = default_scanner()
+            .with_analyzer(Box::new(analyzer))
+            .scan(&project)
+            .await
+            .unwrap();
+        assert!(result.issues.iter().any(|i| i.id == "DRF-001"));
+    }
+
+    #[tokio::test]
+    async fn test_scanner_hides_baselined_issues() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+
+        let full = default_scanner().scan(&project).await.unwrap();
+        let license: Vec<Issue> = full
+            .issues
+            .iter()
+            .filter(|i| i.id == "STR-004")
+            .cloned()
+            .collect();
+        assert!(!license.is_empty());
+        Baseline::from_issues(&license, &project.path)
+            .save(&project.path)
+            .unwrap();
+
+        let result = default_scanner().scan(&project).await.unwrap();
+        assert!(!result.issues.iter().any(|i| i.id == "STR-004"));
+        assert_eq!(result.issues.len(), full.issues.len() - license.len());
+
+        let unfiltered = default_scanner()
+            .with_baseline(false)
+            .scan(&project)
+            .await
+            .unwrap();
+        assert_eq!(unfiltered.issues.len(), full.issues.len());
+    }
+
+    #[tokio::test]
+    async fn test_policy_overrides_project_ignores() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+        fs::write(
+            tmp.path().join(".repodoctor.yml"),
+            "severity_threshold: critical\nignore:\n  rules:\n    - STR-004\n",
+        )
+        .unwrap();
+        let policy: OrgPolicy = serde_yaml::from_str(
+            "name: ACME\nmandatory_rules:\n  - id: STR-004\n    min_severity: high\n",
+        )
+        .unwrap();
+
+        let result = default_scanner()
+            .with_policy(Some(policy))
+            .scan(&project)
+            .await
+            .unwrap();
+        let report = result.policy.unwrap();
+        assert_eq!(report.name, "ACME");
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(
+            report.violations[0].severity,
+            crate::analyzers::traits::Severity::High
+        );
+        assert!(result.issues.iter().any(|i| i.id == "STR-004"));
+    }
+
+    #[tokio::test]
+    async fn test_policy_survives_only_and_allowlist() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+        fs::write(tmp.path().join("app.yml"), "password: 'example-password'\n").unwrap();
+        let mut allowlist = SecretAllowlist::default();
+        allowlist.add("app.yml", "password: 'example-password'", None);
+        allowlist.save(tmp.path()).unwrap();
+        let policy: OrgPolicy = serde_yaml::from_str("mandatory_rules:\n  - SEC-001\n").unwrap();
+
+        let result = default_scanner()
+            .with_only(Some(vec!["structure".to_string()]))
+            .with_policy(Some(policy))
+            .scan(&project)
+            .await
+            .unwrap();
+        let violations = result.policy.unwrap().violations;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].id, "SEC-001");
+        // Analyzers outside --only contribute nothing but mandatory rules
+        assert!(result
+            .issues
+            .iter()
+            .all(|i| i.analyzer == "structure" || i.id == "SEC-001"));
+    }
+
+    #[tokio::test]
+    async fn test_allowlisted_secret_is_not_reported() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp);
+        fs::write(
+            tmp.path().join("fixtures.yml"),
+            "password: 'example-password'\npassword: 'hunter2-prod'\n",
+        )
+        .unwrap();
+        let mut allowlist = SecretAllowlist::default();
+        allowlist.add("fixtures.yml", "password: 'example-password'", None);
+        allowlist.save(tmp.path()).unwrap();
+
+        let scanner = default_scanner().with_only(Some(vec!["security".to_string()]));
+        let result = scanner.scan(&project).await.unwrap();
+        let lines: Vec<_> = result
+            .issues
+            .iter()
+            .filter(|i| i.id == "SEC-001")
+            .map(|i| i.line)
+            .collect();
+        assert_eq!(lines, [Some(2)]);
+
+        // The same value in another file is still reported
+        fs::rename(tmp.path().join("fixtures.yml"), tmp.path().join("app.yml")).unwrap();
+        let result = scanner.scan(&project).await.unwrap();
+        assert_eq!(
+            result.issues.iter().filter(|i| i.id == "SEC-001").count(),
+            2
+        );
+    }
+
     #[tokio::test]
     async fn test_scanner_respects_config_ignored_rules() {
         let tmp = TempDir::new().unwrap();
@@ -12,6 +12,19 @@ pub enum Grade {
     F,
 }
 
+impl Grade {
+    /// Letter grade for a 0-100 score.
+    pub fn from_score(score: u8) -> Self {
+        match score {
+            90..=100 => Grade::A,
+            80..=89 => Grade::B,
+            70..=79 => Grade::C,
+            60..=69 => Grade::D,
+            _ => Grade::F,
+        }
+    }
+}
+
 impl std::fmt::Display for Grade {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -42,12 +55,14 @@ pub struct HealthScore {
 impl HealthScore {
     pub fn calculate(issues: &[Issue]) -> Self {
         let weights: HashMap<AnalyzerCategory, f64> = HashMap::from([
-            (AnalyzerCategory::Structure, 0.20),
+            (AnalyzerCategory::Structure, 0.15),
             (AnalyzerCategory::Dependencies, 0.20),
-            (AnalyzerCategory::Configuration, 0.15),
-            (AnalyzerCategory::Testing, 0.25),
+            (AnalyzerCategory::Configuration, 0.10),
+            (AnalyzerCategory::Testing, 0.20),
             (AnalyzerCategory::Security, 0.15),
             (AnalyzerCategory::Documentation, 0.05),
+            (AnalyzerCategory::Performance, 0.05),
+            (AnalyzerCategory::CiCd, 0.10),
         ]);
 
         let mut category_issues: HashMap<AnalyzerCategory, Vec<&Issue>> = HashMap::new();
@@ -69,6 +84,8 @@ impl HealthScore {
             AnalyzerCategory::Testing,
             AnalyzerCategory::Security,
             AnalyzerCategory::Documentation,
+            AnalyzerCategory::Performance,
+            AnalyzerCategory::CiCd,
         ];
 
         for category in &categories {
@@ -108,13 +125,7 @@ impl HealthScore {
             100
         };
 
-        let grade = match total {
-            90..=100 => Grade::A,
-            80..=89 => Grade::B,
-            70..=79 => Grade::C,
-            60..=69 => Grade::D,
-            _ => Grade::F,
-        };
+        let grade = Grade::from_score(total);
 
         HealthScore {
             total,
@@ -138,6 +149,11 @@ mod tests {
             description: "Test issue".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: None,
             auto_fixable: false,
             references: vec![],
@@ -212,7 +228,18 @@ mod tests {
     #[test]
     fn test_breakdown_has_all_categories() {
         let score = HealthScore::calculate(&[]);
-        assert_eq!(score.breakdown.len(), 6);
+        assert_eq!(score.breakdown.len(), 8);
+        assert!(score.breakdown.iter().any(|b| b.name == "Performance"));
+        assert!(score.breakdown.iter().any(|b| b.name == "CI/CD"));
+    }
+
+    #[test]
+    fn test_ci_issue_only_affects_ci_category() {
+        let score = HealthScore::calculate(&[make_issue(AnalyzerCategory::CiCd, Severity::High)]);
+        let ci = score.breakdown.iter().find(|b| b.name == "CI/CD").unwrap();
+        assert_eq!(ci.score, 85);
+        let structure = score.breakdown.iter().find(|b| b.name == "Structure").unwrap();
+        assert_eq!(structure.score, 100);
     }
 
     #[test]
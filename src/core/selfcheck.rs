@@ -0,0 +1,403 @@
+//! Environment diagnostics for `repodoctor selfcheck`.
+//!
+//! Each check covers one thing a scan depends on outside the project's own
+//! files (tools, tokens, network, writable directories, and user-supplied
+//! rules and templates) and says how to fix it when it is not usable.
+
+use serde::Serialize;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::analyzers::containers::ContainerImagesAnalyzer;
+use crate::analyzers::custom_rules::compile_rules;
+use crate::analyzers::GitHubSettingsAnalyzer;
+use crate::core::cache;
+use crate::core::config::Config;
+use crate::core::policy::OrgPolicy;
+use crate::core::project::Project;
+use crate::reporters::template::ReportTemplates;
+use crate::utils::{git, patterns};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    /// Works, but some features are unavailable
+    Warn,
+    /// Something the current setup relies on is broken
+    Fail,
+}
+
+/// Outcome of one check.
+#[derive(Debug, Clone, Serialize)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    pub detail: String,
+    /// What to do about a warning or failure
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+impl Check {
+    fn ok(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Ok,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Warn,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, hint: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: Status::Fail,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+/// Run every check for `project`. With `offline`, nothing is sent over the network.
+pub fn run(project: &Project, offline: bool) -> Vec<Check> {
+    let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+    let mut checks = vec![
+        check_git(),
+        check_cache(&project.path),
+        check_config(&project.path),
+        check_containers(project),
+        check_github(
+            offline,
+            var("REPODOCTOR_GITHUB_TOKEN")
+                .or_else(|| var("GITHUB_TOKEN"))
+                .is_some(),
+        ),
+    ];
+    if let Some(endpoint) = var("OTEL_EXPORTER_OTLP_ENDPOINT") {
+        checks.push(check_otlp(&endpoint, offline));
+    }
+    if let Some(dir) = var("REPODOCTOR_REPORT_TEMPLATE") {
+        checks.push(check_report_templates(Path::new(&dir)));
+    }
+    if let Some(source) = var("REPODOCTOR_POLICY") {
+        checks.push(check_policy(&source, offline));
+    }
+    checks
+}
+
+fn check_git() -> Check {
+    match Command::new("git").arg("--version").output() {
+        Ok(output) if output.status.success() => {
+            Check::ok("git", String::from_utf8_lossy(&output.stdout).trim().to_string())
+        }
+        Ok(output) => Check::fail(
+            "git",
+            format!("git --version failed: {}", String::from_utf8_lossy(&output.stderr).trim()),
+            "Reinstall git; commit checks, the report cache, --template, and git URLs in --batch need it",
+        ),
+        Err(_) => Check::fail(
+            "git",
+            "git is not installed or not on PATH",
+            "Install git and add it to PATH; commit checks, the report cache, --template, and git URLs in --batch need it",
+        ),
+    }
+}
+
+/// The report cache lives in the repository's `.git` directory.
+fn check_cache(project: &Path) -> Check {
+    let Some(dir) = cache::cache_dir(project) else {
+        return Check::warn(
+            "cache",
+            format!("{} is not a git checkout", project.display()),
+            "`report` only caches results for git repositories; run inside a clone to enable it",
+        );
+    };
+    let probe = dir.join(".selfcheck");
+    let writable = std::fs::create_dir_all(&dir).and_then(|_| std::fs::write(&probe, b""));
+    let _ = std::fs::remove_file(&probe);
+    match writable {
+        Ok(()) => Check::ok("cache", format!("{} is writable", dir.display())),
+        Err(err) => Check::fail(
+            "cache",
+            format!("Cannot write to {}: {}", dir.display(), err),
+            "Give this user write access to the .git directory, or pass --no-cache to `report`",
+        ),
+    }
+}
+
+/// `.repodoctor.yml` and the rules it defines. A broken file is otherwise
+/// ignored silently in favor of the defaults.
+fn check_config(project: &Path) -> Check {
+    let config = match Config::validate(project) {
+        Ok(Some(config)) => config,
+        Ok(None) => return Check::ok("config", "No .repodoctor.yml; using defaults"),
+        Err(err) => {
+            return Check::fail(
+                "config",
+                format!(".repodoctor.yml is invalid: {}", err),
+                "Fix the file; until then every setting in it is ignored and defaults are used",
+            )
+        }
+    };
+
+    let mut problems = Vec::new();
+    let mut invalid = Vec::new();
+    let rules = config.custom_rules.as_deref().unwrap_or_default();
+    compile_rules(rules, &mut invalid);
+    problems.extend(invalid.into_iter().map(|issue| issue.description));
+    if let Some(commits) = &config.commits {
+        for (key, pattern) in [
+            ("pattern", &commits.pattern),
+            ("issue_pattern", &commits.issue_pattern),
+        ] {
+            if let Some(Err(err)) = pattern.as_deref().map(patterns::compile) {
+                problems.push(format!("commits.{} is not a valid regex: {}", key, err));
+            }
+        }
+    }
+    if let Some(dir) = &config.templates_dir {
+        if !project.join(dir).is_dir() {
+            problems.push(format!("templates_dir {} does not exist", dir));
+        }
+    }
+
+    if problems.is_empty() {
+        Check::ok(
+            "config",
+            format!(".repodoctor.yml is valid ({} custom rule(s))", rules.len()),
+        )
+    } else {
+        Check::fail(
+            "config",
+            problems.join("; "),
+            "Fix these entries in .repodoctor.yml",
+        )
+    }
+}
+
+fn check_containers(project: &Project) -> Check {
+    let settings = ContainerImagesAnalyzer::settings(project);
+    match ContainerImagesAnalyzer::tool(&settings) {
+        Some((tool, Some(path))) => Check::ok(
+            "container scanner",
+            format!("{} found at {}", tool.program(), path.display()),
+        ),
+        Some((tool, None)) => Check::fail(
+            "container scanner",
+            format!(
+                "containers.scanner is {} but it is not on PATH",
+                tool.program()
+            ),
+            format!(
+                "Install {} or set containers.scanner to auto or none",
+                tool.program()
+            ),
+        ),
+        None if settings
+            .scanner
+            .is_some_and(|s| matches!(s.as_str(), "none" | "off" | "false")) =>
+        {
+            Check::ok("container scanner", "Disabled in .repodoctor.yml")
+        }
+        None => Check::warn(
+            "container scanner",
+            "Neither trivy nor grype is on PATH",
+            "Install trivy or grype to check container images for vulnerabilities (IMG-001)",
+        ),
+    }
+}
+
+fn check_github(offline: bool, has_token: bool) -> Check {
+    if !has_token {
+        return Check::warn(
+            "github",
+            "No token in REPODOCTOR_GITHUB_TOKEN or GITHUB_TOKEN",
+            "Set one of them to check repository settings with --online",
+        );
+    }
+    if offline {
+        return Check::ok("github", "Token set; API not contacted (--offline)");
+    }
+    let access = GitHubSettingsAnalyzer::from_env().and_then(|github| github.check_access());
+    match access {
+        Ok(detail) => Check::ok("github", detail),
+        Err(err) => Check::fail(
+            "github",
+            format!("{:#}", err),
+            "Check network access to the GitHub API (GITHUB_API_URL for GitHub Enterprise) and that the token is valid",
+        ),
+    }
+}
+
+/// Any HTTP response counts: the collector only has to be listening.
+fn check_otlp(endpoint: &str, offline: bool) -> Check {
+    if offline {
+        return Check::ok(
+            "telemetry",
+            format!("Exporting to {}; not contacted (--offline)", endpoint),
+        );
+    }
+    match ureq::get(endpoint).timeout(Duration::from_secs(5)).call() {
+        Ok(_) | Err(ureq::Error::Status(..)) => {
+            Check::ok("telemetry", format!("{} is reachable", endpoint))
+        }
+        Err(err) => Check::fail(
+            "telemetry",
+            format!("Cannot reach {}: {}", endpoint, err),
+            "Start the OpenTelemetry collector or fix OTEL_EXPORTER_OTLP_ENDPOINT; spans and metrics are dropped until then",
+        ),
+    }
+}
+
+fn check_report_templates(dir: &Path) -> Check {
+    match ReportTemplates::load(dir) {
+        Ok(_) => Check::ok("report templates", format!("{} loads", dir.display())),
+        Err(err) => Check::fail(
+            "report templates",
+            format!("{:#}", err),
+            "Fix the templates or unset REPODOCTOR_REPORT_TEMPLATE; `report` stops before scanning until then",
+        ),
+    }
+}
+
+fn check_policy(source: &str, offline: bool) -> Check {
+    if offline && git::is_git_url(source) {
+        return Check::ok("policy", format!("{}; not fetched (--offline)", source));
+    }
+    match OrgPolicy::load(source) {
+        Ok(policy) => Check::ok(
+            "policy",
+            format!("{} loads ({})", source, policy.display_name()),
+        ),
+        Err(err) => Check::fail(
+            "policy",
+            format!("{:#}", err),
+            "Fix REPODOCTOR_POLICY; `scan` and `report` refuse to run until the policy loads",
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework: Framework::Unknown,
+                language: Language::Unknown,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_missing_config_uses_defaults() {
+        let tmp = TempDir::new().unwrap();
+        assert_eq!(check_config(tmp.path()).status, Status::Ok);
+    }
+
+    #[test]
+    fn test_broken_config_fails_with_hint() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join(".repodoctor.yml"), "ignore: [unclosed\n").unwrap();
+        let check = check_config(tmp.path());
+        assert_eq!(check.status, Status::Fail);
+        assert!(check.detail.starts_with(".repodoctor.yml is invalid"));
+        assert!(check.hint.unwrap().contains("defaults are used"));
+    }
+
+    #[test]
+    fn test_invalid_rules_and_patterns_fail() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join(".repodoctor.yml"),
+            r#"
+custom_rules:
+  - id: CUSTOM-001
+    message: Broken
+    files: "**/*.rs"
+    pattern: "(unclosed"
+commits:
+  pattern: "[a-"
+templates_dir: missing/
+"#,
+        )
+        .unwrap();
+        let check = check_config(tmp.path());
+        assert_eq!(check.status, Status::Fail);
+        assert!(check.detail.contains("CUSTOM-001"));
+        assert!(check.detail.contains("commits.pattern"));
+        assert!(check.detail.contains("templates_dir missing/"));
+    }
+
+    #[test]
+    fn test_cache_outside_git_checkout_warns() {
+        let tmp = TempDir::new().unwrap();
+        assert_eq!(check_cache(tmp.path()).status, Status::Warn);
+    }
+
+    #[test]
+    fn test_cache_in_git_checkout_is_writable() {
+        let tmp = TempDir::new().unwrap();
+        let init = Command::new("git")
+            .arg("init")
+            .arg("-q")
+            .arg(tmp.path())
+            .status();
+        if !init.is_ok_and(|s| s.success()) {
+            return;
+        }
+        let check = check_cache(tmp.path());
+        assert_eq!(check.status, Status::Ok, "{}", check.detail);
+        let dir = cache::cache_dir(tmp.path()).unwrap();
+        assert!(!dir.join(".selfcheck").exists());
+    }
+
+    #[test]
+    fn test_disabled_container_scanner_is_ok() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(
+            tmp.path().join(".repodoctor.yml"),
+            "containers:\n  scanner: none\n",
+        )
+        .unwrap();
+        let check = check_containers(&make_project(&tmp));
+        assert_eq!(check.status, Status::Ok);
+        assert_eq!(check.detail, "Disabled in .repodoctor.yml");
+    }
+
+    #[test]
+    fn test_github_without_token_warns() {
+        let check = check_github(false, false);
+        assert_eq!(check.status, Status::Warn);
+        assert!(check.hint.unwrap().contains("--online"));
+        assert_eq!(check_github(true, true).status, Status::Ok);
+    }
+
+    #[test]
+    fn test_missing_report_templates_fail() {
+        let tmp = TempDir::new().unwrap();
+        let check = check_report_templates(&tmp.path().join("missing"));
+        assert_eq!(check.status, Status::Fail);
+        assert!(check.detail.contains("does not exist"));
+    }
+}
@@ -0,0 +1,521 @@
+//! OpenTelemetry export of scan spans and issue-count metrics.
+//!
+//! Spans and metrics are buffered in memory and sent once, at the end of the
+//! command, to an OTLP/HTTP endpoint using the JSON encoding. Nothing is
+//! recorded unless [`init`] was called with an endpoint.
+
+use once_cell::sync::OnceCell;
+use serde_json::{json, Value};
+use std::collections::hash_map::RandomState;
+use std::collections::BTreeMap;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::core::scanner::ScanResult;
+
+static TELEMETRY: OnceCell<Telemetry> = OnceCell::new();
+
+/// Where and what to export.
+#[derive(Debug, Clone)]
+pub struct ExportConfig {
+    /// Base OTLP/HTTP URL; `/v1/traces` and `/v1/metrics` are appended
+    pub endpoint: String,
+    /// Extra request headers, e.g. for authentication
+    pub headers: Vec<(String, String)>,
+    pub service_name: String,
+    /// Also export issue counts and scores as metrics
+    pub metrics: bool,
+}
+
+impl ExportConfig {
+    /// Build from the endpoint and the standard `OTEL_*` variables
+    /// (`OTEL_EXPORTER_OTLP_HEADERS`, `OTEL_SERVICE_NAME`).
+    pub fn from_env(endpoint: &str, metrics: bool) -> Self {
+        Self::from_vars(endpoint, metrics, |key| std::env::var(key).ok())
+    }
+
+    fn from_vars<F: Fn(&str) -> Option<String>>(endpoint: &str, metrics: bool, var: F) -> Self {
+        let headers = var("OTEL_EXPORTER_OTLP_HEADERS")
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .filter(|(key, _)| !key.is_empty())
+            .collect();
+        Self {
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            headers,
+            service_name: var("OTEL_SERVICE_NAME")
+                .filter(|name| !name.trim().is_empty())
+                .unwrap_or_else(|| "repodoctor".to_string()),
+            metrics,
+        }
+    }
+}
+
+/// Attribute value on a span or data point.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttrValue {
+    Str(String),
+    Int(i64),
+}
+
+impl From<&str> for AttrValue {
+    fn from(value: &str) -> Self {
+        AttrValue::Str(value.to_string())
+    }
+}
+
+impl From<String> for AttrValue {
+    fn from(value: String) -> Self {
+        AttrValue::Str(value)
+    }
+}
+
+impl From<usize> for AttrValue {
+    fn from(value: usize) -> Self {
+        AttrValue::Int(value as i64)
+    }
+}
+
+/// A finished span.
+#[derive(Debug, Clone)]
+pub struct SpanData {
+    pub trace_id: String,
+    pub span_id: String,
+    pub parent_span_id: Option<String>,
+    pub name: String,
+    pub start: SystemTime,
+    pub end: SystemTime,
+    pub attributes: Vec<(String, AttrValue)>,
+    pub error: bool,
+}
+
+/// A span in progress; call [`Span::end`] to record it.
+pub struct Span {
+    data: SpanData,
+}
+
+impl Span {
+    pub fn set_attribute(&mut self, key: &str, value: impl Into<AttrValue>) {
+        self.data.attributes.push((key.to_string(), value.into()));
+    }
+
+    pub fn set_error(&mut self) {
+        self.data.error = true;
+    }
+
+    /// Start a child span in the same trace.
+    pub fn child(&self, name: &str) -> Span {
+        Span {
+            data: SpanData {
+                trace_id: self.data.trace_id.clone(),
+                span_id: random_hex(1),
+                parent_span_id: Some(self.data.span_id.clone()),
+                name: name.to_string(),
+                start: SystemTime::now(),
+                end: SystemTime::now(),
+                attributes: Vec::new(),
+                error: false,
+            },
+        }
+    }
+
+    pub fn end(mut self) {
+        self.data.end = SystemTime::now();
+        if let Some(telemetry) = TELEMETRY.get() {
+            telemetry.spans.lock().unwrap().push(self.data);
+        }
+    }
+}
+
+/// Gauge data point for a scan.
+#[derive(Debug, Clone)]
+struct DataPoint {
+    metric: &'static str,
+    time: SystemTime,
+    attributes: Vec<(String, AttrValue)>,
+    value: f64,
+}
+
+/// Buffered telemetry for the whole process.
+pub struct Telemetry {
+    config: ExportConfig,
+    spans: Mutex<Vec<SpanData>>,
+    points: Mutex<Vec<DataPoint>>,
+}
+
+/// Turn on telemetry for this process. Later calls are ignored.
+pub fn init(config: ExportConfig) {
+    let _ = TELEMETRY.set(Telemetry {
+        config,
+        spans: Mutex::new(Vec::new()),
+        points: Mutex::new(Vec::new()),
+    });
+}
+
+/// Start a root span in a new trace, or `None` when telemetry is off.
+pub fn root_span(name: &str) -> Option<Span> {
+    TELEMETRY.get()?;
+    Some(Span {
+        data: SpanData {
+            trace_id: random_hex(2),
+            span_id: random_hex(1),
+            parent_span_id: None,
+            name: name.to_string(),
+            start: SystemTime::now(),
+            end: SystemTime::now(),
+            attributes: Vec::new(),
+            error: false,
+        },
+    })
+}
+
+/// Record issue counts, the health score, and the duration of a finished scan.
+pub fn record_scan(result: &ScanResult) {
+    let Some(telemetry) = TELEMETRY.get() else {
+        return;
+    };
+    if !telemetry.config.metrics {
+        return;
+    }
+    telemetry.points.lock().unwrap().extend(scan_points(result, SystemTime::now()));
+}
+
+fn scan_points(result: &ScanResult, time: SystemTime) -> Vec<DataPoint> {
+    let project = crate::utils::fs::display_path(&result.project.path);
+    let base = vec![
+        ("repodoctor.project".to_string(), AttrValue::Str(project)),
+        (
+            "repodoctor.framework".to_string(),
+            AttrValue::Str(result.project.detected.framework.to_string()),
+        ),
+    ];
+
+    let mut counts: BTreeMap<(String, String), usize> = BTreeMap::new();
+    for issue in &result.issues {
+        *counts
+            .entry((issue.severity.to_string(), issue.category.to_string()))
+            .or_default() += 1;
+    }
+
+    let mut points: Vec<DataPoint> = counts
+        .into_iter()
+        .map(|((severity, category), count)| {
+            let mut attributes = base.clone();
+            attributes.push(("repodoctor.severity".to_string(), AttrValue::Str(severity)));
+            attributes.push(("repodoctor.category".to_string(), AttrValue::Str(category)));
+            DataPoint {
+                metric: "repodoctor.issues",
+                time,
+                attributes,
+                value: count as f64,
+            }
+        })
+        .collect();
+    points.push(DataPoint {
+        metric: "repodoctor.health_score",
+        time,
+        attributes: base.clone(),
+        value: f64::from(result.score.total),
+    });
+    points.push(DataPoint {
+        metric: "repodoctor.scan.duration",
+        time,
+        attributes: base,
+        value: result.duration.as_secs_f64(),
+    });
+    points
+}
+
+/// Send everything recorded so far. Failures are reported on stderr and
+/// never fail the command.
+pub fn flush() {
+    let Some(telemetry) = TELEMETRY.get() else {
+        return;
+    };
+    let spans = std::mem::take(&mut *telemetry.spans.lock().unwrap());
+    let points = std::mem::take(&mut *telemetry.points.lock().unwrap());
+
+    if !spans.is_empty() {
+        telemetry.post("/v1/traces", &traces_payload(&telemetry.config, &spans));
+    }
+    if !points.is_empty() {
+        telemetry.post("/v1/metrics", &metrics_payload(&telemetry.config, &points));
+    }
+}
+
+impl Telemetry {
+    fn post(&self, path: &str, payload: &Value) {
+        let url = format!("{}{}", self.config.endpoint, path);
+        let mut request = ureq::post(&url)
+            .timeout(Duration::from_secs(10))
+            .set("Content-Type", "application/json");
+        for (key, value) in &self.config.headers {
+            request = request.set(key, value);
+        }
+        if let Err(e) = request.send_string(&payload.to_string()) {
+            eprintln!("warning: failed to export telemetry to {}: {}", url, e);
+        }
+    }
+}
+
+/// Random lowercase hex of `words` 64-bit words (1 for span IDs, 2 for trace IDs).
+fn random_hex(words: usize) -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    (0..words)
+        .map(|_| {
+            // RandomState is seeded per instance, so every hasher yields fresh bits
+            let mut hasher = RandomState::new().build_hasher();
+            hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+            format!("{:016x}", hasher.finish())
+        })
+        .collect()
+}
+
+fn unix_nanos(time: SystemTime) -> String {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0)
+        .to_string()
+}
+
+fn attributes_json(attributes: &[(String, AttrValue)]) -> Value {
+    attributes
+        .iter()
+        .map(|(key, value)| {
+            let value = match value {
+                AttrValue::Str(s) => json!({ "stringValue": s }),
+                // int64 values are strings in the OTLP JSON encoding
+                AttrValue::Int(i) => json!({ "intValue": i.to_string() }),
+            };
+            json!({ "key": key, "value": value })
+        })
+        .collect()
+}
+
+fn resource_and_scope(config: &ExportConfig) -> (Value, Value) {
+    (
+        json!({ "attributes": attributes_json(&[(
+            "service.name".to_string(),
+            AttrValue::Str(config.service_name.clone()),
+        )]) }),
+        json!({ "name": "repodoctor", "version": env!("CARGO_PKG_VERSION") }),
+    )
+}
+
+fn traces_payload(config: &ExportConfig, spans: &[SpanData]) -> Value {
+    let (resource, scope) = resource_and_scope(config);
+    let spans: Vec<Value> = spans
+        .iter()
+        .map(|span| {
+            let mut value = json!({
+                "traceId": span.trace_id,
+                "spanId": span.span_id,
+                "name": span.name,
+                // SPAN_KIND_INTERNAL
+                "kind": 1,
+                "startTimeUnixNano": unix_nanos(span.start),
+                "endTimeUnixNano": unix_nanos(span.end),
+                "attributes": attributes_json(&span.attributes),
+                // STATUS_CODE_OK / STATUS_CODE_ERROR
+                "status": { "code": if span.error { 2 } else { 1 } },
+            });
+            if let Some(parent) = &span.parent_span_id {
+                value["parentSpanId"] = json!(parent);
+            }
+            value
+        })
+        .collect();
+    json!({
+        "resourceSpans": [{
+            "resource": resource,
+            "scopeSpans": [{ "scope": scope, "spans": spans }],
+        }]
+    })
+}
+
+fn metrics_payload(config: &ExportConfig, points: &[DataPoint]) -> Value {
+    let (resource, scope) = resource_and_scope(config);
+    let mut by_metric: BTreeMap<&str, Vec<Value>> = BTreeMap::new();
+    for point in points {
+        let mut value = json!({
+            "attributes": attributes_json(&point.attributes),
+            "timeUnixNano": unix_nanos(point.time),
+        });
+        if point.metric == "repodoctor.scan.duration" {
+            value["asDouble"] = json!(point.value);
+        } else {
+            value["asInt"] = json!((point.value as i64).to_string());
+        }
+        by_metric.entry(point.metric).or_default().push(value);
+    }
+    let metrics: Vec<Value> = by_metric
+        .into_iter()
+        .map(|(name, data_points)| {
+            let (unit, description) = match name {
+                "repodoctor.issues" => ("{issue}", "Issues found by severity and category"),
+                "repodoctor.health_score" => ("1", "Health score from 0 to 100"),
+                _ => ("s", "Wall-clock duration of the scan"),
+            };
+            json!({
+                "name": name,
+                "unit": unit,
+                "description": description,
+                "gauge": { "dataPoints": data_points },
+            })
+        })
+        .collect();
+    json!({
+        "resourceMetrics": [{
+            "resource": resource,
+            "scopeMetrics": [{ "scope": scope, "metrics": metrics }],
+        }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::{AnalyzerCategory, Issue, Severity};
+    use crate::core::project::Project;
+    use crate::core::score::HealthScore;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+
+    fn config() -> ExportConfig {
+        ExportConfig::from_vars("http://collector:4318/", true, |key| match key {
+            "OTEL_EXPORTER_OTLP_HEADERS" => Some("x-api-key=abc, tenant = acme".to_string()),
+            _ => None,
+        })
+    }
+
+    fn make_issue(id: &str, severity: Severity) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Security,
+            severity,
+            title: "Test issue".to_string(),
+            description: String::new(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        }
+    }
+
+    #[test]
+    fn test_config_from_vars() {
+        let config = config();
+        assert_eq!(config.endpoint, "http://collector:4318");
+        assert_eq!(config.service_name, "repodoctor");
+        assert_eq!(
+            config.headers,
+            [
+                ("x-api-key".to_string(), "abc".to_string()),
+                ("tenant".to_string(), "acme".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_random_ids() {
+        let (a, b) = (random_hex(2), random_hex(2));
+        assert_eq!(a.len(), 32);
+        assert_eq!(random_hex(1).len(), 16);
+        assert_ne!(a, b);
+        assert!(a.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn test_traces_payload() {
+        let root = SpanData {
+            trace_id: random_hex(2),
+            span_id: random_hex(1),
+            parent_span_id: None,
+            name: "scan".to_string(),
+            start: UNIX_EPOCH + Duration::from_secs(1),
+            end: UNIX_EPOCH + Duration::from_secs(2),
+            attributes: vec![("repodoctor.issues".to_string(), AttrValue::Int(3))],
+            error: false,
+        };
+        let child = SpanData {
+            span_id: random_hex(1),
+            parent_span_id: Some(root.span_id.clone()),
+            name: "analyzer security".to_string(),
+            error: true,
+            ..root.clone()
+        };
+        let payload = traces_payload(&config(), &[root.clone(), child]);
+
+        let scope = &payload["resourceSpans"][0]["scopeSpans"][0];
+        assert_eq!(
+            payload["resourceSpans"][0]["resource"]["attributes"][0]["value"]["stringValue"],
+            "repodoctor"
+        );
+        assert_eq!(scope["scope"]["name"], "repodoctor");
+        let spans = scope["spans"].as_array().unwrap();
+        assert_eq!(spans[0]["startTimeUnixNano"], "1000000000");
+        assert_eq!(spans[0]["attributes"][0]["value"]["intValue"], "3");
+        assert!(spans[0].get("parentSpanId").is_none());
+        assert_eq!(spans[1]["parentSpanId"], json!(root.span_id));
+        assert_eq!(spans[1]["status"]["code"], 2);
+    }
+
+    #[test]
+    fn test_metrics_payload_counts_issues() {
+        let issues = vec![
+            make_issue("SEC-001", Severity::Critical),
+            make_issue("SEC-002", Severity::Critical),
+            make_issue("SEC-003", Severity::Low),
+        ];
+        let result = ScanResult {
+            project: Project {
+                path: "/tmp/test-project".into(),
+                detected: DetectedProject {
+                    framework: Framework::RustCargo,
+                    language: Language::Rust,
+                    version: None,
+                    package_manager: None,
+                    has_git: false,
+                    has_ci: None,
+                },
+            },
+            score: HealthScore::calculate(&issues),
+            issues,
+            duration: Duration::from_millis(1500),
+            expiring: vec![],
+            policy: None,
+            skipped_files: vec![],
+            stats: vec![],
+            metrics: Default::default(),
+        };
+        let points = scan_points(&result, UNIX_EPOCH);
+        let payload = metrics_payload(&config(), &points);
+
+        let metrics = payload["resourceMetrics"][0]["scopeMetrics"][0]["metrics"]
+            .as_array()
+            .unwrap();
+        let names: Vec<&str> = metrics.iter().map(|m| m["name"].as_str().unwrap()).collect();
+        assert_eq!(
+            names,
+            ["repodoctor.health_score", "repodoctor.issues", "repodoctor.scan.duration"]
+        );
+        let issues = metrics[1]["gauge"]["dataPoints"].as_array().unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0]["asInt"], "2");
+        assert!(issues[0]["attributes"]
+            .as_array()
+            .unwrap()
+            .contains(&json!({ "key": "repodoctor.severity", "value": { "stringValue": "CRITICAL" } })));
+        assert_eq!(metrics[2]["gauge"]["dataPoints"][0]["asDouble"], 1.5);
+    }
+}
@@ -0,0 +1,197 @@
+//! Correlation of related findings into themes.
+//!
+//! One underlying gap often trips several rules: a project without tests
+//! reports a missing test directory, missing test configuration, and the
+//! framework's own missing test folder. Reports show such findings as a
+//! single theme with one recommendation instead of separate issues.
+
+use serde::Serialize;
+
+use crate::analyzers::traits::{Issue, Severity};
+
+/// Distinct rules that must fire before their findings form a theme.
+const MIN_RULES: usize = 2;
+
+struct ThemeRule {
+    id: &'static str,
+    title: &'static str,
+    recommendation: &'static str,
+    rules: &'static [&'static str],
+}
+
+/// Built-in themes. A rule belongs to at most one theme.
+const THEMES: &[ThemeRule] = &[
+    ThemeRule {
+        id: "test-setup",
+        title: "No working test setup",
+        recommendation: "Set up the project's test framework: add its configuration, a test directory with at least one test, and run the suite in CI.",
+        rules: &[
+            "TST-001", "TST-002", "TST-003", "NJS-031", "LAR-031", "SYM-031", "RST-020", "RB-020",
+            "FLT-031",
+        ],
+    },
+    ThemeRule {
+        id: "env-files",
+        title: "Environment files are exposed",
+        recommendation: "Keep .env files out of git: ignore them, commit a .env.example with placeholder values instead, and rotate any secret that was committed.",
+        rules: &["CFG-003", "SEC-003", "NJS-050"],
+    },
+    ThemeRule {
+        id: "gitignore",
+        title: "Incomplete .gitignore",
+        recommendation: "Start from the .gitignore template for the framework (`repodoctor fix` can create one) and remove build output that is already tracked.",
+        rules: &["STR-003", "FLT-053", "LAR-050", "SYM-050", "RST-040"],
+    },
+    ThemeRule {
+        id: "community-files",
+        title: "Missing project documentation",
+        recommendation: "Add the standard community files (README, LICENSE, CONTRIBUTING, CODE_OF_CONDUCT); `repodoctor fix` creates starting points for the README and LICENSE.",
+        rules: &["STR-002", "STR-004", "DOC-001", "DOC-003", "DOC-004", "DOC-005"],
+    },
+    ThemeRule {
+        id: "ci-pipeline",
+        title: "CI pipeline missing or out of date",
+        recommendation: "Add a CI workflow that builds, lints, and tests every push, based on the organization template when there is one.",
+        rules: &["CFG-005", "DRF-002", "DRF-003"],
+    },
+    ThemeRule {
+        id: "reproducible-builds",
+        title: "Dependencies are not reproducible",
+        recommendation: "Commit the lock file and pin dependency versions so every install resolves the same packages.",
+        rules: &["DEP-001", "DEP-004", "RST-011"],
+    },
+];
+
+/// Related findings grouped under one recommendation.
+#[derive(Debug, Clone, Serialize)]
+pub struct Theme {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub recommendation: &'static str,
+    /// Highest severity among the grouped issues
+    pub severity: Severity,
+    /// Rules that fired, in theme order
+    pub rules: Vec<String>,
+    /// Fingerprints of the grouped issues
+    pub fingerprints: Vec<String>,
+    #[serde(skip)]
+    pub issues: Vec<Issue>,
+}
+
+impl Theme {
+    /// Whether `issue` is reported as part of this theme.
+    pub fn contains(&self, issue: &Issue) -> bool {
+        self.rules.contains(&issue.id)
+    }
+}
+
+/// Group issues whose rules belong to the same theme, when at least two
+/// different rules of that theme fired. Issue order is preserved.
+pub fn correlate(issues: &[Issue]) -> Vec<Theme> {
+    THEMES
+        .iter()
+        .filter_map(|theme| {
+            let rules: Vec<String> = theme
+                .rules
+                .iter()
+                .filter(|rule| issues.iter().any(|i| i.id == **rule))
+                .map(|rule| rule.to_string())
+                .collect();
+            if rules.len() < MIN_RULES {
+                return None;
+            }
+            let members: Vec<Issue> = issues
+                .iter()
+                .filter(|i| rules.contains(&i.id))
+                .cloned()
+                .collect();
+            Some(Theme {
+                id: theme.id,
+                title: theme.title,
+                recommendation: theme.recommendation,
+                severity: members.iter().map(|i| i.severity).max()?,
+                rules,
+                fingerprints: members.iter().filter_map(|i| i.fingerprint.clone()).collect(),
+                issues: members,
+            })
+        })
+        .collect()
+}
+
+/// Issues that are not part of any theme.
+pub fn unthemed<'a>(issues: &'a [Issue], themes: &[Theme]) -> Vec<&'a Issue> {
+    issues
+        .iter()
+        .filter(|i| !themes.iter().any(|t| t.contains(i)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::AnalyzerCategory;
+
+    fn make_issue(id: &str, severity: Severity) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Testing,
+            severity,
+            title: format!("Issue {}", id),
+            description: String::new(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: Some(format!("fp-{}", id)),
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        }
+    }
+
+    #[test]
+    fn test_related_rules_form_a_theme() {
+        let issues = vec![
+            make_issue("TST-002", Severity::Medium),
+            make_issue("SEC-001", Severity::Critical),
+            make_issue("TST-001", Severity::High),
+            make_issue("RST-020", Severity::Medium),
+        ];
+        let themes = correlate(&issues);
+
+        assert_eq!(themes.len(), 1);
+        let theme = &themes[0];
+        assert_eq!(theme.id, "test-setup");
+        assert_eq!(theme.severity, Severity::High);
+        assert_eq!(theme.rules, ["TST-001", "TST-002", "RST-020"]);
+        assert_eq!(theme.fingerprints, ["fp-TST-002", "fp-TST-001", "fp-RST-020"]);
+
+        let rest = unthemed(&issues, &themes);
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].id, "SEC-001");
+    }
+
+    #[test]
+    fn test_single_rule_is_not_a_theme() {
+        // Repeated findings of one rule are not correlated
+        let issues = vec![
+            make_issue("DEP-001", Severity::High),
+            make_issue("DEP-001", Severity::High),
+        ];
+        assert!(correlate(&issues).is_empty());
+    }
+
+    #[test]
+    fn test_rules_belong_to_one_theme() {
+        let mut seen: Vec<&str> = Vec::new();
+        for theme in THEMES {
+            for rule in theme.rules {
+                assert!(!seen.contains(rule), "{} is in two themes", rule);
+                seen.push(rule);
+            }
+        }
+    }
+}
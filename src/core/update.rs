@@ -0,0 +1,516 @@
+//! New-version checks and in-place self-update from GitHub releases.
+//!
+//! `scan` starts a background check at most once a day and caches the answer
+//! in the user cache directory, so the notice never delays a scan.
+//! `self-update` downloads the release archive for this platform, verifies
+//! it against the `.sha256` the release publishes (refusing to install
+//! without one unless told to), and swaps the running binary.
+//!
+//! Both honor `updates.check: false` in the user config file, for
+//! air-gapped environments. The scan notice also honors the project's
+//! `.repodoctor.yml`; `self-update` ignores it, since the project in the
+//! current directory says nothing about how this binary is managed.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::utils::date;
+
+const REPOSITORY: &str = "Jonathanlight/repodoctor";
+const API_URL: &str = "https://api.github.com";
+
+/// Seconds between two background checks.
+const CHECK_INTERVAL: i64 = 24 * 60 * 60;
+const STATE_FILE: &str = "update-check.json";
+
+#[cfg(windows)]
+const BINARY_NAME: &str = "repodoctor.exe";
+#[cfg(not(windows))]
+const BINARY_NAME: &str = "repodoctor";
+
+pub fn current_version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
+#[derive(Debug, Clone)]
+pub struct Release {
+    /// Version without the tag's `v` prefix
+    pub version: String,
+    pub tag: String,
+    pub assets: Vec<Asset>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Asset {
+    pub name: String,
+    pub url: String,
+}
+
+impl Release {
+    /// Fetch the latest published (non-prerelease) release.
+    pub fn latest() -> Result<Self> {
+        let url = format!("{}/repos/{}/releases/latest", API_URL, REPOSITORY);
+        let body = ureq::get(&url)
+            .set("Accept", "application/vnd.github+json")
+            .set(
+                "User-Agent",
+                concat!("repodoctor/", env!("CARGO_PKG_VERSION")),
+            )
+            .timeout(Duration::from_secs(10))
+            .call()
+            .with_context(|| format!("Failed to query {}", url))?
+            .into_string()?;
+        let body: serde_json::Value = serde_json::from_str(&body)?;
+        Self::parse(&body).with_context(|| format!("Unexpected response from {}", url))
+    }
+
+    fn parse(body: &serde_json::Value) -> Option<Self> {
+        let tag = body["tag_name"].as_str()?.to_string();
+        let assets = body["assets"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|asset| {
+                Some(Asset {
+                    name: asset["name"].as_str()?.to_string(),
+                    url: asset["browser_download_url"].as_str()?.to_string(),
+                })
+            })
+            .collect();
+        Some(Self {
+            version: tag.trim_start_matches('v').to_string(),
+            tag,
+            assets,
+        })
+    }
+
+    /// The `.tar.gz` or `.zip` archive built for this OS and architecture.
+    pub fn platform_asset(&self) -> Option<&Asset> {
+        self.asset_for(std::env::consts::OS, std::env::consts::ARCH)
+    }
+
+    fn asset_for(&self, os: &str, arch: &str) -> Option<&Asset> {
+        let os_names: &[&str] = match os {
+            "macos" => &["darwin", "macos", "apple"],
+            other => &[other],
+        };
+        let arch_names: &[&str] = match arch {
+            "x86_64" => &["x86_64", "amd64"],
+            "aarch64" => &["aarch64", "arm64"],
+            other => &[other],
+        };
+        self.assets.iter().find(|asset| {
+            let name = asset.name.to_lowercase();
+            (name.ends_with(".tar.gz") || name.ends_with(".zip"))
+                && os_names.iter().any(|n| name.contains(n))
+                && arch_names.iter().any(|n| name.contains(n))
+        })
+    }
+
+    /// Download `asset` and check it against `<asset>.sha256`. Without that
+    /// file the download fails, unless `verify` is off.
+    pub fn download(&self, asset: &Asset, verify: bool) -> Result<Vec<u8>> {
+        let checksum_name = format!("{}.sha256", asset.name);
+        let checksum = self.assets.iter().find(|a| a.name == checksum_name);
+        if verify && checksum.is_none() {
+            bail!(
+                "Release {} publishes no {}, so the download cannot be verified; rerun with --no-verify to install it anyway",
+                self.tag,
+                checksum_name
+            );
+        }
+        let bytes = fetch(&asset.url)?;
+        if let Some(checksum) = checksum.filter(|_| verify) {
+            let expected = String::from_utf8_lossy(&fetch(&checksum.url)?)
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_lowercase();
+            let actual: String = Sha256::digest(&bytes)
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect();
+            if expected != actual {
+                bail!(
+                    "Checksum mismatch for {}: expected {}, got {}",
+                    asset.name,
+                    expected,
+                    actual
+                );
+            }
+        }
+        Ok(bytes)
+    }
+}
+
+fn fetch(url: &str) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    ureq::get(url)
+        .set(
+            "User-Agent",
+            concat!("repodoctor/", env!("CARGO_PKG_VERSION")),
+        )
+        .timeout(Duration::from_secs(120))
+        .call()
+        .with_context(|| format!("Failed to download {}", url))?
+        .into_reader()
+        .read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Whether `candidate` is a later `MAJOR.MINOR.PATCH` than `current`.
+/// Pre-release and build suffixes are ignored.
+pub fn is_newer(candidate: &str, current: &str) -> bool {
+    let parse = |version: &str| -> Vec<u64> {
+        version
+            .trim_start_matches('v')
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    };
+    parse(candidate) > parse(current)
+}
+
+/// Pull the `repodoctor` executable out of a release archive.
+pub fn extract_binary(archive_name: &str, bytes: &[u8]) -> Result<Vec<u8>> {
+    let is_binary = |path: &Path| path.file_name().is_some_and(|n| n == BINARY_NAME);
+    let mut binary = Vec::new();
+    if archive_name.ends_with(".zip") {
+        let mut zip = zip::ZipArchive::new(std::io::Cursor::new(bytes))?;
+        for i in 0..zip.len() {
+            let mut entry = zip.by_index(i)?;
+            if entry.is_file() && is_binary(Path::new(entry.name())) {
+                entry.read_to_end(&mut binary)?;
+                return Ok(binary);
+            }
+        }
+    } else {
+        let mut tar = tar::Archive::new(flate2::read::GzDecoder::new(bytes));
+        for entry in tar.entries()? {
+            let mut entry = entry?;
+            if entry.header().entry_type().is_file() && is_binary(&entry.path()?) {
+                entry.read_to_end(&mut binary)?;
+                return Ok(binary);
+            }
+        }
+    }
+    bail!("{} does not contain {}", archive_name, BINARY_NAME)
+}
+
+/// Replace the running executable with `binary`. Returns its path.
+pub fn replace_current_exe(binary: &[u8]) -> Result<PathBuf> {
+    let exe = std::env::current_exe()?.canonicalize()?;
+    replace_exe(&exe, binary).with_context(|| {
+        format!(
+            "Failed to replace {}; check write access to it",
+            exe.display()
+        )
+    })?;
+    Ok(exe)
+}
+
+fn replace_exe(exe: &Path, binary: &[u8]) -> Result<()> {
+    let staged = exe.with_extension("update");
+    std::fs::write(&staged, binary)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o755))?;
+    }
+    // A running executable cannot be overwritten on Windows, but it can be renamed
+    #[cfg(windows)]
+    {
+        let old = exe.with_extension("old");
+        let _ = std::fs::remove_file(&old);
+        std::fs::rename(exe, &old)?;
+        if let Err(err) = std::fs::rename(&staged, exe) {
+            // Put the previous binary back rather than leave none at all
+            let _ = std::fs::rename(&old, exe);
+            let _ = std::fs::remove_file(&staged);
+            return Err(err.into());
+        }
+    }
+    #[cfg(not(windows))]
+    std::fs::rename(&staged, exe)?;
+    Ok(())
+}
+
+/// The last background check, cached between runs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CheckState {
+    checked_at: i64,
+    latest: Option<String>,
+}
+
+/// `$XDG_CACHE_HOME`, `%LOCALAPPDATA%`, or `~/.cache`, followed by `repodoctor/`.
+fn state_path() -> Option<PathBuf> {
+    let var = |key: &str| {
+        std::env::var_os(key)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    let base = var("XDG_CACHE_HOME")
+        .or_else(|| var("LOCALAPPDATA"))
+        .or_else(|| var("HOME").map(|home| home.join(".cache")))?;
+    Some(base.join("repodoctor").join(STATE_FILE))
+}
+
+fn save_state(path: &Path, state: &CheckState) {
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    if let Ok(content) = serde_json::to_string(state) {
+        let _ = std::fs::write(path, content);
+    }
+}
+
+/// A new-version check running alongside a scan.
+pub struct UpdateNotice {
+    cached: Option<String>,
+    pending: Option<JoinHandle<Option<String>>>,
+}
+
+impl UpdateNotice {
+    /// Use the cached answer when it is less than a day old; otherwise ask
+    /// GitHub on a background thread.
+    pub fn start() -> Self {
+        match state_path() {
+            Some(path) => Self::start_with(path, date::now(), || {
+                Release::latest().ok().map(|r| r.version)
+            }),
+            None => Self {
+                cached: None,
+                pending: None,
+            },
+        }
+    }
+
+    fn start_with<F>(path: PathBuf, now: i64, fetch_latest: F) -> Self
+    where
+        F: FnOnce() -> Option<String> + Send + 'static,
+    {
+        let state: CheckState = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        if (0..CHECK_INTERVAL).contains(&(now - state.checked_at)) {
+            return Self {
+                cached: state.latest,
+                pending: None,
+            };
+        }
+        // Record the attempt before asking, so a scan that exits before the
+        // answer arrives still waits a day before the next check. Failures
+        // keep the previous answer, and an offline machine retries daily.
+        let previous = state.latest;
+        save_state(
+            &path,
+            &CheckState {
+                checked_at: now,
+                latest: previous.clone(),
+            },
+        );
+        let fallback = previous.clone();
+        let pending = std::thread::spawn(move || {
+            let latest = fetch_latest().or(previous);
+            save_state(
+                &path,
+                &CheckState {
+                    checked_at: now,
+                    latest: latest.clone(),
+                },
+            );
+            latest
+        });
+        Self {
+            cached: fallback,
+            pending: Some(pending),
+        }
+    }
+
+    /// The newer release, if one is known by now. Never waits for the
+    /// network; a check still in flight falls back to the previous answer.
+    pub fn newer_version(self) -> Option<String> {
+        let latest = match self.pending {
+            Some(pending) if pending.is_finished() => pending.join().ok().flatten(),
+            _ => self.cached,
+        };
+        latest.filter(|version| is_newer(version, current_version()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    fn release(assets: &[&str]) -> Release {
+        Release {
+            version: "1.2.0".to_string(),
+            tag: "v1.2.0".to_string(),
+            assets: assets
+                .iter()
+                .map(|name| Asset {
+                    name: name.to_string(),
+                    url: format!("https://example.com/{}", name),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_is_newer() {
+        assert!(is_newer("0.2.0", "0.1.9"));
+        assert!(is_newer("v1.0.0", "0.9.0"));
+        assert!(is_newer("0.10.0", "0.9.0"));
+        assert!(is_newer("1.0.1", "1.0"));
+        assert!(!is_newer("0.1.0", "0.1.0"));
+        assert!(!is_newer("0.1.0-rc.1", "0.1.0"));
+        assert!(!is_newer("0.0.9", "0.1.0"));
+    }
+
+    #[test]
+    fn test_parse_release() {
+        let body = serde_json::json!({
+            "tag_name": "v0.3.1",
+            "assets": [{
+                "name": "repodoctor-x86_64-unknown-linux-gnu.tar.gz",
+                "browser_download_url": "https://example.com/a.tar.gz"
+            }]
+        });
+        let release = Release::parse(&body).unwrap();
+        assert_eq!(release.version, "0.3.1");
+        assert_eq!(release.tag, "v0.3.1");
+        assert_eq!(release.assets[0].url, "https://example.com/a.tar.gz");
+        assert!(Release::parse(&serde_json::json!({"message": "Not Found"})).is_none());
+    }
+
+    #[test]
+    fn test_asset_for_platform() {
+        let release = release(&[
+            "repodoctor-x86_64-unknown-linux-gnu.tar.gz",
+            "repodoctor-x86_64-unknown-linux-gnu.tar.gz.sha256",
+            "repodoctor-aarch64-apple-darwin.tar.gz",
+            "repodoctor-x86_64-pc-windows-msvc.zip",
+        ]);
+        let name = |os, arch| release.asset_for(os, arch).map(|a| a.name.as_str());
+        assert_eq!(
+            name("linux", "x86_64"),
+            Some("repodoctor-x86_64-unknown-linux-gnu.tar.gz")
+        );
+        assert_eq!(
+            name("macos", "aarch64"),
+            Some("repodoctor-aarch64-apple-darwin.tar.gz")
+        );
+        assert_eq!(
+            name("windows", "x86_64"),
+            Some("repodoctor-x86_64-pc-windows-msvc.zip")
+        );
+        assert_eq!(name("linux", "aarch64"), None);
+    }
+
+    #[test]
+    fn test_extract_binary_from_tarball() {
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+            Vec::new(),
+            flate2::Compression::fast(),
+        ));
+        for (path, content) in [("dist/README.md", "docs"), ("dist/repodoctor", "binary")] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o755);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, path, content.as_bytes())
+                .unwrap();
+        }
+        let bytes = builder.into_inner().unwrap().finish().unwrap();
+
+        if cfg!(not(windows)) {
+            assert_eq!(extract_binary("r.tar.gz", &bytes).unwrap(), b"binary");
+        }
+        assert!(extract_binary("r.tar.gz", b"not a tarball").is_err());
+    }
+
+    #[test]
+    fn test_extract_binary_from_zip() {
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        zip.start_file(BINARY_NAME, zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"binary").unwrap();
+        let bytes = zip.finish().unwrap().into_inner();
+        assert_eq!(extract_binary("r.zip", &bytes).unwrap(), b"binary");
+    }
+
+    #[test]
+    fn test_replace_exe() {
+        let tmp = TempDir::new().unwrap();
+        let exe = tmp.path().join(BINARY_NAME);
+        std::fs::write(&exe, "old").unwrap();
+        replace_exe(&exe, b"new").unwrap();
+        assert_eq!(std::fs::read(&exe).unwrap(), b"new");
+        assert!(!exe.with_extension("update").exists());
+    }
+
+    #[test]
+    fn test_notice_checks_once_a_day() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("cache").join(STATE_FILE);
+
+        let notice =
+            UpdateNotice::start_with(path.clone(), 1_000_000, || Some("999.0.0".to_string()));
+        let handle = notice.pending.as_ref().unwrap();
+        while !handle.is_finished() {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert_eq!(notice.newer_version().as_deref(), Some("999.0.0"));
+
+        // Within a day the cached answer is used without fetching
+        let notice =
+            UpdateNotice::start_with(path.clone(), 1_000_000 + 3600, || panic!("fetched again"));
+        assert!(notice.pending.is_none());
+        assert_eq!(notice.newer_version().as_deref(), Some("999.0.0"));
+
+        let notice = UpdateNotice::start_with(path, 1_000_000 + CHECK_INTERVAL, || {
+            Some(current_version().to_string())
+        });
+        assert!(notice.pending.is_some());
+    }
+
+    #[test]
+    fn test_check_is_recorded_before_the_answer_arrives() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join(STATE_FILE);
+        let (release, wait) = std::sync::mpsc::channel::<()>();
+
+        // The scan ends while the request is still in flight
+        let notice = UpdateNotice::start_with(path.clone(), 1_000_000, move || {
+            let _ = wait.recv();
+            None
+        });
+        assert_eq!(notice.newer_version(), None);
+
+        let state: CheckState =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(state.checked_at, 1_000_000);
+        let notice = UpdateNotice::start_with(path, 1_000_000 + 60, || panic!("fetched again"));
+        assert!(notice.pending.is_none());
+        drop(release);
+    }
+
+    #[test]
+    fn test_download_requires_checksum() {
+        let release = release(&["repodoctor-x86_64-unknown-linux-gnu.tar.gz"]);
+        let err = release.download(&release.assets[0], true).unwrap_err();
+        assert!(err.to_string().contains("--no-verify"));
+    }
+}
@@ -23,6 +23,7 @@ impl DirectoryFixer {
             "SYM-031" => Some("tests".to_string()),
             "FLT-031" => Some("integration_test".to_string()),
             "NJS-031" => Some("__tests__".to_string()),
+            "RB-020" => Some("spec".to_string()),
             _ => None,
         }
     }
@@ -31,7 +32,7 @@ impl DirectoryFixer {
 impl Fixer for DirectoryFixer {
     fn handles(&self) -> &[&str] {
         &[
-            "STR-001", "SYM-001", "SYM-002", "SYM-031", "FLT-031", "NJS-031",
+            "STR-001", "SYM-001", "SYM-002", "SYM-031", "FLT-031", "NJS-031", "RB-020",
         ]
     }
 
@@ -98,6 +99,11 @@ mod tests {
             description: String::new(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: None,
             auto_fixable: true,
             references: vec![],
@@ -4,6 +4,7 @@ use std::fs;
 use crate::analyzers::traits::Issue;
 use crate::core::project::Project;
 
+use super::templates;
 use super::traits::{FixResult, Fixer};
 
 pub struct EditorConfigFixer;
@@ -24,8 +25,16 @@ impl Fixer for EditorConfigFixer {
         &["CFG-002"]
     }
 
-    fn describe(&self, _issue: &Issue, _project: &Project) -> String {
-        "Create .editorconfig with standard settings".to_string()
+    fn describe(&self, _issue: &Issue, project: &Project) -> String {
+        if templates::has_override(project, ".editorconfig") {
+            "Create .editorconfig from user template".to_string()
+        } else {
+            "Create .editorconfig with standard settings".to_string()
+        }
+    }
+
+    fn target(&self, _issue: &Issue, _project: &Project) -> Option<String> {
+        Some(".editorconfig".to_string())
     }
 
     fn apply(&self, _issue: &Issue, project: &Project) -> Result<FixResult> {
@@ -35,7 +44,7 @@ impl Fixer for EditorConfigFixer {
                 reason: ".editorconfig already exists".to_string(),
             });
         }
-        fs::write(&path, EDITORCONFIG_TEMPLATE)?;
+        fs::write(&path, templates::load(project, ".editorconfig", EDITORCONFIG_TEMPLATE))?;
         Ok(FixResult::Applied {
             description: "Created .editorconfig".to_string(),
         })
@@ -74,6 +83,11 @@ mod tests {
             description: String::new(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: None,
             auto_fixable: true,
             references: vec![],
@@ -96,6 +110,23 @@ mod tests {
         assert!(content.contains("indent_size = 4"));
     }
 
+    #[test]
+    fn test_uses_user_template() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::create_dir_all(tmp.path().join(".templates")).unwrap();
+        stdfs::write(
+            tmp.path().join(".templates/.editorconfig"),
+            "root = true\n\n[*]\nindent_size = 2\n",
+        )
+        .unwrap();
+        stdfs::write(tmp.path().join(".repodoctor.yml"), "templates_dir: .templates\n").unwrap();
+        let project = make_project(&tmp);
+
+        EditorConfigFixer.apply(&make_issue(), &project).unwrap();
+        let content = stdfs::read_to_string(tmp.path().join(".editorconfig")).unwrap();
+        assert!(content.contains("indent_size = 2"));
+    }
+
     #[test]
     fn test_skips_existing_editorconfig() {
         let tmp = TempDir::new().unwrap();
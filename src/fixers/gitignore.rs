@@ -5,6 +5,7 @@ use crate::analyzers::traits::Issue;
 use crate::core::project::Project;
 use crate::frameworks::detector::Framework;
 
+use super::templates;
 use super::traits::{FixResult, Fixer};
 
 pub struct GitignoreFixer;
@@ -18,6 +19,8 @@ impl GitignoreFixer {
             }
             Framework::NextJs => ".next/\nnode_modules/\n.env.local\n.env*.local\n",
             Framework::RustCargo => "target/\n",
+            Framework::NodeJs => "node_modules/\n.env\n*.log\n",
+            Framework::Python => "__pycache__/\n*.pyc\n.venv/\n.env\n",
             _ => ".env\n*.log\n.DS_Store\n",
         }
     }
@@ -48,10 +51,13 @@ impl Fixer for GitignoreFixer {
 
     fn describe(&self, issue: &Issue, project: &Project) -> String {
         match issue.id.as_str() {
+            "STR-003" if templates::has_override(project, ".gitignore") => {
+                "Create .gitignore from user template".to_string()
+            }
             "STR-003" => {
                 format!(
                     "Create .gitignore with {} template",
-                    project.detected.framework
+                    project.detected.kind()
                 )
             }
             _ => {
@@ -61,6 +67,10 @@ impl Fixer for GitignoreFixer {
         }
     }
 
+    fn target(&self, _issue: &Issue, _project: &Project) -> Option<String> {
+        Some(".gitignore".to_string())
+    }
+
     fn apply(&self, issue: &Issue, project: &Project) -> Result<FixResult> {
         let gitignore_path = project.path.join(".gitignore");
 
@@ -71,12 +81,16 @@ impl Fixer for GitignoreFixer {
                         reason: ".gitignore already exists".to_string(),
                     });
                 }
-                let template = Self::gitignore_template(&project.detected.framework);
+                let template = templates::load(
+                    project,
+                    ".gitignore",
+                    Self::gitignore_template(&project.detected.generic_framework()),
+                );
                 fs::write(&gitignore_path, template)?;
                 Ok(FixResult::Applied {
                     description: format!(
                         "Created .gitignore with {} template",
-                        project.detected.framework
+                        project.detected.kind()
                     ),
                 })
             }
@@ -149,6 +163,11 @@ mod tests {
             description: String::new(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: None,
             auto_fixable: true,
             references: vec![],
@@ -171,6 +190,23 @@ mod tests {
         assert!(content.contains(".env"));
     }
 
+    #[test]
+    fn test_creates_gitignore_for_inferred_language() {
+        let tmp = TempDir::new().unwrap();
+        let mut project = make_project(&tmp, Framework::Unknown);
+        project.detected.language = Language::Python;
+        let issue = make_issue("STR-003", "Missing .gitignore");
+
+        let result = GitignoreFixer.apply(&issue, &project).unwrap();
+
+        assert!(matches!(
+            result,
+            FixResult::Applied { description } if description.contains("Python")
+        ));
+        let content = stdfs::read_to_string(tmp.path().join(".gitignore")).unwrap();
+        assert!(content.contains("__pycache__/"));
+    }
+
     #[test]
     fn test_appends_to_existing_gitignore() {
         let tmp = TempDir::new().unwrap();
@@ -2,6 +2,7 @@ pub mod directory;
 pub mod editorconfig;
 pub mod gitignore;
 pub mod registry;
+pub mod templates;
 pub mod traits;
 
 pub use registry::default_registry;
@@ -64,6 +64,7 @@ pub fn default_registry() -> FixerRegistry {
         Box::new(super::directory::DirectoryFixer),
         Box::new(super::gitignore::GitignoreFixer),
         Box::new(super::editorconfig::EditorConfigFixer),
+        Box::new(super::templates::TemplateFixer),
     ];
     FixerRegistry::new(fixers)
 }
@@ -107,6 +108,11 @@ mod tests {
             description: String::new(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: None,
             auto_fixable: true,
             references: vec![],
@@ -119,6 +125,7 @@ mod tests {
         assert!(registry.find_fixer("STR-001").is_some());
         assert!(registry.find_fixer("STR-003").is_some());
         assert!(registry.find_fixer("CFG-002").is_some());
+        assert!(registry.find_fixer("STR-002").is_some());
         assert!(registry.find_fixer("UNKNOWN-999").is_none());
     }
 
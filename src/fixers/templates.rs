@@ -0,0 +1,301 @@
+use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::analyzers::traits::Issue;
+use crate::core::config::Config;
+use crate::core::project::Project;
+use crate::frameworks::detector::Framework;
+use crate::utils::date;
+
+use super::traits::{FixResult, Fixer};
+
+/// Resolve the user template directory configured via `templates_dir`, if any.
+///
+/// Relative paths are resolved against the project root.
+pub fn templates_dir(project: &Project) -> Option<PathBuf> {
+    let dir = Config::load(&project.path).templates_dir?;
+    let dir = PathBuf::from(dir);
+    let dir = if dir.is_absolute() {
+        dir
+    } else {
+        project.path.join(dir)
+    };
+    dir.is_dir().then_some(dir)
+}
+
+/// Load the template for `name` (a path mirroring the target file, e.g.
+/// `.github/workflows/ci.yml`), preferring the user template directory over
+/// the built-in content. Placeholders are expanded in both cases.
+pub fn load(project: &Project, name: &str, builtin: &str) -> String {
+    let content = templates_dir(project)
+        .and_then(|dir| fs::read_to_string(dir.join(name)).ok())
+        .unwrap_or_else(|| builtin.to_string());
+    render(&content, project)
+}
+
+/// Whether the user template directory provides an override for `name`.
+pub fn has_override(project: &Project, name: &str) -> bool {
+    templates_dir(project).is_some_and(|dir| dir.join(name).is_file())
+}
+
+/// Expand `{{project_name}}`, `{{framework}}`, and `{{year}}` placeholders.
+fn render(content: &str, project: &Project) -> String {
+    content
+        .replace("{{project_name}}", &project_name(&project.path))
+        .replace("{{framework}}", &project.detected.framework.to_string())
+        .replace("{{year}}", &current_year().to_string())
+}
+
+fn project_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "project".to_string())
+}
+
+fn current_year() -> i64 {
+    date::civil_from_days(date::today()).0
+}
+
+const README_TEMPLATE: &str = "# {{project_name}}
+
+A short description of what this project does.
+
+## Installation
+
+Describe how to install the project.
+
+## Usage
+
+Describe how to use the project.
+
+## License
+
+See [LICENSE](LICENSE).
+";
+
+const LICENSE_TEMPLATE: &str = "MIT License
+
+Copyright (c) {{year}} The {{project_name}} authors
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the \"Software\"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED \"AS IS\", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+";
+
+const NEXTJS_LAYOUT_TEMPLATE: &str = "export const metadata = {
+  title: '{{project_name}}',
+};
+
+export default function RootLayout({ children }: { children: React.ReactNode }) {
+  return (
+    <html lang=\"en\">
+      <body>{children}</body>
+    </html>
+  );
+}
+";
+
+/// Creates boilerplate files (README, LICENSE, CI workflow, Next.js layout)
+/// from built-in or user-provided templates.
+pub struct TemplateFixer;
+
+impl TemplateFixer {
+    /// Target path and built-in template for an issue.
+    fn target_for_issue(issue: &Issue, framework: &Framework) -> Option<(&'static str, String)> {
+        match issue.id.as_str() {
+            "STR-002" => Some(("README.md", README_TEMPLATE.to_string())),
+            "STR-004" => Some(("LICENSE", LICENSE_TEMPLATE.to_string())),
+            "NJS-001" => Some(("app/layout.tsx", NEXTJS_LAYOUT_TEMPLATE.to_string())),
+            "CFG-005" => Some((".github/workflows/ci.yml", ci_workflow_template(framework))),
+            _ => None,
+        }
+    }
+}
+
+fn ci_workflow_template(framework: &Framework) -> String {
+    let steps = match framework {
+        Framework::RustCargo => {
+            "      - uses: dtolnay/rust-toolchain@stable\n      - run: cargo build --locked\n      - run: cargo test --locked\n"
+        }
+        Framework::NodeJs | Framework::NextJs => {
+            "      - uses: actions/setup-node@v4\n        with:\n          node-version: 20\n      - run: npm ci\n      - run: npm test\n"
+        }
+        Framework::Symfony | Framework::Laravel => {
+            "      - uses: shivammathur/setup-php@v2\n        with:\n          php-version: '8.3'\n      - run: composer install --no-interaction\n      - run: vendor/bin/phpunit\n"
+        }
+        Framework::Flutter => {
+            "      - uses: subosito/flutter-action@v2\n      - run: flutter pub get\n      - run: flutter test\n"
+        }
+        Framework::Python => {
+            "      - uses: actions/setup-python@v5\n        with:\n          python-version: '3.12'\n      - run: pip install -r requirements.txt\n      - run: python -m pytest\n"
+        }
+        Framework::Unknown => "      - run: echo \"Add build and test steps\"\n",
+    };
+    format!(
+        "name: CI\n\non:\n  push:\n  pull_request:\n\njobs:\n  build:\n    runs-on: ubuntu-latest\n    steps:\n      - uses: actions/checkout@v4\n{}",
+        steps
+    )
+}
+
+impl Fixer for TemplateFixer {
+    fn handles(&self) -> &[&str] {
+        &["STR-002", "STR-004", "NJS-001", "CFG-005"]
+    }
+
+    fn describe(&self, issue: &Issue, project: &Project) -> String {
+        match Self::target_for_issue(issue, &project.detected.framework) {
+            Some((target, _)) if has_override(project, target) => {
+                format!("Create {} from user template", target)
+            }
+            Some((target, _)) => format!("Create {} from built-in template", target),
+            None => "Create file from template".to_string(),
+        }
+    }
+
+    fn target(&self, issue: &Issue, project: &Project) -> Option<String> {
+        Self::target_for_issue(issue, &project.detected.framework)
+            .map(|(target, _)| target.to_string())
+    }
+
+    fn apply(&self, issue: &Issue, project: &Project) -> Result<FixResult> {
+        let (target, builtin) = match Self::target_for_issue(issue, &project.detected.framework) {
+            Some(t) => t,
+            None => {
+                return Ok(FixResult::Skipped {
+                    reason: "No template for this issue".to_string(),
+                })
+            }
+        };
+
+        let full_path = project.path.join(target);
+        if full_path.exists() {
+            return Ok(FixResult::Skipped {
+                reason: format!("{} already exists", target),
+            });
+        }
+
+        if let Some(parent) = full_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&full_path, load(project, target, &builtin))?;
+        Ok(FixResult::Applied {
+            description: format!("Created {}", target),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::{AnalyzerCategory, Severity};
+    use crate::frameworks::detector::{DetectedProject, Language};
+    use std::fs as stdfs;
+    use tempfile::TempDir;
+
+    fn make_project(tmp: &TempDir, framework: Framework) -> Project {
+        Project {
+            path: tmp.path().to_path_buf(),
+            detected: DetectedProject {
+                framework,
+                language: Language::Unknown,
+                version: None,
+                package_manager: None,
+                has_git: false,
+                has_ci: None,
+            },
+        }
+    }
+
+    fn make_issue(id: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Structure,
+            severity: Severity::Medium,
+            title: String::new(),
+            description: String::new(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: true,
+            references: vec![],
+        }
+    }
+
+    #[test]
+    fn test_creates_builtin_readme() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp, Framework::Unknown);
+        let result = TemplateFixer.apply(&make_issue("STR-002"), &project).unwrap();
+        assert!(matches!(result, FixResult::Applied { .. }));
+        let content = stdfs::read_to_string(tmp.path().join("README.md")).unwrap();
+        assert!(content.contains("## Installation"));
+        assert!(!content.contains("{{project_name}}"));
+    }
+
+    #[test]
+    fn test_user_template_overrides_builtin() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::create_dir_all(tmp.path().join("templates")).unwrap();
+        stdfs::write(
+            tmp.path().join("templates/LICENSE"),
+            "Proprietary - ACME Corp {{year}}\n",
+        )
+        .unwrap();
+        stdfs::write(tmp.path().join(".repodoctor.yml"), "templates_dir: templates\n").unwrap();
+        let project = make_project(&tmp, Framework::Unknown);
+
+        assert!(TemplateFixer
+            .describe(&make_issue("STR-004"), &project)
+            .contains("user template"));
+        TemplateFixer.apply(&make_issue("STR-004"), &project).unwrap();
+        let content = stdfs::read_to_string(tmp.path().join("LICENSE")).unwrap();
+        assert!(content.starts_with("Proprietary - ACME Corp 2"));
+    }
+
+    #[test]
+    fn test_creates_ci_workflow_for_framework() {
+        let tmp = TempDir::new().unwrap();
+        let project = make_project(&tmp, Framework::RustCargo);
+        TemplateFixer.apply(&make_issue("CFG-005"), &project).unwrap();
+        let content =
+            stdfs::read_to_string(tmp.path().join(".github/workflows/ci.yml")).unwrap();
+        assert!(content.contains("cargo test"));
+    }
+
+    #[test]
+    fn test_skips_existing_file() {
+        let tmp = TempDir::new().unwrap();
+        stdfs::create_dir_all(tmp.path().join("app")).unwrap();
+        stdfs::write(tmp.path().join("app/layout.tsx"), "custom").unwrap();
+        let project = make_project(&tmp, Framework::NextJs);
+        let result = TemplateFixer.apply(&make_issue("NJS-001"), &project).unwrap();
+        assert!(matches!(result, FixResult::Skipped { .. }));
+    }
+
+    #[test]
+    fn test_current_year_is_plausible() {
+        let year = current_year();
+        assert!((2024..2200).contains(&year));
+    }
+}
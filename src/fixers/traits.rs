@@ -15,6 +15,11 @@ pub trait Fixer: Send + Sync {
     /// Describe what would be done (for dry-run)
     fn describe(&self, issue: &Issue, project: &Project) -> String;
 
+    /// Project-relative file the fix creates or edits, if it writes one
+    fn target(&self, _issue: &Issue, _project: &Project) -> Option<String> {
+        None
+    }
+
     /// Apply the fix
     fn apply(&self, issue: &Issue, project: &Project) -> Result<FixResult>;
 }
@@ -92,6 +92,35 @@ pub struct DetectedProject {
     pub has_ci: Option<CIProvider>,
 }
 
+impl DetectedProject {
+    /// Framework whose generic conventions (test layout, linters, `.gitignore`)
+    /// fit this project. Projects without a recognized manifest fall back to
+    /// the ecosystem of the language inferred from their source files.
+    pub fn generic_framework(&self) -> Framework {
+        match (&self.framework, &self.language) {
+            (Framework::Unknown, Language::Rust) => Framework::RustCargo,
+            (Framework::Unknown, Language::JavaScript | Language::TypeScript) => Framework::NodeJs,
+            (Framework::Unknown, Language::Python) => Framework::Python,
+            (Framework::Unknown, Language::Php) => Framework::Symfony,
+            (Framework::Unknown, Language::Dart) => Framework::Flutter,
+            (framework, _) => framework.clone(),
+        }
+    }
+
+    /// Name used in messages: the framework, or the inferred language when
+    /// no framework was recognized.
+    pub fn kind(&self) -> String {
+        match (&self.framework, &self.language) {
+            (Framework::Unknown, Language::Unknown) => "Unknown".to_string(),
+            (Framework::Unknown, language) => language.to_string(),
+            (framework, _) => framework.to_string(),
+        }
+    }
+}
+
+/// Files sampled when inferring the language of a project without a manifest.
+const MAX_SAMPLED_FILES: usize = 500;
+
 pub struct FrameworkDetector;
 
 impl FrameworkDetector {
@@ -132,7 +161,7 @@ impl FrameworkDetector {
 
         DetectedProject {
             framework: Framework::Unknown,
-            language: Language::Unknown,
+            language: Self::infer_language(path),
             version: None,
             package_manager: None,
             has_git,
@@ -140,6 +169,76 @@ impl FrameworkDetector {
         }
     }
 
+    /// Infer the dominant language from file extensions and shebang lines.
+    fn infer_language(path: &Path) -> Language {
+        let mut counts: Vec<(Language, usize)> = Vec::new();
+        let files = fs::walk(path)
+            .into_iter()
+            .filter_entry(|e| {
+                e.depth() == 0 || !fs::is_ignored_name(&e.file_name().to_string_lossy())
+            })
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .take(MAX_SAMPLED_FILES);
+
+        for entry in files {
+            let language = match entry.path().extension() {
+                Some(ext) => Self::language_for_extension(&ext.to_string_lossy()),
+                None => Self::language_for_shebang(entry.path()),
+            };
+            let Some(language) = language else { continue };
+            match counts.iter_mut().find(|(l, _)| *l == language) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((language, 1)),
+            }
+        }
+
+        // Ties go to the language seen first
+        counts
+            .into_iter()
+            .rev()
+            .max_by_key(|(_, count)| *count)
+            .map_or(Language::Unknown, |(language, _)| language)
+    }
+
+    fn language_for_extension(ext: &str) -> Option<Language> {
+        match ext.to_ascii_lowercase().as_str() {
+            "rs" => Some(Language::Rust),
+            "php" => Some(Language::Php),
+            "dart" => Some(Language::Dart),
+            "js" | "jsx" | "mjs" | "cjs" => Some(Language::JavaScript),
+            "ts" | "tsx" | "mts" | "cts" => Some(Language::TypeScript),
+            "py" => Some(Language::Python),
+            _ => None,
+        }
+    }
+
+    /// Language of an extensionless script, from its `#!` line.
+    fn language_for_shebang(file: &Path) -> Option<Language> {
+        use std::io::{BufRead, BufReader, Read};
+
+        let mut first_line = String::new();
+        let reader = BufReader::new(std::fs::File::open(file).ok()?);
+        reader.take(256).read_line(&mut first_line).ok()?;
+        // `#!/usr/bin/env python3`, `#!/usr/bin/node --flag`: the interpreter
+        // is the last word that is not an option
+        let interpreter = first_line
+            .strip_prefix("#!")?
+            .split_whitespace()
+            .rev()
+            .find(|word| !word.starts_with('-'))?;
+        let name = interpreter.rsplit('/').next()?;
+        if name.starts_with("python") {
+            Some(Language::Python)
+        } else if matches!(name, "node" | "nodejs" | "deno" | "bun") {
+            Some(Language::JavaScript)
+        } else if name.starts_with("php") {
+            Some(Language::Php)
+        } else {
+            None
+        }
+    }
+
     fn detect_version(path: &Path, framework: &Framework) -> Option<String> {
         match framework {
             Framework::RustCargo => Self::version_from_cargo_toml(path),
@@ -149,6 +248,14 @@ impl FrameworkDetector {
         }
     }
 
+    /// The project's own version from whichever manifest declares one.
+    pub fn manifest_version(path: &Path) -> Option<String> {
+        Self::version_from_cargo_toml(path)
+            .or_else(|| Self::version_from_package_json(path))
+            .or_else(|| Self::version_from_pubspec(path))
+            .or_else(|| Self::version_from_pyproject(path))
+    }
+
     fn version_from_cargo_toml(path: &Path) -> Option<String> {
         let content = std::fs::read_to_string(path.join("Cargo.toml")).ok()?;
         for line in content.lines() {
@@ -181,6 +288,22 @@ impl FrameworkDetector {
         None
     }
 
+    fn version_from_pyproject(path: &Path) -> Option<String> {
+        let content = std::fs::read_to_string(path.join("pyproject.toml")).ok()?;
+        let mut in_package = false;
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if trimmed.starts_with('[') {
+                in_package = trimmed == "[project]" || trimmed == "[tool.poetry]";
+            } else if in_package && trimmed.starts_with("version") {
+                if let Some(val) = trimmed.split('=').nth(1) {
+                    return Some(val.trim().trim_matches(['"', '\'']).to_string());
+                }
+            }
+        }
+        None
+    }
+
     fn detect_package_manager(path: &Path) -> Option<PackageManager> {
         if path.join("yarn.lock").exists() {
             Some(PackageManager::Yarn)
@@ -212,6 +335,52 @@ mod tests {
         assert_eq!(detected.language, Language::Unknown);
     }
 
+    #[test]
+    fn test_infer_language_without_manifest() {
+        let tmp = setup_tmp();
+        stdfs::create_dir_all(tmp.path().join("lib")).unwrap();
+        stdfs::create_dir_all(tmp.path().join("node_modules/pkg")).unwrap();
+        stdfs::write(tmp.path().join("lib/app.py"), "").unwrap();
+        stdfs::write(tmp.path().join("lib/util.py"), "").unwrap();
+        stdfs::write(tmp.path().join("manage"), "#!/usr/bin/env python3\n").unwrap();
+        stdfs::write(tmp.path().join("build.js"), "").unwrap();
+        // Dependencies are not sampled
+        for i in 0..5 {
+            stdfs::write(tmp.path().join(format!("node_modules/pkg/{}.js", i)), "").unwrap();
+        }
+
+        let detected = FrameworkDetector::detect(tmp.path());
+        assert_eq!(detected.framework, Framework::Unknown);
+        assert_eq!(detected.language, Language::Python);
+        assert_eq!(detected.generic_framework(), Framework::Python);
+        assert_eq!(detected.kind(), "Python");
+    }
+
+    #[test]
+    fn test_language_for_shebang() {
+        let tmp = setup_tmp();
+        let shebang = |line: &str| {
+            let file = tmp.path().join("script");
+            stdfs::write(&file, line).unwrap();
+            FrameworkDetector::language_for_shebang(&file)
+        };
+        assert_eq!(shebang("#!/usr/bin/env node\n"), Some(Language::JavaScript));
+        assert_eq!(shebang("#!/usr/bin/python3 -u\n"), Some(Language::Python));
+        assert_eq!(shebang("#!/usr/bin/env php\n"), Some(Language::Php));
+        assert_eq!(shebang("#!/bin/sh\n"), None);
+        assert_eq!(shebang("plain text\n"), None);
+    }
+
+    #[test]
+    fn test_generic_framework_keeps_detected_framework() {
+        let tmp = setup_tmp();
+        stdfs::write(tmp.path().join("Cargo.toml"), "[package]\n").unwrap();
+        stdfs::write(tmp.path().join("tool.py"), "").unwrap();
+        let detected = FrameworkDetector::detect(tmp.path());
+        assert_eq!(detected.generic_framework(), Framework::RustCargo);
+        assert_eq!(detected.kind(), "Rust/Cargo");
+    }
+
     #[test]
     fn test_detect_rust_cargo() {
         let tmp = setup_tmp();
@@ -314,4 +483,16 @@ mod tests {
         let detected = FrameworkDetector::detect(tmp.path());
         assert_eq!(detected.package_manager, Some(PackageManager::Yarn));
     }
+
+    #[test]
+    fn test_manifest_version() {
+        let tmp = setup_tmp();
+        assert_eq!(FrameworkDetector::manifest_version(tmp.path()), None);
+        stdfs::write(
+            tmp.path().join("pyproject.toml"),
+            "[build-system]\nrequires = [\"hatchling\"]\n\n[project]\nname = \"pkg\"\nversion = \"0.4.1\"\n",
+        )
+        .unwrap();
+        assert_eq!(FrameworkDetector::manifest_version(tmp.path()).as_deref(), Some("0.4.1"));
+    }
 }
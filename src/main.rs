@@ -14,21 +14,31 @@ use cli::{Cli, Commands};
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
-
-    match &cli.command {
-        Commands::Scan(args) => {
-            cli::commands::scan::execute(args).await?;
-        }
-        Commands::Fix(args) => {
-            cli::commands::fix::execute(args).await?;
-        }
-        Commands::Report(args) => {
-            cli::commands::report::execute(args).await?;
-        }
-        Commands::Init(args) => {
-            cli::commands::init::execute(args).await?;
-        }
+    utils::fs::set_follow_symlinks(cli.follow_symlinks);
+    if let Some(endpoint) = cli.otlp_endpoint.as_deref().filter(|e| !e.is_empty()) {
+        core::telemetry::init(core::telemetry::ExportConfig::from_env(
+            endpoint,
+            cli.otlp_metrics,
+        ));
     }
 
-    Ok(())
+    // Flush telemetry whether or not the command succeeded
+    let result = match &cli.command {
+        Commands::Scan(args) => cli::commands::scan::execute(args).await,
+        Commands::Fix(args) => cli::commands::fix::execute(args).await,
+        Commands::Doctor(args) => cli::commands::doctor::execute(args).await,
+        Commands::Plan(args) => cli::commands::plan::execute(args).await,
+        Commands::Report(args) => cli::commands::report::execute(args).await,
+        Commands::Baseline(args) => cli::commands::baseline::execute(args).await,
+        Commands::Allowlist(args) => cli::commands::allowlist::execute(args).await,
+        Commands::Rules(args) => cli::commands::rules::execute(args).await,
+        Commands::Graph(args) => cli::commands::graph::execute(args).await,
+        Commands::Sbom(args) => cli::commands::sbom::execute(args).await,
+        Commands::Selfcheck(args) => cli::commands::selfcheck::execute(args).await,
+        Commands::SelfUpdate(args) => cli::commands::self_update::execute(args).await,
+        Commands::Init(args) => cli::commands::init::execute(args).await,
+    };
+
+    core::telemetry::flush();
+    result
 }
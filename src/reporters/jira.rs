@@ -0,0 +1,544 @@
+use anyhow::Result;
+use serde_json::{json, Value};
+
+use crate::analyzers::traits::{Issue, Severity};
+use crate::core::scanner::ScanResult;
+use crate::utils::fs::display_path;
+
+use super::traits::Reporter;
+
+/// Jira wiki markup, for pasting into Jira Server/Data Center tickets and
+/// Confluence pages.
+pub struct JiraWikiReporter;
+
+/// Atlassian Document Format JSON, the `description` body expected by the
+/// Jira Cloud REST API (v3).
+pub struct AdfReporter;
+
+const SEVERITIES: [Severity; 5] = [
+    Severity::Critical,
+    Severity::High,
+    Severity::Medium,
+    Severity::Low,
+    Severity::Info,
+];
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "CRITICAL",
+        Severity::High => "HIGH",
+        Severity::Medium => "MEDIUM",
+        Severity::Low => "LOW",
+        Severity::Info => "INFO",
+    }
+}
+
+/// Hex color for the wiki `{color}` macro, matching Atlassian's palette.
+fn severity_color(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "#bf2600",
+        Severity::High => "#de350b",
+        Severity::Medium => "#ff8b00",
+        Severity::Low => "#0065ff",
+        Severity::Info => "#6b778c",
+    }
+}
+
+/// Color of the ADF status lozenge; ADF only offers a fixed set.
+fn status_color(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "red",
+        Severity::Medium => "yellow",
+        Severity::Low => "blue",
+        Severity::Info => "neutral",
+    }
+}
+
+fn location(issue: &Issue) -> Option<String> {
+    let file = issue.file.as_ref()?;
+    Some(format!(
+        "{}{}",
+        display_path(file),
+        issue.line.map(|l| format!(":{}", l)).unwrap_or_default()
+    ))
+}
+
+fn issues_with(result: &ScanResult, severity: Severity) -> Vec<&Issue> {
+    result.issues.iter().filter(|i| i.severity == severity).collect()
+}
+
+// ---------------------------------------------------------------------------
+// Wiki markup
+// ---------------------------------------------------------------------------
+
+/// Escape characters that wiki markup would treat as formatting or macros.
+fn escape_wiki(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '{' | '}' | '[' | ']' | '|' | '*' | '_' | '^' | '~' | '+' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn wiki_severity(severity: Severity) -> String {
+    format!("{{color:{}}}*{}*{{color}}", severity_color(severity), severity_label(severity))
+}
+
+fn render_wiki(result: &ScanResult) -> String {
+    let mut wiki = String::with_capacity(4096);
+
+    wiki.push_str("h1. RepoDoctor Health Report\n\n");
+    wiki.push_str("||Field||Value||\n");
+    wiki.push_str(&format!(
+        "|*Path*|{{{{{}}}}}|\n",
+        escape_wiki(&display_path(&result.project.path))
+    ));
+    wiki.push_str(&format!(
+        "|*Framework*|{} {}|\n",
+        result.project.detected.framework,
+        escape_wiki(result.project.detected.version.as_deref().unwrap_or(""))
+    ));
+    wiki.push_str(&format!(
+        "|*Health score*|*{}/100* (Grade {})|\n",
+        result.score.total, result.score.grade
+    ));
+    if result.metrics.files > 0 {
+        wiki.push_str(&format!("|*Size*|{}|\n", result.metrics.summary()));
+        wiki.push_str(&format!("|*File types*|{}|\n", result.metrics.top_types(6)));
+    }
+    wiki.push('\n');
+
+    wiki.push_str("h2. Category Breakdown\n\n");
+    wiki.push_str("||Category||Score||Issues||\n");
+    for cat in &result.score.breakdown {
+        wiki.push_str(&format!("|{}|{}/100|{}|\n", cat.name, cat.score, cat.issues_count));
+    }
+    wiki.push('\n');
+
+    if let Some(policy) = result.policy.as_ref().filter(|p| !p.violations.is_empty()) {
+        wiki.push_str(&format!(
+            "h2. Policy Violations ({})\n\n",
+            escape_wiki(&policy.name)
+        ));
+        wiki.push_str("{warning}These rules are mandated by organization policy and cannot be suppressed by project configuration.{warning}\n\n");
+        for issue in &policy.violations {
+            wiki.push_str(&format!(
+                "* {} *{}* - {}\n",
+                wiki_severity(issue.severity),
+                issue.id,
+                escape_wiki(&issue.title)
+            ));
+        }
+        wiki.push('\n');
+    }
+
+    wiki.push_str("h2. Issues\n\n");
+    if result.issues.is_empty() {
+        wiki.push_str("No issues found!\n\n");
+    }
+    for severity in SEVERITIES {
+        let group = issues_with(result, severity);
+        if group.is_empty() {
+            continue;
+        }
+        wiki.push_str(&format!("h3. {} - {} issue(s)\n\n", wiki_severity(severity), group.len()));
+        for issue in group {
+            wiki.push_str(&format!("* *{}* - {}", issue.id, escape_wiki(&issue.title)));
+            if issue.auto_fixable {
+                wiki.push_str(" _(auto-fixable)_");
+            }
+            wiki.push('\n');
+            if let Some(location) = location(issue) {
+                wiki.push_str(&format!("** File: {{{{{}}}}}\n", escape_wiki(&location)));
+            }
+            if let Some(suggestion) = &issue.suggestion {
+                wiki.push_str(&format!("** Suggestion: {}\n", escape_wiki(suggestion)));
+            }
+            if !issue.references.is_empty() {
+                let links: Vec<String> =
+                    issue.references.iter().map(|r| format!("[{}]", r)).collect();
+                wiki.push_str(&format!("** See: {}\n", links.join(", ")));
+            }
+        }
+        wiki.push('\n');
+    }
+
+    wiki.push_str("----\n_Generated by RepoDoctor_\n");
+    wiki
+}
+
+impl Reporter for JiraWikiReporter {
+    fn name(&self) -> &str {
+        "Jira wiki"
+    }
+
+    fn extension(&self) -> &str {
+        "jira"
+    }
+
+    fn generate(&self, result: &ScanResult) -> Result<String> {
+        Ok(render_wiki(result))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Atlassian Document Format
+// ---------------------------------------------------------------------------
+
+fn text(value: impl Into<String>) -> Value {
+    json!({ "type": "text", "text": value.into() })
+}
+
+fn marked(value: impl Into<String>, mark: &str) -> Value {
+    json!({ "type": "text", "text": value.into(), "marks": [{ "type": mark }] })
+}
+
+fn link(href: &str) -> Value {
+    json!({ "type": "text", "text": href, "marks": [{ "type": "link", "attrs": { "href": href } }] })
+}
+
+fn paragraph(content: Vec<Value>) -> Value {
+    json!({ "type": "paragraph", "content": content })
+}
+
+fn heading(level: u8, content: Vec<Value>) -> Value {
+    json!({ "type": "heading", "attrs": { "level": level }, "content": content })
+}
+
+fn status(severity: Severity) -> Value {
+    json!({
+        "type": "status",
+        "attrs": { "text": severity_label(severity), "color": status_color(severity) }
+    })
+}
+
+fn table(header: &[&str], rows: Vec<Vec<Value>>) -> Value {
+    let header_row = json!({
+        "type": "tableRow",
+        "content": header
+            .iter()
+            .map(|h| json!({ "type": "tableHeader", "content": [paragraph(vec![text(*h)])] }))
+            .collect::<Vec<_>>()
+    });
+    let body = rows.into_iter().map(|cells| {
+        json!({
+            "type": "tableRow",
+            "content": cells
+                .into_iter()
+                .map(|cell| json!({ "type": "tableCell", "content": [paragraph(vec![cell])] }))
+                .collect::<Vec<_>>()
+        })
+    });
+    json!({
+        "type": "table",
+        "content": std::iter::once(header_row).chain(body).collect::<Vec<_>>()
+    })
+}
+
+fn issue_item(issue: &Issue) -> Value {
+    let mut line = vec![
+        status(issue.severity),
+        text(" "),
+        marked(&issue.id, "strong"),
+        text(format!(" - {}", issue.title)),
+    ];
+    if issue.auto_fixable {
+        line.push(marked(" (auto-fixable)", "em"));
+    }
+    let mut content = vec![paragraph(line)];
+
+    let mut details = Vec::new();
+    if let Some(location) = location(issue) {
+        details.push(paragraph(vec![text("File: "), marked(location, "code")]));
+    }
+    if let Some(suggestion) = &issue.suggestion {
+        details.push(paragraph(vec![text(format!("Suggestion: {}", suggestion))]));
+    }
+    if !issue.references.is_empty() {
+        let mut see = vec![text("See: ")];
+        for (i, reference) in issue.references.iter().enumerate() {
+            if i > 0 {
+                see.push(text(", "));
+            }
+            see.push(link(reference));
+        }
+        details.push(paragraph(see));
+    }
+    if !details.is_empty() {
+        content.push(json!({
+            "type": "bulletList",
+            "content": details
+                .into_iter()
+                .map(|d| json!({ "type": "listItem", "content": [d] }))
+                .collect::<Vec<_>>()
+        }));
+    }
+    json!({ "type": "listItem", "content": content })
+}
+
+fn bullet_list(issues: &[&Issue]) -> Value {
+    json!({
+        "type": "bulletList",
+        "content": issues.iter().map(|i| issue_item(i)).collect::<Vec<_>>()
+    })
+}
+
+fn render_adf(result: &ScanResult) -> Value {
+    let mut doc = vec![heading(1, vec![text("RepoDoctor Health Report")])];
+
+    let mut fields = vec![
+        vec![marked("Path", "strong"), marked(display_path(&result.project.path), "code")],
+        vec![
+            marked("Framework", "strong"),
+            text(format!(
+                "{} {}",
+                result.project.detected.framework,
+                result.project.detected.version.as_deref().unwrap_or("")
+            )),
+        ],
+        vec![
+            marked("Health score", "strong"),
+            text(format!("{}/100 (Grade {})", result.score.total, result.score.grade)),
+        ],
+    ];
+    if result.metrics.files > 0 {
+        fields.push(vec![marked("Size", "strong"), text(result.metrics.summary())]);
+        fields.push(vec![marked("File types", "strong"), text(result.metrics.top_types(6))]);
+    }
+    doc.push(table(&["Field", "Value"], fields));
+
+    doc.push(heading(2, vec![text("Category Breakdown")]));
+    doc.push(table(
+        &["Category", "Score", "Issues"],
+        result
+            .score
+            .breakdown
+            .iter()
+            .map(|cat| {
+                vec![
+                    text(&cat.name),
+                    text(format!("{}/100", cat.score)),
+                    text(cat.issues_count.to_string()),
+                ]
+            })
+            .collect(),
+    ));
+
+    if let Some(policy) = result.policy.as_ref().filter(|p| !p.violations.is_empty()) {
+        doc.push(heading(2, vec![text(format!("Policy Violations ({})", policy.name))]));
+        doc.push(json!({
+            "type": "panel",
+            "attrs": { "panelType": "warning" },
+            "content": [paragraph(vec![text(
+                "These rules are mandated by organization policy and cannot be suppressed by project configuration."
+            )])]
+        }));
+        let violations: Vec<&Issue> = policy.violations.iter().collect();
+        doc.push(bullet_list(&violations));
+    }
+
+    doc.push(heading(2, vec![text("Issues")]));
+    if result.issues.is_empty() {
+        doc.push(paragraph(vec![text("No issues found!")]));
+    }
+    for severity in SEVERITIES {
+        let group = issues_with(result, severity);
+        if group.is_empty() {
+            continue;
+        }
+        doc.push(heading(
+            3,
+            vec![status(severity), text(format!(" {} issue(s)", group.len()))],
+        ));
+        doc.push(bullet_list(&group));
+    }
+
+    doc.push(json!({ "type": "rule" }));
+    doc.push(paragraph(vec![marked("Generated by RepoDoctor", "em")]));
+
+    json!({ "version": 1, "type": "doc", "content": doc })
+}
+
+impl Reporter for AdfReporter {
+    fn name(&self) -> &str {
+        "Jira ADF"
+    }
+
+    fn extension(&self) -> &str {
+        "adf.json"
+    }
+
+    fn generate(&self, result: &ScanResult) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&render_adf(result))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::AnalyzerCategory;
+    use crate::core::project::Project;
+    use crate::core::score::HealthScore;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn make_result(issues: Vec<Issue>) -> ScanResult {
+        let score = HealthScore::calculate(&issues);
+        ScanResult {
+            project: Project {
+                path: "/tmp/test-project".into(),
+                detected: DetectedProject {
+                    framework: Framework::RustCargo,
+                    language: Language::Rust,
+                    version: None,
+                    package_manager: None,
+                    has_git: true,
+                    has_ci: None,
+                },
+            },
+            issues,
+            score,
+            duration: Duration::from_millis(500),
+            expiring: vec![],
+            policy: None,
+            skipped_files: vec![],
+            stats: vec![],
+            metrics: Default::default(),
+        }
+    }
+
+    fn make_issue(id: &str, severity: Severity) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Security,
+            severity,
+            title: "Secret in config_prod.yml".to_string(),
+            description: String::new(),
+            file: Some(PathBuf::from("config/app_prod.yml")),
+            line: Some(4),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: Some("Move it to {env}".to_string()),
+            auto_fixable: false,
+            references: vec![],
+        }
+    }
+
+    #[test]
+    fn test_escape_wiki() {
+        assert_eq!(escape_wiki("a_b {c} [d] |e| *f*"), "a\\_b \\{c\\} \\[d\\] \\|e\\| \\*f\\*");
+        assert_eq!(escape_wiki("SEC-001"), "SEC-001");
+    }
+
+    #[test]
+    fn test_wiki_report() {
+        let result = make_result(vec![
+            make_issue("SEC-001", Severity::Critical),
+            make_issue("DOC-003", Severity::Low),
+        ]);
+        let wiki = JiraWikiReporter.generate(&result).unwrap();
+
+        assert!(wiki.starts_with("h1. RepoDoctor Health Report"));
+        assert!(wiki.contains("||Category||Score||Issues||"));
+        assert!(wiki.contains("h3. {color:#bf2600}*CRITICAL*{color} - 1 issue(s)"));
+        assert!(wiki.contains("h3. {color:#0065ff}*LOW*{color} - 1 issue(s)"));
+        assert!(wiki.contains("* *SEC-001* - Secret in config\\_prod.yml"));
+        assert!(wiki.contains("** File: {{config/app\\_prod.yml:4}}"));
+        assert!(wiki.contains("** Suggestion: Move it to \\{env\\}"));
+    }
+
+    #[test]
+    fn test_wiki_no_issues() {
+        let wiki = render_wiki(&make_result(vec![]));
+        assert!(wiki.contains("No issues found!"));
+        assert!(!wiki.contains("h3."));
+        assert!(!wiki.contains("|*Size*|"));
+    }
+
+    #[test]
+    fn test_wiki_repository_size() {
+        let mut result = make_result(vec![]);
+        result.metrics.files = 1200;
+        result.metrics.lines = 45000;
+        let wiki = render_wiki(&result);
+        assert!(wiki.contains("|*Size*|1,200 files, 45,000 lines|\n"));
+    }
+
+    #[test]
+    fn test_adf_document() {
+        let mut issue = make_issue("SEC-001", Severity::High);
+        issue.auto_fixable = true;
+        let result = make_result(vec![issue]);
+        let adf: Value = serde_json::from_str(&AdfReporter.generate(&result).unwrap()).unwrap();
+
+        assert_eq!(adf["version"], 1);
+        assert_eq!(adf["type"], "doc");
+        let content = adf["content"].as_array().unwrap();
+        let group = content
+            .iter()
+            .position(|node| node["type"] == "heading" && node["content"][0]["type"] == "status")
+            .unwrap();
+        assert_eq!(content[group]["content"][0]["attrs"]["text"], "HIGH");
+        assert_eq!(content[group]["content"][0]["attrs"]["color"], "red");
+
+        let item = &content[group + 1]["content"][0];
+        assert_eq!(item["type"], "listItem");
+        let line = &item["content"][0]["content"];
+        assert_eq!(line[2]["text"], "SEC-001");
+        assert_eq!(line[2]["marks"][0]["type"], "strong");
+        assert_eq!(line[4]["text"], " (auto-fixable)");
+        let file = &item["content"][1]["content"][0]["content"][0]["content"][1];
+        assert_eq!(file["text"], "config/app_prod.yml:4");
+        assert_eq!(file["marks"][0]["type"], "code");
+    }
+
+    #[test]
+    fn test_adf_policy_panel() {
+        let issue = make_issue("SEC-001", Severity::Critical);
+        let mut result = make_result(vec![issue.clone()]);
+        result.policy = Some(crate::core::policy::PolicyReport {
+            name: "ACME".to_string(),
+            violations: vec![issue],
+        });
+        let adf = render_adf(&result);
+        let panel = adf["content"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|node| node["type"] == "panel")
+            .unwrap();
+        assert_eq!(panel["attrs"]["panelType"], "warning");
+    }
+
+    #[test]
+    fn test_references_are_linked() {
+        let mut issue = make_issue("SEC-001", Severity::High);
+        issue.references = vec!["https://a.example/x".to_string(), "https://b.example/y".to_string()];
+        let result = make_result(vec![issue]);
+
+        let wiki = render_wiki(&result);
+        assert!(wiki.contains("** See: [https://a.example/x], [https://b.example/y]"));
+
+        let adf = render_adf(&result);
+        let see = adf["content"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|node| node["type"] == "bulletList")
+            .flat_map(|list| list["content"][0]["content"][1]["content"].as_array().cloned().unwrap_or_default())
+            .map(|item| item["content"][0]["content"].clone())
+            .find(|line| line[0]["text"] == "See: ")
+            .unwrap();
+        assert_eq!(see[1]["marks"][0]["type"], "link");
+        assert_eq!(see[1]["marks"][0]["attrs"]["href"], "https://a.example/x");
+        assert_eq!(see[3]["text"], "https://b.example/y");
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::Result;
 
 use crate::core::scanner::ScanResult;
 use crate::reporters::traits::Reporter;
+use crate::utils::fs::display_path;
 
 pub struct JsonReporter;
 
@@ -17,7 +18,7 @@ impl Reporter for JsonReporter {
     fn generate(&self, result: &ScanResult) -> Result<String> {
         let output = serde_json::json!({
             "project": {
-                "path": result.project.path.to_string_lossy(),
+                "path": display_path(&result.project.path),
                 "framework": result.project.detected.framework,
                 "language": result.project.detected.language,
                 "version": result.project.detected.version,
@@ -31,6 +32,7 @@ impl Reporter for JsonReporter {
                 "breakdown": result.score.breakdown,
             },
             "issues": result.issues,
+            "themes": result.themes(),
             "summary": {
                 "total_issues": result.issues.len(),
                 "critical": result.issues.iter().filter(|i| i.severity == crate::analyzers::traits::Severity::Critical).count(),
@@ -40,6 +42,10 @@ impl Reporter for JsonReporter {
                 "info": result.issues.iter().filter(|i| i.severity == crate::analyzers::traits::Severity::Info).count(),
                 "auto_fixable": result.issues.iter().filter(|i| i.auto_fixable).count(),
             },
+            "expiring_suppressions": result.expiring,
+            "policy": result.policy,
+            "skipped_files": result.skipped_files,
+            "metrics": result.metrics,
             "duration_ms": result.duration.as_millis(),
         });
         Ok(serde_json::to_string_pretty(&output)?)
@@ -72,6 +78,11 @@ mod tests {
             score: HealthScore::calculate(&issues),
             issues,
             duration: Duration::from_millis(42),
+            expiring: vec![],
+            policy: None,
+            skipped_files: vec![],
+            stats: vec![],
+            metrics: Default::default(),
         }
     }
 
@@ -84,6 +95,7 @@ mod tests {
         assert_eq!(parsed["score"]["total"], 100);
         assert_eq!(parsed["score"]["grade"], "A");
         assert_eq!(parsed["summary"]["total_issues"], 0);
+        assert_eq!(parsed["themes"], serde_json::json!([]));
     }
 
     #[test]
@@ -97,6 +109,11 @@ mod tests {
             description: "No src directory".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Create src/".to_string()),
             auto_fixable: true,
             references: vec![],
@@ -123,6 +140,18 @@ mod tests {
         assert_eq!(parsed["duration_ms"], 42);
     }
 
+    #[test]
+    fn test_json_report_metrics() {
+        let mut result = make_result(vec![]);
+        result.metrics.files = 12;
+        result.metrics.lines = 340;
+        let output = JsonReporter.generate(&result).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["metrics"]["files"], 12);
+        assert_eq!(parsed["metrics"]["lines"], 340);
+        assert!(parsed["metrics"]["growth"].is_null());
+    }
+
     #[test]
     fn test_json_reporter_metadata() {
         let reporter = JsonReporter;
@@ -1,183 +1,44 @@
 use anyhow::Result;
 
-use crate::analyzers::traits::Severity;
 use crate::core::scanner::ScanResult;
-use crate::core::score::Grade;
 
+use super::template::{self, ReportTemplates};
 use super::traits::Reporter;
 
-pub struct MarkdownReporter;
-
-impl Reporter for MarkdownReporter {
-    fn name(&self) -> &str {
-        "markdown"
-    }
-
-    fn extension(&self) -> &str {
-        "md"
-    }
-
-    fn generate(&self, result: &ScanResult) -> Result<String> {
-        Ok(render_markdown(result))
-    }
-}
-
-fn grade_emoji(grade: Grade) -> &'static str {
-    match grade {
-        Grade::A => "A",
-        Grade::B => "B",
-        Grade::C => "C",
-        Grade::D => "D",
-        Grade::F => "F",
-    }
+pub struct MarkdownReporter {
+    templates: ReportTemplates,
 }
 
-fn severity_badge(severity: Severity) -> &'static str {
-    match severity {
-        Severity::Critical => "CRITICAL",
-        Severity::High => "HIGH",
-        Severity::Medium => "MEDIUM",
-        Severity::Low => "LOW",
-        Severity::Info => "INFO",
+impl MarkdownReporter {
+    pub fn new(templates: ReportTemplates) -> Self {
+        Self { templates }
     }
 }
 
-fn status_text(score: u8) -> &'static str {
-    match score {
-        80..=100 => "Good",
-        60..=79 => "Needs attention",
-        _ => "Poor",
+impl Default for MarkdownReporter {
+    fn default() -> Self {
+        Self::new(ReportTemplates::builtin())
     }
 }
 
-fn render_markdown(result: &ScanResult) -> String {
-    let mut md = String::with_capacity(4096);
-
-    // Title
-    md.push_str("# RepoDoctor Health Report\n\n");
-
-    // Project info
-    md.push_str("## Project Info\n\n");
-    md.push_str(&format!(
-        "| Field | Value |\n|-------|-------|\n| **Path** | `{}` |\n| **Framework** | {} {} |\n| **Scan duration** | {:.1}s |\n\n",
-        result.project.path.to_string_lossy(),
-        result.project.detected.framework,
-        result.project.detected.version.as_deref().unwrap_or(""),
-        result.duration.as_secs_f64(),
-    ));
-
-    // Health score
-    md.push_str(&format!(
-        "## Health Score: {}/100 (Grade {})\n\n",
-        result.score.total,
-        grade_emoji(result.score.grade),
-    ));
-
-    // Category breakdown
-    md.push_str("## Category Breakdown\n\n");
-    md.push_str("| Category | Score | Issues | Status |\n");
-    md.push_str("|----------|-------|--------|--------|\n");
-
-    for cat in &result.score.breakdown {
-        md.push_str(&format!(
-            "| {} | {}/100 | {} | {} |\n",
-            cat.name,
-            cat.score,
-            cat.issues_count,
-            status_text(cat.score),
-        ));
+impl Reporter for MarkdownReporter {
+    fn name(&self) -> &str {
+        "markdown"
     }
-    md.push('\n');
-
-    // Issues
-    md.push_str("## Issues\n\n");
-
-    let severity_groups = [
-        (Severity::Critical, "Critical"),
-        (Severity::High, "High"),
-        (Severity::Medium, "Medium"),
-        (Severity::Low, "Low"),
-        (Severity::Info, "Info"),
-    ];
-
-    let mut has_issues = false;
-    for (severity, label) in &severity_groups {
-        let group: Vec<_> = result
-            .issues
-            .iter()
-            .filter(|i| i.severity == *severity)
-            .collect();
-
-        if group.is_empty() {
-            continue;
-        }
-        has_issues = true;
-
-        md.push_str(&format!(
-            "### {} ({}) - {} issue(s)\n\n",
-            severity_badge(*severity),
-            label,
-            group.len()
-        ));
-
-        for issue in &group {
-            md.push_str(&format!("- **{}** - {}", issue.id, issue.title));
-            if issue.auto_fixable {
-                md.push_str(" *(auto-fixable)*");
-            }
-            md.push('\n');
 
-            if let Some(file) = &issue.file {
-                md.push_str(&format!(
-                    "  - File: `{}{}`\n",
-                    file.to_string_lossy(),
-                    issue
-                        .line
-                        .map(|l| format!(":{}", l))
-                        .unwrap_or_default(),
-                ));
-            }
-
-            if let Some(suggestion) = &issue.suggestion {
-                md.push_str(&format!("  - Suggestion: {}\n", suggestion));
-            }
-        }
-        md.push('\n');
+    fn extension(&self) -> &str {
+        "md"
     }
 
-    if !has_issues {
-        md.push_str("No issues found!\n\n");
+    fn generate(&self, result: &ScanResult) -> Result<String> {
+        self.templates.render(template::MARKDOWN, result)
     }
-
-    // Summary
-    let total = result.issues.len();
-    let critical = result
-        .issues
-        .iter()
-        .filter(|i| i.severity == Severity::Critical)
-        .count();
-    let high = result
-        .issues
-        .iter()
-        .filter(|i| i.severity == Severity::High)
-        .count();
-    let fixable = result.issues.iter().filter(|i| i.auto_fixable).count();
-
-    md.push_str("## Summary\n\n");
-    md.push_str(&format!(
-        "- **{}** issues found ({} critical, {} high)\n",
-        total, critical, high,
-    ));
-    md.push_str(&format!("- **{}** auto-fixable issues\n", fixable));
-    md.push_str("\n---\n*Generated by RepoDoctor v0.1.0*\n");
-
-    md
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::analyzers::traits::{AnalyzerCategory, Issue};
+    use crate::analyzers::traits::{AnalyzerCategory, Issue, Severity};
     use crate::core::project::Project;
     use crate::core::score::HealthScore;
     use crate::frameworks::detector::{DetectedProject, Framework, Language};
@@ -200,6 +61,11 @@ mod tests {
             issues,
             score,
             duration: Duration::from_millis(500),
+            expiring: vec![],
+            policy: None,
+            skipped_files: vec![],
+            stats: vec![],
+            metrics: Default::default(),
         }
     }
 
@@ -213,6 +79,11 @@ mod tests {
             description: "A test issue".to_string(),
             file: None,
             line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
             suggestion: Some("Fix it".to_string()),
             auto_fixable: false,
             references: vec![],
@@ -222,7 +93,7 @@ mod tests {
     #[test]
     fn test_markdown_report_structure() {
         let result = make_result(vec![make_issue("SEC-001", Severity::Critical)]);
-        let reporter = MarkdownReporter;
+        let reporter = MarkdownReporter::default();
         let md = reporter.generate(&result).unwrap();
 
         assert!(md.contains("# RepoDoctor Health Report"));
@@ -237,7 +108,7 @@ mod tests {
     #[test]
     fn test_markdown_no_issues() {
         let result = make_result(vec![]);
-        let reporter = MarkdownReporter;
+        let reporter = MarkdownReporter::default();
         let md = reporter.generate(&result).unwrap();
 
         assert!(md.contains("100/100"));
@@ -250,9 +121,90 @@ mod tests {
         let mut issue = make_issue("TST-001", Severity::Low);
         issue.auto_fixable = true;
         let result = make_result(vec![issue]);
-        let reporter = MarkdownReporter;
+        let reporter = MarkdownReporter::default();
         let md = reporter.generate(&result).unwrap();
 
         assert!(md.contains("*(auto-fixable)*"));
     }
+
+    #[test]
+    fn test_markdown_lists_references() {
+        let mut issue = make_issue("SEC-001", Severity::High);
+        issue.references = vec!["https://a.example".to_string(), "https://b.example".to_string()];
+        let md = MarkdownReporter::default().generate(&make_result(vec![issue])).unwrap();
+        assert!(md.contains("  - Suggestion: Fix it\n  - See: https://a.example, https://b.example\n"));
+    }
+
+    #[test]
+    fn test_markdown_lists_quick_wins() {
+        let mut issue = make_issue("STR-003", Severity::High);
+        issue.auto_fixable = true;
+        let md = MarkdownReporter::default().generate(&make_result(vec![issue])).unwrap();
+        assert!(md.contains("## Quick Wins\n"));
+        assert!(md.contains("  - Effort: trivial, impact: high\n"));
+
+        let md = MarkdownReporter::default()
+            .generate(&make_result(vec![make_issue("TST-001", Severity::Low)]))
+            .unwrap();
+        assert!(!md.contains("## Quick Wins"));
+    }
+
+    #[test]
+    fn test_markdown_groups_related_issues_into_themes() {
+        let result = make_result(vec![
+            make_issue("TST-001", Severity::High),
+            make_issue("TST-002", Severity::Medium),
+        ]);
+        let md = MarkdownReporter::default().generate(&result).unwrap();
+        assert!(md.contains("## Themes\n\n### No working test setup (2 issues)\n"));
+        assert!(md.contains("- **TST-002** [MEDIUM] - "));
+        assert!(md.contains("All issues are grouped under themes above."));
+        assert!(!md.contains("No issues found!"));
+        assert!(!md.contains("### HIGH"));
+    }
+
+    #[test]
+    fn test_markdown_lists_expiring_suppressions() {
+        let mut result = make_result(vec![]);
+        result.expiring = vec![crate::core::config::ExpiringSuppression {
+            source: "config".to_string(),
+            rule: "SEC-001".to_string(),
+            file: None,
+            expires: "2025-12-31".to_string(),
+            days_left: 7,
+        }];
+        let md = MarkdownReporter::default().generate(&result).unwrap();
+        assert!(md.contains("## Suppressions Expiring"));
+        assert!(md.contains("| config | SEC-001 | expires in 7 day(s) (2025-12-31) |"));
+    }
+
+    #[test]
+    fn test_markdown_policy_section() {
+        let issue = Issue {
+            id: "SEC-001".to_string(),
+            analyzer: "security".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::Critical,
+            title: "Hardcoded secret".to_string(),
+            description: String::new(),
+            file: None,
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        };
+        let mut result = make_result(vec![issue.clone()]);
+        result.policy = Some(crate::core::policy::PolicyReport {
+            name: "ACME".to_string(),
+            violations: vec![issue],
+        });
+        let md = MarkdownReporter::default().generate(&result).unwrap();
+        assert!(md.contains("## Policy Violations (ACME) - 1 violation(s)"));
+        assert!(md.contains("- **SEC-001** [CRITICAL] - Hardcoded secret"));
+    }
 }
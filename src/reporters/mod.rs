@@ -1,5 +1,10 @@
 pub mod badge;
 pub mod html;
+pub mod jira;
 pub mod json;
 pub mod markdown;
+pub mod sarif;
+pub mod summary;
+pub mod template;
 pub mod traits;
+
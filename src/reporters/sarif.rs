@@ -0,0 +1,223 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::analyzers::traits::{Issue, Severity};
+use crate::core::content::relative_path;
+use crate::core::scanner::ScanResult;
+use crate::reporters::traits::Reporter;
+
+/// SARIF 2.1.0 output for code scanning dashboards (e.g. GitHub code scanning).
+pub struct SarifReporter;
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low | Severity::Info => "note",
+    }
+}
+
+fn location(issue: &Issue, root: &Path) -> Option<serde_json::Value> {
+    let file = issue.file.as_ref()?;
+    let mut physical = serde_json::json!({
+        "artifactLocation": { "uri": relative_path(root, file) },
+    });
+    if let Some(line) = issue.line {
+        let mut region = serde_json::json!({ "startLine": line });
+        if let Some(column) = issue.column {
+            region["startColumn"] = column.into();
+        }
+        if let Some(snippet) = &issue.snippet {
+            region["snippet"] = serde_json::json!({ "text": snippet });
+        }
+        physical["region"] = region;
+    }
+    Some(serde_json::json!({ "physicalLocation": physical }))
+}
+
+impl Reporter for SarifReporter {
+    fn name(&self) -> &str {
+        "SARIF"
+    }
+
+    fn extension(&self) -> &str {
+        "sarif"
+    }
+
+    fn generate(&self, result: &ScanResult) -> Result<String> {
+        let root = &result.project.path;
+
+        // One rule per distinct issue ID, described by its first occurrence
+        let mut rules = BTreeMap::new();
+        for issue in &result.issues {
+            rules.entry(issue.id.as_str()).or_insert_with(|| {
+                let mut rule = serde_json::json!({
+                    "id": issue.id,
+                    "shortDescription": { "text": issue.title },
+                    "fullDescription": { "text": issue.description },
+                    "defaultConfiguration": { "level": level(issue.severity) },
+                    "properties": { "category": format!("{}", issue.category) },
+                });
+                if let Some(reference) = issue.references.first() {
+                    rule["helpUri"] = serde_json::json!(reference);
+                }
+                if issue.references.len() > 1 {
+                    rule["help"] = serde_json::json!({
+                        "text": format!("See: {}", issue.references.join(", "))
+                    });
+                }
+                rule
+            });
+        }
+
+        let results: Vec<_> = result
+            .issues
+            .iter()
+            .map(|issue| {
+                let mut entry = serde_json::json!({
+                    "ruleId": issue.id,
+                    "level": level(issue.severity),
+                    "message": { "text": match &issue.suggestion {
+                        Some(suggestion) => format!("{} {}", issue.description, suggestion),
+                        None => issue.description.clone(),
+                    } },
+                });
+                if let Some(fingerprint) = &issue.fingerprint {
+                    entry["partialFingerprints"] =
+                        serde_json::json!({ "repodoctorOccurrence/v1": fingerprint });
+                }
+                if let Some(location) = location(issue, root) {
+                    entry["locations"] = serde_json::json!([location]);
+                }
+                entry
+            })
+            .collect();
+
+        let output = serde_json::json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "RepoDoctor",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules.into_values().collect::<Vec<_>>(),
+                    }
+                },
+                "results": results,
+                "properties": { "metrics": result.metrics },
+            }],
+        });
+        Ok(serde_json::to_string_pretty(&output)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::AnalyzerCategory;
+    use crate::core::project::Project;
+    use crate::core::score::HealthScore;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn make_result(issues: Vec<Issue>) -> ScanResult {
+        ScanResult {
+            project: Project {
+                path: PathBuf::from("/tmp/test"),
+                detected: DetectedProject {
+                    framework: Framework::NextJs,
+                    language: Language::TypeScript,
+                    version: None,
+                    package_manager: None,
+                    has_git: true,
+                    has_ci: None,
+                },
+            },
+            score: HealthScore::calculate(&issues),
+            issues,
+            duration: Duration::from_millis(42),
+            expiring: vec![],
+            policy: None,
+            skipped_files: vec![],
+            stats: vec![],
+            metrics: Default::default(),
+        }
+    }
+
+    fn make_issue(id: &str, severity: Severity, file: Option<&str>) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Security,
+            severity,
+            title: "Test issue".to_string(),
+            description: "A test issue.".to_string(),
+            file: file.map(PathBuf::from),
+            line: None,
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        }
+    }
+
+    #[test]
+    fn test_sarif_structure() {
+        let mut located = make_issue("NJS-042", Severity::High, Some("/tmp/test/app/page.tsx"));
+        located.line = Some(4);
+        let located = located.with_snippet("<div dangerouslySetInnerHTML={x} />", 5);
+        let result = make_result(vec![
+            located,
+            make_issue("STR-004", Severity::Low, None),
+            make_issue("STR-004", Severity::Low, None),
+        ]);
+
+        let sarif: serde_json::Value =
+            serde_json::from_str(&SarifReporter.generate(&result).unwrap()).unwrap();
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+        assert_eq!(run["results"].as_array().unwrap().len(), 3);
+
+        let first = &run["results"][0];
+        assert_eq!(first["level"], "error");
+        let location = &first["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "app/page.tsx");
+        assert_eq!(location["region"]["startLine"], 4);
+        assert_eq!(location["region"]["startColumn"], 6);
+        assert_eq!(
+            location["region"]["snippet"]["text"],
+            "<div dangerouslySetInnerHTML={x} />"
+        );
+        assert!(run["results"][1].get("locations").is_none());
+        assert_eq!(run["results"][1]["level"], "note");
+    }
+
+    #[test]
+    fn test_sarif_rule_help_links() {
+        let mut issue = make_issue("SEC-001", Severity::Critical, None);
+        issue.references = vec![
+            "https://example.com/secrets".to_string(),
+            "https://example.com/cwe".to_string(),
+        ];
+        let result = make_result(vec![issue, make_issue("STR-004", Severity::Low, None)]);
+        let sarif: serde_json::Value =
+            serde_json::from_str(&SarifReporter.generate(&result).unwrap()).unwrap();
+        let rules = &sarif["runs"][0]["tool"]["driver"]["rules"];
+
+        assert_eq!(rules[0]["id"], "SEC-001");
+        assert_eq!(rules[0]["helpUri"], "https://example.com/secrets");
+        assert_eq!(
+            rules[0]["help"]["text"],
+            "See: https://example.com/secrets, https://example.com/cwe"
+        );
+        assert!(rules[1].get("helpUri").is_none());
+    }
+}
@@ -0,0 +1,339 @@
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::analyzers::traits::{Issue, Severity};
+use crate::core::content::relative_path;
+use crate::core::scanner::ScanResult;
+
+use super::traits::Reporter;
+
+/// Issues listed in the summary before collapsing the rest.
+const MAX_LISTED: usize = 5;
+
+/// A compact markdown block sized for sticky PR comments and CI step
+/// summaries: score, delta against a previous scan, the top new issues, and
+/// the fixable count.
+pub struct SummaryReporter {
+    previous: Option<PreviousScan>,
+}
+
+/// The parts of an earlier JSON report the summary compares against.
+#[derive(Debug, Clone, Default)]
+pub struct PreviousScan {
+    pub score: u8,
+    keys: HashSet<String>,
+}
+
+impl PreviousScan {
+    /// Read a report written by `scan --format json` or `report --format json`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read previous scan {}", path.display()))?;
+        Self::parse(&content)
+            .with_context(|| format!("{} is not a repodoctor JSON report", path.display()))
+    }
+
+    fn parse(content: &str) -> Result<Self> {
+        let report: serde_json::Value = serde_json::from_str(content)?;
+        let score = report["score"]["total"]
+            .as_u64()
+            .context("missing score.total")?
+            .min(100) as u8;
+        let keys = report["issues"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|issue| {
+                issue_key(
+                    issue["fingerprint"].as_str(),
+                    issue["id"].as_str().unwrap_or_default(),
+                    issue["file"].as_str(),
+                    issue["title"].as_str().unwrap_or_default(),
+                )
+            })
+            .collect();
+        Ok(Self { score, keys })
+    }
+}
+
+/// Identity of an issue across scans: its fingerprint when present.
+fn issue_key(fingerprint: Option<&str>, id: &str, file: Option<&str>, title: &str) -> String {
+    match fingerprint {
+        Some(fingerprint) => fingerprint.to_string(),
+        None => format!("{}|{}|{}", id, file.unwrap_or_default(), title),
+    }
+}
+
+fn key_of(issue: &Issue) -> String {
+    let file = issue.file.as_ref().map(|f| f.to_string_lossy().replace('\\', "/"));
+    issue_key(issue.fingerprint.as_deref(), &issue.id, file.as_deref(), &issue.title)
+}
+
+fn severity_emoji(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "🔴",
+        Severity::High => "🟠",
+        Severity::Medium => "🟡",
+        Severity::Low => "🔵",
+        Severity::Info => "⚪",
+    }
+}
+
+fn delta_text(delta: i16) -> String {
+    match delta {
+        d if d > 0 => format!("📈 +{}", d),
+        d if d < 0 => format!("📉 {}", d),
+        _ => "➖ ±0".to_string(),
+    }
+}
+
+impl SummaryReporter {
+    pub fn new(previous: Option<PreviousScan>) -> Self {
+        Self { previous }
+    }
+
+    fn render(&self, result: &ScanResult) -> String {
+        let mut md = String::with_capacity(1024);
+
+        md.push_str(&format!(
+            "### 🩺 RepoDoctor: {}/100 ({})",
+            result.score.total, result.score.grade
+        ));
+        if let Some(previous) = &self.previous {
+            let delta = i16::from(result.score.total) - i16::from(previous.score);
+            md.push_str(&format!(" {}", delta_text(delta)));
+        }
+        md.push_str("\n\n");
+
+        let count = |severity: Severity| result.issues.iter().filter(|i| i.severity == severity).count();
+        let fixable = result.issues.iter().filter(|i| i.auto_fixable).count();
+        md.push_str("| 🔴 Critical | 🟠 High | 🟡 Medium | 🔵 Low | ⚪ Info | 🔧 Fixable |\n");
+        md.push_str("|:-:|:-:|:-:|:-:|:-:|:-:|\n");
+        md.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} |\n\n",
+            count(Severity::Critical),
+            count(Severity::High),
+            count(Severity::Medium),
+            count(Severity::Low),
+            count(Severity::Info),
+            fixable
+        ));
+
+        if result.metrics.files > 0 {
+            md.push_str(&format!("📦 {}\n\n", result.metrics.summary()));
+        }
+
+        if let Some(policy) = result.policy.as_ref().filter(|p| !p.violations.is_empty()) {
+            md.push_str(&format!(
+                "⛔ **{} {} violation(s)**\n\n",
+                policy.violations.len(),
+                policy.name
+            ));
+        }
+
+        let (heading, listed): (String, Vec<&Issue>) = match &self.previous {
+            Some(previous) => {
+                let current: HashSet<String> = result.issues.iter().map(key_of).collect();
+                let resolved = previous.keys.difference(&current).count();
+                let new: Vec<&Issue> = result
+                    .issues
+                    .iter()
+                    .filter(|i| !previous.keys.contains(&key_of(i)))
+                    .collect();
+                let mut heading = format!("**🆕 {} new issue(s)**", new.len());
+                if resolved > 0 {
+                    heading.push_str(&format!(" · ✅ {} resolved", resolved));
+                }
+                (heading, new)
+            }
+            None => (
+                format!("**Top issues ({})**", result.issues.len()),
+                result.issues.iter().collect(),
+            ),
+        };
+        md.push_str(&heading);
+        md.push('\n');
+
+        if listed.is_empty() {
+            md.push_str("\nNo new issues 🎉\n");
+        } else {
+            md.push('\n');
+            for issue in listed.iter().take(MAX_LISTED) {
+                let id = match issue.references.first() {
+                    Some(reference) => format!("[`{}`]({})", issue.id, reference),
+                    None => format!("`{}`", issue.id),
+                };
+                md.push_str(&format!(
+                    "- {} {} {}",
+                    severity_emoji(issue.severity),
+                    id,
+                    issue.title
+                ));
+                if let Some(file) = &issue.file {
+                    md.push_str(&format!(
+                        " · `{}{}`",
+                        relative_path(&result.project.path, file),
+                        issue.line.map(|l| format!(":{}", l)).unwrap_or_default()
+                    ));
+                }
+                if issue.auto_fixable {
+                    md.push_str(" 🔧");
+                }
+                md.push('\n');
+            }
+            if listed.len() > MAX_LISTED {
+                md.push_str(&format!("- …and {} more\n", listed.len() - MAX_LISTED));
+            }
+        }
+
+        if fixable > 0 {
+            md.push_str(&format!(
+                "\n<sub>🔧 {} issue(s) can be fixed with `repodoctor fix --auto`.</sub>\n",
+                fixable
+            ));
+        }
+        md
+    }
+}
+
+impl Reporter for SummaryReporter {
+    fn name(&self) -> &str {
+        "summary markdown"
+    }
+
+    fn extension(&self) -> &str {
+        "summary.md"
+    }
+
+    fn generate(&self, result: &ScanResult) -> Result<String> {
+        Ok(self.render(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::AnalyzerCategory;
+    use crate::core::project::Project;
+    use crate::core::score::HealthScore;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use crate::reporters::json::JsonReporter;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn make_result(issues: Vec<Issue>) -> ScanResult {
+        ScanResult {
+            project: Project {
+                path: PathBuf::from("/tmp/test"),
+                detected: DetectedProject {
+                    framework: Framework::RustCargo,
+                    language: Language::Rust,
+                    version: None,
+                    package_manager: None,
+                    has_git: true,
+                    has_ci: None,
+                },
+            },
+            score: HealthScore::calculate(&issues),
+            issues,
+            duration: Duration::from_millis(42),
+            expiring: vec![],
+            policy: None,
+            skipped_files: vec![],
+            stats: vec![],
+            metrics: Default::default(),
+        }
+    }
+
+    fn make_issue(id: &str, severity: Severity) -> Issue {
+        Issue {
+            id: id.to_string(),
+            analyzer: "test".to_string(),
+            category: AnalyzerCategory::Security,
+            severity,
+            title: format!("Issue {}", id),
+            description: String::new(),
+            file: Some(PathBuf::from("src/main.rs")),
+            line: Some(3),
+            column: None,
+            snippet: None,
+            fingerprint: Some(format!("fp-{}", id)),
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        }
+    }
+
+    #[test]
+    fn test_summary_without_previous_lists_top_issues() {
+        let mut issues: Vec<Issue> = (1..=7)
+            .map(|n| make_issue(&format!("SEC-00{}", n), Severity::High))
+            .collect();
+        issues[0].auto_fixable = true;
+        let md = SummaryReporter::new(None).render(&make_result(issues));
+
+        assert!(md.starts_with("### 🩺 RepoDoctor: "));
+        assert!(!md.contains("📈") && !md.contains("📉"));
+        assert!(md.contains("| 0 | 7 | 0 | 0 | 0 | 1 |"));
+        assert!(md.contains("**Top issues (7)**"));
+        assert!(md.contains("- 🟠 `SEC-001` Issue SEC-001 · `src/main.rs:3` 🔧"));
+        assert!(!md.contains("`SEC-006`"));
+        assert!(md.contains("- …and 2 more"));
+        assert!(md.contains("1 issue(s) can be fixed"));
+    }
+
+    #[test]
+    fn test_summary_links_rule_to_first_reference() {
+        let mut issue = make_issue("SEC-001", Severity::Critical);
+        issue.references = vec!["https://example.com/sec".to_string()];
+        let md = SummaryReporter::new(None).render(&make_result(vec![issue]));
+        assert!(md.contains("- 🔴 [`SEC-001`](https://example.com/sec) Issue SEC-001"));
+    }
+
+    #[test]
+    fn test_summary_compares_with_previous_report() {
+        let before = make_result(vec![
+            make_issue("SEC-001", Severity::Critical),
+            make_issue("DOC-001", Severity::Low),
+        ]);
+        let previous =
+            PreviousScan::parse(&JsonReporter.generate(&before).unwrap()).unwrap();
+        assert_eq!(previous.score, before.score.total);
+
+        let after = make_result(vec![
+            make_issue("SEC-001", Severity::Critical),
+            make_issue("CFG-001", Severity::Medium),
+        ]);
+        let md = SummaryReporter::new(Some(previous)).render(&after);
+        let delta = i16::from(after.score.total) - i16::from(before.score.total);
+
+        assert!(md.contains(&delta_text(delta)));
+        assert!(md.contains("**🆕 1 new issue(s)** · ✅ 1 resolved"));
+        assert!(md.contains("`CFG-001`"));
+        assert!(!md.contains("`SEC-001`"));
+    }
+
+    #[test]
+    fn test_summary_no_new_issues() {
+        let result = make_result(vec![make_issue("SEC-001", Severity::Critical)]);
+        let previous = PreviousScan::parse(&JsonReporter.generate(&result).unwrap()).unwrap();
+        let md = SummaryReporter::new(Some(previous)).render(&result);
+        assert!(md.contains("➖ ±0"));
+        assert!(md.contains("No new issues 🎉"));
+    }
+
+    #[test]
+    fn test_previous_scan_rejects_other_json() {
+        assert!(PreviousScan::parse("{\"issues\": []}").is_err());
+        assert!(PreviousScan::parse("not json").is_err());
+    }
+
+    #[test]
+    fn test_delta_text() {
+        assert_eq!(delta_text(4), "📈 +4");
+        assert_eq!(delta_text(-3), "📉 -3");
+    }
+}
@@ -0,0 +1,558 @@
+use anyhow::{bail, Context as _, Result};
+use serde::Serialize;
+use std::path::Path;
+use tera::{Context, Tera};
+
+use crate::analyzers::traits::{Issue, Severity};
+use crate::core::metrics::{DirMetrics, FileTypeMetrics};
+use crate::core::scanner::ScanResult;
+use crate::core::score::Grade;
+use crate::core::themes;
+use crate::utils::fs::display_path;
+
+use super::html::escape_html;
+
+const HTML_TEMPLATE: &str = include_str!("templates/report.html.tera");
+const MARKDOWN_TEMPLATE: &str = include_str!("templates/report.md.tera");
+
+/// Template rendered for HTML reports; a file with this name in the template
+/// directory replaces the built-in one.
+pub const HTML: &str = "report.html";
+/// Template rendered for Markdown reports.
+pub const MARKDOWN: &str = "report.md";
+
+/// The Tera templates behind the HTML and Markdown reporters.
+///
+/// The built-ins are always registered as `repodoctor/report.html` and
+/// `repodoctor/report.md`, so an override can `{% extends %}` them and only
+/// replace the blocks it cares about. Every other file in the template
+/// directory is available to `{% include %}`.
+pub struct ReportTemplates {
+    tera: Tera,
+    graph: Option<String>,
+}
+
+impl ReportTemplates {
+    pub fn builtin() -> Self {
+        Self::with_overrides(Vec::new()).expect("built-in report templates are valid")
+    }
+
+    /// Load overrides from `dir` on top of the built-in templates.
+    pub fn load(dir: &Path) -> Result<Self> {
+        if !dir.is_dir() {
+            bail!("Report template directory {} does not exist", dir.display());
+        }
+        let mut templates = Vec::new();
+        for entry in crate::utils::fs::walk(dir)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+        {
+            let relative = entry.path().strip_prefix(dir).unwrap_or(entry.path());
+            let name = relative.to_string_lossy().replace('\\', "/");
+            let name = name.strip_suffix(".tera").unwrap_or(&name).to_string();
+            let content = std::fs::read_to_string(entry.path())
+                .with_context(|| format!("Failed to read template {}", entry.path().display()))?;
+            templates.push((name, content));
+        }
+        Self::with_overrides(templates)
+            .with_context(|| format!("Invalid report templates in {}", dir.display()))
+    }
+
+    fn with_overrides(overrides: Vec<(String, String)>) -> Result<Self> {
+        let mut tera = Tera::default();
+        tera.set_escape_fn(escape_html);
+        tera.add_raw_templates(vec![
+            ("repodoctor/report.html", HTML_TEMPLATE),
+            ("repodoctor/report.md", MARKDOWN_TEMPLATE),
+            (HTML, HTML_TEMPLATE),
+            (MARKDOWN, MARKDOWN_TEMPLATE),
+        ])?;
+        if !overrides.is_empty() {
+            tera.add_raw_templates(overrides)?;
+        }
+        Ok(Self { tera, graph: None })
+    }
+
+    /// Embed a Mermaid dependency graph in the report.
+    pub fn with_graph(mut self, graph: Option<String>) -> Self {
+        self.graph = graph;
+        self
+    }
+
+    pub fn render(&self, name: &str, result: &ScanResult) -> Result<String> {
+        let mut report = ReportContext::new(result);
+        report.graph = self.graph.clone();
+        let context = Context::from_serialize(report)?;
+        self.tera
+            .render(name, &context)
+            .map_err(|e| anyhow::anyhow!(describe(&e)))
+            .with_context(|| format!("Failed to render {} template", name))
+    }
+}
+
+/// Tera nests the useful part of a render error (the undefined variable, the
+/// bad filter) in its source chain.
+fn describe(error: &tera::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = std::error::Error::source(error);
+    while let Some(inner) = source {
+        message.push_str(": ");
+        message.push_str(&inner.to_string());
+        source = inner.source();
+    }
+    message
+}
+
+/// Everything a report template can reference.
+#[derive(Serialize)]
+struct ReportContext {
+    project: ProjectContext,
+    duration: String,
+    score: ScoreContext,
+    breakdown: Vec<CategoryContext>,
+    metrics: MetricsContext,
+    policy: Option<PolicyContext>,
+    quick_wins: Vec<IssueContext>,
+    themes: Vec<ThemeContext>,
+    groups: Vec<GroupContext>,
+    issues: Vec<IssueContext>,
+    summary: SummaryContext,
+    expiring: Vec<ExpiringContext>,
+    /// Mermaid source of the dependency graph
+    graph: Option<String>,
+    generator: &'static str,
+}
+
+#[derive(Serialize)]
+struct ProjectContext {
+    path: String,
+    framework: String,
+    language: String,
+    version: String,
+}
+
+#[derive(Serialize)]
+struct ScoreContext {
+    total: u8,
+    grade: String,
+    color: &'static str,
+}
+
+#[derive(Serialize)]
+struct CategoryContext {
+    name: String,
+    score: u8,
+    issues: usize,
+    status: &'static str,
+    color: &'static str,
+}
+
+#[derive(Serialize)]
+struct PolicyContext {
+    name: String,
+    violations: Vec<IssueContext>,
+}
+
+#[derive(Serialize)]
+struct ThemeContext {
+    id: &'static str,
+    title: &'static str,
+    recommendation: &'static str,
+    severity: String,
+    color: &'static str,
+    issues: Vec<IssueContext>,
+}
+
+#[derive(Serialize)]
+struct GroupContext {
+    severity: String,
+    label: &'static str,
+    color: &'static str,
+    issues: Vec<IssueContext>,
+}
+
+#[derive(Serialize)]
+struct IssueContext {
+    id: String,
+    severity: String,
+    category: String,
+    analyzer: String,
+    title: String,
+    description: String,
+    file: Option<String>,
+    line: Option<usize>,
+    column: Option<usize>,
+    snippet: Option<SnippetContext>,
+    suggestion: Option<String>,
+    auto_fixable: bool,
+    effort: String,
+    impact: String,
+    references: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct SnippetContext {
+    before: String,
+    highlighted: String,
+    after: String,
+}
+
+#[derive(Serialize)]
+struct MetricsContext {
+    files: usize,
+    summary: String,
+    file_types: Vec<FileTypeMetrics>,
+    largest_dirs: Vec<DirMetrics>,
+}
+
+#[derive(Serialize)]
+struct SummaryContext {
+    total: usize,
+    critical: usize,
+    high: usize,
+    medium: usize,
+    low: usize,
+    info: usize,
+    fixable: usize,
+}
+
+#[derive(Serialize)]
+struct ExpiringContext {
+    source: String,
+    target: String,
+    status: String,
+}
+
+const SEVERITY_GROUPS: [(Severity, &str); 5] = [
+    (Severity::Critical, "Critical"),
+    (Severity::High, "High"),
+    (Severity::Medium, "Medium"),
+    (Severity::Low, "Low"),
+    (Severity::Info, "Info"),
+];
+
+fn grade_color(grade: Grade) -> &'static str {
+    match grade {
+        Grade::A => "#4caf50",
+        Grade::B => "#2196f3",
+        Grade::C => "#ff9800",
+        Grade::D => "#f44336",
+        Grade::F => "#9e0000",
+    }
+}
+
+fn severity_color(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "#d32f2f",
+        Severity::High => "#f57c00",
+        Severity::Medium => "#1976d2",
+        Severity::Low => "#757575",
+        Severity::Info => "#9e9e9e",
+    }
+}
+
+fn status(score: u8) -> (&'static str, &'static str) {
+    match score {
+        80..=100 => ("Good", "#4caf50"),
+        60..=79 => ("Needs attention", "#ff9800"),
+        _ => ("Poor", "#f44336"),
+    }
+}
+
+impl ReportContext {
+    fn new(result: &ScanResult) -> Self {
+        let detected = &result.project.detected;
+        let count = |severity: Severity| result.issues.iter().filter(|i| i.severity == severity).count();
+        let themes = result.themes();
+        let unthemed = themes::unthemed(&result.issues, &themes);
+
+        Self {
+            project: ProjectContext {
+                path: display_path(&result.project.path),
+                framework: detected.framework.to_string(),
+                language: detected.language.to_string(),
+                version: detected.version.clone().unwrap_or_default(),
+            },
+            duration: format!("{:.1}", result.duration.as_secs_f64()),
+            score: ScoreContext {
+                total: result.score.total,
+                grade: result.score.grade.to_string(),
+                color: grade_color(result.score.grade),
+            },
+            breakdown: result
+                .score
+                .breakdown
+                .iter()
+                .map(|cat| {
+                    let (status, color) = status(cat.score);
+                    CategoryContext {
+                        name: cat.name.clone(),
+                        score: cat.score,
+                        issues: cat.issues_count,
+                        status,
+                        color,
+                    }
+                })
+                .collect(),
+            metrics: MetricsContext {
+                files: result.metrics.files,
+                summary: result.metrics.summary(),
+                file_types: result.metrics.file_types.iter().take(8).cloned().collect(),
+                largest_dirs: result.metrics.largest_dirs.clone(),
+            },
+            policy: result
+                .policy
+                .as_ref()
+                .filter(|p| !p.violations.is_empty())
+                .map(|p| PolicyContext {
+                    name: p.name.clone(),
+                    violations: p.violations.iter().map(IssueContext::new).collect(),
+                }),
+            quick_wins: result
+                .issues
+                .iter()
+                .filter(|i| i.is_quick_win())
+                .map(IssueContext::new)
+                .collect(),
+            themes: themes
+                .iter()
+                .map(|theme| ThemeContext {
+                    id: theme.id,
+                    title: theme.title,
+                    recommendation: theme.recommendation,
+                    severity: theme.severity.to_string(),
+                    color: severity_color(theme.severity),
+                    issues: theme.issues.iter().map(IssueContext::new).collect(),
+                })
+                .collect(),
+            groups: SEVERITY_GROUPS
+                .iter()
+                .map(|(severity, label)| GroupContext {
+                    severity: severity.to_string(),
+                    label,
+                    color: severity_color(*severity),
+                    issues: unthemed
+                        .iter()
+                        .filter(|i| i.severity == *severity)
+                        .map(|i| IssueContext::new(i))
+                        .collect(),
+                })
+                .filter(|g| !g.issues.is_empty())
+                .collect(),
+            issues: result.issues.iter().map(IssueContext::new).collect(),
+            summary: SummaryContext {
+                total: result.issues.len(),
+                critical: count(Severity::Critical),
+                high: count(Severity::High),
+                medium: count(Severity::Medium),
+                low: count(Severity::Low),
+                info: count(Severity::Info),
+                fixable: result.issues.iter().filter(|i| i.auto_fixable).count(),
+            },
+            expiring: result
+                .expiring
+                .iter()
+                .map(|s| ExpiringContext {
+                    source: s.source.clone(),
+                    target: s.target(),
+                    status: s.status(),
+                })
+                .collect(),
+            graph: None,
+            generator: "RepoDoctor v0.1.0",
+        }
+    }
+}
+
+impl IssueContext {
+    fn new(issue: &Issue) -> Self {
+        Self {
+            id: issue.id.clone(),
+            severity: issue.severity.to_string(),
+            category: issue.category.to_string(),
+            analyzer: issue.analyzer.clone(),
+            title: issue.title.clone(),
+            description: issue.description.clone(),
+            file: issue.file.as_deref().map(display_path),
+            line: issue.line,
+            column: issue.column,
+            snippet: issue
+                .snippet_parts()
+                .map(|(before, highlighted, after)| SnippetContext {
+                    before: before.to_string(),
+                    highlighted: highlighted.to_string(),
+                    after: after.to_string(),
+                }),
+            suggestion: issue.suggestion.clone(),
+            auto_fixable: issue.auto_fixable,
+            effort: issue.resolved_effort().to_string(),
+            impact: issue.resolved_impact().to_string(),
+            references: issue.references.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzers::traits::AnalyzerCategory;
+    use crate::core::metrics::RepoMetrics;
+    use crate::core::project::Project;
+    use crate::core::score::HealthScore;
+    use crate::frameworks::detector::{DetectedProject, Framework, Language};
+    use std::fs;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn make_result() -> ScanResult {
+        let issues = vec![Issue {
+            id: "SEC-001".to_string(),
+            analyzer: "security".to_string(),
+            category: AnalyzerCategory::Security,
+            severity: Severity::Critical,
+            title: "Hardcoded <secret>".to_string(),
+            description: String::new(),
+            file: Some("config/app.yml".into()),
+            line: Some(3),
+            column: None,
+            snippet: None,
+            fingerprint: None,
+            estimated_effort: None,
+            impact: None,
+            suggestion: None,
+            auto_fixable: false,
+            references: vec![],
+        }];
+        ScanResult {
+            project: Project {
+                path: "/tmp/test-project".into(),
+                detected: DetectedProject {
+                    framework: Framework::Laravel,
+                    language: Language::Php,
+                    version: None,
+                    package_manager: None,
+                    has_git: true,
+                    has_ci: None,
+                },
+            },
+            score: HealthScore::calculate(&issues),
+            issues,
+            duration: Duration::from_millis(250),
+            expiring: vec![],
+            policy: None,
+            skipped_files: vec![],
+            stats: vec![],
+            metrics: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_renders_repository_metrics() {
+        let mut result = make_result();
+        let html = ReportTemplates::builtin().render(HTML, &result).unwrap();
+        assert!(!html.contains("<h2>Repository</h2>"));
+
+        result.metrics = RepoMetrics {
+            files: 3,
+            lines: 1500,
+            file_types: vec![FileTypeMetrics {
+                kind: "rs".to_string(),
+                files: 3,
+                lines: 1500,
+            }],
+            largest_dirs: vec![DirMetrics {
+                path: "src".to_string(),
+                files: 2,
+                lines: 1200,
+            }],
+            ..Default::default()
+        };
+        let html = ReportTemplates::builtin().render(HTML, &result).unwrap();
+        assert!(html.contains("<h2>Repository</h2>"));
+        assert!(html.contains("3 files, 1,500 lines"));
+        assert!(html.contains("<td><code>src/</code></td>"));
+
+        let md = ReportTemplates::builtin().render(MARKDOWN, &result).unwrap();
+        assert!(md.contains("## Repository\n\n3 files, 1,500 lines\n"));
+        assert!(md.contains("| rs | 3 | 1500 |\n"));
+        assert!(md.contains("| `src/` | 2 | 1200 |\n"));
+    }
+
+    #[test]
+    fn test_embeds_dependency_graph() {
+        let result = make_result();
+        let graph = "graph LR\n  n0[\"api\"] --> n1([\"serde\"])\n".to_string();
+
+        let html = ReportTemplates::builtin().render(HTML, &result).unwrap();
+        assert!(!html.contains("Dependency Graph"));
+
+        let templates = ReportTemplates::builtin().with_graph(Some(graph));
+        let html = templates.render(HTML, &result).unwrap();
+        assert!(html.contains("<pre class=\"mermaid\">graph LR\n  n0[&quot;api&quot;] --&gt; n1"));
+        assert!(html.contains("mermaid.initialize"));
+
+        let md = templates.render(MARKDOWN, &result).unwrap();
+        assert!(md.contains("## Dependency Graph\n\n```mermaid\ngraph LR\n  n0[\"api\"] --> n1([\"serde\"])\n```\n"));
+    }
+
+    #[test]
+    fn test_override_extends_builtin_block() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("report.html"),
+            "{% extends \"repodoctor/report.html\" %}\n\
+             {% block header %}<h1>ACME Health</h1>{% include \"legal.html\" %}{% endblock header %}",
+        )
+        .unwrap();
+        fs::write(dir.path().join("legal.html"), "<p>Internal use only</p>").unwrap();
+
+        let templates = ReportTemplates::load(dir.path()).unwrap();
+        let html = templates.render(HTML, &make_result()).unwrap();
+        assert!(html.contains("<h1>ACME Health</h1><p>Internal use only</p>"));
+        assert!(!html.contains("RepoDoctor Health Report"));
+        assert!(html.contains("Hardcoded &lt;secret&gt;"));
+
+        // The Markdown template is untouched by an HTML-only override
+        let md = templates.render(MARKDOWN, &make_result()).unwrap();
+        assert!(md.contains("# RepoDoctor Health Report"));
+    }
+
+    #[test]
+    fn test_markdown_override_is_not_escaped() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("report.md.tera"),
+            "{% for issue in issues %}{{ issue.id }} {{ issue.title }} {{ issue.file }}:{{ issue.line }}\n{% endfor %}",
+        )
+        .unwrap();
+
+        let md = ReportTemplates::load(dir.path())
+            .unwrap()
+            .render(MARKDOWN, &make_result())
+            .unwrap();
+        assert_eq!(md, "SEC-001 Hardcoded <secret> config/app.yml:3\n");
+    }
+
+    #[test]
+    fn test_load_reports_template_errors() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("report.html"), "{% if %}").unwrap();
+        let err = ReportTemplates::load(dir.path()).err().unwrap();
+        assert!(format!("{:#}", err).contains("Invalid report templates"));
+
+        let missing = dir.path().join("missing");
+        assert!(ReportTemplates::load(&missing).is_err());
+    }
+
+    #[test]
+    fn test_render_reports_unknown_variables() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("report.md"), "{{ nope }}").unwrap();
+        let err = ReportTemplates::load(dir.path())
+            .unwrap()
+            .render(MARKDOWN, &make_result())
+            .unwrap_err();
+        assert!(format!("{:#}", err).contains("nope"));
+    }
+}
@@ -0,0 +1,420 @@
+use anyhow::{bail, Context, Result};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+use crate::utils::git;
+
+/// Archive formats that can be scanned directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveKind {
+    TarGz,
+    Tar,
+    Zip,
+}
+
+impl ArchiveKind {
+    /// Detect the archive format from the file name.
+    pub fn from_path(path: &Path) -> Option<ArchiveKind> {
+        let name = path.file_name()?.to_string_lossy().to_lowercase();
+        if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(ArchiveKind::TarGz)
+        } else if name.ends_with(".tar") {
+            Some(ArchiveKind::Tar)
+        } else if name.ends_with(".zip") {
+            Some(ArchiveKind::Zip)
+        } else {
+            None
+        }
+    }
+}
+
+/// Caps on what an archive may expand to, so a zip or gzip bomb fails instead
+/// of filling the disk.
+#[derive(Debug, Clone, Copy)]
+struct Limits {
+    entries: usize,
+    bytes: u64,
+}
+
+const LIMITS: Limits = Limits {
+    entries: 100_000,
+    bytes: 2 << 30,
+};
+
+impl Limits {
+    fn check_entries(&self, entries: usize) -> Result<()> {
+        if entries > self.entries {
+            bail!("Archive has more than {} entries", self.entries);
+        }
+        Ok(())
+    }
+
+    fn check_bytes(&self, bytes: u64) -> Result<()> {
+        if bytes > self.bytes {
+            bail!(
+                "Archive expands to more than {} MiB",
+                self.bytes / (1 << 20)
+            );
+        }
+        Ok(())
+    }
+}
+
+/// An archive unpacked into a temporary directory, removed on drop.
+pub struct ExtractedArchive {
+    _dir: TempDir,
+    /// Project root inside the extracted tree.
+    pub root: PathBuf,
+}
+
+/// Extract `path` into a temporary directory when it is a supported archive.
+///
+/// Returns `None` for regular directories. When the archive wraps everything
+/// in a single top-level directory (as release tarballs usually do), that
+/// directory is used as the project root. Archives with more than 100,000
+/// entries or over 2 GiB of content are rejected.
+pub fn extract_if_archive(path: &Path) -> Result<Option<ExtractedArchive>> {
+    extract_with_limits(path, LIMITS)
+}
+
+fn extract_with_limits(path: &Path, limits: Limits) -> Result<Option<ExtractedArchive>> {
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let Some(kind) = ArchiveKind::from_path(path) else {
+        return Ok(None);
+    };
+
+    let dir = tempfile::Builder::new().prefix("repodoctor-").tempdir()?;
+    let file =
+        File::open(path).with_context(|| format!("Failed to open archive {}", path.display()))?;
+
+    match kind {
+        ArchiveKind::TarGz => extract_tar(flate2::read::GzDecoder::new(file), dir.path(), limits),
+        ArchiveKind::Tar => extract_tar(file, dir.path(), limits),
+        ArchiveKind::Zip => extract_zip(file, dir.path(), limits),
+    }
+    .with_context(|| format!("Failed to extract {}", path.display()))?;
+
+    let root = single_top_level_dir(dir.path()).unwrap_or_else(|| dir.path().to_path_buf());
+    Ok(Some(ExtractedArchive { _dir: dir, root }))
+}
+
+/// Extract the project to scan from `path`: the files of `git_ref` when one is
+/// given, the `HEAD` commit of a bare repository, or a supported archive.
+///
+/// Returns `None` when `path` should be scanned in place.
+pub fn extract_source(path: &Path, git_ref: Option<&str>) -> Result<Option<ExtractedArchive>> {
+    match git_ref {
+        Some(rev) => extract_git_ref(path, rev).map(Some),
+        None if path.is_dir() && git::is_bare(path) => extract_git_ref(path, "HEAD").map(Some),
+        None => extract_if_archive(path),
+    }
+}
+
+/// Export `rev` of the repository at `path` into a temporary directory, named
+/// after the repository so the project keeps its name.
+pub fn extract_git_ref(path: &Path, rev: &str) -> Result<ExtractedArchive> {
+    let name = path
+        .canonicalize()
+        .with_context(|| format!("Failed to open repository {}", path.display()))?
+        .file_name()
+        .map(|n| {
+            let n = n.to_string_lossy();
+            n.strip_suffix(".git").unwrap_or(&n).to_string()
+        })
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| "repository".to_string());
+
+    let dir = tempfile::Builder::new().prefix("repodoctor-").tempdir()?;
+    git::export_tree(path, rev, &name, dir.path())?;
+    // An empty tree writes nothing under the prefix
+    let root = dir.path().join(&name);
+    std::fs::create_dir_all(&root)?;
+    Ok(ExtractedArchive { _dir: dir, root })
+}
+
+fn extract_tar(reader: impl Read, dest: &Path, limits: Limits) -> Result<()> {
+    let mut archive = tar::Archive::new(reader);
+    let mut bytes = 0u64;
+    for (i, entry) in archive.entries()?.enumerate() {
+        limits.check_entries(i + 1)?;
+        let mut entry = entry?;
+        bytes += entry.size();
+        limits.check_bytes(bytes)?;
+        // Skips entries that would escape the destination
+        entry.unpack_in(dest)?;
+    }
+    Ok(())
+}
+
+fn extract_zip(file: File, dest: &Path, limits: Limits) -> Result<()> {
+    let mut archive = zip::ZipArchive::new(file)?;
+    limits.check_entries(archive.len())?;
+    let mut bytes = 0u64;
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        // Skip entries that would escape the destination (zip-slip).
+        let Some(relative) = entry.enclosed_name() else {
+            continue;
+        };
+        let out_path = dest.join(relative);
+        if entry.is_dir() {
+            std::fs::create_dir_all(&out_path)?;
+            continue;
+        }
+        if let Some(parent) = out_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Count what is actually inflated; the sizes in the header can lie
+        let mut out = File::create(&out_path)?;
+        let remaining = limits.bytes - bytes;
+        bytes += std::io::copy(&mut (&mut entry).take(remaining + 1), &mut out)?;
+        limits.check_bytes(bytes)?;
+    }
+    Ok(())
+}
+
+fn single_top_level_dir(path: &Path) -> Option<PathBuf> {
+    let mut entries = std::fs::read_dir(path).ok()?.filter_map(|e| e.ok());
+    let first = entries.next()?;
+    if entries.next().is_some() || !first.file_type().ok()?.is_dir() {
+        return None;
+    }
+    Some(first.path())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_archive_kind_from_path() {
+        assert_eq!(
+            ArchiveKind::from_path(Path::new("release-1.0.tar.gz")),
+            Some(ArchiveKind::TarGz)
+        );
+        assert_eq!(ArchiveKind::from_path(Path::new("a.TGZ")), Some(ArchiveKind::TarGz));
+        assert_eq!(ArchiveKind::from_path(Path::new("a.tar")), Some(ArchiveKind::Tar));
+        assert_eq!(ArchiveKind::from_path(Path::new("a.zip")), Some(ArchiveKind::Zip));
+        assert_eq!(ArchiveKind::from_path(Path::new("project")), None);
+    }
+
+    #[test]
+    fn test_directory_is_not_extracted() {
+        let tmp = TempDir::new().unwrap();
+        assert!(extract_if_archive(tmp.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_extracts_tar_gz_with_single_root() {
+        let tmp = TempDir::new().unwrap();
+        let archive_path = tmp.path().join("vendor-1.0.tar.gz");
+
+        let encoder = flate2::write::GzEncoder::new(
+            File::create(&archive_path).unwrap(),
+            flate2::Compression::default(),
+        );
+        let mut builder = tar::Builder::new(encoder);
+        let content = b"[package]\nname = \"vendor\"\n";
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, "vendor-1.0/Cargo.toml", &content[..])
+            .unwrap();
+        builder.into_inner().unwrap().finish().unwrap();
+
+        let extracted = extract_if_archive(&archive_path).unwrap().unwrap();
+        assert!(extracted.root.ends_with("vendor-1.0"));
+        assert!(extracted.root.join("Cargo.toml").is_file());
+    }
+
+    #[test]
+    fn test_extracts_zip() {
+        let tmp = TempDir::new().unwrap();
+        let archive_path = tmp.path().join("delivery.zip");
+
+        let mut writer = zip::ZipWriter::new(File::create(&archive_path).unwrap());
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file("README.md", options).unwrap();
+        writer.write_all(b"# Delivery\n").unwrap();
+        writer.start_file("src/main.rs", options).unwrap();
+        writer.write_all(b"fn main() {}\n").unwrap();
+        writer.finish().unwrap();
+
+        let extracted = extract_if_archive(&archive_path).unwrap().unwrap();
+        assert!(extracted.root.join("README.md").is_file());
+        assert!(extracted.root.join("src/main.rs").is_file());
+    }
+
+    #[test]
+    fn test_rejects_archives_over_the_limits() {
+        let tmp = TempDir::new().unwrap();
+        let limits = Limits {
+            entries: 2,
+            bytes: 1024,
+        };
+
+        let zip_path = tmp.path().join("many.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&zip_path).unwrap());
+        for name in ["a.txt", "b.txt", "c.txt"] {
+            writer
+                .start_file(name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+        }
+        writer.finish().unwrap();
+        let err = extract_with_limits(&zip_path, limits).err().unwrap();
+        assert!(format!("{:#}", err).contains("Archive has more than 2 entries"));
+
+        // Highly compressible content, as in a zip bomb
+        let zip_path = tmp.path().join("bomb.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&zip_path).unwrap());
+        writer
+            .start_file("zeros", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(&[0; 4096]).unwrap();
+        writer.finish().unwrap();
+        let err = extract_with_limits(&zip_path, limits).err().unwrap();
+        assert!(format!("{:#}", err).contains("Archive expands to more than"));
+
+        let tar_path = tmp.path().join("bomb.tar.gz");
+        let encoder = flate2::write::GzEncoder::new(
+            File::create(&tar_path).unwrap(),
+            flate2::Compression::default(),
+        );
+        let mut builder = tar::Builder::new(encoder);
+        let mut header = tar::Header::new_gnu();
+        header.set_size(4096);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, "zeros", &[0; 4096][..])
+            .unwrap();
+        builder.into_inner().unwrap().finish().unwrap();
+        let err = extract_with_limits(&tar_path, limits).err().unwrap();
+        assert!(format!("{:#}", err).contains("Archive expands to more than"));
+
+        assert!(extract_with_limits(&tar_path, LIMITS).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_temp_dir_removed_on_drop() {
+        let tmp = TempDir::new().unwrap();
+        let archive_path = tmp.path().join("a.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&archive_path).unwrap());
+        writer
+            .start_file("a.txt", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        writer.finish().unwrap();
+
+        let extracted = extract_if_archive(&archive_path).unwrap().unwrap();
+        let root = extracted.root.clone();
+        assert!(root.exists());
+        drop(extracted);
+        assert!(!root.exists());
+    }
+
+    fn git(dir: &Path, args: &[&str]) -> bool {
+        std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .map(|o| o.status.success())
+            .unwrap_or(false)
+    }
+
+    /// A repository with `v1.0` tagged before a second commit, or `None`
+    /// when git is not available.
+    fn make_tagged_repo() -> Option<TempDir> {
+        let tmp = TempDir::new().unwrap();
+        let repo = tmp.path().join("billing");
+        std::fs::create_dir(&repo).unwrap();
+        if !git(&repo, &["init", "-q"]) {
+            return None;
+        }
+        let commit = |message: &str| {
+            git(&repo, &["add", "-A"]);
+            git(
+                &repo,
+                &["-c", "user.name=t", "-c", "user.email=t@t", "commit", "-qm", message],
+            );
+        };
+        std::fs::write(repo.join("README.md"), "# Billing 1.0\n").unwrap();
+        std::fs::create_dir(repo.join("src")).unwrap();
+        std::fs::write(repo.join("src/main.rs"), "fn main() {}\n").unwrap();
+        commit("1.0");
+        git(&repo, &["tag", "v1.0"]);
+        std::fs::write(repo.join("README.md"), "# Billing 2.0\n").unwrap();
+        std::fs::write(repo.join("CHANGELOG.md"), "# Changelog\n").unwrap();
+        commit("2.0");
+        Some(tmp)
+    }
+
+    #[test]
+    fn test_extracts_git_ref_without_checkout() {
+        let Some(tmp) = make_tagged_repo() else { return };
+        let repo = tmp.path().join("billing");
+
+        let extracted = extract_source(&repo, Some("v1.0")).unwrap().unwrap();
+        assert!(extracted.root.ends_with("billing"));
+        assert_eq!(
+            std::fs::read_to_string(extracted.root.join("README.md")).unwrap(),
+            "# Billing 1.0\n"
+        );
+        assert!(extracted.root.join("src/main.rs").is_file());
+        assert!(!extracted.root.join("CHANGELOG.md").exists());
+        // The working tree is untouched
+        assert_eq!(
+            std::fs::read_to_string(repo.join("README.md")).unwrap(),
+            "# Billing 2.0\n"
+        );
+
+        let err = extract_source(&repo, Some("v9.9")).err().unwrap();
+        assert!(err.to_string().contains("Unknown revision v9.9"));
+
+        // Revisions are never parsed as git options
+        let output = tmp.path().join("out.tar");
+        let rev = format!("--output={}", output.display());
+        let err = extract_source(&repo, Some(&rev)).err().unwrap();
+        assert!(err.to_string().contains("Unknown revision --output="));
+        assert!(!output.exists());
+    }
+
+    #[test]
+    fn test_extracts_head_of_bare_repo() {
+        let Some(tmp) = make_tagged_repo() else { return };
+        let bare = tmp.path().join("billing.git");
+        assert!(git(
+            tmp.path(),
+            &["clone", "-q", "--bare", "billing", "billing.git"]
+        ));
+
+        let extracted = extract_source(&bare, None).unwrap().unwrap();
+        assert!(extracted.root.ends_with("billing"));
+        assert!(extracted.root.join("CHANGELOG.md").is_file());
+
+        let tagged = extract_source(&bare, Some("v1.0")).unwrap().unwrap();
+        assert!(!tagged.root.join("CHANGELOG.md").exists());
+
+        // `export-ignore` only applies to release archives, not to scans
+        let repo = tmp.path().join("billing");
+        std::fs::write(repo.join(".gitattributes"), "/src export-ignore\n").unwrap();
+        git(&repo, &["add", "-A"]);
+        git(
+            &repo,
+            &["-c", "user.name=t", "-c", "user.email=t@t", "commit", "-qm", "attrs"],
+        );
+        let exported = extract_source(&repo, Some("HEAD")).unwrap().unwrap();
+        assert!(exported.root.join("src/main.rs").is_file());
+
+        // A regular checkout is scanned in place
+        assert!(extract_source(&tmp.path().join("billing"), None)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -1,18 +1,173 @@
 use serde::{Deserialize, Serialize};
-use std::path::Path;
-use walkdir::WalkDir;
+use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use walkdir::{DirEntry, FilterEntry, WalkDir};
+
+/// Deepest directory level any project walk descends to.
+pub const MAX_WALK_DEPTH: usize = 64;
+
+static FOLLOW_SYMLINKS: AtomicBool = AtomicBool::new(false);
+
+/// File system work done through [`walk`], [`read_text`] and
+/// [`read_to_string`], counted per thread for `scan --stats`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IoStats {
+    /// Files yielded by directory walks
+    pub files_walked: u64,
+    pub files_read: u64,
+    pub bytes_read: u64,
+}
+
+thread_local! {
+    static IO_STATS: Cell<IoStats> = const {
+        Cell::new(IoStats {
+            files_walked: 0,
+            files_read: 0,
+            bytes_read: 0,
+        })
+    };
+}
+
+fn record_io(update: impl FnOnce(&mut IoStats)) {
+    IO_STATS.with(|cell| {
+        let mut stats = cell.get();
+        update(&mut stats);
+        cell.set(stats);
+    });
+}
+
+/// Run `f` and return the file system work it did on the current thread.
+pub fn measure_io<T>(f: impl FnOnce() -> T) -> (T, IoStats) {
+    let before = IO_STATS.with(Cell::get);
+    let value = f();
+    let after = IO_STATS.with(Cell::get);
+    let stats = IoStats {
+        files_walked: after.files_walked - before.files_walked,
+        files_read: after.files_read - before.files_read,
+        bytes_read: after.bytes_read - before.bytes_read,
+    };
+    (value, stats)
+}
+
+/// Opt in to following symbolic links in [`walk`] (off by default).
+pub fn set_follow_symlinks(follow: bool) {
+    FOLLOW_SYMLINKS.store(follow, Ordering::Relaxed);
+}
+
+/// Whether [`walk`] follows symbolic links.
+pub fn follows_symlinks() -> bool {
+    FOLLOW_SYMLINKS.load(Ordering::Relaxed)
+}
+
+/// Start a directory walk with the shared symlink policy and depth limit.
+///
+/// Symlinks are reported as entries but not descended into unless enabled
+/// with [`set_follow_symlinks`]. When they are followed, walkdir detects links
+/// pointing back at an ancestor and yields an error for them instead of
+/// recursing, so a cyclic link cannot hang a scan.
+pub fn walk(root: &Path) -> Walk {
+    Walk(walk_with(root, follows_symlinks()))
+}
+
+/// A directory walk started by [`walk`]; files it yields count towards [`IoStats`].
+pub struct Walk(WalkDir);
+
+impl Walk {
+    pub fn max_depth(self, depth: usize) -> Self {
+        Self(self.0.max_depth(depth))
+    }
+}
+
+impl IntoIterator for Walk {
+    type Item = walkdir::Result<DirEntry>;
+    type IntoIter = WalkIter<walkdir::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        WalkIter(self.0.into_iter())
+    }
+}
+
+/// Iterator over a [`Walk`].
+pub struct WalkIter<I>(I);
+
+impl WalkIter<walkdir::IntoIter> {
+    /// Skip entries, and the contents of directories, for which `predicate` is false.
+    pub fn filter_entry<P>(self, predicate: P) -> WalkIter<FilterEntry<walkdir::IntoIter, P>>
+    where
+        P: FnMut(&DirEntry) -> bool,
+    {
+        WalkIter(self.0.filter_entry(predicate))
+    }
+
+    /// Do not descend into the directory that was yielded last.
+    pub fn skip_current_dir(&mut self) {
+        self.0.skip_current_dir();
+    }
+}
+
+impl<I: Iterator<Item = walkdir::Result<DirEntry>>> Iterator for WalkIter<I> {
+    type Item = walkdir::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.0.next();
+        if let Some(Ok(entry)) = &entry {
+            if entry.file_type().is_file() {
+                record_io(|stats| stats.files_walked += 1);
+            }
+        }
+        entry
+    }
+}
+
+fn walk_with(root: &Path, follow_symlinks: bool) -> WalkDir {
+    WalkDir::new(root)
+        .follow_links(follow_symlinks)
+        .max_depth(MAX_WALK_DEPTH)
+}
 
 pub fn path_exists(base: &Path, relative: &str) -> bool {
     base.join(relative).exists()
 }
 
+/// Find a file directly in `base`, ignoring ASCII case so `readme.md` or
+/// `License` count the same on every platform. Returns the first of `names`
+/// present, with its on-disk spelling.
+pub fn find_file_ci(base: &Path, names: &[&str]) -> Option<PathBuf> {
+    let files: Vec<(String, PathBuf)> = std::fs::read_dir(base)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file())
+        .map(|e| (e.file_name().to_string_lossy().to_string(), e.path()))
+        .collect();
+    names.iter().find_map(|name| {
+        files
+            .iter()
+            .find(|(file_name, _)| file_name.eq_ignore_ascii_case(name))
+            .map(|(_, path)| path.clone())
+    })
+}
+
+/// Path for display, without the `\\?\` verbatim prefix Windows adds to
+/// canonicalized paths (`\\?\UNC\server\share` is shown as `\\server\share`).
+pub fn display_path(path: &Path) -> String {
+    let text = path.to_string_lossy();
+    if let Some(rest) = text.strip_prefix(r"\\?\UNC\") {
+        format!(r"\\{}", rest)
+    } else if let Some(rest) = text.strip_prefix(r"\\?\") {
+        rest.to_string()
+    } else {
+        text.into_owned()
+    }
+}
+
 pub fn has_git_repo(path: &Path) -> bool {
     path.join(".git").is_dir()
 }
 
 pub fn max_directory_depth(path: &Path) -> usize {
     let mut max_depth = 0;
-    for entry in WalkDir::new(path)
+    for entry in walk(path)
         .into_iter()
         .filter_entry(|e| {
             if e.depth() == 0 {
@@ -40,7 +195,7 @@ pub fn max_directory_depth(path: &Path) -> usize {
 #[allow(dead_code)]
 pub fn find_files_by_name(path: &Path, name: &str) -> Vec<std::path::PathBuf> {
     let mut results = Vec::new();
-    for entry in WalkDir::new(path)
+    for entry in walk(path)
         .into_iter()
         .filter_entry(|e| {
             if e.depth() == 0 {
@@ -61,6 +216,77 @@ pub fn find_files_by_name(path: &Path, name: &str) -> Vec<std::path::PathBuf> {
     results
 }
 
+/// Hidden entries and dependency/build directories skipped by source walks.
+pub fn is_ignored_name(name: &str) -> bool {
+    name.starts_with('.') || name == "node_modules" || name == "vendor" || name == "target"
+}
+
+/// Locate `program` in the directories listed in `PATH`.
+pub fn find_executable(program: &str) -> Option<PathBuf> {
+    let names: Vec<String> = if cfg!(windows) {
+        vec![format!("{}.exe", program), program.to_string()]
+    } else {
+        vec![program.to_string()]
+    };
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .flat_map(|dir| names.iter().map(move |name| dir.join(name)))
+        .find(|candidate| candidate.is_file())
+}
+
+/// [`std::fs::read_to_string`] that counts towards [`IoStats`].
+pub fn read_to_string<P: AsRef<Path>>(path: P) -> std::io::Result<String> {
+    let content = std::fs::read_to_string(path)?;
+    record_io(|stats| {
+        stats.files_read += 1;
+        stats.bytes_read += content.len() as u64;
+    });
+    Ok(content)
+}
+
+/// Outcome of [`read_text`].
+pub enum TextFile {
+    Text(String),
+    /// Larger than the read limit; holds the file size in bytes
+    TooLarge(u64),
+    /// Contains NUL bytes, so it is not a text file we can check
+    Binary,
+}
+
+/// Read a file for content checks without failing on its encoding.
+///
+/// Invalid UTF-8 (e.g. ISO-8859-1 files) is replaced instead of aborting the
+/// read, a leading byte order mark is dropped, and nothing beyond `max_bytes`
+/// is ever loaded.
+pub fn read_text(path: &Path, max_bytes: u64) -> std::io::Result<TextFile> {
+    use std::io::Read;
+
+    let file = std::fs::File::open(path)?;
+    let size = file.metadata()?.len();
+    if size > max_bytes {
+        return Ok(TextFile::TooLarge(size));
+    }
+    let mut bytes = Vec::with_capacity(size as usize);
+    // The file may grow between the size check and the read
+    file.take(max_bytes).read_to_end(&mut bytes)?;
+    record_io(|stats| {
+        stats.files_read += 1;
+        stats.bytes_read += bytes.len() as u64;
+    });
+    if bytes[..bytes.len().min(8192)].contains(&0) {
+        return Ok(TextFile::Binary);
+    }
+
+    let mut text = match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(err) => String::from_utf8_lossy(err.as_bytes()).into_owned(),
+    };
+    if text.starts_with('\u{feff}') {
+        text.drain(..'\u{feff}'.len_utf8());
+    }
+    Ok(TextFile::Text(text))
+}
+
+#[allow(dead_code)]
 pub fn find_files_with_extension(path: &Path, ext: &str) -> Vec<std::path::PathBuf> {
     let mut results = Vec::new();
     let dot_ext = if ext.starts_with('.') {
@@ -68,17 +294,13 @@ pub fn find_files_with_extension(path: &Path, ext: &str) -> Vec<std::path::PathB
     } else {
         format!(".{}", ext)
     };
-    for entry in WalkDir::new(path)
+    for entry in walk(path)
         .into_iter()
         .filter_entry(|e| {
             if e.depth() == 0 {
                 return true;
             }
-            let n = e.file_name().to_string_lossy();
-            !n.starts_with('.')
-                && n != "node_modules"
-                && n != "vendor"
-                && n != "target"
+            !is_ignored_name(&e.file_name().to_string_lossy())
         })
         .filter_map(|e| e.ok())
     {
@@ -123,6 +345,29 @@ mod tests {
     use tempfile::TempDir;
     use std::fs;
 
+    #[test]
+    fn test_measure_io_counts_walks_and_reads() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir(tmp.path().join("src")).unwrap();
+        fs::write(tmp.path().join("src/a.txt"), "hello").unwrap();
+        fs::write(tmp.path().join("b.txt"), "hi").unwrap();
+
+        let (walked, stats) = measure_io(|| walk(tmp.path()).into_iter().flatten().count());
+        assert_eq!(walked, 4);
+        assert_eq!(stats, IoStats { files_walked: 2, files_read: 0, bytes_read: 0 });
+
+        let (_, stats) = measure_io(|| {
+            read_to_string(tmp.path().join("b.txt")).unwrap();
+            read_text(&tmp.path().join("src/a.txt"), 1024).unwrap();
+            // Filtered entries are not walked
+            walk(tmp.path())
+                .into_iter()
+                .filter_entry(|e| e.file_name() != "src")
+                .count()
+        });
+        assert_eq!(stats, IoStats { files_walked: 1, files_read: 2, bytes_read: 7 });
+    }
+
     #[test]
     fn test_path_exists() {
         let tmp = TempDir::new().unwrap();
@@ -131,6 +376,29 @@ mod tests {
         assert!(!path_exists(tmp.path(), "missing.txt"));
     }
 
+    #[test]
+    fn test_find_file_ci() {
+        let tmp = TempDir::new().unwrap();
+        fs::write(tmp.path().join("readme.MD"), "hello").unwrap();
+        fs::create_dir(tmp.path().join("LICENSE")).unwrap();
+        assert_eq!(
+            find_file_ci(tmp.path(), &["README.md"]),
+            Some(tmp.path().join("readme.MD"))
+        );
+        // Directories never match
+        assert_eq!(find_file_ci(tmp.path(), &["LICENSE", "LICENSE.md"]), None);
+    }
+
+    #[test]
+    fn test_display_path() {
+        assert_eq!(display_path(Path::new(r"\\?\C:\repo")), r"C:\repo");
+        assert_eq!(
+            display_path(Path::new(r"\\?\UNC\server\share\repo")),
+            r"\\server\share\repo"
+        );
+        assert_eq!(display_path(Path::new("/home/dev/repo")), "/home/dev/repo");
+    }
+
     #[test]
     fn test_has_git_repo() {
         let tmp = TempDir::new().unwrap();
@@ -139,6 +407,60 @@ mod tests {
         assert!(has_git_repo(tmp.path()));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_walk_survives_symlink_loops() {
+        let tmp = TempDir::new().unwrap();
+        fs::create_dir_all(tmp.path().join("a/b")).unwrap();
+        fs::write(tmp.path().join("a/b/file.txt"), "x").unwrap();
+        std::os::unix::fs::symlink(tmp.path().join("a"), tmp.path().join("a/b/loop")).unwrap();
+
+        // Not followed: the link is a single non-directory entry
+        let entries: Vec<_> = walk_with(tmp.path(), false).into_iter().collect();
+        assert!(entries.iter().all(|e| e.is_ok()));
+        assert!(entries
+            .iter()
+            .flatten()
+            .any(|e| e.path_is_symlink() && !e.file_type().is_dir()));
+
+        // Followed: the cycle is reported as an error instead of recursing
+        let entries: Vec<_> = walk_with(tmp.path(), true).into_iter().collect();
+        assert!(entries
+            .iter()
+            .any(|e| e.as_ref().err().and_then(|e| e.loop_ancestor()).is_some()));
+        assert!(entries.len() < 10);
+    }
+
+    #[test]
+    fn test_walk_depth_limit() {
+        let tmp = TempDir::new().unwrap();
+        let deep = (0..MAX_WALK_DEPTH + 5).fold(tmp.path().to_path_buf(), |p, _| p.join("d"));
+        fs::create_dir_all(&deep).unwrap();
+        let depth = walk(tmp.path()).into_iter().flatten().map(|e| e.depth()).max();
+        assert_eq!(depth, Some(MAX_WALK_DEPTH));
+    }
+
+    #[test]
+    fn test_read_text() {
+        let tmp = TempDir::new().unwrap();
+        let read = |name: &str, bytes: &[u8], limit: u64| {
+            fs::write(tmp.path().join(name), bytes).unwrap();
+            read_text(&tmp.path().join(name), limit).unwrap()
+        };
+
+        // ISO-8859-1 "café" is not valid UTF-8
+        match read("latin1.txt", b"caf\xe9 = 1\n", 1024) {
+            TextFile::Text(text) => assert_eq!(text, "caf\u{fffd} = 1\n"),
+            _ => panic!("expected text"),
+        }
+        match read("bom.txt", b"\xef\xbb\xbfkey = 1\n", 1024) {
+            TextFile::Text(text) => assert_eq!(text, "key = 1\n"),
+            _ => panic!("expected text"),
+        }
+        assert!(matches!(read("big.txt", &[b'a'; 64], 16), TextFile::TooLarge(64)));
+        assert!(matches!(read("image.png", b"\x89PNG\0\0", 1024), TextFile::Binary));
+    }
+
     #[test]
     fn test_max_directory_depth() {
         let tmp = TempDir::new().unwrap();
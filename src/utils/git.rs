@@ -0,0 +1,282 @@
+use anyhow::{bail, Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use tempfile::TempDir;
+
+/// Whether `source` looks like a git remote rather than a local path.
+pub fn is_git_url(source: &str) -> bool {
+    source.starts_with("https://")
+        || source.starts_with("http://")
+        || source.starts_with("ssh://")
+        || source.starts_with("git://")
+        || source.starts_with("git@")
+}
+
+/// Shallow-clone `url` into a temporary directory, removed on drop.
+pub fn shallow_clone(url: &str) -> Result<TempDir> {
+    let dir = tempfile::Builder::new().prefix("repodoctor-").tempdir()?;
+    let output = Command::new("git")
+        .args(["clone", "--depth", "1", "--quiet", url])
+        .arg(dir.path())
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .context("Failed to run git")?;
+
+    if !output.status.success() {
+        bail!(
+            "git clone {} failed: {}",
+            url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(dir)
+}
+
+/// Trimmed stdout of `git <args>` run in `dir`, if git succeeded.
+fn git_output(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim_end().to_string())
+}
+
+/// Commit hash of `HEAD` for the repository containing `dir`.
+pub fn head_commit(dir: &Path) -> Option<String> {
+    git_output(dir, &["rev-parse", "--verify", "--quiet", "HEAD"])
+}
+
+/// The repository's `.git` directory (resolved for worktrees).
+pub fn git_dir(dir: &Path) -> Option<PathBuf> {
+    git_output(dir, &["rev-parse", "--absolute-git-dir"]).map(PathBuf::from)
+}
+
+/// `dir` relative to the root of its working tree (`services/api`), empty at
+/// the root itself.
+pub fn path_in_repo(dir: &Path) -> Option<String> {
+    let prefix = git_output(dir, &["rev-parse", "--show-prefix"])?;
+    Some(prefix.trim_end_matches('/').to_string())
+}
+
+/// The fetch URL of `remote`.
+pub fn remote_url(dir: &Path, remote: &str) -> Option<String> {
+    git_output(dir, &["remote", "get-url", remote]).filter(|url| !url.is_empty())
+}
+
+/// All tag names in the repository.
+pub fn tags(dir: &Path) -> Option<Vec<String>> {
+    let tags = git_output(dir, &["tag", "--list"])?;
+    Some(tags.lines().map(str::to_string).collect())
+}
+
+/// Whether `dir` is a bare repository (or the inside of a `.git` directory).
+pub fn is_bare(dir: &Path) -> bool {
+    git_output(dir, &["rev-parse", "--is-bare-repository"]).as_deref() == Some("true")
+}
+
+/// Write the files of `rev` (a tag, branch or commit) from the object database
+/// of the repository at `dir` into `dest`, under `prefix`. Works for bare
+/// repositories and leaves the working tree and index alone.
+///
+/// The tree is read into a throwaway index and checked out from there rather
+/// than through `git archive`, which would drop `export-ignore` paths such as
+/// tests/ or .github/ and make them look missing.
+pub fn export_tree(dir: &Path, rev: &str, prefix: &str, dest: &Path) -> Result<()> {
+    // Resolve first so a rev such as `--output=...` is never parsed as an option
+    let Some(tree) = git_output(
+        dir,
+        &["rev-parse", "--verify", "--quiet", "--end-of-options", &format!("{}^{{tree}}", rev)],
+    ) else {
+        bail!("Unknown revision {} in {}", rev, dir.display());
+    };
+
+    let index = TempDir::new()?;
+    let run = |args: &[&str]| -> Result<()> {
+        let output = Command::new("git")
+            .arg("--work-tree")
+            .arg(dest)
+            .args(args)
+            .env("GIT_INDEX_FILE", index.path().join("index"))
+            .current_dir(dir)
+            .stdin(Stdio::null())
+            .output()
+            .context("Failed to run git")?;
+        if !output.status.success() {
+            bail!(
+                "Failed to export {} from {}: {}",
+                rev,
+                dir.display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(())
+    };
+    run(&["read-tree", &tree])?;
+    run(&["checkout-index", "--all", &format!("--prefix={}/", prefix)])
+}
+
+/// A commit message from [`recent_commits`].
+#[derive(Debug, Clone)]
+pub struct CommitMessage {
+    pub hash: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// The default branch: what `origin/HEAD` points to, else a local `main` or `master`.
+pub fn default_branch(dir: &Path) -> Option<String> {
+    if let Some(remote_head) =
+        git_output(dir, &["symbolic-ref", "--quiet", "--short", "refs/remotes/origin/HEAD"])
+    {
+        return Some(remote_head);
+    }
+    ["main", "master"].into_iter().map(str::to_string).find(|branch| {
+        git_output(dir, &["rev-parse", "--verify", "--quiet", &format!("refs/heads/{}", branch)])
+            .is_some()
+    })
+}
+
+/// The last `count` non-merge commits reachable from `rev`, newest first.
+pub fn recent_commits(dir: &Path, rev: &str, count: usize) -> Option<Vec<CommitMessage>> {
+    let log = git_output(
+        dir,
+        &["log", "--no-merges", &format!("-n{}", count), "--format=%H%x1f%s%x1f%b%x1e", rev],
+    )?;
+    Some(
+        log.split('\x1e')
+            .filter_map(|record| {
+                let mut fields = record.trim_start_matches('\n').splitn(3, '\x1f');
+                Some(CommitMessage {
+                    hash: fields.next().filter(|h| !h.is_empty())?.to_string(),
+                    subject: fields.next()?.to_string(),
+                    body: fields.next().unwrap_or_default().trim().to_string(),
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Repository-relative paths with uncommitted changes, untracked files included.
+pub fn dirty_paths(dir: &Path) -> Option<Vec<String>> {
+    let status = git_output(dir, &["status", "--porcelain", "--untracked-files=all"])?;
+    Some(
+        status
+            .lines()
+            .filter_map(|line| line.get(3..))
+            .map(|path| match path.split_once(" -> ") {
+                Some((_, renamed)) => renamed.to_string(),
+                None => path.to_string(),
+            })
+            .collect(),
+    )
+}
+
+/// Untracked paths under `dir` that `.gitignore` hides, relative to it.
+/// Directories ignored as a whole are listed once, with a trailing `/`.
+pub fn ignored_paths(dir: &Path) -> Option<Vec<String>> {
+    let listing = git_output(
+        dir,
+        &[
+            "ls-files",
+            "--others",
+            "--ignored",
+            "--exclude-standard",
+            "--directory",
+        ],
+    )?;
+    Some(listing.lines().map(str::to_string).collect())
+}
+
+/// Paths under `dir`, relative to it, whose staged content differs from `HEAD`.
+/// Deleted files are left out.
+pub fn staged_paths(dir: &Path) -> Option<Vec<String>> {
+    let diff = git_output(
+        dir,
+        &[
+            "-c",
+            "core.quotepath=off",
+            "diff",
+            "--cached",
+            "--name-only",
+            "--relative",
+            "--diff-filter=d",
+        ],
+    )?;
+    Some(diff.lines().map(str::to_string).collect())
+}
+
+/// Paths under `dir`, relative to it, with changes that are not staged.
+pub fn unstaged_paths(dir: &Path) -> Option<Vec<String>> {
+    let diff = git_output(
+        dir,
+        &["-c", "core.quotepath=off", "diff", "--name-only", "--relative"],
+    )?;
+    Some(diff.lines().map(str::to_string).collect())
+}
+
+/// `git add` the `dir`-relative `paths`.
+pub fn stage(dir: &Path, paths: &[String]) -> Result<()> {
+    if paths.is_empty() {
+        return Ok(());
+    }
+    let output = Command::new("git")
+        .args(["add", "--"])
+        .args(paths)
+        .current_dir(dir)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git add failed in {}: {}",
+            dir.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_git_url() {
+        assert!(is_git_url("https://github.com/acme/api.git"));
+        assert!(is_git_url("git@github.com:acme/api.git"));
+        assert!(is_git_url("ssh://git@host/acme/api"));
+        assert!(!is_git_url("../services/api"));
+        assert!(!is_git_url("/srv/repos/api"));
+    }
+
+    #[test]
+    fn test_shallow_clone_local_repo() {
+        let source = TempDir::new().unwrap();
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(source.path())
+                .output()
+                .map(|o| o.status.success())
+                .unwrap_or(false)
+        };
+        if !git(&["init", "-q"]) {
+            return; // git not available
+        }
+        std::fs::write(source.path().join("README.md"), "# Test\n").unwrap();
+        git(&["add", "."]);
+        git(&["-c", "user.name=t", "-c", "user.email=t@t", "commit", "-qm", "init"]);
+
+        let url = format!("file://{}", source.path().display());
+        let clone = shallow_clone(&url).unwrap();
+        assert!(clone.path().join("README.md").is_file());
+    }
+
+    #[test]
+    fn test_shallow_clone_failure() {
+        assert!(shallow_clone("file:///nonexistent/repodoctor-missing-repo").is_err());
+    }
+}
@@ -1 +1,5 @@
+pub mod archive;
+pub mod date;
 pub mod fs;
+pub mod git;
+pub mod patterns;
@@ -0,0 +1,42 @@
+//! Shared compiled-regex cache for patterns only known at runtime (custom
+//! rules from `.repodoctor.yml`). Built-in patterns live in `Lazy` statics
+//! next to the check that uses them.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+static CACHE: Lazy<Mutex<HashMap<String, Regex>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Compile `pattern`, reusing the result of any earlier call with the same
+/// pattern. `Regex` is reference-counted, so returned clones are cheap.
+pub fn compile(pattern: &str) -> Result<Regex, regex::Error> {
+    let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(regex) = cache.get(pattern) {
+        return Ok(regex.clone());
+    }
+    let regex = Regex::new(pattern)?;
+    cache.insert(pattern.to_string(), regex.clone());
+    Ok(regex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_reuses_cached_regex() {
+        let first = compile(r"TODO\(\w+\)").unwrap();
+        let second = compile(r"TODO\(\w+\)").unwrap();
+        assert!(second.is_match("TODO(alice)"));
+        assert_eq!(first.as_str(), second.as_str());
+        assert!(CACHE.lock().unwrap().contains_key(r"TODO\(\w+\)"));
+    }
+
+    #[test]
+    fn test_compile_invalid_pattern() {
+        assert!(compile("(unclosed").is_err());
+        assert!(!CACHE.lock().unwrap().contains_key("(unclosed"));
+    }
+}